@@ -14,7 +14,7 @@ use neon::mem::Handle;
 use neon::js::{JsString, JsValue, JsFunction};
 use neon::js::class::{Class, JsClass};
 
-use fall_tree::{TextEditBuilder, Text, TextRange, TextEdit, TextEditOp, tu};
+use fall_tree::{TextEditBuilder, Text, TextRange, TextEdit, TextEditOp, EditError, tu};
 use fall_editor::EditorFileImpl;
 
 mod support;
@@ -71,14 +71,20 @@ macro_rules! declare_editor_file_class {
                             let mut file: Handle<$cls> = call.arguments.require(scope, 0)?.check::<$cls>()?;
                             let edits = call.arguments.require(scope, 1)?;
                             let edits: Vec<$crate::VsEdit> = $crate::neon_serde::from_value(scope, edits)?;
-                            file.grab(|file| {
+                            let file = file.grab(|file| {
                                 let inner: &$t = file;
-                                let edit = $crate::from_vs_edits(
+                                $crate::from_vs_edits(
                                     <$t as $crate::fall_editor::EditorFileImpl>::file(inner).text(),
                                     edits,
-                                );
-                                $crate::EditorFile::edit(file, &edit)
-                            })
+                                ).map(|edit| $crate::EditorFile::edit(file, &edit))
+                            });
+                            match file {
+                                Ok(file) => file,
+                                Err(e) => {
+                                    use $crate::neon::js::error::{JsError, Kind};
+                                    return JsError::throw(Kind::Error, &format!("invalid edit: {}", e));
+                                }
+                            }
                         }
                         _ => unreachable!()
                     };
@@ -191,12 +197,12 @@ pub struct VsEdit {
     insert: String,
 }
 
-pub fn from_vs_edits(text: Text, edits: Vec<VsEdit>) -> TextEdit {
+pub fn from_vs_edits(text: Text, edits: Vec<VsEdit>) -> Result<TextEdit, EditError> {
     let mut edit = TextEditBuilder::new(text);
     for e in edits {
         edit.replace(e.delete, e.insert)
     }
-    edit.build()
+    edit.try_build()
 }
 
 pub fn to_vs_edits(edit: TextEdit) -> Vec<VsEdit> {
@@ -3,6 +3,15 @@ use fall_tree::search::{ancestors, find_leaf_at_offset, find_covering_node};
 
 
 pub fn extend_selection(file: &File, range: TextRange) -> Option<TextRange> {
+    for injected in file.injections() {
+        let inner = match injected.from_host(range) {
+            Some(inner) => inner,
+            None => continue,
+        };
+        if let Some(result) = extend_selection(&injected.file, inner) {
+            return Some(injected.to_host(result));
+        }
+    }
     let lang = file.language();
     let is_ws = |node: Node| lang.node_type_info(node.ty()).whitespace_like;
     if range.is_empty() {
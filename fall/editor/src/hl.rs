@@ -1,4 +1,4 @@
-use fall_tree::{TextRange, NodeType, Node, tu, ERROR as ERROR_TY};
+use fall_tree::{TextRange, NodeType, Node, File, tu, ERROR as ERROR_TY};
 
 use fall_tree::visitor::{Visit, VisitorBuilder};
 
@@ -53,3 +53,15 @@ pub fn hl(node: Node, tag: HlTag, highlights: &mut Highlights) {
     highlights.push((node.range(), tag))
 }
 
+/// Highlights regions written in other languages with `highlight`,
+/// mapping the ranges back to the host file.
+pub fn injections(file: &File, highlight: &dyn Fn(&File) -> Highlights) -> Highlights {
+    let mut result = Vec::new();
+    for injected in file.injections() {
+        result.extend(
+            highlight(&injected.file).into_iter()
+                .map(|(range, tag)| (injected.to_host(range), tag))
+        );
+    }
+    result
+}
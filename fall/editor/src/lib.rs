@@ -54,7 +54,7 @@ pub trait EditorFileImpl: Sync + 'static {
     }
 
     fn diagnostics(&self) -> Vec<Diagnostic> {
-        Vec::new()
+        syntax_errors(self.file())
     }
 
     fn context_actions(&self, _range: TextRange) -> Vec<&'static str> {
@@ -72,3 +72,9 @@ pub trait EditorFileImpl: Sync + 'static {
 pub fn gen_syntax_tree(file: &File) -> String {
     dump_file(&file)
 }
+
+pub fn syntax_errors(file: &File) -> Vec<Diagnostic> {
+    file.errors().into_iter()
+        .map(|(range, message)| Diagnostic { range, severity: Severity::Error, message })
+        .collect()
+}
@@ -18,10 +18,14 @@ fn main() -> Result<()> {
     let matches = App::new("Fall parser generator")
         .arg(Arg::with_name("grammar").index(1).required(true))
         .arg(Arg::with_name("tests").long("tests"))
+        .arg(Arg::with_name("native").long("native").takes_value(true).value_name("OUTPUT")
+            .help("Compiles the rules to Rust code into OUTPUT, as if the first one were #[native]"))
         .get_matches();
     let grammar = PathBuf::from(matches.value_of("grammar").unwrap());
     let command = if matches.is_present("tests") {
         Task::Examples(grammar)
+    } else if let Some(output) = matches.value_of("native") {
+        Task::GenerateNative(grammar, PathBuf::from(output))
     } else {
         Task::Generate(grammar)
     };
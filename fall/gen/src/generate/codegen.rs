@@ -4,11 +4,14 @@ use tera::Context;
 use fall_tree::{Text, AstNode};
 use lang_fall::syntax::{FallFile, SynRule, LexRule, Expr, BlockExpr,
                         MethodDef, Parameter};
-use lang_fall::{RefKind, CallKind, MethodKind, Analysis, PratVariant, PrattOp, Arity, ChildKind};
+use lang_fall::{RefKind, CallKind, MethodKind, Analysis, PratVariant, PrattOp, Assoc, Arity, ChildKind};
 
 use fall_parse as dst;
 
 use crate::util::{scream, camel};
+use super::left_rec::left_recursive_rules;
+use super::native::{gen_rules, gen_native};
+use super::lexer::{self, gen_lexer};
 
 
 pub type Result<T> = std::result::Result<T, ::failure::Error>;
@@ -18,15 +21,18 @@ pub(super) struct Codegen<'a, 'f: 'a> {
     node_types: Vec<(Text<'f>, bool)>,
 
     expressions: Vec<dst::Expr>,
+    predicates: Vec<Text<'f>>,
+    /// Compile the rules to Rust code even without `#[native]`.
+    native: bool,
 }
 
 impl<'a, 'f> Codegen<'a, 'f> {
-    pub fn new(analysis: &'a Analysis<'f>) -> Codegen<'a, 'f> {
+    pub fn new(analysis: &'a Analysis<'f>, native: bool) -> Codegen<'a, 'f> {
         let node_types = {
             let mut result = Vec::new();
             if let Some(tokenizer) = analysis.ast().tokenizer_def() {
                 result.extend(
-                    tokenizer.lex_rules()
+                    tokenizer.all_lex_rules()
                         .map(|r| (r.node_type(), r.is_skip()))
                 )
             }
@@ -44,6 +50,8 @@ impl<'a, 'f> Codegen<'a, 'f> {
             analysis,
             node_types,
             expressions: Vec::new(),
+            predicates: Vec::new(),
+            native,
         }
     }
 
@@ -58,23 +66,96 @@ impl<'a, 'f> Codegen<'a, 'f> {
             let expr = self.gen_rule(r)?;
             self.expressions[i] = expr;
         }
-        let parser = serde_json::to_string(&self.expressions).unwrap();
+        let n_rules = self.file().syn_rules().count();
+        for i in left_recursive_rules(&self.expressions, n_rules) {
+            let rule = self.file().syn_rules().nth(i).unwrap();
+            if rule.parameters().is_some() {
+                bail!("left-recursive rules can't have parameters");
+            }
+            let body = ::std::mem::replace(&mut self.expressions[i], dst::Expr::Any);
+            let body = self.push_expr(body);
+            self.expressions[i] = dst::Expr::LeftRec(body);
+        }
+        let is_native = self.native || self.file().syn_rules().next().map_or(false, |r| r.is_native());
+        let (parser, native_rules, native) = if is_native {
+            (None, Some(gen_rules(&self.expressions)), Some(gen_native(&self.expressions, n_rules)))
+        } else {
+            (Some(serde_json::to_string(&self.expressions).unwrap()), None, None)
+        };
         context.insert("parser_json", &parser);
-
-        let lex_rules = self.file().tokenizer_def()
-            .ok_or(format_err!("no tokens defined"))?
-            .lex_rules()
-            .filter(|r| !r.is_contextual())
+        context.insert("native_rules", &native_rules);
+        context.insert("native", &native);
+        context.insert("predicates", &self.predicates.iter().map(|p| p.to_string()).collect::<Vec<_>>());
+
+        let tokenizer = self.file().tokenizer_def()
+            .ok_or(format_err!("no tokens defined"))?;
+        let modes: Vec<Text<'f>> = tokenizer.modes().map(|m| m.name()).collect();
+        let mode_idx = |name: Text<'f>| -> Result<u16> {
+            if name == "default" {
+                return Ok(0);
+            }
+            let idx = modes.iter().position(|&m| m == name)
+                .ok_or(format_err!("Unknown mode {}", name))?;
+            Ok(idx as u16 + 1)
+        };
+        let lex_rules = tokenizer.all_lex_rules()
+            .filter(|r| !r.is_contextual() && r.layout().is_none())
             .map(|r| {
                 let re = r.token_re().ok_or(format_err!("Bad token"))?;
-                Ok(CtxLexRule { ty: r.node_type(), re: format!("{:?}", re), f: r.extern_fn() })
+                let transition = match r.push_mode() {
+                    Some(mode) => dst::ModeTransition::Push(mode_idx(mode)?),
+                    None if r.is_pop() => dst::ModeTransition::Pop,
+                    None => dst::ModeTransition::Stay,
+                };
+                Ok(lexer::LexRule {
+                    ty: r.node_type().to_string().to_uppercase(),
+                    re,
+                    f: r.extern_fn().map(|f| f.to_string()),
+                    mode: match r.mode() {
+                        Some(mode) => mode_idx(mode.name())?,
+                        None => 0,
+                    },
+                    transition,
+                })
             }).collect::<Result<Vec<_>>>()?;
 
-        context.insert("lex_rules", &lex_rules);
+        let layout = {
+            let token = |kind: &str| tokenizer.all_lex_rules()
+                .find(|r| r.layout().map_or(false, |l| l == kind))
+                .map(|r| r.node_type().to_string().to_uppercase());
+            match (token("indent"), token("dedent"), token("newline")) {
+                (Some(indent), Some(dedent), Some(newline)) => Some(lexer::Layout {
+                    indent,
+                    dedent,
+                    newline,
+                    skip: tokenizer.all_lex_rules()
+                        .filter(|r| r.is_skip())
+                        .map(|r| r.node_type().to_string().to_uppercase())
+                        .collect(),
+                }),
+                (None, None, None) => None,
+                _ => bail!("incomplete layout tokens"),
+            }
+        };
+
+        context.insert("lexer", &gen_lexer(&lex_rules, modes.len() + 1, layout.as_ref())?);
 
         let verbatim = self.file().verbatim_def().map(|v| v.contents());
         context.insert("verbatim", &verbatim);
         context.insert("has_whitespace_binder", &verbatim.map(|t| t.contains("whitespace_binder")).unwrap_or(false));
+        let missing_tokens = self.file().syn_rules().next().map_or(false, |r| r.is_missing_tokens());
+        context.insert("missing_tokens", &missing_tokens);
+        let injections = self.file().syn_rules()
+            .filter_map(|r| r.inject_attr().map(|attr| (r, attr)))
+            .map(|(r, attr)| {
+                let ty = r.ty_name().ok_or(format_err!("only public rules can be injected"))?;
+                let mut values = attr.values().map(|v| v.node().text().to_string());
+                match (values.next(), values.next(), values.next()) {
+                    (Some(lang), Some(range), None) => Ok((scream(ty), lang, range)),
+                    _ => bail!("#[inject] requires a language and a range function"),
+                }
+            }).collect::<Result<Vec<_>>>()?;
+        context.insert("injections", &injections);
 
 
         if let Some(ast) = self.file().ast_def() {
@@ -148,6 +229,17 @@ impl<'a, 'f> Codegen<'a, 'f> {
         dst::Arg(idx as u32)
     }
 
+    fn predicate_ref(&mut self, name: Text<'f>) -> dst::PredicateRef {
+        let idx = match self.predicates.iter().position(|&p| p == name) {
+            Some(idx) => idx,
+            None => {
+                self.predicates.push(name);
+                self.predicates.len() - 1
+            }
+        };
+        dst::PredicateRef(idx as u32)
+    }
+
     fn gen_rule(&mut self, rule: SynRule<'f>) -> Result<dst::Expr> {
         let body = match (rule.is_pratt(), rule.body()) {
             (true, Expr::BlockExpr(block)) => {
@@ -190,6 +282,18 @@ impl<'a, 'f> Codegen<'a, 'f> {
         Ok(body)
     }
 
+    fn gen_seq(&mut self, parts: Vec<Expr<'f>>) -> Result<dst::Expr> {
+        fn is_commit(part: Expr) -> bool {
+            part.node().text() == "<commit>"
+        }
+        let commit = parts.iter().position(|&p| is_commit(p));
+        let parts = parts.into_iter()
+            .filter(|&p| !is_commit(p))
+            .map(|e| self.gen_expr(e))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(dst::Expr::And(parts, commit))
+    }
+
     fn push_expr(&mut self, expr: dst::Expr) -> dst::ExprRef {
         let idx = self.expressions.len();
         self.expressions.push(expr);
@@ -201,17 +305,7 @@ impl<'a, 'f> Codegen<'a, 'f> {
             Expr::BlockExpr(block) =>
                 dst::Expr::Or(block.alts().map(|e| self.gen_expr(e)).collect::<Result<Vec<_>>>()?),
 
-            Expr::SeqExpr(seq) => {
-                fn is_commit(part: Expr) -> bool {
-                    part.node().text() == "<commit>"
-                }
-                let commit = seq.parts().position(is_commit);
-                let parts = seq.parts()
-                    .filter(|&p| !is_commit(p))
-                    .map(|e| self.gen_expr(e))
-                    .collect::<Result<Vec<_>>>()?;
-                dst::Expr::And(parts, commit)
-            }
+            Expr::SeqExpr(seq) => self.gen_seq(seq.parts().collect())?,
 
             Expr::RefExpr(ref_) => {
                 let ref_ = self.analysis.resolve_reference(ref_)
@@ -276,6 +370,7 @@ impl<'a, 'f> Codegen<'a, 'f> {
                     CallKind::PrevIs(tokens) => dst::Expr::PrevIs(
                         tokens.iter().map(|&r| self.syn_rule_ty(r).unwrap()).collect()
                     ),
+                    CallKind::Pred(name) => dst::Expr::Pred(self.predicate_ref(name)),
                     CallKind::Commit => panic!("Should be handled specially"),
                 }
             }
@@ -323,6 +418,7 @@ impl<'a, 'f> Codegen<'a, 'f> {
                         op: self.gen_expr(op)?,
                         priority,
                         has_rhs: false,
+                        assoc: dst::Assoc::Left,
                     });
                 }
                 PratVariant::Prefix(PrattOp { op, priority }) => {
@@ -332,14 +428,34 @@ impl<'a, 'f> Codegen<'a, 'f> {
                         priority,
                     })
                 }
-                PratVariant::Bin(PrattOp { op, priority }) => {
+                PratVariant::Bin(PrattOp { op, priority }, assoc) => {
                     result.infixes.push(dst::Infix {
                         ty,
                         op: self.gen_expr(op)?,
                         priority,
                         has_rhs: true,
+                        assoc: gen_assoc(assoc),
                     });
                 }
+                PratVariant::Mixfix(mixfix) => {
+                    let op = self.gen_seq(mixfix.op_parts())?;
+                    let op = self.push_expr(op);
+                    if mixfix.lhs {
+                        result.infixes.push(dst::Infix {
+                            ty,
+                            op,
+                            priority: mixfix.priority,
+                            has_rhs: mixfix.rhs,
+                            assoc: gen_assoc(mixfix.assoc),
+                        });
+                    } else {
+                        result.prefixes.push(dst::Prefix {
+                            ty,
+                            op,
+                            priority: mixfix.priority,
+                        })
+                    }
+                }
             };
         }
 
@@ -407,12 +523,12 @@ impl<'a, 'f> Codegen<'a, 'f> {
     }
 }
 
-
-#[derive(Serialize)]
-struct CtxLexRule<'f> {
-    ty: Text<'f>,
-    re: String,
-    f: Option<Text<'f>>,
+fn gen_assoc(assoc: Assoc) -> dst::Assoc {
+    match assoc {
+        Assoc::Left => dst::Assoc::Left,
+        Assoc::Right => dst::Assoc::Right,
+        Assoc::NonAssoc => dst::Assoc::NonAssoc,
+    }
 }
 
 #[derive(Serialize)]
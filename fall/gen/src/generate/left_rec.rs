@@ -0,0 +1,162 @@
+use fall_parse as dst;
+
+/// Something an expression can parse first, before consuming any tokens.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Leftmost {
+    Rule(usize),
+    Var(u32),
+}
+
+/// Rules which can call themselves before consuming any tokens,
+/// directly or through other rules. The first `n_rules` expressions
+/// are the rules.
+pub(super) fn left_recursive_rules(exprs: &[dst::Expr], n_rules: usize) -> Vec<usize> {
+    let nullable = nullable(exprs);
+
+    // An argument of a call is parsed first only if the callee
+    // starts with the corresponding parameter, so iterate to a fixpoint.
+    let mut leftmost: Vec<Vec<Leftmost>> = vec![Vec::new(); n_rules];
+    loop {
+        let next: Vec<Vec<Leftmost>> = (0..n_rules)
+            .map(|rule| {
+                let mut acc = Vec::new();
+                let cx = Cx { exprs, n_rules, nullable: &nullable, leftmost: &leftmost };
+                cx.visit_expr(&exprs[rule], &mut acc);
+                acc
+            })
+            .collect();
+        if next == leftmost {
+            break;
+        }
+        leftmost = next;
+    }
+
+    (0..n_rules)
+        .filter(|&rule| reachable(&leftmost, rule).contains(&Leftmost::Rule(rule)))
+        .collect()
+}
+
+/// Everything `rule` can parse first, transitively.
+fn reachable(leftmost: &[Vec<Leftmost>], rule: usize) -> Vec<Leftmost> {
+    let mut result = Vec::new();
+    let mut stack = leftmost[rule].clone();
+    while let Some(item) = stack.pop() {
+        if result.contains(&item) {
+            continue;
+        }
+        result.push(item);
+        if let Leftmost::Rule(callee) = item {
+            stack.extend(leftmost[callee].iter().cloned());
+        }
+    }
+    result
+}
+
+/// Whether each expression can succeed without consuming tokens.
+fn nullable(exprs: &[dst::Expr]) -> Vec<bool> {
+    let mut result = vec![false; exprs.len()];
+    loop {
+        let mut changed = false;
+        for (i, expr) in exprs.iter().enumerate() {
+            if !result[i] && is_nullable(expr, &result) {
+                result[i] = true;
+                changed = true;
+            }
+        }
+        if !changed {
+            return result;
+        }
+    }
+}
+
+/// Whether `expr` can succeed without consuming tokens,
+/// given what is known about its subexpressions.
+fn is_nullable(expr: &dst::Expr, nullable: &[bool]) -> bool {
+    let n = |e: dst::ExprRef| nullable[e.0 as usize];
+    match *expr {
+        dst::Expr::Token(_) | dst::Expr::ContextualToken(..) | dst::Expr::Any | dst::Expr::Var(_) => false,
+        dst::Expr::Eof | dst::Expr::IsIn(_) | dst::Expr::PrevIs(_) | dst::Expr::Pred(_) |
+        dst::Expr::Opt(_) | dst::Expr::Rep(_) | dst::Expr::Not(_) => true,
+        dst::Expr::Pub { body, .. } | dst::Expr::PubReplace { body, .. } |
+        dst::Expr::Enter(_, body) | dst::Expr::Exit(_, body) | dst::Expr::Call(body, _) |
+        dst::Expr::Cached(body) | dst::Expr::LeftRec(body) => n(body),
+        dst::Expr::Or(ref options) => options.iter().any(|&e| n(e)),
+        dst::Expr::And(ref parts, _) => parts.iter().all(|&e| n(e)),
+        dst::Expr::Layer(cover, _) => n(cover),
+        dst::Expr::WithSkip(_, body) => n(body),
+        dst::Expr::Inject(prefix, body) => n(prefix) && n(body),
+        dst::Expr::Pratt(ref table) => table.atoms.iter().any(|&e| n(e)),
+    }
+}
+
+struct Cx<'a> {
+    exprs: &'a [dst::Expr],
+    n_rules: usize,
+    nullable: &'a [bool],
+    leftmost: &'a [Vec<Leftmost>],
+}
+
+impl<'a> Cx<'a> {
+    fn visit(&self, e: dst::ExprRef, acc: &mut Vec<Leftmost>) {
+        let idx = e.0 as usize;
+        if idx < self.n_rules {
+            push(acc, Leftmost::Rule(idx));
+        } else {
+            self.visit_expr(&self.exprs[idx], acc)
+        }
+    }
+
+    fn visit_expr(&self, expr: &dst::Expr, acc: &mut Vec<Leftmost>) {
+        match *expr {
+            dst::Expr::Token(_) | dst::Expr::ContextualToken(..) | dst::Expr::Any |
+            dst::Expr::Eof | dst::Expr::IsIn(_) | dst::Expr::PrevIs(_) | dst::Expr::Pred(_) => (),
+            dst::Expr::Var(arg) => push(acc, Leftmost::Var(arg.0)),
+            dst::Expr::Opt(body) | dst::Expr::Rep(body) | dst::Expr::Not(body) |
+            dst::Expr::Pub { body, .. } | dst::Expr::PubReplace { body, .. } |
+            dst::Expr::Enter(_, body) | dst::Expr::Exit(_, body) |
+            dst::Expr::Cached(body) | dst::Expr::LeftRec(body) => self.visit(body, acc),
+            dst::Expr::Call(body, ref args) => {
+                self.visit(body, acc);
+                let callee = reachable(self.leftmost, body.0 as usize);
+                for &(arg, e) in args.iter() {
+                    if callee.contains(&Leftmost::Var(arg.0)) {
+                        self.visit(e, acc);
+                    }
+                }
+            }
+            dst::Expr::Or(ref options) => for &e in options.iter() {
+                self.visit(e, acc);
+            },
+            dst::Expr::And(ref parts, _) => for &e in parts.iter() {
+                self.visit(e, acc);
+                if !self.nullable[e.0 as usize] {
+                    break;
+                }
+            },
+            dst::Expr::Layer(first, second) | dst::Expr::WithSkip(first, second) => {
+                self.visit(first, acc);
+                self.visit(second, acc);
+            }
+            dst::Expr::Inject(prefix, body) => {
+                self.visit(prefix, acc);
+                if self.nullable[prefix.0 as usize] {
+                    self.visit(body, acc);
+                }
+            }
+            dst::Expr::Pratt(ref table) => {
+                for &e in table.atoms.iter() {
+                    self.visit(e, acc);
+                }
+                for prefix in table.prefixes.iter() {
+                    self.visit(prefix.op, acc);
+                }
+            }
+        }
+    }
+}
+
+fn push(acc: &mut Vec<Leftmost>, item: Leftmost) {
+    if !acc.contains(&item) {
+        acc.push(item);
+    }
+}
@@ -0,0 +1,502 @@
+use std::collections::HashMap;
+use std::fmt::Write;
+
+use regex::Regex;
+use regex_syntax::hir::{Class, Hir, HirKind};
+
+use fall_parse as dst;
+
+use super::Result;
+
+pub(super) struct LexRule {
+    pub ty: String,
+    pub re: String,
+    pub f: Option<String>,
+    pub mode: u16,
+    pub transition: dst::ModeTransition,
+}
+
+/// Node types of the tokens synthesized by `rt::Layout`.
+pub(super) struct Layout {
+    pub indent: String,
+    pub dedent: String,
+    pub newline: String,
+    pub skip: Vec<String>,
+}
+
+/// Compiles the tokenizer to the static tables of `rt::DfaLexer`.
+///
+/// Modes are numbered from `0`, the default one.
+pub(super) fn gen_lexer(rules: &[LexRule], n_modes: usize, layout: Option<&Layout>) -> Result<String> {
+    let hirs = rules.iter()
+        .map(|r| {
+            regex_syntax::Parser::new().parse(&r.re)
+                .map_err(|e| format_err!("invalid token regex {:?}:\n{}", r.re, e))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let keywords = find_keywords(rules, &hirs)?;
+
+    let mut nfa = Nfa { states: Vec::new() };
+    let mut main = vec![Vec::new(); n_modes];
+    let mut externs = Vec::new();
+    for (i, (rule, hir)) in rules.iter().zip(hirs.iter()).enumerate() {
+        if keywords.iter().any(|&(_, kw)| kw == i) {
+            continue;
+        }
+        let accept = nfa.add(NfaState::Match(i));
+        let start = nfa.compile(hir, accept)?;
+        if rule.f.is_some() {
+            externs.push((i, start));
+        } else {
+            main[rule.mode as usize].push(start);
+        }
+    }
+
+    let mut starts: Vec<usize> = main.into_iter()
+        .map(|alts| nfa.add(NfaState::Split(alts)))
+        .collect();
+    starts.extend(externs.iter().map(|&(_, s)| s));
+    let dfa = Dfa::build(&nfa, &starts)?.minimize();
+
+    let (seed, table) = keyword_table(rules, &keywords);
+
+    let mut buf = String::new();
+    buf.push_str("static RULES: &[rt::DfaRule] = &[\n");
+    for (i, rule) in rules.iter().enumerate() {
+        let f = match rule.f {
+            Some(ref f) => {
+                let start = externs.iter().position(|&(r, _)| r == i).unwrap() + n_modes;
+                format!("Some(({}, {}))", dfa.starts[start], f)
+            }
+            None => "None".to_owned(),
+        };
+        let transition = match rule.transition {
+            dst::ModeTransition::Stay => "Stay".to_owned(),
+            dst::ModeTransition::Push(mode) => format!("Push({})", mode),
+            dst::ModeTransition::Pop => "Pop".to_owned(),
+        };
+        writeln!(
+            buf, "    rt::DfaRule {{ ty: {}, mode: {}, transition: rt::ModeTransition::{}, f: {} }},",
+            rule.ty, rule.mode, transition, f,
+        ).unwrap();
+    }
+    buf.push_str("];\n");
+
+    if dfa.n_classes > 256 {
+        bail!("too many character classes in tokens");
+    }
+    let ascii: Vec<String> = (0..0x80u32).map(|c| class_of(&dfa.char_classes, c).to_string()).collect();
+    buf.push_str("static ASCII_CLASSES: [u8; 128] = [\n");
+    for chunk in ascii.chunks(32) {
+        writeln!(buf, "    {},", chunk.join(", ")).unwrap();
+    }
+    buf.push_str("];\n");
+    let unicode: Vec<String> = dfa.char_classes.iter()
+        .filter(|&&(c, _)| c >= 0x80)
+        .map(|&(c, class)| format!("(0x{:X}, {})", c, class))
+        .collect();
+    buf.push_str("static UNICODE_CLASSES: &[(u32, u8)] = &[\n");
+    for chunk in unicode.chunks(8) {
+        writeln!(buf, "    {},", chunk.join(", ")).unwrap();
+    }
+    buf.push_str("];\n");
+
+    buf.push_str("static TRANSITIONS: &[u16] = &[\n");
+    for row in dfa.transitions.chunks(dfa.n_classes) {
+        let row: Vec<String> = row.iter().map(|s| s.to_string()).collect();
+        writeln!(buf, "    {},", row.join(", ")).unwrap();
+    }
+    buf.push_str("];\n");
+
+    let accepts: Vec<String> = dfa.accepts.iter()
+        .map(|a| a.map_or(0, |r| r + 1).to_string())
+        .collect();
+    let starts: Vec<String> = dfa.starts[..n_modes].iter().map(|s| s.to_string()).collect();
+    let starts = starts.join(", ");
+
+    writeln!(buf, "static ACCEPTS: &[u16] = &[{}];", accepts.join(", ")).unwrap();
+
+    buf.push_str("static KEYWORDS: &[(&str, u16)] = &[\n");
+    for slot in table.iter() {
+        match *slot {
+            Some((ref text, kw)) => writeln!(buf, "    ({:?}, {}),", text, kw).unwrap(),
+            None => buf.push_str("    (\"\", 0),\n"),
+        }
+    }
+    buf.push_str("];\n");
+
+    let layout = match layout {
+        Some(layout) => format!(
+            "Some(rt::Layout {{\n        \
+                indent: {},\n        \
+                dedent: {},\n        \
+                newline: {},\n        \
+                skip: &[{}],\n    \
+            }})",
+            layout.indent, layout.dedent, layout.newline, layout.skip.join(", "),
+        ),
+        None => "None".to_owned(),
+    };
+    write!(
+        buf,
+        "rt::DfaLexer {{\n    \
+            rules: RULES,\n    \
+            ascii_classes: &ASCII_CLASSES,\n    \
+            unicode_classes: UNICODE_CLASSES,\n    \
+            n_classes: {},\n    \
+            transitions: TRANSITIONS,\n    \
+            accepts: ACCEPTS,\n    \
+            starts: &[{}],\n    \
+            keyword_seed: {},\n    \
+            keywords: KEYWORDS,\n    \
+            layout: {},\n\
+        }}",
+        dfa.n_classes, starts, seed, layout,
+    ).unwrap();
+
+    Ok(buf.lines()
+        .map(|line| if line.is_empty() { String::new() } else { format!("        {}", line) })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_start()
+        .to_owned())
+}
+
+fn class_of(char_classes: &[(u32, usize)], c: u32) -> usize {
+    let i = char_classes.iter().rposition(|&(first, _)| first <= c).unwrap();
+    char_classes[i].1
+}
+
+/// Literal tokens which are also matched by a regex of the same mode,
+/// as `(text, rule)`.
+///
+/// Such a token wins only if the regex matches exactly its text, so
+/// it is enough to look up the text of each token in a table of them.
+fn find_keywords(rules: &[LexRule], hirs: &[Hir]) -> Result<Vec<(String, usize)>> {
+    let hosts = rules.iter().zip(hirs.iter())
+        .filter(|&(rule, hir)| rule.f.is_none() && !is_literal(hir))
+        .map(|(rule, _)| Ok((rule.mode, Regex::new(&format!("^(?:{})$", rule.re))?)))
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut result = Vec::new();
+    for (i, hir) in hirs.iter().enumerate() {
+        if rules[i].f.is_some() {
+            continue;
+        }
+        let text = match *hir.kind() {
+            HirKind::Literal(ref lit) => match ::std::str::from_utf8(&lit.0) {
+                Ok(text) => text,
+                Err(_) => continue,
+            },
+            _ => continue,
+        };
+        if hosts.iter().any(|&(mode, ref re)| mode == rules[i].mode && re.is_match(text)) {
+            result.push((text.to_owned(), i));
+        }
+    }
+    Ok(result)
+}
+
+fn is_literal(hir: &Hir) -> bool {
+    matches!(*hir.kind(), HirKind::Literal(_))
+}
+
+/// Searches for a seed of `keyword_hash` without collisions.
+fn keyword_table(rules: &[LexRule], keywords: &[(String, usize)]) -> (u32, Vec<Option<(String, usize)>>) {
+    if keywords.is_empty() {
+        return (0, Vec::new());
+    }
+    let size = (keywords.len() * 4).next_power_of_two();
+    for seed in 0.. {
+        let mut table = vec![None; size];
+        let collision_free = keywords.iter().all(|kw| {
+            let hash = dst::keyword_hash(seed, rules[kw.1].mode, kw.0.as_bytes());
+            let slot = &mut table[hash as usize % size];
+            if slot.is_some() {
+                return false;
+            }
+            *slot = Some(kw.clone());
+            true
+        });
+        if collision_free {
+            return (seed, table);
+        }
+    }
+    unreachable!()
+}
+
+enum NfaState {
+    /// Consumes a character from one of the sorted ranges.
+    Class(Vec<(u32, u32)>, usize),
+    Split(Vec<usize>),
+    Match(usize),
+}
+
+struct Nfa {
+    states: Vec<NfaState>,
+}
+
+impl Nfa {
+    fn add(&mut self, state: NfaState) -> usize {
+        self.states.push(state);
+        self.states.len() - 1
+    }
+
+    /// Adds states matching `hir` followed by `next`, returns the first one.
+    ///
+    /// The DFA always takes the longest match, so unlike with `regex`,
+    /// alternatives are not tried in order: `a|ab` matches all of `ab`.
+    fn compile(&mut self, hir: &Hir, next: usize) -> Result<usize> {
+        let result = match *hir.kind() {
+            HirKind::Empty => next,
+            HirKind::Literal(ref lit) => {
+                let text = ::std::str::from_utf8(&lit.0)
+                    .map_err(|_| format_err!("tokens must be valid UTF-8"))?;
+                text.chars().rev().fold(next, |next, c| {
+                    self.add(NfaState::Class(vec![(c as u32, c as u32)], next))
+                })
+            }
+            HirKind::Class(Class::Unicode(ref class)) => {
+                let ranges = class.iter().map(|r| (r.start() as u32, r.end() as u32)).collect();
+                self.add(NfaState::Class(ranges, next))
+            }
+            HirKind::Class(Class::Bytes(_)) => bail!("byte classes are not supported in tokens"),
+            HirKind::Look(_) => bail!("anchors and word boundaries are not supported in tokens"),
+            HirKind::Repetition(ref rep) => {
+                if !rep.greedy {
+                    bail!("lazy quantifiers are not supported in tokens");
+                }
+                let mut start = match rep.max {
+                    None => {
+                        let split = self.add(NfaState::Split(Vec::new()));
+                        let body = self.compile(&rep.sub, split)?;
+                        self.states[split] = NfaState::Split(vec![body, next]);
+                        split
+                    }
+                    Some(max) => {
+                        let mut start = next;
+                        for _ in rep.min..max {
+                            let body = self.compile(&rep.sub, start)?;
+                            start = self.add(NfaState::Split(vec![body, next]));
+                        }
+                        start
+                    }
+                };
+                for _ in 0..rep.min {
+                    start = self.compile(&rep.sub, start)?;
+                }
+                start
+            }
+            HirKind::Capture(ref cap) => self.compile(&cap.sub, next)?,
+            HirKind::Concat(ref hirs) => {
+                let mut start = next;
+                for hir in hirs.iter().rev() {
+                    start = self.compile(hir, start)?;
+                }
+                start
+            }
+            HirKind::Alternation(ref hirs) => {
+                let alts = hirs.iter()
+                    .map(|hir| self.compile(hir, next))
+                    .collect::<Result<Vec<_>>>()?;
+                self.add(NfaState::Split(alts))
+            }
+        };
+        Ok(result)
+    }
+
+    /// Class and match states reachable from `states` without consuming input.
+    fn closure(&self, states: &[usize]) -> Vec<usize> {
+        let mut visited = vec![false; self.states.len()];
+        let mut stack = states.to_vec();
+        let mut result = Vec::new();
+        while let Some(s) = stack.pop() {
+            if visited[s] {
+                continue;
+            }
+            visited[s] = true;
+            match self.states[s] {
+                NfaState::Split(ref next) => stack.extend(next.iter().cloned()),
+                _ => result.push(s),
+            }
+        }
+        result.sort();
+        result
+    }
+
+    /// Splits characters into classes which no state distinguishes,
+    /// as a list of `(first char, class)` plus a representative of each class.
+    fn char_classes(&self) -> (Vec<(u32, usize)>, Vec<u32>) {
+        let classes: Vec<&[(u32, u32)]> = self.states.iter()
+            .filter_map(|s| match *s {
+                NfaState::Class(ref ranges, _) => Some(ranges.as_slice()),
+                _ => None,
+            })
+            .collect();
+        let mut boundaries = vec![0, 0x80];
+        for ranges in classes.iter() {
+            for &(lo, hi) in ranges.iter() {
+                boundaries.push(lo);
+                boundaries.push(hi + 1);
+            }
+        }
+        boundaries.sort();
+        boundaries.dedup();
+        boundaries.retain(|&b| b <= 0x10_FFFF);
+
+        let mut ids: HashMap<Vec<bool>, usize> = HashMap::new();
+        let mut representatives = Vec::new();
+        let mut result: Vec<(u32, usize)> = Vec::new();
+        for &b in boundaries.iter() {
+            let signature: Vec<bool> = classes.iter().map(|ranges| contains(ranges, b)).collect();
+            let id = *ids.entry(signature).or_insert_with(|| {
+                representatives.push(b);
+                representatives.len() - 1
+            });
+            if b == 0x80 || result.last().map(|&(_, c)| c) != Some(id) {
+                result.push((b, id));
+            }
+        }
+        (result, representatives)
+    }
+}
+
+fn contains(ranges: &[(u32, u32)], c: u32) -> bool {
+    match ranges.binary_search_by_key(&c, |&(lo, _)| lo) {
+        Ok(_) => true,
+        Err(0) => false,
+        Err(i) => c <= ranges[i - 1].1,
+    }
+}
+
+/// Limits the subset construction, in NFA states visited by the closures.
+/// The lexers of the bundled languages need less than a million.
+const MAX_DFA_WORK: usize = 100_000_000;
+
+struct Dfa {
+    /// Sorted list of `(first char, class)`.
+    char_classes: Vec<(u32, usize)>,
+    n_classes: usize,
+    transitions: Vec<u16>,
+    /// The rule with the smallest index among matched ones.
+    accepts: Vec<Option<usize>>,
+    starts: Vec<u16>,
+}
+
+impl Dfa {
+    /// Subset construction. State `0` is the dead state.
+    ///
+    /// Fails if the states do not fit into the `u16` tables,
+    /// or if it takes more than `MAX_DFA_WORK`.
+    fn build(nfa: &Nfa, starts: &[usize]) -> Result<Dfa> {
+        let (char_classes, representatives) = nfa.char_classes();
+        let n_classes = representatives.len();
+
+        let mut sets: Vec<Vec<usize>> = vec![Vec::new()];
+        let mut ids: HashMap<Vec<usize>, usize> = HashMap::new();
+        ids.insert(Vec::new(), 0);
+        let mut start_ids = Vec::new();
+        for &start in starts {
+            let set = nfa.closure(&[start]);
+            let id = *ids.entry(set.clone()).or_insert_with(|| {
+                sets.push(set);
+                sets.len() - 1
+            });
+            start_ids.push(id);
+        }
+
+        let mut transitions = Vec::new();
+        let mut work = 0;
+        let mut current = 0;
+        while current < sets.len() {
+            for &c in representatives.iter() {
+                work += nfa.states.len();
+                if work > MAX_DFA_WORK {
+                    bail!("token regexes are too complex to build a DFA");
+                }
+                let next: Vec<usize> = sets[current].iter()
+                    .filter_map(|&s| match nfa.states[s] {
+                        NfaState::Class(ref ranges, next) if contains(ranges, c) => Some(next),
+                        _ => None,
+                    })
+                    .collect();
+                let set = nfa.closure(&next);
+                let id = *ids.entry(set.clone()).or_insert_with(|| {
+                    sets.push(set);
+                    sets.len() - 1
+                });
+                if sets.len() > u16::MAX as usize {
+                    bail!("token regexes need more than {} DFA states", u16::MAX);
+                }
+                transitions.push(id as u16);
+            }
+            current += 1;
+        }
+
+        let accepts = sets.iter()
+            .map(|set| {
+                set.iter()
+                    .filter_map(|&s| match nfa.states[s] {
+                        NfaState::Match(rule) => Some(rule),
+                        _ => None,
+                    })
+                    .min()
+            })
+            .collect();
+
+        Ok(Dfa {
+            char_classes,
+            n_classes,
+            transitions,
+            accepts,
+            starts: start_ids.into_iter().map(|s| s as u16).collect(),
+        })
+    }
+
+    /// Merges equivalent states by partition refinement,
+    /// keeping the dead state at `0`.
+    fn minimize(self) -> Dfa {
+        let n_states = self.accepts.len();
+        let mut keys: HashMap<(bool, Option<usize>), usize> = HashMap::new();
+        let mut block: Vec<usize> = (0..n_states)
+            .map(|s| {
+                let n = keys.len();
+                *keys.entry((s == 0, self.accepts[s])).or_insert(n)
+            })
+            .collect();
+        let mut n_blocks = keys.len();
+        loop {
+            let mut keys: HashMap<(usize, Vec<usize>), usize> = HashMap::new();
+            let new_block: Vec<usize> = (0..n_states)
+                .map(|s| {
+                    let row = &self.transitions[s * self.n_classes..(s + 1) * self.n_classes];
+                    let key = (block[s], row.iter().map(|&t| block[t as usize]).collect());
+                    let n = keys.len();
+                    *keys.entry(key).or_insert(n)
+                })
+                .collect();
+            block = new_block;
+            if keys.len() == n_blocks {
+                break;
+            }
+            n_blocks = keys.len();
+        }
+
+        let mut transitions = vec![0u16; n_blocks * self.n_classes];
+        let mut accepts = vec![None; n_blocks];
+        for s in 0..n_states {
+            let b = block[s];
+            accepts[b] = self.accepts[s];
+            for c in 0..self.n_classes {
+                transitions[b * self.n_classes + c] = block[self.transitions[s * self.n_classes + c] as usize] as u16;
+            }
+        }
+        Dfa {
+            char_classes: self.char_classes,
+            n_classes: self.n_classes,
+            transitions,
+            accepts,
+            starts: self.starts.iter().map(|&s| block[s as usize] as u16).collect(),
+        }
+    }
+}
@@ -2,11 +2,14 @@ use lang_fall::Analysis;
 use tera::Tera;
 
 mod codegen;
+mod left_rec;
+mod lexer;
+mod native;
 
 pub type Result<T> = ::std::result::Result<T, ::failure::Error>;
 
-pub fn generate(analysis: &Analysis) -> Result<String> {
-    let mut cg = codegen::Codegen::new(analysis);
+pub fn generate(analysis: &Analysis, native: bool) -> Result<String> {
+    let mut cg = codegen::Codegen::new(analysis, native);
     let context = cg.generate()?;
     Tera::one_off(TEMPLATE.trim(), &context, false)
         .map_err(|e| format_err!("Failed to format template:\n{:?}", e))
@@ -22,40 +25,53 @@ pub const {{ node_type.0 | upper }}: rt::NodeType = rt::NodeType({{ 100 + loop.i
 
 
 pub fn language() -> &'static rt::Language {
-    fn create_lexer() -> rt::RegexLexer {
-        rt::RegexLexer::new(vec![
-            {% for rule in lex_rules %}
-            rt::LexRule::new({{ rule.ty | upper }}, {{ rule.re }}, {% if rule.f is string %}Some({{ rule.f }}){% else %}None{% endif %}),{% endfor %}
-        ])
+    fn create_lexer() -> rt::DfaLexer {
+        {{ lexer }}
     }
 
     fn create_parser_definition() -> rt::ParserDefinition {
+        {% if parser_json is string %}
         let parser_json = r##"{{ parser_json }}"##;
+        {% endif %}
 
         ::fall_parse::ParserDefinition {
             node_types: vec![
                 rt::ERROR,
                 {% for node_type in node_types %}{{ node_type.0 | upper }}, {% endfor %}
             ],
+            {% if native is string %}
+            syntactical_rules: {{ native_rules }},
+            {% else %}
             syntactical_rules: rt::parser_from_str(parser_json),
+            {% endif %}
             {% if has_whitespace_binder %}
                 whitespace_binder,
             {% endif %}
+            {% if missing_tokens %}
+                missing_tokens: true,
+            {% endif %}
+            {% if native is string %}
+                native: Some(native_parser),
+            {% endif %}
+            {% if predicates %}
+                predicates: vec![{% for p in predicates %}{{ p }} as rt::SemanticPredicate, {% endfor %}],
+            {% endif %}
             .. Default::default()
         }
     }
     use self::rt::*;
     lazy_static! {
         static ref LANG: rt::Language = {
-            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::RegexLexer };
+            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::DfaLexer };
             impl rt::LanguageImpl for Impl {
                 fn parse(
                     &self,
                     text: rt::Text,
+                    options: &rt::ParseOptions,
                     metrics: &rt::Metrics,
                     builder: &mut rt::TreeBuilder,
                 ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
-                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, metrics, builder)
+                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, options, metrics, builder)
                 }
 
                 fn reparse(
@@ -63,10 +79,11 @@ pub fn language() -> &'static rt::Language {
                     incremental_data: &dyn std::any::Any,
                     edit: &rt::TextEdit,
                     new_text: rt::Text,
+                    options: &rt::ParseOptions,
                     metrics: &rt::Metrics,
                     builder: &mut rt::TreeBuilder,
                 ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
-                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, metrics, builder)
+                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, options, metrics, builder)
                 }
 
                 fn node_type_info(&self, ty: rt::NodeType) -> rt::NodeTypeInfo {
@@ -78,6 +95,15 @@ pub fn language() -> &'static rt::Language {
                         _ => panic!("Unknown rt::NodeType: {:?}", ty)
                     }
                 }
+                {% if injections %}
+                fn injections(&self) -> Vec<rt::Injection> {
+                    vec![
+                        {% for i in injections %}
+                        rt::Injection { ty: {{ i.0 }}, lang: {{ i.1 }}, range: {{ i.2 }} },
+                        {% endfor %}
+                    ]
+                }
+                {% endif %}
             }
 
             rt::Language::new(Impl {
@@ -90,6 +116,10 @@ pub fn language() -> &'static rt::Language {
     &*LANG
 }
 
+{% if native is string %}
+{{ native }}
+{% endif %}
+
 {% if verbatim is string %}
 {{ verbatim }}
 {% endif %}
@@ -0,0 +1,183 @@
+use std::fmt::Write;
+
+use fall_parse as dst;
+
+/// The rules as a Rust expression, to avoid parsing JSON at startup.
+pub(super) fn gen_rules(exprs: &[dst::Expr]) -> String {
+    let mut buf = String::from("vec![\n");
+    for expr in exprs {
+        writeln!(buf, "                {},", expr_literal(expr)).unwrap();
+    }
+    buf.push_str("            ]");
+    buf
+}
+
+fn expr_literal(expr: &dst::Expr) -> String {
+    let e = |e: dst::ExprRef| format!("rt::ExprRef({})", e.0);
+    let ty = |ty: dst::NodeTypeRef| format!("rt::NodeTypeRef({})", ty.0);
+    let list = |items: Vec<String>| format!("vec![{}]", items.join(", "));
+    let exprs = |es: &[dst::ExprRef]| list(es.iter().map(|&x| e(x)).collect());
+
+    match *expr {
+        dst::Expr::Pub { ty: t, body, replaceable } =>
+            format!("rt::Expr::Pub {{ ty: {}, body: {}, replaceable: {} }}", ty(t), e(body), replaceable),
+        dst::Expr::PubReplace { ty: t, body } =>
+            format!("rt::Expr::PubReplace {{ ty: {}, body: {} }}", ty(t), e(body)),
+        dst::Expr::Or(ref options) => format!("rt::Expr::Or({})", exprs(options)),
+        dst::Expr::And(ref parts, commit) => format!("rt::Expr::And({}, {:?})", exprs(parts), commit),
+        dst::Expr::Token(t) => format!("rt::Expr::Token({})", ty(t)),
+        dst::Expr::ContextualToken(t, ref text) =>
+            format!("rt::Expr::ContextualToken({}, {:?}.to_owned())", ty(t), text),
+        dst::Expr::Rep(body) => format!("rt::Expr::Rep({})", e(body)),
+        dst::Expr::WithSkip(first, body) => format!("rt::Expr::WithSkip({}, {})", e(first), e(body)),
+        dst::Expr::Opt(body) => format!("rt::Expr::Opt({})", e(body)),
+        dst::Expr::Not(body) => format!("rt::Expr::Not({})", e(body)),
+        dst::Expr::Eof => "rt::Expr::Eof".to_owned(),
+        dst::Expr::Any => "rt::Expr::Any".to_owned(),
+        dst::Expr::Layer(l, body) => format!("rt::Expr::Layer({}, {})", e(l), e(body)),
+        dst::Expr::Pratt(ref table) => {
+            let prefixes = table.prefixes.iter()
+                .map(|p| format!("rt::Prefix {{ ty: {}, op: {}, priority: {} }}", ty(p.ty), e(p.op), p.priority))
+                .collect();
+            let infixes = table.infixes.iter()
+                .map(|i| format!(
+                    "rt::Infix {{ ty: {}, op: {}, priority: {}, has_rhs: {}, assoc: rt::Assoc::{:?} }}",
+                    ty(i.ty), e(i.op), i.priority, i.has_rhs, i.assoc,
+                ))
+                .collect();
+            format!(
+                "rt::Expr::Pratt(Box::new(rt::PrattTable {{ atoms: {}, prefixes: {}, infixes: {} }}))",
+                exprs(&table.atoms), list(prefixes), list(infixes),
+            )
+        }
+        dst::Expr::Enter(ctx, body) => format!("rt::Expr::Enter(rt::Context({}), {})", ctx.0, e(body)),
+        dst::Expr::Exit(ctx, body) => format!("rt::Expr::Exit(rt::Context({}), {})", ctx.0, e(body)),
+        dst::Expr::IsIn(ctx) => format!("rt::Expr::IsIn(rt::Context({}))", ctx.0),
+        dst::Expr::Call(body, ref args) => {
+            let args = args.iter().map(|&(arg, x)| format!("(rt::Arg({}), {})", arg.0, e(x))).collect();
+            format!("rt::Expr::Call({}, {})", e(body), list(args))
+        }
+        dst::Expr::Var(arg) => format!("rt::Expr::Var(rt::Arg({}))", arg.0),
+        dst::Expr::PrevIs(ref tys) => format!("rt::Expr::PrevIs({})", list(tys.iter().map(|&t| ty(t)).collect())),
+        dst::Expr::Inject(prefix, body) => format!("rt::Expr::Inject({}, {})", e(prefix), e(body)),
+        dst::Expr::Cached(body) => format!("rt::Expr::Cached({})", e(body)),
+        dst::Expr::LeftRec(body) => format!("rt::Expr::LeftRec({})", e(body)),
+        dst::Expr::Pred(pred) => format!("rt::Expr::Pred(rt::PredicateRef({}))", pred.0),
+    }
+}
+
+/// Compiles each expression to a function built of `fall_parse::native`
+/// combinators. The first `n_rules` expressions are the syntactical rules.
+///
+/// The combinators are shared with the interpreter, so the result
+/// produces exactly the same events as the interpreted parser.
+pub(super) fn gen_native(exprs: &[dst::Expr], n_rules: usize) -> String {
+    let mut buf = String::new();
+    buf.push_str("fn native_parser(p: &mut rt::native::Parser, expr: rt::ExprRef, pos: rt::native::Pos) -> Option<Option<rt::native::Pos>> {\n");
+    buf.push_str("    let result = match expr.0 {\n");
+    for i in 0..exprs.len() {
+        writeln!(buf, "        {} => {}(p, pos),", i, fn_name(n_rules, i)).unwrap();
+    }
+    buf.push_str("        _ => return None,\n");
+    buf.push_str("    };\n");
+    buf.push_str("    Some(result)\n");
+    buf.push_str("}\n");
+
+    for (i, expr) in exprs.iter().enumerate() {
+        buf.push('\n');
+        writeln!(buf, "fn {}(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {{", fn_name(n_rules, i)).unwrap();
+        writeln!(buf, "    {}", body(expr, n_rules, i)).unwrap();
+        buf.push_str("}\n");
+    }
+    buf
+}
+
+fn fn_name(n_rules: usize, idx: usize) -> String {
+    if idx < n_rules { format!("rule_{}", idx) } else { format!("expr_{}", idx) }
+}
+
+/// Code which parses expression `idx` and does the bookkeeping
+/// the interpreter does for each expression.
+fn call(n_rules: usize, idx: dst::ExprRef) -> String {
+    format!("rt::native::expr(p, {}, pos, {})", idx.0, fn_name(n_rules, idx.0 as usize))
+}
+
+fn body(expr: &dst::Expr, n_rules: usize, idx: usize) -> String {
+    let call = |e: dst::ExprRef| call(n_rules, e);
+    let closure = |e: dst::ExprRef| format!("|p, pos| {}", call(e));
+    let branches = |parts: &[dst::ExprRef]| {
+        if parts.is_empty() {
+            return "|_, _, _| unreachable!()".to_owned();
+        }
+        let mut arms = String::new();
+        for (i, &part) in parts.iter().enumerate() {
+            write!(arms, "{} => {}, ", i, call(part)).unwrap();
+        }
+        format!("|p, i, pos| match i {{ {}_ => unreachable!() }}", arms)
+    };
+    let e = |e: dst::ExprRef| format!("rt::ExprRef({})", e.0);
+    let ty = |ty: dst::NodeTypeRef| format!("rt::NodeTypeRef({})", ty.0);
+    let list = |items: Vec<String>| format!("&[{}]", items.join(", "));
+
+    match *expr {
+        dst::Expr::Pub { ty, body, replaceable } =>
+            format!("rt::native::pub_node(p, {}, {}, pos, {})", ty.0, replaceable, closure(body)),
+        dst::Expr::PubReplace { ty, body } =>
+            format!("rt::native::pub_replace(p, {}, pos, {})", ty.0, closure(body)),
+        dst::Expr::Or(ref options) =>
+            format!("rt::native::or(p, {}, pos, {})", options.len(), branches(options)),
+        dst::Expr::And(ref parts, commit) =>
+            format!("rt::native::and(p, {}, {:?}, pos, {})", list(parts.iter().map(|&x| e(x)).collect()), commit, branches(parts)),
+        dst::Expr::Token(ty) =>
+            format!("rt::native::token(p, {}, pos)", ty.0),
+        dst::Expr::ContextualToken(ty, ref text) =>
+            format!("rt::native::contextual_token(p, {}, {:?}, pos)", ty.0, text),
+        dst::Expr::Rep(body) =>
+            format!("rt::native::rep(p, pos, {})", closure(body)),
+        dst::Expr::WithSkip(first, body) =>
+            format!("rt::native::with_skip(p, {}, pos, {}, {})", first.0, closure(first), closure(body)),
+        dst::Expr::Opt(body) =>
+            format!("rt::native::opt(p, pos, {})", closure(body)),
+        dst::Expr::Not(body) =>
+            format!("rt::native::not(p, pos, {})", closure(body)),
+        dst::Expr::Eof => "rt::native::eof(p, pos)".to_owned(),
+        dst::Expr::Any => "rt::native::any(p, pos)".to_owned(),
+        dst::Expr::Layer(cover, body) =>
+            format!("rt::native::layer(p, {}, pos, {}, {})", idx, closure(cover), closure(body)),
+        dst::Expr::Pratt(ref table) => {
+            let atoms = table.atoms.iter().map(|&x| e(x)).collect();
+            let prefixes = table.prefixes.iter()
+                .map(|p| format!("rt::Prefix {{ ty: {}, op: {}, priority: {} }}", ty(p.ty), e(p.op), p.priority))
+                .collect();
+            let infixes = table.infixes.iter()
+                .map(|i| format!(
+                    "rt::Infix {{ ty: {}, op: {}, priority: {}, has_rhs: {}, assoc: rt::Assoc::{:?} }}",
+                    ty(i.ty), e(i.op), i.priority, i.has_rhs, i.assoc,
+                ))
+                .collect();
+            format!("rt::native::pratt(p, {}, {}, {}, pos)", list(atoms), list(prefixes), list(infixes))
+        }
+        dst::Expr::Enter(ctx, body) =>
+            format!("rt::native::enter(p, {}, pos, {})", ctx.0, closure(body)),
+        dst::Expr::Exit(ctx, body) =>
+            format!("rt::native::exit(p, {}, pos, {})", ctx.0, closure(body)),
+        dst::Expr::IsIn(ctx) =>
+            format!("rt::native::is_in(p, {}, pos)", ctx.0),
+        dst::Expr::Call(body, ref args) => {
+            let args = args.iter().map(|&(arg, x)| format!("(rt::Arg({}), {})", arg.0, e(x))).collect();
+            format!("rt::native::call(p, {}, {}, pos, {})", idx, list(args), closure(body))
+        }
+        dst::Expr::Var(arg) =>
+            format!("rt::native::var(p, {}, pos)", arg.0),
+        dst::Expr::PrevIs(ref tys) =>
+            format!("rt::native::prev_is(p, {}, pos)", list(tys.iter().map(|&t| ty(t)).collect())),
+        dst::Expr::Inject(prefix, body) =>
+            format!("rt::native::inject(p, pos, {}, {})", closure(prefix), closure(body)),
+        dst::Expr::Cached(body) =>
+            format!("rt::native::cached(p, {}, pos, {})", body.0, closure(body)),
+        dst::Expr::LeftRec(body) =>
+            format!("rt::native::left_rec(p, {}, pos, {})", idx, closure(body)),
+        dst::Expr::Pred(pred) =>
+            format!("rt::native::pred(p, {}, pos)", pred.0),
+    }
+}
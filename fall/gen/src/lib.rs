@@ -2,6 +2,7 @@ extern crate serde_json;
 #[macro_use]
 extern crate serde_derive;
 extern crate regex;
+extern crate regex_syntax;
 extern crate tera;
 #[macro_use]
 extern crate failure;
@@ -28,6 +29,9 @@ pub type Result<T> = ::std::result::Result<T, Error>;
 
 pub enum Task {
     Generate(PathBuf),
+    /// Generates the parser of the grammar as Rust code, as if its first
+    /// rule were `#[native]`, into the second path.
+    GenerateNative(PathBuf, PathBuf),
     Examples(PathBuf),
 }
 
@@ -46,6 +50,11 @@ pub fn process(command: Task) -> Result<()> {
             let result = lang_fall::analyse(input).analyse(generate)?;
             fs::write(grammar.with_extension("rs"), result)?;
         }
+        Task::GenerateNative(grammar, output) => {
+            let input = read_file(&grammar)?;
+            let result = lang_fall::analyse(input).analyse(generate_native)?;
+            fs::write(output, result)?;
+        }
         Task::Examples(grammar) => {
             let input = read_file(&grammar)?;
             let result = renderer.render_all(input, None)?;
@@ -57,7 +66,12 @@ pub fn process(command: Task) -> Result<()> {
 
 
 pub fn generate(analysis: &lang_fall::Analysis) -> Result<String> {
-    generate::generate(analysis)
+    generate::generate(analysis, false)
+}
+
+/// Like `generate`, but always compiles the rules to Rust code.
+pub fn generate_native(analysis: &lang_fall::Analysis) -> Result<String> {
+    generate::generate(analysis, true)
 }
 
 pub struct TestRenderer;
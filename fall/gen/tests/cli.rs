@@ -17,7 +17,15 @@ fn generator_path() -> PathBuf {
 
 fn check_by_path<T: AsRef<Path>>(grammar_path: T, should_rewrite: bool) {
     let grammar_path = grammar_path.as_ref();
-    let generated_path = &grammar_path.with_extension("rs");
+    check_generated(grammar_path, &grammar_path.with_extension("rs"), false, should_rewrite)
+}
+
+/// Checks the parser generated with `--native` from the original grammar.
+fn check_native<T: AsRef<Path>>(grammar_path: T, generated_path: T, should_rewrite: bool) {
+    check_generated(grammar_path.as_ref(), generated_path.as_ref(), true, should_rewrite)
+}
+
+fn check_generated(grammar_path: &Path, generated_path: &Path, native: bool, should_rewrite: bool) {
     let grammar_text = fs::read_to_string(grammar_path).unwrap();
 
     let expected = fs::read_to_string(generated_path).unwrap_or_default();
@@ -27,9 +35,13 @@ fn check_by_path<T: AsRef<Path>>(grammar_path: T, should_rewrite: bool) {
         let tmp_file = dir.path().join("grammar.fall");
         fs::write(&tmp_file, grammar_text).unwrap();
 
-        let output = process::Command::new(generator_path())
-            .arg(&tmp_file)
-            .output()
+        let out_file = if native { dir.path().join("native.rs") } else { tmp_file.with_extension("rs") };
+        let mut command = process::Command::new(generator_path());
+        command.arg(&tmp_file);
+        if native {
+            command.arg("--native").arg(&out_file);
+        }
+        let output = command.output()
             .expect("Failed to execute process");
 
         if !output.status.success() {
@@ -44,7 +56,7 @@ fn check_by_path<T: AsRef<Path>>(grammar_path: T, should_rewrite: bool) {
                 eprintln!("{}", err);
             }
         }
-        fs::read_to_string(tmp_file.with_extension("rs")).unwrap()
+        fs::read_to_string(out_file).unwrap()
     };
 
     let expected = trim_all_ends(expected);
@@ -85,17 +97,88 @@ fn test_grammars_are_fresh() {
         check_by_path("../test/src/sexp.fall", true);
         check_by_path("../test/src/weird.fall", true);
         check_by_path("../test/src/arith.fall", true);
+        check_by_path("../test/src/interp.fall", true);
+        check_by_path("../test/src/leftrec.fall", true);
+        check_by_path("../test/src/limits.fall", true);
+        check_by_path("../test/src/typedef.fall", true);
+        check_by_path("../test/src/inject.fall", true);
+        check_by_path("../test/src/missing.fall", true);
+        check_native("../test/src/arith.fall", "../test/src/arith_native.rs", true);
+        check_native("../test/src/weird.fall", "../test/src/weird_native.rs", true);
+        check_native("../test/src/leftrec.fall", "../test/src/leftrec_native.rs", true);
+        check_native("../test/src/limits.fall", "../test/src/limits_native.rs", true);
+        check_native("../test/src/typedef.fall", "../test/src/typedef_native.rs", true);
+        check_native("../../lang/json/src/json.fall", "../test/src/json_native.rs", true);
         check_by_path("../../lang/rust/syntax/src/rust.fall", true);
         check_by_path("../../lang/json/src/json.fall", true);
         check_by_path("../../lang/bnf/src/bnf.fall", true);
+        check_by_path("../../lang/python/src/python.fall", true);
         return;
     }
 
     check_by_path("../test/src/sexp.fall", false);
     check_by_path("../test/src/weird.fall", false);
     check_by_path("../test/src/arith.fall", false);
+    check_by_path("../test/src/interp.fall", false);
+    check_by_path("../test/src/leftrec.fall", false);
+    check_by_path("../test/src/limits.fall", false);
+    check_by_path("../test/src/typedef.fall", false);
+    check_by_path("../test/src/inject.fall", false);
+    check_by_path("../test/src/missing.fall", false);
+    check_native("../test/src/arith.fall", "../test/src/arith_native.rs", false);
+    check_native("../test/src/weird.fall", "../test/src/weird_native.rs", false);
+    check_native("../test/src/leftrec.fall", "../test/src/leftrec_native.rs", false);
+    check_native("../test/src/limits.fall", "../test/src/limits_native.rs", false);
+    check_native("../test/src/typedef.fall", "../test/src/typedef_native.rs", false);
+    check_native("../../lang/json/src/json.fall", "../test/src/json_native.rs", false);
     check_by_path("../../lang/json/src/json.fall", false);
     check_by_path("../../lang/bnf/src/bnf.fall", false);
+    check_by_path("../../lang/python/src/python.fall", false);
     check_by_path("../../lang/rust/syntax/src/rust.fall", false);
     check_by_path("../../lang/fall/syntax/src/fall.fall", false);
 }
+
+#[test]
+fn test_lazy_quantifiers_are_rejected() {
+    let dir = TempDir::new("gen-tests").unwrap();
+    let tmp_file = dir.path().join("grammar.fall");
+    fs::write(&tmp_file, r#"
+tokenizer {
+  comment r"/\*.*?\*/"
+}
+
+pub rule file { comment* }
+"#).unwrap();
+
+    let output = process::Command::new(generator_path())
+        .arg(&tmp_file)
+        .output()
+        .expect("Failed to execute process");
+
+    assert!(!output.status.success());
+    let err = std::str::from_utf8(&output.stderr).unwrap();
+    assert!(err.contains("lazy quantifiers are not supported in tokens"), "{}", err);
+}
+
+#[test]
+fn test_too_many_dfa_states_are_rejected() {
+    let dir = TempDir::new("gen-tests").unwrap();
+    let tmp_file = dir.path().join("grammar.fall");
+    fs::write(&tmp_file, format!(r#"
+tokenizer {{
+  tok r"[ab]*a{}"
+  other r"[a-z]+"
+}}
+
+pub rule file {{ tok* }}
+"#, "[ab]".repeat(17))).unwrap();
+
+    let output = process::Command::new(generator_path())
+        .arg(&tmp_file)
+        .output()
+        .expect("Failed to execute process");
+
+    assert!(!output.status.success());
+    let err = std::str::from_utf8(&output.stderr).unwrap();
+    assert!(err.contains("token regexes need more than 65535 DFA states"), "{}", err);
+}
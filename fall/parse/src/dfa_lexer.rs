@@ -0,0 +1,198 @@
+use fall_tree::{tu, NodeType, ERROR};
+
+use crate::CustomLexRule;
+use crate::lex_engine::{Token, Lexer, Layout};
+
+/// Lexer driven by static tables which `fall_gen` computes from
+/// the `tokenizer` block of a grammar.
+///
+/// All regular expressions are compiled into a single DFA over classes
+/// of characters, so a token is recognized in one pass over its text. As with regexes
+/// tried one by one, the longest match wins, and the earlier rule wins
+/// among matches of equal length.
+///
+/// Literal tokens which are also matched by some other regex (keywords,
+/// matched by identifiers) are not part of the automaton: the text
+/// of each token is looked up in a perfect hash table of them instead.
+///
+/// Each lexer mode has its own start state. Mode `0` is the default one,
+/// which is in effect when the mode stack is empty.
+pub struct DfaLexer {
+    pub rules: &'static [DfaRule],
+    /// Character class of each ASCII character.
+    pub ascii_classes: &'static [u8; 128],
+    /// Character classes of the rest of the characters,
+    /// as a sorted list of `(first char, class)` starting at `0x80`.
+    pub unicode_classes: &'static [(u32, u8)],
+    pub n_classes: usize,
+    /// Next state is `transitions[state * n_classes + class]`.
+    /// State `0` is the dead state.
+    pub transitions: &'static [u16],
+    /// Index of the rule matched in each state plus one, or zero.
+    pub accepts: &'static [u16],
+    /// Start state of each mode.
+    pub starts: &'static [u16],
+    pub keyword_seed: u32,
+    /// `(text, rule)`, indexed by `keyword_hash` of the mode and the text.
+    /// Empty slots have empty text.
+    pub keywords: &'static [(&'static str, u16)],
+    /// Tokens synthesized from indentation, for languages with the off-side rule.
+    pub layout: Option<Layout>,
+}
+
+pub struct DfaRule {
+    pub ty: NodeType,
+    /// The mode in which the rule is active.
+    pub mode: u16,
+    pub transition: ModeTransition,
+    /// The custom lexing function, along with the state its regex starts in.
+    /// The function is called only if the regex matches a prefix of the text.
+    pub f: Option<(u16, CustomLexRule)>,
+}
+
+/// What happens to the mode stack after a token is matched.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ModeTransition {
+    Stay,
+    Push(u16),
+    /// Popping the default mode leaves the lexer in it.
+    Pop,
+}
+
+/// Seeded 32-bit FNV-1a, which `fall_gen` uses to build
+/// a collision-free keyword table.
+pub fn keyword_hash(seed: u32, mode: u16, text: &[u8]) -> u32 {
+    let mut h = 0x811c_9dc5 ^ seed;
+    for &b in mode.to_le_bytes().iter().chain(text) {
+        h ^= u32::from(b);
+        h = h.wrapping_mul(0x0100_0193);
+    }
+    h
+}
+
+impl DfaLexer {
+    /// Consumes the character at byte offset `i`, returns
+    /// the new state and the offset of the next character.
+    fn step(&self, state: u16, text: &str, i: usize) -> (u16, usize) {
+        let b = text.as_bytes()[i];
+        let (class, len) = if b < 0x80 {
+            (self.ascii_classes[b as usize], 1)
+        } else {
+            let c = text[i..].chars().next().unwrap();
+            let idx = self.unicode_classes.partition_point(|&(first, _)| first <= c as u32);
+            (self.unicode_classes[idx - 1].1, c.len_utf8())
+        };
+        (self.transitions[state as usize * self.n_classes + class as usize], i + len)
+    }
+
+    /// The longest match of the automaton from `start`, as `(length, rule)`,
+    /// and the number of bytes scanned.
+    fn longest_match(&self, start: u16, text: &str) -> (Option<(usize, usize)>, usize) {
+        let mut result = None;
+        let mut state = start;
+        let mut i = 0;
+        while i < text.len() {
+            let (next, next_i) = self.step(state, text, i);
+            state = next;
+            i = next_i;
+            if state == 0 {
+                return (result, i);
+            }
+            let accept = self.accepts[state as usize];
+            if accept != 0 {
+                result = Some((i, accept as usize - 1));
+            }
+        }
+        (result, text.len() + 1)
+    }
+
+    fn matches_some_prefix(&self, start: u16, text: &str) -> (bool, usize) {
+        let mut state = start;
+        let mut i = 0;
+        while i < text.len() {
+            let (next, next_i) = self.step(state, text, i);
+            state = next;
+            i = next_i;
+            if state == 0 {
+                return (false, i);
+            }
+            if self.accepts[state as usize] != 0 {
+                return (true, i);
+            }
+        }
+        (false, text.len() + 1)
+    }
+
+    /// The keyword rule for `text`, if it takes priority over rule `idx`.
+    fn keyword(&self, mode: u16, idx: usize, text: &str) -> Option<usize> {
+        if self.keywords.is_empty() {
+            return None;
+        }
+        let h = keyword_hash(self.keyword_seed, mode, text.as_bytes());
+        let (kw, rule) = self.keywords[h as usize % self.keywords.len()];
+        if (rule as usize) < idx && kw == text {
+            Some(rule as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl Lexer for DfaLexer {
+    /// Custom lexing functions are assumed to look only at the text
+    /// of the token they return, or at all the text if they return `None`.
+    fn next_token(&self, modes: &mut Vec<u16>, text: &str) -> (Token, usize) {
+        let mode = modes.last().cloned().unwrap_or(0);
+        let (mut best, mut scanned) = self.longest_match(self.starts[mode as usize], text);
+
+        for (idx, rule) in self.rules.iter().enumerate() {
+            let (start, f) = match rule.f {
+                Some(f) if rule.mode == mode => f,
+                _ => continue,
+            };
+            let (matches, prefix_scanned) = self.matches_some_prefix(start, text);
+            scanned = scanned.max(prefix_scanned);
+            if !matches {
+                continue;
+            }
+            let result = f(text);
+            scanned = scanned.max(match result {
+                Some(len) if len < text.len() => len,
+                _ => text.len() + 1,
+            });
+            if let Some(len) = result {
+                let better = match best {
+                    None => len > 0,
+                    Some((best_len, best_idx)) => len > best_len || (len == best_len && idx < best_idx),
+                };
+                if better {
+                    best = Some((len, idx));
+                }
+            }
+        }
+
+        let token = match best {
+            Some((len, idx)) => {
+                let idx = self.keyword(mode, idx, &text[..len]).unwrap_or(idx);
+                let rule = &self.rules[idx];
+                match rule.transition {
+                    ModeTransition::Stay => (),
+                    ModeTransition::Push(mode) => modes.push(mode),
+                    ModeTransition::Pop => {
+                        modes.pop();
+                    }
+                }
+                Token { ty: rule.ty, len: tu(len as u32) }
+            }
+            None => {
+                let len = text.chars().next().map(|c| c.len_utf8()).unwrap_or(0);
+                Token { ty: ERROR, len: tu(len as u32) }
+            }
+        };
+        (token, scanned)
+    }
+
+    fn layout(&self) -> Option<&Layout> {
+        self.layout.as_ref()
+    }
+}
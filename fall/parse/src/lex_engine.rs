@@ -1,5 +1,7 @@
+use std::borrow::Cow;
 use std::cmp::Ordering;
-use fall_tree::{Text, tu, TextSuffix, TextEditOp, TextEdit, TextUnit, NodeType, ERROR};
+use std::mem;
+use fall_tree::{Text, tu, TextRange, TextEditOp, TextEdit, TextUnit, NodeType};
 
 #[derive(Debug, Copy, Clone)]
 pub struct Token {
@@ -8,44 +10,278 @@ pub struct Token {
 }
 
 pub trait Lexer {
-    fn next_token(&self, text: Text) -> Token;
+    /// Lexes a token at the start of `text`.
+    ///
+    /// `modes` is the stack of lexer modes with the current one on top,
+    /// empty in the initial mode. The lexer updates it if the token
+    /// switches modes.
+    ///
+    /// Also returns the number of bytes of `text` the lexer has looked at,
+    /// which is at least the length of the token. If the lexer has reached
+    /// the end of `text`, it counts as one more byte.
+    fn next_token(&self, modes: &mut Vec<u16>, text: &str) -> (Token, usize);
 
-    fn step(&self, text: &mut Text) -> Token {
-        let t = self.next_token(*text);
-        *text = text.slice(TextSuffix::from(t.len));
-        t
+    /// Tokens to synthesize from the indentation, if the language uses the off-side rule.
+    fn layout(&self) -> Option<&Layout> {
+        None
     }
 }
 
-pub fn lex<L: Lexer>(lexer: &L, text: Text) -> Vec<Token> {
-    let mut result = Vec::new();
-    let mut text = text;
-    while !text.is_empty() {
-        let t = lexer.step(&mut text);
-        result.push(t);
+/// Zero-width tokens which `lex_engine` inserts around lines of the text.
+///
+/// At the end of a line which has some non-skip tokens, `newline` is inserted.
+/// Before the first token of the next line, a `dedent` is inserted for each
+/// block which is indented deeper than the line, and an `indent`, if the line is
+/// indented deeper than the current block. At the end of the text, all blocks are
+/// closed. Lines are ignored while the mode stack is not empty, so, with the
+/// brackets pushing the `default` mode, expressions can span several lines.
+///
+/// Indentation is measured in characters, a tab counts as one.
+pub struct Layout {
+    pub indent: NodeType,
+    pub dedent: NodeType,
+    pub newline: NodeType,
+    /// Tokens which do not start a line, such as whitespace and comments.
+    pub skip: &'static [NodeType],
+}
+
+/// Tokens of a text, along with the lexer state needed to relex it.
+pub struct Lexed {
+    pub tokens: Vec<Token>,
+    states: StateLog,
+    /// How far the lexer has looked from the start of each token.
+    scanned: Vec<TextUnit>,
+}
+
+/// The state of the lexer between two tokens.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct LexState {
+    modes: Vec<u16>,
+    /// Columns of the enclosing indented blocks, see `Layout`.
+    indents: Vec<u32>,
+    /// The column of the current line, if it has no non-skip tokens yet.
+    line_start: Option<u32>,
+}
+
+impl LexState {
+    fn new() -> LexState {
+        LexState { modes: Vec::new(), indents: Vec::new(), line_start: Some(0) }
+    }
+}
+
+/// Lexer state at the start of each token.
+///
+/// The state rarely changes, so only the changes are stored, as
+/// `(token index, state)`.
+struct StateLog {
+    changes: Vec<(usize, LexState)>,
+}
+
+impl StateLog {
+    fn new() -> StateLog {
+        StateLog { changes: vec![(0, LexState::new())] }
+    }
+
+    fn at(&self, token: usize) -> &LexState {
+        let idx = self.changes.partition_point(|&(t, _)| t <= token);
+        &self.changes[idx - 1].1
+    }
+
+    fn record(&mut self, token: usize, state: &LexState) {
+        if &self.changes.last().unwrap().1 != state {
+            self.changes.push((token, state.clone()));
+        }
+    }
+}
+
+/// Lexer input backed by a rope.
+///
+/// Lexers need contiguous text, so a window of the rope, starting at the
+/// requested offset, is flattened. If a token reaches the end of the window,
+/// it is lexed again with a window twice as long, so only the text around
+/// the lexed tokens is copied.
+struct Input<'t> {
+    text: Text<'t>,
+    window: Option<(TextUnit, Cow<'t, str>)>,
+}
+
+/// The minimal length of a window, in bytes.
+const WINDOW: u32 = 4096;
+
+impl<'t> Input<'t> {
+    fn new(text: Text<'t>) -> Input<'t> {
+        Input { text, window: None }
+    }
+
+    /// Lexes a token at `offset`, returns it along with its text
+    /// and the number of bytes scanned.
+    fn token_at<L: Lexer>(&mut self, lexer: &L, modes: &mut Vec<u16>, offset: TextUnit) -> (Token, &str, TextUnit) {
+        let mut len = tu(WINDOW);
+        let (token, scanned) = loop {
+            let covered = match self.window {
+                Some((start, ref flat)) => start <= offset && offset < start + tu(flat.len() as u32),
+                None => false,
+            };
+            if !covered {
+                self.flatten(offset, len);
+            }
+            let (start, ref flat) = *self.window.as_ref().unwrap();
+            let rest = &flat[(offset - start).utf8_len()..];
+            let is_last = start + tu(flat.len() as u32) == self.text.len();
+            let old_modes = if is_last { Vec::new() } else { modes.clone() };
+            let (token, scanned) = lexer.next_token(modes, rest);
+            // The end of the window is not the end of the text.
+            if scanned > rest.len() && !is_last {
+                *modes = old_modes;
+                len = tu(2 * rest.len() as u32).max(len);
+                self.window = None;
+                continue;
+            }
+            break (token, scanned);
+        };
+        let (start, ref flat) = *self.window.as_ref().unwrap();
+        let rest = &flat[(offset - start).utf8_len()..];
+        (token, &rest[..token.len.utf8_len()], tu(scanned as u32))
+    }
+
+    /// Flattens at least `len` bytes of the text from `offset`.
+    fn flatten(&mut self, offset: TextUnit, len: TextUnit) {
+        let mut end = (offset + len).min(self.text.len());
+        while !self.text.is_char_boundary(end) {
+            end += tu(1);
+        }
+        self.window = Some((offset, self.text.slice(TextRange::from_to(offset, end)).to_cow()));
+    }
+}
+
+/// Tokens being lexed, along with the current lexer state.
+struct Output {
+    lexed: Lexed,
+    len: TextUnit,
+    state: LexState,
+}
+
+impl Output {
+    fn new() -> Output {
+        Output {
+            lexed: Lexed { tokens: Vec::new(), states: StateLog::new(), scanned: Vec::new() },
+            len: tu(0),
+            state: LexState::new(),
+        }
     }
-    result
+
+    fn lex_token<L: Lexer>(&mut self, lexer: &L, input: &mut Input) {
+        let top_level = self.state.modes.is_empty();
+        let (token, text, scanned) = input.token_at(lexer, &mut self.state.modes, self.len);
+        if let (Some(layout), true) = (lexer.layout(), top_level) {
+            // Layout tokens precede the token, so they are lexed in the initial mode.
+            let modes = mem::take(&mut self.state.modes);
+            self.layout(layout, token, text, scanned);
+            self.state.modes = modes;
+        }
+        self.push(token, scanned);
+    }
+
+    /// Inserts the layout tokens which precede `token`.
+    fn layout(&mut self, layout: &Layout, token: Token, text: &str, scanned: TextUnit) {
+        if layout.skip.contains(&token.ty) {
+            match text.rfind('\n') {
+                Some(idx) => {
+                    if self.state.line_start.is_none() {
+                        self.state.line_start = Some(0);
+                        self.push_layout(layout.newline, scanned);
+                    }
+                    self.state.line_start = Some(text[idx + 1..].chars().count() as u32);
+                }
+                None => if let Some(ref mut column) = self.state.line_start {
+                    *column += text.chars().count() as u32;
+                }
+            }
+            return;
+        }
+
+        let column = match self.state.line_start {
+            Some(column) => column,
+            None => return,
+        };
+        while self.state.indents.last().map_or(false, |&indent| indent > column) {
+            self.state.indents.pop();
+            self.push_layout(layout.dedent, scanned);
+        }
+        if column > self.state.indents.last().cloned().unwrap_or(0) {
+            self.state.indents.push(column);
+            self.push_layout(layout.indent, scanned);
+        }
+        self.state.line_start = None;
+    }
+
+    /// Ends the last line and closes all blocks at the end of the text.
+    fn finish<L: Lexer>(&mut self, lexer: &L) {
+        let layout = match lexer.layout() {
+            Some(layout) => layout,
+            None => return,
+        };
+        // The end of the text counts as a byte, see `Lexer::next_token`.
+        let scanned = tu(1);
+        if self.state.line_start.is_none() {
+            self.state.line_start = Some(0);
+            self.push_layout(layout.newline, scanned);
+        }
+        while self.state.indents.pop().is_some() {
+            self.push_layout(layout.dedent, scanned);
+        }
+    }
+
+    fn push_layout(&mut self, ty: NodeType, scanned: TextUnit) {
+        self.push(Token { ty, len: tu(0) }, scanned)
+    }
+
+    fn push(&mut self, token: Token, scanned: TextUnit) {
+        self.len += token.len;
+        let lexed = &mut self.lexed;
+        lexed.tokens.push(token);
+        lexed.scanned.push(scanned);
+        lexed.states.record(lexed.tokens.len(), &self.state);
+    }
+}
+
+pub fn lex<L: Lexer>(lexer: &L, text: Text) -> Lexed {
+    let mut input = Input::new(text);
+    let mut output = Output::new();
+    while output.len < text.len() {
+        output.lex_token(lexer, &mut input);
+    }
+    output.finish(lexer);
+    output.lexed
 }
 
+/// Relexes the text after the edit, reusing old tokens where possible.
+///
+/// An old token is reused only if everything the lexer has looked at
+/// while lexing it is unchanged, and if the lexer is in the same state
+/// (modes and indentation) at its start. So lexing restarts at the first
+/// token before the edit which has looked into the edited region (an
+/// unterminated string literal, for example), and stops as soon as the new
+/// tokens get back in sync with the old ones past the edit.
+///
+/// Also returns the length of the relexed text.
 pub fn relex<L: Lexer>(
     lexer: &L,
-    old_tokens: &[Token],
+    old: &Lexed,
     edit: &TextEdit,
     new_text: Text
-) -> (Vec<Token>, usize)
+) -> (Lexed, usize)
 {
-    if old_tokens.iter().any(|&token| token.ty == ERROR) {
-        return (lex(lexer, new_text), 0);
-    }
+    let old_text_len = old.tokens.iter().fold(tu(0), |len, token| len + token.len);
 
-    let mut old_tokens = old_tokens.iter().cloned();
+    let mut old_idx = 0;
     let mut old_len = tu(0);
 
-    let mut new_tokens: Vec<Token> = Vec::new();
-    let mut new_len = tu(0);
+    let mut new = Output::new();
 
     let mut edit_point = tu(0);
     let mut reused = tu(0);
+    let mut input = Input::new(new_text);
 
     for op in edit.ops.iter() {
         match *op {
@@ -53,44 +289,45 @@ pub fn relex<L: Lexer>(
                 edit_point += buf.as_text().len()
             }
             TextEditOp::Copy(range) => {
-                let mut txt = new_text.slice(TextSuffix::from(new_len));
-                while new_len < edit_point {
-                    let token = lexer.step(&mut txt);
-                    new_len += token.len;
-                    new_tokens.push(token)
+                while new.len < edit_point {
+                    new.lex_token(lexer, &mut input);
                 }
 
                 while old_len < range.start() {
-                    old_len += old_tokens.next().unwrap().len;
+                    old_len += old.tokens[old_idx].len;
+                    old_idx += 1;
                 }
 
+                // The end of the text counts as a byte, see `Lexer::next_token`.
+                let copy_end = if range.end() == old_text_len && edit_point + range.len() == new_text.len() {
+                    range.end() + tu(1)
+                } else {
+                    range.end()
+                };
+                let unchanged = |idx: usize, start: TextUnit| start + old.scanned[idx] <= copy_end;
+
                 loop {
-                    let new_consumed = new_len - edit_point;
+                    let new_consumed = new.len - edit_point;
                     let old_consumed = old_len - range.start();
                     if new_consumed >= range.len() || old_consumed >= range.len() {
                         break
                     }
 
                     match new_consumed.cmp(&old_consumed) {
-                        Ordering::Less => {
-                            let token = lexer.step(&mut txt);
-                            new_len += token.len;
-                            new_tokens.push(token)
-                        }
-                        Ordering::Equal => {
-                            for token in &mut old_tokens {
+                        Ordering::Equal if old.states.at(old_idx) == &new.state && unchanged(old_idx, old_len) => {
+                            while old_idx < old.tokens.len() && unchanged(old_idx, old_len) {
+                                let (token, scanned) = (old.tokens[old_idx], old.scanned[old_idx]);
                                 old_len += token.len;
-                                if old_len >= range.end() {
-                                    break;
-                                }
+                                old_idx += 1;
                                 reused += token.len;
-                                new_len += token.len;
-                                new_tokens.push(token);
+                                new.state.clone_from(old.states.at(old_idx));
+                                new.push(token, scanned);
                             }
                         }
+                        Ordering::Less | Ordering::Equal => new.lex_token(lexer, &mut input),
                         Ordering::Greater => {
-                            let token = old_tokens.next().unwrap();
-                            old_len += token.len;
+                            old_len += old.tokens[old_idx].len;
+                            old_idx += 1;
                         }
                     }
                 }
@@ -100,11 +337,11 @@ pub fn relex<L: Lexer>(
         }
     }
 
-    let mut txt = new_text.slice(TextSuffix::from(new_len));
-    while !txt.is_empty() {
-        new_tokens.push(lexer.step(&mut txt));
+    while new.len < new_text.len() {
+        new.lex_token(lexer, &mut input);
     };
+    new.finish(lexer);
     let relexed_region = (new_text.len() - reused).utf8_len();
-    (new_tokens, relexed_region)
+    (new.lexed, relexed_region)
 }
 
@@ -5,22 +5,32 @@ extern crate serde_derive;
 extern crate typed_index_derive;
 
 extern crate m_lexer;
+extern crate stacker;
 
 pub extern crate fall_tree;
 pub extern crate serde_json;
 
 use std::any::Any;
-use std::collections::HashMap;
 
-use fall_tree::{Text, Language, NodeType, Metrics, TextEdit, TextUnit, TreeBuilder, ERROR, tu};
+use fall_tree::{Text, Language, NodeType, Metrics, TextEdit, TreeBuilder, ParseOptions, Expected, ERROR, tu};
 
 mod lex_engine;
+mod dfa_lexer;
 
-use crate::lex_engine::{Token, Lexer};
+pub use crate::lex_engine::{Token, Lexer, Layout};
+pub use crate::dfa_lexer::{DfaLexer, DfaRule, ModeTransition, keyword_hash};
 
 mod syn_engine;
 
-use crate::syn_engine::Event;
+use crate::syn_engine::{Event, ErrorKind, LayerState, Failures};
+
+pub use crate::syn_engine::parser::ParserView;
+
+pub mod native {
+    //! Runtime support for parsers compiled to Rust code.
+    pub use crate::syn_engine::native::*;
+    pub use crate::syn_engine::parser::{Parser, Pos};
+}
 
 pub struct RegexLexer {
     tys: Vec<NodeType>,
@@ -51,11 +61,13 @@ impl RegexLexer {
 }
 
 impl Lexer for RegexLexer {
-    fn next_token(&self, text: Text) -> Token {
-        let m_token = self.lexer.next_token(text.to_cow().as_ref());
+    /// Regular expressions might look at any part of the text,
+    /// so relexing with this lexer always starts from scratch.
+    fn next_token(&self, _modes: &mut Vec<u16>, text: &str) -> (Token, usize) {
+        let m_token = self.lexer.next_token(text);
         let ty = self.tys[m_token.kind.0 as usize];
         let len = tu(m_token.len as u32);
-        Token { ty, len }
+        (Token { ty, len }, text.len() + 1)
     }
 }
 
@@ -86,8 +98,21 @@ pub struct ParserDefinition {
     pub node_types: Vec<NodeType>,
     pub syntactical_rules: Vec<Expr>,
     pub whitespace_binder: fn(ty: NodeType, adjacent_spaces: Vec<(NodeType, &str)>, leading: bool) -> usize,
+    /// If a token is absent after `<commit>`, insert a zero-width leaf
+    /// of its type and continue parsing the sequence, instead of
+    /// finishing the sequence with an `ERROR` node.
+    pub missing_tokens: bool,
+    /// Rules compiled to Rust code, see `fall_parse::native`.
+    /// Rules it doesn't know about are interpreted.
+    pub native: Option<native::NativeParser>,
+    /// User-supplied functions called by `Expr::Pred`.
+    pub predicates: Vec<SemanticPredicate>,
 }
 
+/// A custom syntactical check: the `<pred fn_name>` expression
+/// matches the empty string if the function returns `true`.
+pub type SemanticPredicate = fn(&ParserView) -> bool;
+
 impl Default for ParserDefinition {
     fn default() -> Self {
         fn no_binder(_: NodeType, _: Vec<(NodeType, &str)>, _: bool) -> usize {
@@ -98,12 +123,15 @@ impl Default for ParserDefinition {
             node_types: Vec::new(),
             syntactical_rules: Vec::new(),
             whitespace_binder: no_binder,
+            missing_tokens: false,
+            native: None,
+            predicates: Vec::new(),
         }
     }
 }
 
 
-#[derive(Copy, Clone, Serialize, Deserialize, Debug, TypedIndex)]
+#[derive(Copy, Clone, Serialize, Deserialize, Debug, Eq, PartialEq, TypedIndex)]
 #[typed_index(NodeType)]
 pub struct NodeTypeRef(pub u32);
 
@@ -113,6 +141,9 @@ pub struct Context(pub u32);
 #[derive(Copy, Clone, Serialize, Deserialize, Debug)]
 pub struct Arg(pub u32);
 
+#[derive(Copy, Clone, Serialize, Deserialize, Debug)]
+pub struct PredicateRef(pub u32);
+
 #[derive(Copy, Clone, Serialize, Deserialize, Debug, Eq, PartialEq, Hash, TypedIndex)]
 #[typed_index(Expr)]
 pub struct ExprRef(pub u32);
@@ -148,6 +179,11 @@ pub enum Expr {
     PrevIs(Vec<NodeTypeRef>),
     Inject(ExprRef, ExprRef),
     Cached(ExprRef),
+    /// A rule which calls itself before consuming any tokens,
+    /// parsed by growing a seed.
+    LeftRec(ExprRef),
+    /// Calls one of the `ParserDefinition::predicates`.
+    Pred(PredicateRef),
 }
 
 #[derive(Serialize, Deserialize, Debug)]
@@ -157,15 +193,6 @@ pub struct PrattTable {
     pub infixes: Vec<Infix>,
 }
 
-impl PrattTable {
-    fn infixes<'p>(&'p self, min_prior: u32) -> Box<dyn Iterator<Item=&'p Infix> + 'p> {
-        Box::new(
-            self.infixes.iter()
-                .filter(move |ix| ix.priority >= min_prior)
-        )
-    }
-}
-
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Prefix {
     pub ty: NodeTypeRef,
@@ -179,85 +206,137 @@ pub struct Infix {
     pub op: ExprRef,
     pub priority: u32,
     pub has_rhs: bool,
+    pub assoc: Assoc,
+}
+
+/// How an infix operator groups with the operators of the same priority.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Assoc {
+    /// `a - b - c` is `(a - b) - c`.
+    Left,
+    /// `a ^ b ^ c` is `a ^ (b ^ c)`.
+    Right,
+    /// `a == b == c` is not an expression.
+    NonAssoc,
 }
 
 
 struct IncrementalData {
-    tokens: Vec<Token>,
+    lexed: lex_engine::Lexed,
     events: Vec<Event>,
+    layers: Vec<LayerState>,
+    cached_expected: Vec<Failures>,
+    expected: Expected,
 }
 
-pub fn parse(
+pub fn parse<L: Lexer>(
     lang: &Language,
-    lexer_def: &RegexLexer,
+    lexer_def: &L,
     parser_def: &ParserDefinition,
     text: Text,
+    options: &ParseOptions,
     metrics: &Metrics,
     builder: &mut TreeBuilder,
 ) -> Option<Box<dyn Any + Sync + Send>> {
-    let tokens: Vec<Token> = metrics.measure_time("lexing", || {
+    let lexed = metrics.measure_time("lexing", || {
         lex_engine::lex(lexer_def, text)
     });
     metrics.record("relexed region", text.len().utf8_len() as u64, "");
 
-    let events = parser_def.parse(None, text, &tokens, lang, metrics, builder);
-    let incremental_data = IncrementalData { tokens, events };
-    Some(Box::new(incremental_data))
+    parser_def.parse(None, lexed, text, lang, options, metrics, builder)
+        .map(|data| Box::new(data) as Box<dyn Any + Sync + Send>)
 }
 
-pub fn reparse(
+pub fn reparse<L: Lexer>(
     lang: &Language,
-    lexer_def: &RegexLexer,
+    lexer_def: &L,
     parser_def: &ParserDefinition,
     incremental_data: &dyn Any,
     edit: &TextEdit,
     new_text: Text,
+    options: &ParseOptions,
     metrics: &Metrics,
     builder: &mut TreeBuilder,
 ) -> Option<Box<dyn Any + Sync + Send>> {
     let incremental_data: &IncrementalData = incremental_data.downcast_ref().unwrap();
-    let (tokens, relexed_region) = metrics.measure_time("lexing", || {
-        lex_engine::relex(lexer_def, &incremental_data.tokens, edit, new_text)
+    let (lexed, relexed_region) = metrics.measure_time("lexing", || {
+        lex_engine::relex(lexer_def, &incremental_data.lexed, edit, new_text)
     });
     metrics.record("relexed region", relexed_region as u64, "");
 
-    let salvaged = syn_engine::salvage_segments(
-        &incremental_data.events,
-        &incremental_data.tokens,
-        &|t| lang.node_type_info(t.ty).whitespace_like,
-        edit,
-    );
-    let prev = Some((salvaged, incremental_data.events.as_ref()));
-    let events = parser_def.parse(prev, new_text, &tokens, lang, metrics, builder);
-    let incremental_data = IncrementalData { tokens, events };
-    Some(Box::new(incremental_data))
+    parser_def.parse(Some((incremental_data, edit)), lexed, new_text, lang, options, metrics, builder)
+        .map(|data| Box::new(data) as Box<dyn Any + Sync + Send>)
 }
 
 
 impl ParserDefinition {
+    /// Reparses only the layer around the edit if possible, see `Expr::Layer`,
+    /// otherwise reuses the `#[cached]` segments of the `prev` parse.
+    ///
+    /// Returns `None` if parsing was stopped early: such a tree
+    /// can't be reused for incremental reparsing.
     fn parse(
         &self,
-        prev: Option<(HashMap<(TextUnit, ExprRef), (u32, u32, u32)>, &[Event])>,
+        prev: Option<(&IncrementalData, &TextEdit)>,
+        lexed: lex_engine::Lexed,
         text: Text,
-        tokens: &[Token],
         lang: &Language,
+        options: &ParseOptions,
         metrics: &Metrics,
         builder: &mut TreeBuilder,
-    ) -> Vec<Event> {
+    ) -> Option<IncrementalData> {
         let g = syn_engine::Grammar {
             node_types: &self.node_types,
             rules: &self.syntactical_rules,
             start_rule: ExprRef(0),
+            missing_tokens: self.missing_tokens,
+            native: self.native,
+            predicates: &self.predicates,
         };
         let file_ty = match self.syntactical_rules[0] {
             Expr::Pub { ty, .. } => self.node_types[ty.0 as usize],
             _ => unreachable!()
         };
 
-        let (events, ticks) = metrics.measure_time("parsing", || {
-            syn_engine::parse(prev, g, lang, text, &tokens)
+        let tokens = &lexed.tokens;
+        let result = metrics.measure_time("parsing", || {
+            let prev = match prev {
+                Some(prev) => prev,
+                None => return syn_engine::parse(None, g, lang, text, tokens, options),
+            };
+            let (old, edit) = prev;
+            let old_parse = syn_engine::OldParse {
+                tokens: &old.lexed.tokens,
+                events: &old.events,
+                layers: &old.layers,
+                cached_expected: &old.cached_expected,
+                expected: &old.expected,
+            };
+            if let Some(result) = syn_engine::reparse_layer(g, lang, &old_parse, edit, text, tokens, options) {
+                return result;
+            }
+            let salvaged = syn_engine::Salvaged {
+                segments: syn_engine::salvage_segments(
+                    &old.events,
+                    &old.lexed.tokens,
+                    &|t| lang.node_type_info(t.ty).whitespace_like,
+                    edit,
+                ),
+                events: &old.events,
+                layers: &old.layers,
+                expected: &old.cached_expected,
+            };
+            syn_engine::parse(Some(salvaged), g, lang, text, tokens, options)
         });
-        metrics.record("parsing ticks", ticks, "");
+        metrics.record("parsing ticks", result.ticks, "");
+        metrics.record("reparsed tokens", result.n_tokens as u64, "");
+        match result.stopped {
+            Some(ErrorKind::TooDeep) => metrics.record("nesting limit exceeded", 1, ""),
+            Some(_) => metrics.record("parse budget exhausted", 1, ""),
+            None => (),
+        }
+        builder.set_expected(result.expected.clone());
+        let events = result.events;
 
         metrics.measure_time("inode construction", || {
             syn_engine::convert(
@@ -273,10 +352,20 @@ impl ParserDefinition {
                     let spaces = owned.iter().map(|&(t, ref text)| (t, text.as_ref())).collect();
                     (self.whitespace_binder)(ty, spaces, leading)
                 },
+                &|kind| kind.message(&g, lang),
                 builder,
             );
         });
-        events
+        if result.stopped.is_some() {
+            return None;
+        }
+        Some(IncrementalData {
+            lexed,
+            events,
+            layers: result.layers,
+            cached_expected: result.cached_expected,
+            expected: result.expected,
+        })
     }
 }
 
@@ -285,10 +374,13 @@ pub mod runtime {
         ::serde_json::from_str(json).unwrap()
     }
 
-    pub use crate::{ParserDefinition, RegexLexer, LexRule, parse, reparse};
+    pub use crate::{ParserDefinition, RegexLexer, LexRule, DfaLexer, DfaRule, ModeTransition, Layout, parse, reparse, native};
+    pub use crate::{Expr, ExprRef, NodeTypeRef, Context, Arg, PredicateRef, PrattTable, Prefix, Infix, Assoc};
+    pub use crate::{ParserView, SemanticPredicate};
     pub use serde_json;
     pub use fall_tree;
-    pub use fall_tree::{AstNode, AstChildren, Node, NodeType, NodeTypeInfo, Language, LanguageImpl, ERROR, Text, TextEdit, TreeBuilder, Metrics};
+    pub use fall_tree::{AstNode, AstChildren, Node, NodeType, NodeTypeInfo, Language, LanguageImpl, ParseOptions, ERROR, Text, TextEdit, TreeBuilder, Metrics};
+    pub use fall_tree::{Injection, TextRange, TextUnit, tu};
     pub use fall_tree::search::{child_of_type, child_of_type_exn};
     pub use lazy_static::*;
 }
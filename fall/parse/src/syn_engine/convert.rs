@@ -1,6 +1,6 @@
 use fall_tree::{NodeType, Text, TextRange, TreeBuilder, tu};
 use crate::lex_engine::Token;
-use crate::syn_engine::Event;
+use crate::syn_engine::{Event, ErrorKind};
 
 pub(crate) fn convert(
     text: Text,
@@ -8,6 +8,7 @@ pub(crate) fn convert(
     events: &[Event],
     is_whitespace: &dyn Fn(NodeType) -> bool,
     whitespace_binder: &dyn Fn(NodeType, &[(NodeType, Text)], bool) -> usize,
+    error_message: &dyn Fn(ErrorKind) -> String,
     builder: &mut TreeBuilder,
 ) {
     let events = reshuffle_events(events);
@@ -20,7 +21,7 @@ pub(crate) fn convert(
         (t, token_text)
     }).collect::<Vec<_>>();
 
-    let conv = Convertor { is_whitespace, whitespace_binder };
+    let conv = Convertor { is_whitespace, whitespace_binder, error_message };
     match first {
         Event::Start { ty, forward_parent: _ } => {
             conv.go(ty, &tokens, rest, builder);
@@ -71,103 +72,76 @@ fn reshuffle_events(events: &[Event]) -> Vec<Event> {
 struct Convertor<'a> {
     is_whitespace: &'a dyn Fn(NodeType) -> bool,
     whitespace_binder: &'a dyn Fn(NodeType, &[(NodeType, Text)], bool) -> usize,
-}
-
-#[derive(Debug)]
-struct Conversion {
-    right_edge: usize,
-    n_events: usize,
+    error_message: &'a dyn Fn(ErrorKind) -> String,
 }
 
 impl<'a> Convertor<'a> {
+    /// Builds the tree with an explicit stack of the open nodes, so
+    /// arbitrary deep trees are fine.
     fn go(
         &self,
         ty: NodeType,
         tokens: &[(Token, Text)],
         events: &[Event],
         builder: &mut TreeBuilder,
-    ) -> Conversion {
-        builder.start_internal(ty);
-        let (n_tokens, n_events) = self.fill(tokens, events, builder);
-        let tokens = &tokens[n_tokens..];
-
-        let trailing_ws = self.collect_tokens_for_binder(tokens);
-        let right_ws = (self.whitespace_binder)(ty, &trailing_ws, false);
-        for &(t, _) in &tokens[..right_ws] {
-            builder.leaf(t.ty, t.len);
-        }
-        builder.finish_internal();
-
-        let right_edge = n_tokens + right_ws;
-        return Conversion {
-            right_edge,
-            n_events,
-        };
-    }
-
-    fn collect_tokens_for_binder<'t>(&self, tokens: &[(Token, Text<'t>)]) -> Vec<(NodeType, Text<'t>)> {
-        tokens.iter()
-            .take_while(|&&(t, _)| (self.is_whitespace)(t.ty))
-            .map(|&(t, text)| (t.ty, text))
-            .collect()
-    }
-
-    fn fill(
-        &self,
-        tokens: &[(Token, Text)],
-        events: &[Event],
-        builder: &mut TreeBuilder,
-    ) -> (usize, usize) {
+    ) {
         let mut tokens = tokens;
-        let mut n_tokens = 0;
-        let mut events = events;
-        let mut n_events = 0;
-        loop {
-            let (first, rest) = (events[0], &events[1..]);
-            n_events += 1;
-            events = rest;
-            match first {
+        let mut open = vec![ty];
+        builder.start_internal(ty);
+        for &event in events {
+            match event {
                 Event::Start { ty, forward_parent: _ } => {
                     let leading_ws = self.collect_tokens_for_binder(tokens);
                     let left_wd = leading_ws.len() - (self.whitespace_binder)(ty, &leading_ws, true);
-                    for i in 0..left_wd {
-                        let t = tokens[i].0;
+                    for &(t, _) in &tokens[..left_wd] {
                         builder.leaf(t.ty, t.len);
                     }
                     tokens = &tokens[left_wd..];
-                    n_tokens += left_wd;
+                    open.push(ty);
+                    builder.start_internal(ty);
+                }
 
-                    let Conversion { right_edge, n_events: child_events } =
-                        self.go(ty, tokens, events, builder);
-                    tokens = &tokens[right_edge..];
-                    n_tokens += right_edge;
-                    events = &events[child_events..];
-                    n_events += child_events;
+                Event::End => {
+                    let ty = open.pop().unwrap();
+                    let trailing_ws = self.collect_tokens_for_binder(tokens);
+                    let right_ws = (self.whitespace_binder)(ty, &trailing_ws, false);
+                    for &(t, _) in &tokens[..right_ws] {
+                        builder.leaf(t.ty, t.len);
+                    }
+                    tokens = &tokens[right_ws..];
+                    builder.finish_internal();
+                    if open.is_empty() {
+                        return;
+                    }
                 }
 
-                Event::End => return (n_tokens, n_events),
+                Event::Error { kind } => builder.error((self.error_message)(kind)),
+
+                Event::Missing { ty } => builder.missing(ty),
 
                 Event::Token { ty, n_raw_tokens } => {
                     let non_white = tokens.iter().take_while(|&&(t, _)| (self.is_whitespace)(t.ty)).count();
-                    for i in 0..non_white {
-                        let t = tokens[i].0;
+                    for &(t, _) in &tokens[..non_white] {
                         builder.leaf(t.ty, t.len);
                     }
                     tokens = &tokens[non_white..];
-                    n_tokens += non_white;
 
                     let n_raw_tokens = n_raw_tokens as usize;
-                    let mut len = tu(0);
-                    for &(t, _) in &tokens[..n_raw_tokens] {
-                        n_tokens += 1;
-                        len += t.len;
-                    }
+                    let len = tokens[..n_raw_tokens].iter().fold(tu(0), |len, &(t, _)| len + t.len);
                     builder.leaf(ty, len);
                     tokens = &tokens[n_raw_tokens..];
                 }
 
-                Event::Cached { .. } => {}
+                Event::Cached { .. } | Event::Layer { .. } => {}
             }
         }
+        unreachable!()
+    }
+
+    fn collect_tokens_for_binder<'t>(&self, tokens: &[(Token, Text<'t>)]) -> Vec<(NodeType, Text<'t>)> {
+        tokens.iter()
+            .take_while(|&&(t, _)| (self.is_whitespace)(t.ty))
+            .map(|&(t, text)| (t.ty, text))
+            .collect()
     }
 }
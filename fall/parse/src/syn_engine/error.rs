@@ -0,0 +1,65 @@
+use fall_tree::Language;
+
+use crate::{Expr, ExprRef};
+use super::Grammar;
+
+/// Why the parser has created an `ERROR` node.
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum ErrorKind {
+    /// A part of a sequence after `<commit>` is missing.
+    Expected(ExprRef),
+    /// Tokens skipped by `<with_skip>` while looking for the given expression.
+    Skipped(ExprRef),
+    /// The right hand side of a binary operator is missing.
+    ExpectedOperand,
+    /// Tokens left over after a `<layer>` or after the whole file.
+    Unexpected,
+    /// The rest of the file, when the tick budget has run out
+    /// or parsing was cancelled.
+    Stopped,
+    /// The rest of the file, when the nesting limit has been exceeded.
+    TooDeep,
+}
+
+impl ErrorKind {
+    pub(crate) fn message(self, grammar: &Grammar, lang: &Language) -> String {
+        let describe = |expr| describe(grammar, lang, expr)
+            .unwrap_or_else(|| "syntax element".to_owned());
+        match self {
+            ErrorKind::Expected(expr) => format!("{} expected", describe(expr)),
+            ErrorKind::Skipped(expr) => format!("unexpected tokens, {} expected", describe(expr)),
+            ErrorKind::ExpectedOperand => "expression expected".to_owned(),
+            ErrorKind::Unexpected => "unexpected tokens".to_owned(),
+            ErrorKind::Stopped => "parsing was stopped before the end of file".to_owned(),
+            ErrorKind::TooDeep => "nesting is too deep".to_owned(),
+        }
+    }
+}
+
+/// Describes the first token(s) an expression can start with.
+fn describe(grammar: &Grammar, lang: &Language, expr: ExprRef) -> Option<String> {
+    let name = |ty| lang.node_type_info(grammar.node_types[ty]).name.to_owned();
+    let result = match grammar[expr] {
+        Expr::Token(ty) | Expr::Pub { ty, .. } => name(ty),
+        Expr::ContextualToken(_, ref text) => format!("'{}'", text),
+        Expr::Eof => "end of file".to_owned(),
+        Expr::Or(ref options) => {
+            let options = options.iter()
+                .map(|&opt| describe(grammar, lang, opt))
+                .collect::<Option<Vec<_>>>()?;
+            match options.len() {
+                0 => return None,
+                1..=3 => options.join(" or "),
+                _ => format!("{} or {} others", options[0], options.len() - 1),
+            }
+        }
+        Expr::And(ref parts, _) => return describe(grammar, lang, *parts.first()?),
+        Expr::PubReplace { body, .. } | Expr::Opt(body) | Expr::Rep(body)
+        | Expr::Cached(body) | Expr::Enter(_, body) | Expr::Exit(_, body)
+        | Expr::Call(body, _) | Expr::WithSkip(body, _) | Expr::Layer(body, _)
+        | Expr::Inject(_, body) =>
+            return describe(grammar, lang, body),
+        _ => return None,
+    };
+    Some(result)
+}
@@ -1,29 +1,30 @@
-use std::collections::HashMap;
-use fall_tree::{Language, Text, TextUnit};
+use fall_tree::{Language, Text, ExpectedToken, ParseOptions};
 
-use crate::{NodeTypeRef, Context, Arg, ExprRef, Expr};
+use crate::{NodeTypeRef, Context, Arg, PredicateRef, ExprRef, Expr};
 use crate::lex_engine::Token;
-use crate::syn_engine::parser::{Parser, Pos};
-use crate::syn_engine::Event;
+use crate::syn_engine::parser::{Parser, ParserView, Pos, Frame};
+use crate::syn_engine::{ErrorKind, ParseResult, Salvaged};
 
 use super::Grammar;
 use super::pratt::parse_pratt;
 
 pub(crate) fn parse(
-    prev: Option<(HashMap<(TextUnit, ExprRef), (u32, u32, u32)>, &[Event])>,
+    prev: Option<Salvaged>,
     grammar: Grammar,
     lang: &Language,
     text: Text,
     tokens: &[Token],
-) -> (Vec<Event>, u64) {
+    options: &ParseOptions,
+) -> ParseResult {
     let is_ws = |t: Token| lang.node_type_info(t.ty).whitespace_like;
-    let (mut parser, pos) = Parser::new(prev, &grammar, &is_ws, text, tokens);
+    let (mut parser, pos) = Parser::new(prev, &grammar, &is_ws, text, tokens, options);
 
     let start_rule = parser.grammar.start_rule;
     let mut leftover = parse_expr(&mut parser, start_rule, pos).unwrap();
     if !leftover.is_empty() {
         parser.reopen();
-        parser.start_error();
+        let kind = parser.stop_reason().unwrap_or(ErrorKind::Unexpected);
+        parser.start_error(kind);
         while let Some((_, ts)) = parser.bump(leftover) {
             leftover = ts;
         }
@@ -36,24 +37,65 @@ pub(crate) fn parse(
 
 
 pub(crate) fn parse_expr(p: &mut Parser, expr: ExprRef, tokens: Pos) -> Option<Pos> {
+    parse_framed(p, expr, tokens, |p, tokens| parse_expr_inner(p, expr, tokens))
+}
+
+/// Bookkeeping shared by every expression, whether it is interpreted
+/// or compiled to native code: ticks, nesting depth and rollback on failure.
+pub(crate) fn parse_framed<'g, F>(p: &mut Parser<'g>, expr: ExprRef, tokens: Pos, f: F) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
     p.tick();
+    p.enter();
+    // Once stopped, every expression matches without consuming anything,
+    // so that the already started nodes are closed and the remaining
+    // tokens end up in the top-level `ERROR` node.
+    if p.is_stopped() && expr != p.grammar.start_rule {
+        p.exit();
+        return Some(tokens);
+    }
     let mark = p.mark();
-    let result = parse_expr_inner(p, expr, tokens);
+    let result = grow_stack(|| f(p, tokens));
     if result.is_none() {
         p.rollback(mark);
     }
+    p.exit();
     result
 }
 
-fn parse_expr_pred(p: &mut Parser, expr: ExprRef, tokens: Pos) -> Option<Pos> {
+/// Each level of nesting in the input takes dozens of expressions, so the
+/// stack is extended on the heap when it is about to run out, instead of
+/// limiting the recursion to what fits into the stack of the thread.
+pub(super) fn grow_stack<R, F: FnOnce() -> R>(f: F) -> R {
+    const RED_ZONE: usize = 64 * 1024;
+    const NEW_STACK: usize = 1024 * 1024;
+    ::stacker::maybe_grow(RED_ZONE, NEW_STACK, f)
+}
+
+pub(crate) fn parse_expr_pred(p: &mut Parser, expr: ExprRef, tokens: Pos) -> Option<Pos> {
+    pred_with(p, tokens, |p, ts| parse_expr(p, expr, ts))
+}
+
+fn pred_with<'g, F>(p: &mut Parser<'g>, tokens: Pos, body: F) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
     let old_mode = p.predicate_mode;
     p.predicate_mode = true;
-    let result = parse_expr(p, expr, tokens);
+    let result = body(p, tokens);
     p.predicate_mode = old_mode;
     result
 }
 
 fn parse_expr_inner(p: &mut Parser, expr: ExprRef, tokens: Pos) -> Option<Pos> {
+    if let Some(native) = p.grammar.native {
+        if let Some(result) = native(p, expr, tokens) {
+            return result;
+        }
+    }
+    interpret_expr(p, expr, tokens)
+}
+
+fn interpret_expr(p: &mut Parser, expr: ExprRef, tokens: Pos) -> Option<Pos> {
     let grammar = &*p.grammar;
     match grammar[expr] {
         Expr::Pub { ty, body, replaceable } =>
@@ -87,7 +129,7 @@ fn parse_expr_inner(p: &mut Parser, expr: ExprRef, tokens: Pos) -> Option<Pos> {
             p.bump(tokens).map(|(_ty, ts)| ts),
 
         Expr::Layer(l, e) =>
-            parse_layer(p, tokens, l, e),
+            parse_layer(p, tokens, expr, l, e),
 
         Expr::Rep(body) =>
             parse_rep(p, tokens, body),
@@ -108,7 +150,7 @@ fn parse_expr_inner(p: &mut Parser, expr: ExprRef, tokens: Pos) -> Option<Pos> {
             parse_is_in(p, tokens, ctx),
 
         Expr::Call(body, ref args) =>
-            parse_call(p, tokens, body, &*args),
+            parse_call(p, tokens, expr, body, &*args),
 
         Expr::Var(i) =>
             parse_var(p, tokens, i),
@@ -121,6 +163,12 @@ fn parse_expr_inner(p: &mut Parser, expr: ExprRef, tokens: Pos) -> Option<Pos> {
 
         Expr::Cached(body) =>
             parse_cached(p, body, tokens),
+
+        Expr::LeftRec(body) =>
+            parse_left_rec(p, tokens, expr, body),
+
+        Expr::Pred(pred) =>
+            parse_pred(p, tokens, pred),
     }
 }
 
@@ -129,11 +177,20 @@ fn parse_pub<'g>(
     p: &mut Parser<'g>, tokens: Pos,
     ty_idx: NodeTypeRef, body: ExprRef, replaceable: bool,
 ) -> Option<Pos> {
+    pub_with(p, tokens, ty_idx, replaceable, |p, ts| parse_expr(p, body, ts))
+}
+
+pub(crate) fn pub_with<'g, F>(
+    p: &mut Parser<'g>, tokens: Pos,
+    ty_idx: NodeTypeRef, replaceable: bool, body: F,
+) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
     if replaceable {
         p.replacement = None;
     }
     let mark = p.start(ty_idx);
-    let ts = parse_expr(p, body, tokens)?;
+    let ts = body(p, tokens)?;
     if let (true, Some(ty)) = (replaceable, p.replacement) {
         p.replacement = None;
         p.replace(mark, ty)
@@ -147,7 +204,16 @@ fn parse_pub_replace<'g>(
     p: &mut Parser<'g>, tokens: Pos,
     ty_idx: NodeTypeRef, body: ExprRef
 ) -> Option<Pos> {
-    let ts = parse_expr(p, body, tokens)?;
+    pub_replace_with(p, tokens, ty_idx, |p, ts| parse_expr(p, body, ts))
+}
+
+pub(crate) fn pub_replace_with<'g, F>(
+    p: &mut Parser<'g>, tokens: Pos,
+    ty_idx: NodeTypeRef, body: F,
+) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    let ts = body(p, tokens)?;
     p.replacement = Some(ty_idx);
     Some(ts)
 }
@@ -157,20 +223,42 @@ pub(crate) fn parse_or<'t, 'g>(
     options: &'g [ExprRef],
     tokens: Pos
 ) -> Option<Pos> {
-    options.iter().filter_map(|&opt| parse_expr(p, opt, tokens)).next()
+    or_with(p, tokens, options.len(), |p, i, ts| parse_expr(p, options[i], ts))
+}
+
+pub(crate) fn or_with<'g, F>(
+    p: &mut Parser<'g>, tokens: Pos,
+    n_options: usize, mut option: F,
+) -> Option<Pos>
+    where F: FnMut(&mut Parser<'g>, usize, Pos) -> Option<Pos>
+{
+    (0..n_options).filter_map(|i| option(p, i, tokens)).next()
 }
 
 fn parse_and<'g>(
     p: &mut Parser<'g>, tokens: Pos,
     parts: &'g [ExprRef], commit: Option<usize>,
 ) -> Option<Pos> {
+    and_with(p, tokens, parts, commit, |p, i, ts| parse_expr(p, parts[i], ts))
+}
+
+/// `part_fn` is called with the index of the part to parse,
+/// `parts` are used for error recovery only.
+pub(crate) fn and_with<'g, F>(
+    p: &mut Parser<'g>, tokens: Pos,
+    parts: &'g [ExprRef], commit: Option<usize>, mut part_fn: F,
+) -> Option<Pos>
+    where F: FnMut(&mut Parser<'g>, usize, Pos) -> Option<Pos>
+{
     let mut tokens = tokens;
     let mut consumed = 0;
 
-    for &part in parts {
-        if let Some(ts) = parse_expr(p, part, tokens) {
+    for (i, &part) in parts.iter().enumerate() {
+        if let Some(ts) = part_fn(p, i, tokens) {
             consumed += 1;
             tokens = ts;
+        } else if consumed >= commit.unwrap_or(parts.len()) && parse_missing(p, part) {
+            consumed += 1;
         } else {
             break;
         }
@@ -179,28 +267,47 @@ fn parse_and<'g>(
         return None;
     }
     if consumed < parts.len() {
-        p.start_error();
+        p.start_error(ErrorKind::Expected(parts[consumed]));
         p.finish()
     }
     Some(tokens)
 }
 
-fn parse_token<'g>(
+fn parse_missing<'g>(p: &mut Parser<'g>, part: ExprRef) -> bool {
+    let grammar = p.grammar;
+    if !grammar.missing_tokens {
+        return false;
+    }
+    match grammar[part] {
+        Expr::Token(ty) | Expr::ContextualToken(ty, _) => {
+            p.missing(ty);
+            true
+        }
+        _ => false,
+    }
+}
+
+pub(crate) fn parse_token<'g>(
     p: &mut Parser<'g>, tokens: Pos,
     ty_idx: NodeTypeRef,
 ) -> Option<Pos> {
-    let (ty, ts) = p.bump(tokens)?;
-    if p[ty_idx] != ty {
+    let ty = p[ty_idx];
+    if tokens.is_empty() || p[tokens].ty != ty {
+        p.expect(tokens, ExpectedToken::Token(ty));
         return None;
     }
-    Some(ts)
+    p.bump(tokens).map(|(_ty, ts)| ts)
 }
 
-fn parse_contextual_token<'g>(
+pub(crate) fn parse_contextual_token<'g>(
     p: &mut Parser<'g>, tokens: Pos,
     ty_idx: NodeTypeRef, text: &str,
 ) -> Option<Pos> {
-    p.bump_by_text(tokens, text, ty_idx)
+    let result = p.bump_by_text(tokens, text, ty_idx);
+    if result.is_none() {
+        p.expect(tokens, ExpectedToken::Contextual(text.to_owned()));
+    }
+    result
 }
 
 fn parse_opt<'g>(
@@ -214,44 +321,89 @@ fn parse_not<'g>(
     p: &mut Parser<'g>, tokens: Pos,
     e: ExprRef,
 ) -> Option<Pos> {
-    match parse_expr_pred(p, e, tokens) {
+    not_with(p, tokens, |p, ts| parse_expr(p, e, ts))
+}
+
+pub(crate) fn not_with<'g, F>(p: &mut Parser<'g>, tokens: Pos, body: F) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    match pred_with(p, tokens, body) {
         None => Some(tokens),
         Some(_) => None,
     }
 }
 
-fn parse_eof<'g>(
-    _: &mut Parser<'g>, tokens: Pos,
+pub(crate) fn parse_eof<'g>(
+    p: &mut Parser<'g>, tokens: Pos,
 ) -> Option<Pos> {
-    if tokens.is_empty() { Some(tokens) } else { None }
+    if tokens.is_empty() {
+        return Some(tokens);
+    }
+    p.expect(tokens, ExpectedToken::Eof);
+    None
 }
 
 fn parse_layer<'g>(
     p: &mut Parser<'g>, tokens: Pos,
-    l: ExprRef, e: ExprRef,
+    layer: ExprRef, l: ExprRef, e: ExprRef,
 ) -> Option<Pos> {
-    let rest = parse_expr_pred(p, l, tokens)?;
-    let layer = p.cut_suffix(tokens, rest);
-    let mut leftovers = parse_expr(p, e, layer).unwrap_or(layer);
+    layer_with(p, tokens, layer, |p, ts| parse_expr(p, l, ts), |p, ts| parse_expr(p, e, ts))
+}
+
+pub(crate) fn layer_with<'g, C, B>(
+    p: &mut Parser<'g>, tokens: Pos,
+    layer: ExprRef, cover: C, body: B,
+) -> Option<Pos>
+    where C: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>,
+          B: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>,
+{
+    // The cover is parsed in the predicate mode, which still changes these.
+    let (prev, replacement) = (p.prev, p.replacement);
+    let rest = pred_with(p, tokens, cover)?;
+    let mark = p.start_layer(layer, prev, replacement);
+    let layer_tokens = p.cut_suffix(tokens, rest);
+    layer_body_with(p, layer_tokens, body);
+    if let Some(mark) = mark {
+        p.finish_layer(mark);
+    }
+    Some(rest)
+}
+
+/// Parses the body of a layer, the tokens it leaves are wrapped into an `ERROR`.
+pub(crate) fn parse_layer_body(p: &mut Parser, e: ExprRef, layer: Pos) {
+    layer_body_with(p, layer, |p, ts| parse_expr(p, e, ts))
+}
+
+fn layer_body_with<'g, F>(p: &mut Parser<'g>, layer: Pos, body: F)
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    let mut leftovers = body(p, layer).unwrap_or(layer);
 
     if !leftovers.is_empty() {
-        p.start_error();
+        p.start_error(ErrorKind::Unexpected);
         while let Some((_, ts)) = p.bump(leftovers) {
             leftovers = ts;
         }
         p.finish();
     }
-
-    Some(rest)
 }
 
 fn parse_rep<'g>(
     p: &mut Parser<'g>, tokens: Pos,
     body: ExprRef,
 ) -> Option<Pos> {
+    rep_with(p, tokens, |p, ts| parse_expr(p, body, ts))
+}
+
+pub(crate) fn rep_with<'g, F>(p: &mut Parser<'g>, tokens: Pos, mut body: F) -> Option<Pos>
+    where F: FnMut(&mut Parser<'g>, Pos) -> Option<Pos>
+{
     let mut tokens = tokens;
-    while let Some(ts) = parse_expr(p, body, tokens) {
+    while let Some(ts) = body(p, tokens) {
         tokens = ts;
+        if p.is_stopped() {
+            break;
+        }
     }
     Some(tokens)
 }
@@ -260,14 +412,25 @@ fn parse_with_skip<'g>(
     p: &mut Parser<'g>, tokens: Pos,
     first: ExprRef, body: ExprRef,
 ) -> Option<Pos> {
+    with_skip_with(p, tokens, first, |p, ts| parse_expr(p, first, ts), |p, ts| parse_expr(p, body, ts))
+}
+
+/// `first` is used for error reporting only.
+pub(crate) fn with_skip_with<'g, F, B>(
+    p: &mut Parser<'g>, tokens: Pos,
+    first: ExprRef, mut first_fn: F, mut body: B,
+) -> Option<Pos>
+    where F: FnMut(&mut Parser<'g>, Pos) -> Option<Pos>,
+          B: FnMut(&mut Parser<'g>, Pos) -> Option<Pos>,
+{
     let mut skipped = false;
     let mut tokens = tokens;
     loop {
         if skipped {
             p.finish();
         }
-        match parse_expr_pred(p, first, tokens) {
-            Some(_) => if let Some(ts) = parse_expr(p, body, tokens) {
+        match pred_with(p, tokens, &mut first_fn) {
+            Some(_) => if let Some(ts) = body(p, tokens) {
                 return Some(ts);
             }
             None => {}
@@ -275,7 +438,7 @@ fn parse_with_skip<'g>(
         if skipped {
             p.reopen()
         } else {
-            p.start_error()
+            p.start_error(ErrorKind::Skipped(first))
         }
         skipped = true;
         match p.bump(tokens) {
@@ -289,60 +452,77 @@ fn parse_enter<'g>(
     p: &mut Parser<'g>, tokens: Pos,
     ctx: Context, e: ExprRef,
 ) -> Option<Pos> {
-    let idx = ctx.0 as usize;
-    let old = p.contexts[idx];
-    p.contexts[idx] = true;
-    let result = parse_expr(p, e, tokens);
-    p.contexts[idx] = old;
-    result
+    context_with(p, tokens, ctx, true, |p, ts| parse_expr(p, e, ts))
 }
 
 fn parse_exit<'g>(
     p: &mut Parser<'g>, tokens: Pos,
     ctx: Context, e: ExprRef,
 ) -> Option<Pos> {
-    let idx = ctx.0 as usize;
-    let old = p.contexts[idx];
-    p.contexts[idx] = false;
-    let result = parse_expr(p, e, tokens);
-    p.contexts[idx] = old;
+    context_with(p, tokens, ctx, false, |p, ts| parse_expr(p, e, ts))
+}
+
+/// Parses `body` with `ctx` entered or exited.
+pub(crate) fn context_with<'g, F>(
+    p: &mut Parser<'g>, tokens: Pos,
+    ctx: Context, value: bool, body: F,
+) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    let old = p.contexts.set(ctx, value);
+    let result = body(p, tokens);
+    p.contexts.set(ctx, old);
     result
 }
 
-fn parse_is_in<'g>(
+pub(crate) fn parse_is_in<'g>(
     p: &mut Parser<'g>, tokens: Pos,
     ctx: Context,
 ) -> Option<Pos> {
-    if p.contexts[ctx.0 as usize] { Some(tokens) } else { None }
+    if p.contexts.contains(ctx) { Some(tokens) } else { None }
 }
 
 fn parse_call<'g>(
     p: &mut Parser<'g>, tokens: Pos,
-    body: ExprRef, args: &'g [(Arg, ExprRef)],
+    call: ExprRef, body: ExprRef, args: &'g [(Arg, ExprRef)],
 ) -> Option<Pos> {
-    let old = p.args;
-    for &(arg_pos, arg) in args {
-        let arg = match p.grammar[arg] {
-            Expr::Var(i) => p.args[i.0 as usize].unwrap(),
-            _ => arg
-        };
+    call_with(p, tokens, call, args, |p, ts| parse_expr(p, body, ts))
+}
 
-        p.args[arg_pos.0 as usize] = Some(arg);
-    }
-    let result = parse_expr(p, body, tokens);
-    p.args = old;
+pub(crate) fn call_with<'g, F>(
+    p: &mut Parser<'g>, tokens: Pos,
+    call: ExprRef, args: &'g [(Arg, ExprRef)], body: F,
+) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    let (old_frame, n_frames) = (p.frame, p.frames.len());
+    p.frames.push(Frame { call: Some(call), args, parent: old_frame });
+    p.frame = n_frames;
+    let result = body(p, tokens);
+    p.frames.truncate(n_frames);
+    p.frame = old_frame;
     result
 }
 
-fn parse_var<'g>(
+/// Parses the argument in the frame of the caller,
+/// where the argument expression was written.
+pub(crate) fn parse_var<'g>(
     p: &mut Parser<'g>, tokens: Pos,
     i: Arg,
 ) -> Option<Pos> {
-    let expr = p.args[i.0 as usize].unwrap();
-    parse_expr(p, expr, tokens)
+    let old_frame = p.frame;
+    let (expr, parent) = {
+        let frame = &p.frames[old_frame];
+        let &(_, expr) = frame.args.iter().find(|&&(arg, _)| arg.0 == i.0).unwrap();
+        (expr, frame.parent)
+    };
+    p.frame = parent;
+    let result = parse_expr(p, expr, tokens);
+    p.frame = old_frame;
+    result
 }
 
-fn parse_prev_is<'g>(
+pub(crate) fn parse_prev_is<'g>(
     p: &mut Parser<'g>, tokens: Pos,
     ts: &[NodeTypeRef],
 ) -> Option<Pos> {
@@ -357,14 +537,35 @@ fn parse_prev_is<'g>(
     None
 }
 
+pub(crate) fn parse_pred<'g>(
+    p: &mut Parser<'g>, tokens: Pos,
+    pred: PredicateRef,
+) -> Option<Pos> {
+    let f = p.grammar.predicates[pred.0 as usize];
+    p.copy_pending();
+    if f(&ParserView::new(p, tokens)) { Some(tokens) } else { None }
+}
+
 fn parse_inject<'g>(
     p: &mut Parser<'g>, pos: Pos,
     prefix: ExprRef, body: ExprRef,
 ) -> Option<Pos> {
-    let prefix_mark = p.mark();
-    let after_prefix = parse_expr(p, prefix, pos)?;
+    inject_with(p, pos, |p, ts| parse_expr(p, prefix, ts), |p, ts| parse_expr(p, body, ts))
+}
+
+pub(crate) fn inject_with<'g, F, B>(
+    p: &mut Parser<'g>, pos: Pos,
+    prefix: F, body: B,
+) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>,
+          B: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>,
+{
+    let prefix_mark = p.child_mark();
+    let after_prefix = prefix(p, pos);
+    p.child_parsed();
+    let after_prefix = after_prefix?;
     let body_mark = p.mark();
-    let result = parse_expr(p, body, after_prefix)?;
+    let result = body(p, after_prefix)?;
     if after_prefix != pos {
         p.forward_parent(prefix_mark, body_mark);
     }
@@ -372,10 +573,55 @@ fn parse_inject<'g>(
 }
 
 fn parse_cached<'g>(p: &mut Parser<'g>, expr: ExprRef, pos: Pos) -> Option<Pos> {
+    cached_with(p, expr, pos, |p, ts| parse_expr(p, expr, ts))
+}
 
-    let mark = p.start_cached(expr);
-    let result = p.get_from_cache(expr, pos).or_else(|| parse_expr(p, expr, pos))?;
+pub(crate) fn cached_with<'g, F>(p: &mut Parser<'g>, expr: ExprRef, pos: Pos, body: F) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    let mark = p.start_cached(expr, pos);
+    let result = p.get_from_cache(expr, pos).or_else(|| body(p, pos));
     p.finish_cached(mark);
+    result
+}
 
-    Some(result)
+/// Parses a left-recursive rule by growing a seed.
+///
+/// First, the body is parsed with the recursive call at `tokens`
+/// failing. Then the body is parsed again and again, with the recursive
+/// call returning the previous result, for as long as the result gets
+/// longer. So `rule e { e '+' t | t }` produces a left-nested tree.
+fn parse_left_rec<'g>(
+    p: &mut Parser<'g>, tokens: Pos,
+    expr: ExprRef, body: ExprRef,
+) -> Option<Pos> {
+    left_rec_with(p, tokens, expr, |p, ts| parse_expr(p, body, ts))
+}
+
+pub(crate) fn left_rec_with<'g, F>(
+    p: &mut Parser<'g>, tokens: Pos,
+    expr: ExprRef, mut body: F,
+) -> Option<Pos>
+    where F: FnMut(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    if let Some(result) = p.replay_seed(expr, tokens) {
+        return result;
+    }
+    p.plant_seed(expr, tokens);
+    loop {
+        let mark = p.mark();
+        let grown = match (body(p, tokens), p.seed_end(expr, tokens)) {
+            (Some(end), Some(prev_end)) if end.is_after(prev_end) => Some(end),
+            (Some(end), None) => Some(end),
+            _ => None,
+        };
+        match grown {
+            Some(end) => p.grow_seed(expr, tokens, end),
+            None => {
+                p.rollback(mark);
+                break;
+            }
+        }
+    }
+    p.harvest_seed(expr, tokens)
 }
@@ -1,13 +1,17 @@
 use std::collections::HashMap;
 
-use fall_tree::{NodeType, TextEdit, TextUnit, TextEditOp, tu, TextRange, ERROR};
+use fall_tree::{NodeType, TextEdit, TextUnit, TextEditOp, tu, TextRange, Expected, ERROR};
 use crate::lex_engine::Token;
-use crate::{Expr, ExprRef};
+use crate::{Expr, ExprRef, SemanticPredicate};
 
+#[derive(Clone, Copy)]
 pub struct Grammar<'g> {
     pub node_types: &'g [NodeType],
     pub rules: &'g [Expr],
     pub start_rule: ExprRef,
+    pub missing_tokens: bool,
+    pub native: Option<NativeParser>,
+    pub predicates: &'g [SemanticPredicate],
 }
 
 impl<'g> ::std::ops::Index<ExprRef> for Grammar<'g> {
@@ -18,23 +22,62 @@ impl<'g> ::std::ops::Index<ExprRef> for Grammar<'g> {
     }
 }
 
-mod parser;
+pub(crate) mod parser;
 mod expr;
+mod reparse;
 mod pratt;
+mod error;
+pub(crate) mod native;
+
+pub(crate) use self::native::NativeParser;
+
+pub(crate) use self::error::ErrorKind;
 
 pub(crate) use self::expr::parse;
+pub(crate) use self::parser::{LayerState, Failures, UNKNOWN};
+pub(crate) use self::reparse::{reparse_layer, OldParse};
 
 mod convert;
 
 pub(crate) use self::convert::convert;
 
 
+pub(crate) struct ParseResult {
+    pub events: Vec<Event>,
+    pub layers: Vec<LayerState>,
+    pub cached_expected: Vec<Failures>,
+    pub ticks: u64,
+    pub expected: Expected,
+    /// Why parsing was stopped early, see `ParseOptions`.
+    pub stopped: Option<ErrorKind>,
+    /// The number of non-whitespace tokens parsed.
+    pub n_tokens: u32,
+}
+
 #[derive(Copy, Clone, Debug)]
 pub(crate) enum Event {
     Start { ty: NodeType, forward_parent: Option<u32> },
+    /// Always follows the `Start` of an `ERROR` node.
+    Error { kind: ErrorKind },
     Token { ty: NodeType, n_raw_tokens: u16 },
+    /// A zero-width token synthesized in place of an absent one.
+    Missing { ty: NodeType },
     End,
-    Cached { key: u32, n_events: u32 },
+    /// Precedes the events of a `#[cached]` segment, `expected` is the index
+    /// of the failures inside it, or `UNKNOWN` if they are not known.
+    Cached { key: u32, n_events: u32, expected: u32 },
+    /// Precedes the events of a layer's body, `key` is the index of its `LayerState`.
+    Layer { key: u32, n_events: u32 },
+}
+
+/// Parts of the previous parse which can be reused.
+pub(crate) struct Salvaged<'a> {
+    /// `#[cached]` segments by the start offset in the new text and the rule,
+    /// as `(first event, number of events, number of tokens)`.
+    pub segments: HashMap<(TextUnit, ExprRef), (u32, u32, u32)>,
+    pub events: &'a [Event],
+    pub layers: &'a [LayerState],
+    pub expected: &'a [Failures],
 }
 
 pub(crate) fn salvage_segments(
@@ -75,12 +118,16 @@ pub(crate) fn salvage_segments(
         start_event += 1;
         match *event {
             Event::Start { .. } => (),
+            Event::Error { .. } => (),
+            Event::Missing { .. } => (),
             Event::End => (),
+            Event::Layer { .. } => (),
             Event::Token { n_raw_tokens, .. } =>
                 eat_tokens(&mut raw_token_pos, &mut text_pos, n_raw_tokens),
 
-            Event::Cached { key, n_events } => {
+            Event::Cached { key, n_events, expected } => {
                 let start = text_pos;
+                let start_token = raw_token_pos;
                 let mut n_tokens = 0u32;
                 let mut has_error = false;
                 for _ in 0..n_events {
@@ -89,11 +136,14 @@ pub(crate) fn salvage_segments(
                             eat_tokens(&mut raw_token_pos, &mut text_pos, n_raw_tokens);
                             n_tokens += n_raw_tokens as u32;
                         }
-                        Event::Start { ty: ERROR, .. } => has_error = true,
+                        Event::Start { ty: ERROR, .. } | Event::Missing { .. } => has_error = true,
                         _ => (),
                     }
                 }
-                if has_error {
+                // Zero-width layout tokens depend on the indentation
+                // outside of the segment, see `Layout`.
+                let has_layout = old_tokens[start_token..raw_token_pos].iter().any(|t| t.len == tu(0));
+                if has_error || has_layout || expected == UNKNOWN {
                     start_event += n_events;
                     continue
                 }
@@ -0,0 +1,170 @@
+//! Building blocks for parsers compiled to Rust code by `fall_gen`.
+//!
+//! A compiled parser is a function per expression of the grammar, built out
+//! of the combinators below. They share the implementation with the
+//! interpreter, so both produce exactly the same events. Node types, tokens
+//! and operator tables are passed to the combinators as constants, and
+//! subexpressions as closures, so the compiled parser does not look into
+//! `ParserDefinition::syntactical_rules`. Expressions referenced by index
+//! (rule arguments, pratt operators, cached and left-recursive bodies)
+//! are dispatched back to the compiled functions by the `NativeParser`.
+
+use crate::{ExprRef, NodeTypeRef, Context, Arg, PredicateRef, Prefix, Infix};
+use crate::syn_engine::parser::{Parser, Pos};
+use crate::syn_engine::pratt::{pratt_with, Operators};
+use crate::syn_engine::expr::{
+    parse_framed, pub_with, pub_replace_with, and_with, or_with, not_with, rep_with,
+    parse_token, parse_contextual_token, parse_eof, layer_with, with_skip_with,
+    context_with, parse_is_in, call_with, parse_var, parse_prev_is, parse_pred,
+    inject_with, cached_with, left_rec_with,
+};
+
+/// Parses expression `expr` if it is compiled, returns `None` otherwise.
+pub type NativeParser = fn(&mut Parser, ExprRef, Pos) -> Option<Option<Pos>>;
+
+/// Runs the compiled body of `expr` with the same bookkeeping
+/// the interpreter does for each expression.
+pub fn expr<'g, F>(p: &mut Parser<'g>, expr: u32, pos: Pos, body: F) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    parse_framed(p, ExprRef(expr), pos, body)
+}
+
+pub fn pub_node<'g, F>(p: &mut Parser<'g>, ty: u32, replaceable: bool, pos: Pos, body: F) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    pub_with(p, pos, NodeTypeRef(ty), replaceable, body)
+}
+
+pub fn pub_replace<'g, F>(p: &mut Parser<'g>, ty: u32, pos: Pos, body: F) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    pub_replace_with(p, pos, NodeTypeRef(ty), body)
+}
+
+/// `parts` are used for error recovery only.
+pub fn and<'g, F>(p: &mut Parser<'g>, parts: &'g [ExprRef], commit: Option<usize>, pos: Pos, part: F) -> Option<Pos>
+    where F: FnMut(&mut Parser<'g>, usize, Pos) -> Option<Pos>
+{
+    and_with(p, pos, parts, commit, part)
+}
+
+pub fn or<'g, F>(p: &mut Parser<'g>, n_options: usize, pos: Pos, option: F) -> Option<Pos>
+    where F: FnMut(&mut Parser<'g>, usize, Pos) -> Option<Pos>
+{
+    or_with(p, pos, n_options, option)
+}
+
+pub fn opt<'g, F>(p: &mut Parser<'g>, pos: Pos, body: F) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    Some(body(p, pos).unwrap_or(pos))
+}
+
+pub fn rep<'g, F>(p: &mut Parser<'g>, pos: Pos, body: F) -> Option<Pos>
+    where F: FnMut(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    rep_with(p, pos, body)
+}
+
+pub fn not<'g, F>(p: &mut Parser<'g>, pos: Pos, body: F) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    not_with(p, pos, body)
+}
+
+pub fn token(p: &mut Parser, ty: u32, pos: Pos) -> Option<Pos> {
+    parse_token(p, pos, NodeTypeRef(ty))
+}
+
+pub fn contextual_token(p: &mut Parser, ty: u32, text: &str, pos: Pos) -> Option<Pos> {
+    parse_contextual_token(p, pos, NodeTypeRef(ty), text)
+}
+
+pub fn eof(p: &mut Parser, pos: Pos) -> Option<Pos> {
+    parse_eof(p, pos)
+}
+
+pub fn any(p: &mut Parser, pos: Pos) -> Option<Pos> {
+    p.bump(pos).map(|(_ty, ts)| ts)
+}
+
+/// `expr` is the layer expression itself, it identifies the layer when reparsing.
+pub fn layer<'g, C, B>(p: &mut Parser<'g>, expr: u32, pos: Pos, cover: C, body: B) -> Option<Pos>
+    where C: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>,
+          B: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>,
+{
+    layer_with(p, pos, ExprRef(expr), cover, body)
+}
+
+/// `first` is the index of the expression parsed by `first_fn`, for error reporting.
+pub fn with_skip<'g, F, B>(p: &mut Parser<'g>, first: u32, pos: Pos, first_fn: F, body: B) -> Option<Pos>
+    where F: FnMut(&mut Parser<'g>, Pos) -> Option<Pos>,
+          B: FnMut(&mut Parser<'g>, Pos) -> Option<Pos>,
+{
+    with_skip_with(p, pos, ExprRef(first), first_fn, body)
+}
+
+pub fn pratt<'g>(
+    p: &mut Parser<'g>,
+    atoms: &'g [ExprRef], prefixes: &'g [Prefix], infixes: &'g [Infix],
+    pos: Pos,
+) -> Option<Pos> {
+    pratt_with(p, Operators { atoms, prefixes, infixes }, pos)
+}
+
+pub fn enter<'g, F>(p: &mut Parser<'g>, ctx: u32, pos: Pos, body: F) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    context_with(p, pos, Context(ctx), true, body)
+}
+
+pub fn exit<'g, F>(p: &mut Parser<'g>, ctx: u32, pos: Pos, body: F) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    context_with(p, pos, Context(ctx), false, body)
+}
+
+pub fn is_in(p: &mut Parser, ctx: u32, pos: Pos) -> Option<Pos> {
+    parse_is_in(p, pos, Context(ctx))
+}
+
+/// `expr` is the call expression itself, `args` are parsed on `var`.
+pub fn call<'g, F>(p: &mut Parser<'g>, expr: u32, args: &'g [(Arg, ExprRef)], pos: Pos, body: F) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    call_with(p, pos, ExprRef(expr), args, body)
+}
+
+pub fn var(p: &mut Parser, arg: u32, pos: Pos) -> Option<Pos> {
+    parse_var(p, pos, Arg(arg))
+}
+
+pub fn prev_is(p: &mut Parser, tys: &[NodeTypeRef], pos: Pos) -> Option<Pos> {
+    parse_prev_is(p, pos, tys)
+}
+
+pub fn inject<'g, F, B>(p: &mut Parser<'g>, pos: Pos, prefix: F, body: B) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>,
+          B: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>,
+{
+    inject_with(p, pos, prefix, body)
+}
+
+/// `body` is the index of the expression parsed by `body_fn`, it is the key in the cache.
+pub fn cached<'g, F>(p: &mut Parser<'g>, body: u32, pos: Pos, body_fn: F) -> Option<Pos>
+    where F: FnOnce(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    cached_with(p, ExprRef(body), pos, body_fn)
+}
+
+/// `expr` is the left-recursive rule itself, its seeds are keyed by it.
+pub fn left_rec<'g, F>(p: &mut Parser<'g>, expr: u32, pos: Pos, body: F) -> Option<Pos>
+    where F: FnMut(&mut Parser<'g>, Pos) -> Option<Pos>
+{
+    left_rec_with(p, pos, ExprRef(expr), body)
+}
+
+pub fn pred(p: &mut Parser, pred: u32, pos: Pos) -> Option<Pos> {
+    parse_pred(p, pos, PredicateRef(pred))
+}
@@ -1,48 +1,361 @@
-use std::collections::HashMap;
+use std::cell::{Ref, RefCell};
+use std::cmp::min;
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Range;
 
-use fall_tree::{TextUnit, Text, TextSuffix, NodeType, tu};
+use fall_tree::{TextUnit, Text, TextRange, TextSuffix, NodeType, Expected, ExpectedToken, ParseOptions, tu};
 use crate::lex_engine::Token;
-use crate::syn_engine::{Grammar, Event};
-use crate::{NodeTypeRef, ExprRef};
+use crate::syn_engine::{Grammar, Event, ErrorKind, ParseResult, Salvaged};
+use crate::{Expr, NodeTypeRef, ExprRef, Context, Arg};
 
-pub(crate) struct Parser<'g> {
-    cache: Option<(HashMap<(TextUnit, ExprRef), (u32, u32, u32)>, &'g [Event])>,
-    pub grammar: &'g Grammar<'g>,
+pub struct Parser<'g> {
+    cache: Option<Salvaged<'g>>,
+    /// Left-recursive rules which are being parsed, see `Expr::LeftRec`.
+    seeds: HashMap<(ExprRef, Pos), Seed>,
+    /// Keys of the `seeds`, innermost last.
+    growing: Vec<(ExprRef, Pos)>,
+    /// Uses of the seeds, undone by `rollback`.
+    replays: Vec<Replay>,
+    /// Events after the `events`, see `PendingCopy`.
+    pending: Option<PendingCopy>,
+    /// Starts of the children which may get a forward parent, see `child_mark`.
+    forward_children: Vec<u32>,
+    pub(crate) grammar: &'g Grammar<'g>,
     text: Text<'g>,
     tokens: &'g [Token],
     non_ws_indexes: Vec<(TextUnit, usize)>,
 
+    options: &'g ParseOptions,
     ticks: u64,
+    depth: u32,
+    /// Set when parsing has to stop early, see `ParseOptions`.
+    stopped: Option<ErrorKind>,
     events: Vec<Event>,
-    pub replacement: Option<NodeTypeRef>,
-    pub predicate_mode: bool,
-    pub contexts: [bool; 16],
-    pub args: [Option<ExprRef>; 16],
-    pub prev: Option<NodeType>,
+    /// See `ParserView::completed_nodes`.
+    completed: RefCell<CompletedNodes<'g>>,
+    /// Index of the furthest non-whitespace token at which a token
+    /// failed to match, and the tokens tried there.
+    expected: Failures,
+    /// Failures inside the `#[cached]` segments, indexed by `Event::Cached::expected`.
+    cached_expected: Vec<Failures>,
+    /// Starts of the `#[cached]` segments which are being parsed, and the failures
+    /// inside them, recorded even after the first error.
+    open_cached: Vec<(u32, Failures)>,
+    /// Position of the first `ERROR` or `Missing` event. Failures after the first error
+    /// are mostly caused by the error recovery, so they are not recorded.
+    first_error: Option<u32>,
+    pub(crate) replacement: Option<NodeTypeRef>,
+    pub(crate) predicate_mode: bool,
+    pub(crate) contexts: Contexts,
+    /// Arguments of the parameterized rules being parsed, see `Expr::Call`.
+    pub(crate) frames: Vec<Frame<'g>>,
+    /// Index of the frame `Expr::Var` refers to.
+    pub(crate) frame: usize,
+    pub(crate) prev: Option<NodeType>,
+    /// States at the start of the layers, indexed by `Event::Layer::key`.
+    layers: Vec<LayerState>,
 }
 
-#[derive(Copy, Clone, Debug, Eq, PartialEq)]
-pub(crate) struct Pos(u32, u32);
+/// Tokens which failed to match, as the index of the furthest token
+/// and the tokens tried there. In a `#[cached]` segment, the index
+/// is relative to the start of the segment.
+pub(crate) type Failures = (u32, Vec<ExpectedToken>);
+
+/// `Event::Cached::expected` of a segment which encloses a reparsed layer.
+pub(crate) const UNKNOWN: u32 = !0;
+
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub struct Pos(pub(crate) u32, pub(crate) u32);
 
 impl Pos {
-    pub fn next(self) -> Pos {
+    pub(crate) fn next(self) -> Pos {
         Pos(self.0 + 1, self.1)
     }
 
-    pub fn is_empty(self) -> bool {
+    pub(crate) fn is_empty(self) -> bool {
         self.0 == self.1
     }
+
+    pub(crate) fn is_after(self, other: Pos) -> bool {
+        self.0 > other.0
+    }
+}
+
+/// Number of events and of seed replays.
+pub(crate) struct Mark(u32, u32);
+
+/// The set of contexts the parser is in, see `Expr::Enter`.
+#[derive(Default, Clone)]
+pub(crate) struct Contexts(Vec<u64>);
+
+impl Contexts {
+    pub(crate) fn contains(&self, ctx: Context) -> bool {
+        let (word, bit) = (ctx.0 as usize / 64, ctx.0 % 64);
+        self.0.get(word).map_or(false, |&w| w & (1 << bit) != 0)
+    }
+
+    /// Returns the previous value.
+    pub(crate) fn set(&mut self, ctx: Context, value: bool) -> bool {
+        let old = self.contains(ctx);
+        let (word, bit) = (ctx.0 as usize / 64, ctx.0 % 64);
+        if self.0.len() <= word {
+            self.0.resize(word + 1, 0);
+        }
+        if value {
+            self.0[word] |= 1 << bit;
+        } else {
+            self.0[word] &= !(1 << bit);
+        }
+        old
+    }
+}
+
+/// Arguments of a call of a parameterized rule.
+pub(crate) struct Frame<'g> {
+    /// The `Expr::Call`, `None` for the root frame.
+    pub(crate) call: Option<ExprRef>,
+    pub(crate) args: &'g [(Arg, ExprRef)],
+    /// The frame of the caller, in which the arguments are parsed.
+    pub(crate) parent: usize,
+}
+
+/// The parser state at the start of a layer, which is enough
+/// to parse the layer again on its own, see `reparse_layer`.
+#[derive(Clone)]
+pub(crate) struct LayerState {
+    /// The `Expr::Layer`.
+    pub(crate) layer: ExprRef,
+    /// Calls of the parameterized rules the layer is nested in, outermost first.
+    pub(crate) calls: Vec<ExprRef>,
+    pub(crate) contexts: Contexts,
+    pub(crate) prev: Option<NodeType>,
+    pub(crate) replacement: Option<NodeTypeRef>,
+    /// The parsing after the layer depends on these.
+    pub(crate) prev_after: Option<NodeType>,
+    pub(crate) replacement_after: Option<NodeTypeRef>,
+}
+
+/// What a semantic predicate can look at, see `Expr::Pred`.
+pub struct ParserView<'p, 'g: 'p> {
+    parser: &'p Parser<'g>,
+    pos: Pos,
+}
+
+impl<'p, 'g> ParserView<'p, 'g> {
+    pub(crate) fn new(parser: &'p Parser<'g>, pos: Pos) -> ParserView<'p, 'g> {
+        ParserView { parser, pos }
+    }
+
+    /// The `n`-th upcoming non-whitespace token, starting from zero,
+    /// with its text.
+    pub fn nth_token(&self, n: u32) -> Option<(NodeType, Text<'p>)> {
+        let idx = self.pos.0 + n;
+        if idx >= self.pos.1 {
+            return None;
+        }
+        let (offset, token_idx) = self.parser.non_ws_indexes[idx as usize];
+        let token = self.parser.tokens[token_idx];
+        Some((token.ty, self.parser.text.slice(TextRange::from_len(offset, token.len))))
+    }
+
+    /// The type of the last completed public node.
+    pub fn prev(&self) -> Option<NodeType> {
+        self.parser.prev
+    }
+
+    /// Nodes completed so far, in the order they were finished,
+    /// with their text.
+    pub fn completed_nodes(&self) -> Ref<'_, [(NodeType, Text<'g>)]> {
+        let p = self.parser;
+        p.completed.borrow_mut().update(p);
+        Ref::map(p.completed.borrow(), |completed| &completed.nodes[..])
+    }
+}
+
+/// The nodes completed so far. Only the events which were added
+/// or changed since the previous `update` are scanned.
+#[derive(Default)]
+struct CompletedNodes<'g> {
+    nodes: Vec<(NodeType, Text<'g>)>,
+    /// The `Start` and `End` events of the `nodes`, and their first tokens.
+    spans: Vec<(u32, u32, usize)>,
+    /// The `Start` events of the nodes which are not completed yet,
+    /// and their first tokens.
+    open: Vec<(u32, usize)>,
+    /// A node which is a forward parent starts where its child does.
+    /// Keyed by the `Start` of the parent, with the `Start` of the child.
+    forwarded: BTreeMap<u32, (u32, usize)>,
+    /// The number of tokens before each of the scanned events, and after the last one.
+    n_tokens: Vec<usize>,
+    /// The events of the seeds which are not a part of the tree, see `Parser::is_hidden`.
+    hidden: Vec<Range<u32>>,
+    /// The number of the scanned events which did not change since.
+    valid: u32,
 }
 
-pub(crate) struct Mark(u32);
+impl<'g> CompletedNodes<'g> {
+    fn invalidate(&mut self, event: u32) {
+        self.valid = min(self.valid, event);
+    }
+
+    fn update(&mut self, p: &Parser<'g>) {
+        let mut hidden: Vec<Range<u32>> = p.seeds.iter()
+            .filter(|&(&key, _)| p.is_hidden(key))
+            .map(|(_, seed)| seed.own_start..seed.events_end)
+            .collect();
+        hidden.sort_by_key(|range| range.start);
+        if hidden != self.hidden {
+            let changed = hidden.iter().filter(|range| !self.hidden.contains(range))
+                .chain(self.hidden.iter().filter(|range| !hidden.contains(range)))
+                .map(|range| range.start)
+                .min();
+            if let Some(event) = changed {
+                self.invalidate(event);
+            }
+            self.hidden = hidden;
+        }
+        self.rewind();
+
+        let range = |start: usize, end: usize| {
+            if start == end {
+                let offset = p.non_ws_indexes.get(start).map_or(p.text.len(), |&(offset, _)| offset);
+                return TextRange::from_len(offset, tu(0));
+            }
+            let (last, last_idx) = p.non_ws_indexes[end - 1];
+            TextRange::from_to(p.non_ws_indexes[start].0, last + p.tokens[last_idx].len)
+        };
+        let mut i = self.valid;
+        while (i as usize) < p.events.len() {
+            let mut n_tokens = *self.n_tokens.last().unwrap();
+            if let Some(hidden) = self.hidden.iter().find(|range| range.contains(&i)) {
+                let end = min(hidden.end as usize, p.events.len());
+                self.n_tokens.resize(end + 1, n_tokens);
+                i = end as u32;
+                continue;
+            }
+            match p.events[i as usize] {
+                Event::Start { forward_parent, .. } => {
+                    let start = self.forwarded.get(&i).map_or(n_tokens, |&(_, start)| start);
+                    if let Some(offset) = forward_parent {
+                        self.forwarded.insert(i + offset, (i, start));
+                    }
+                    self.open.push((i, start));
+                }
+                Event::Token { n_raw_tokens, .. } => n_tokens += n_raw_tokens as usize,
+                Event::End => {
+                    let (start_event, start) = self.open.pop().unwrap();
+                    let ty = match p.events[start_event as usize] {
+                        Event::Start { ty, .. } => ty,
+                        _ => unreachable!(),
+                    };
+                    self.nodes.push((ty, p.text.slice(range(start, n_tokens))));
+                    self.spans.push((start_event, i, start));
+                }
+                Event::Error { .. } | Event::Missing { .. } | Event::Cached { .. } | Event::Layer { .. } => (),
+            }
+            self.n_tokens.push(n_tokens);
+            i += 1;
+        }
+        self.valid = i;
+    }
+
+    /// Restores the state after the `valid` events.
+    fn rewind(&mut self) {
+        let valid = self.valid;
+        if self.n_tokens.is_empty() {
+            self.n_tokens.push(0);
+        }
+        if self.n_tokens.len() <= valid as usize + 1 {
+            return;
+        }
+        self.n_tokens.truncate(valid as usize + 1);
+        let n_open = self.open.partition_point(|&(start, _)| start < valid);
+        self.open.truncate(n_open);
+        let n_nodes = self.spans.partition_point(|&(_, end, _)| end < valid);
+        self.open.extend(self.spans[n_nodes..].iter()
+            .filter(|&&(start, _, _)| start < valid)
+            .map(|&(start, _, first_token)| (start, first_token)));
+        self.open.sort_by_key(|&(start, _)| start);
+        self.spans.truncate(n_nodes);
+        self.nodes.truncate(n_nodes);
+        let forwarded = self.forwarded.split_off(&valid);
+        self.forwarded.extend(forwarded.into_iter().filter(|&(_, (child, _))| child < valid));
+    }
+}
+
+/// The longest match of a left-recursive rule found so far. Its events
+/// stay where they were parsed, so that growing the seed does not copy them.
+struct Seed {
+    end: Option<Pos>,
+    /// The events of the seed are `events[start..events_end]`. They begin
+    /// with the events of the `outer` seed, if it was replayed in place.
+    start: u32,
+    /// Where the seed was planted, the events from here on are dropped
+    /// when the seed is replaced.
+    own_start: u32,
+    events_end: u32,
+    /// The chain of forward parents the events of the seed end with.
+    /// It starts at `start`, or at the end of the chain of the `outer` seed.
+    chain: Vec<u32>,
+    /// Index of the replay of an outer seed in place at the start of the events.
+    outer: Option<usize>,
+    /// Replays which use the events in place and were not rolled back.
+    n_in_place: u32,
+    /// Index of the first of `Parser::replays` made by the events of the seed.
+    old_replays: usize,
+    /// Index of the first of `Parser::replays` made since the seed was grown.
+    new_replays: usize,
+    /// There was an error before the seed, so errors of the seed are not hidden.
+    after_error: bool,
+    /// Position of the first error among the events of the seed,
+    /// which is hidden until the seed is replayed.
+    first_error: Option<u32>,
+    prev: Option<NodeType>,
+}
+
+/// A use of a seed, undone by `rollback`.
+struct Replay {
+    seed: (ExprRef, Pos),
+    kind: ReplayKind,
+    /// `Parser::first_error` before the replay.
+    first_error: Option<u32>,
+}
+
+#[derive(Clone, Copy)]
+enum ReplayKind {
+    /// The events of the seed directly precede the replay.
+    InPlace,
+    /// The only event between the seed and the replay is a `Start`,
+    /// which became the forward parent of the `Start` at `from`.
+    Linked { from: u32 },
+    /// Like `Linked`, but the seed was used by the events of the innermost
+    /// seed, and was moved from there. `lend` is that use, now `Suspended`.
+    Moved { from: u32, lend: usize },
+    /// A `Linked` replay of a seed which was `Moved`.
+    Suspended { from: u32 },
+    Copied,
+    /// The replay was made by the events of a seed which was replaced.
+    Dropped,
+}
+
+/// A copy of a seed, which is made only when an event is added after it:
+/// most of the replays which copy a seed fail and are rolled back right away.
+struct PendingCopy {
+    start: u32,
+    end: u32,
+    /// The copy of the `Start` at `fix.0` gets the forward parent at `fix.1`,
+    /// instead of the one outside of the seed.
+    fix: Option<(u32, Option<u32>)>,
+}
 
 impl<'g> Parser<'g> {
-    pub fn new(
-        cache: Option<(HashMap<(TextUnit, ExprRef), (u32, u32, u32)>, &'g [Event])>,
+    pub(crate) fn new(
+        cache: Option<Salvaged<'g>>,
         grammar: &'g Grammar<'g>,
         is_ws: &dyn Fn(Token) -> bool,
         text: Text<'g>,
         tokens: &'g [Token],
+        options: &'g ParseOptions,
     ) -> (Parser<'g>, Pos) {
         let non_ws_indexes = {
             let mut indexes = Vec::new();
@@ -60,50 +373,153 @@ impl<'g> Parser<'g> {
 
         let parser = Parser {
             cache,
+            seeds: HashMap::new(),
+            growing: Vec::new(),
+            replays: Vec::new(),
+            pending: None,
+            forward_children: Vec::new(),
             grammar,
             text,
             tokens,
             non_ws_indexes,
 
+            options,
             ticks: 0,
+            depth: 0,
+            stopped: None,
             events: Vec::new(),
+            completed: RefCell::new(CompletedNodes::default()),
+            expected: (0, Vec::new()),
+            cached_expected: Vec::new(),
+            open_cached: Vec::new(),
+            first_error: None,
             replacement: None,
             predicate_mode: false,
-            contexts: [false; 16],
-            args: [None; 16],
+            contexts: Contexts::default(),
+            frames: vec![Frame { call: None, args: &[], parent: 0 }],
+            frame: 0,
             prev: None,
+            layers: Vec::new(),
         };
         (parser, pos)
     }
 
 
-    pub fn done(self) -> (Vec<Event>, u64) {
-        (self.events, self.ticks)
+    pub(crate) fn done(mut self) -> ParseResult {
+        self.copy_pending();
+        let (idx, tokens) = self.expected;
+        let offset = match self.non_ws_indexes.get(idx as usize) {
+            Some(&(offset, _)) => offset,
+            None => self.text.len(),
+        };
+        // Layers and segments of the rolled back events are dropped.
+        let old_layers = ::std::mem::take(&mut self.layers);
+        let old_expected = ::std::mem::take(&mut self.cached_expected);
+        let (layers, cached_expected) = compact(
+            &mut self.events,
+            &|key| &old_layers[key as usize],
+            &|key| &old_expected[key as usize],
+        );
+        ParseResult {
+            events: self.events,
+            layers,
+            cached_expected,
+            ticks: self.ticks,
+            expected: Expected { offset, tokens },
+            stopped: self.stopped,
+            n_tokens: self.non_ws_indexes.len() as u32,
+        }
     }
 
-    pub fn tick(&mut self) {
-        self.ticks += 1
+    pub(crate) fn non_ws_index(&self, raw_index: usize) -> usize {
+        self.non_ws_indexes.partition_point(|&(_, idx)| idx < raw_index)
     }
 
+    pub(crate) fn tick(&mut self) {
+        self.ticks += 1;
+        if self.stopped.is_none() && (
+            self.options.tick_budget.map_or(false, |budget| self.ticks > budget)
+                || self.options.is_cancelled()
+        ) {
+            self.stopped = Some(ErrorKind::Stopped);
+        }
+    }
 
-    pub fn start(&mut self, ty_idx: NodeTypeRef) -> Mark {
+    pub(crate) fn enter(&mut self) {
+        self.depth += 1;
+        if self.stopped.is_none() && self.depth > self.options.max_depth {
+            self.stopped = Some(ErrorKind::TooDeep);
+        }
+    }
+
+    pub(crate) fn exit(&mut self) {
+        self.depth -= 1;
+    }
+
+    pub(crate) fn is_stopped(&self) -> bool {
+        self.stopped.is_some()
+    }
+
+    pub(crate) fn stop_reason(&self) -> Option<ErrorKind> {
+        self.stopped
+    }
+
+
+    pub(crate) fn start(&mut self, ty_idx: NodeTypeRef) -> Mark {
         let ty = self[ty_idx];
         self.start_ty(ty)
     }
 
-    pub fn start_error(&mut self) {
+    pub(crate) fn start_error(&mut self, kind: ErrorKind) {
+        self.mark_error();
         self.start_ty(::fall_tree::ERROR);
+        self.event(Event::Error { kind });
+    }
+
+    pub(crate) fn missing(&mut self, ty_idx: NodeTypeRef) {
+        self.mark_error();
+        let ty = self[ty_idx];
+        self.event(Event::Missing { ty });
     }
 
-    pub fn finish(&mut self) {
+    pub(crate) fn finish(&mut self) {
         self.event(Event::End)
     }
 
-    pub fn get_from_cache(&mut self, expr: ExprRef, pos: Pos) -> Option<Pos> {
-        if let Some((ref cache, events)) = self.cache {
-            let text_pos = self.non_ws_indexes[pos.0 as usize].0;
-            if let Some(&(start_event, n_events, n_tokens)) = cache.get(&(text_pos, expr)) {
-                self.events.extend_from_slice(&events[start_event as usize..(start_event + n_events) as usize]);
+    /// Salvages a segment of the previous parse, unless the grammar has
+    /// semantic predicates: the segment may depend on their old state.
+    pub(crate) fn get_from_cache(&mut self, expr: ExprRef, pos: Pos) -> Option<Pos> {
+        if !self.grammar.predicates.is_empty() {
+            return None;
+        }
+        if let Some(Salvaged { ref segments, events, layers, expected }) = self.cache {
+            let &(text_pos, idx) = self.non_ws_indexes.get(pos.0 as usize)?;
+            // Segments are keyed by offset, which zero-width tokens share with the next token.
+            if self.tokens[idx].len == tu(0) {
+                return None;
+            }
+            if let Some(&(start_event, n_events, n_tokens)) = segments.get(&(text_pos, expr)) {
+                for &event in &events[start_event as usize..(start_event + n_events) as usize] {
+                    let event = match event {
+                        Event::Layer { key, n_events } if !self.predicate_mode => {
+                            self.layers.push(layers[key as usize].clone());
+                            Event::Layer { key: self.layers.len() as u32 - 1, n_events }
+                        }
+                        Event::Cached { key, n_events, expected: failures } if !self.predicate_mode && failures != UNKNOWN => {
+                            self.cached_expected.push(expected[failures as usize].clone());
+                            Event::Cached { key, n_events, expected: self.cached_expected.len() as u32 - 1 }
+                        }
+                        event => event,
+                    };
+                    self.event(event);
+                }
+                // The segment is preceded by its own `Event::Cached`.
+                if let Event::Cached { expected: failures, .. } = events[start_event as usize - 1] {
+                    let (idx, ref tokens) = expected[failures as usize];
+                    for token in tokens.iter() {
+                        self.expect(Pos(pos.0 + idx, pos.1), token.clone());
+                    }
+                }
                 return Some(Pos(pos.0 + n_tokens, pos.1));
             }
         }
@@ -111,61 +527,456 @@ impl<'g> Parser<'g> {
         return None;
     }
 
-    pub fn start_cached(&mut self, expr: ExprRef) -> Mark {
+    pub(crate) fn start_cached(&mut self, expr: ExprRef, pos: Pos) -> Mark {
+        self.open_cached.push((pos.0, (0, Vec::new())));
         let mark = self.mark();
-        self.event(Event::Cached { key: expr.0, n_events: 0 });
+        self.event(Event::Cached { key: expr.0, n_events: 0, expected: 0 });
         mark
     }
 
-    pub fn finish_cached(&mut self, mark: Mark) {
-        if !self.predicate_mode {
-            let len = self.events.len() as u32 - (mark.0 + 1);
-            match self.events[mark.0 as usize] {
-                Event::Cached { ref mut n_events, .. } => *n_events = len,
+    /// Records the failures inside the segment, which also count for the enclosing one.
+    pub(crate) fn finish_cached(&mut self, mark: Mark) {
+        let (start, (idx, tokens)) = self.open_cached.pop().unwrap();
+        if let Some(&mut (_, ref mut outer)) = self.open_cached.last_mut() {
+            for token in tokens.iter() {
+                record_failure(outer, idx, token.clone());
+            }
+        }
+        if self.predicate_mode {
+            return;
+        }
+        let failures = if tokens.is_empty() { (0, tokens) } else { (idx - start, tokens) };
+        self.cached_expected.push(failures);
+        let key = self.cached_expected.len() as u32 - 1;
+        let len = self.len() - (mark.0 + 1);
+        match self.events[mark.0 as usize] {
+            Event::Cached { ref mut n_events, ref mut expected, .. } => {
+                *n_events = len;
+                *expected = key;
+            }
+            _ => unreachable!(),
+        }
+    }
+
+    /// Records the state at the start of a layer, unless the layer can't be
+    /// reparsed on its own: the state of the semantic predicates is not known.
+    ///
+    /// `prev` and `replacement` are the values before the cover rule.
+    pub(crate) fn start_layer(
+        &mut self,
+        layer: ExprRef,
+        prev: Option<NodeType>,
+        replacement: Option<NodeTypeRef>,
+    ) -> Option<Mark> {
+        if self.predicate_mode || !self.grammar.predicates.is_empty() {
+            return None;
+        }
+        let mut calls = Vec::new();
+        let mut frame = self.frame;
+        while let Some(call) = self.frames[frame].call {
+            calls.push(call);
+            frame = self.frames[frame].parent;
+        }
+        calls.reverse();
+        self.layers.push(LayerState {
+            layer,
+            calls,
+            contexts: self.contexts.clone(),
+            prev,
+            replacement,
+            prev_after: None,
+            replacement_after: None,
+        });
+        let mark = self.mark();
+        self.event(Event::Layer { key: self.layers.len() as u32 - 1, n_events: 0 });
+        Some(mark)
+    }
+
+    pub(crate) fn finish_layer(&mut self, mark: Mark) {
+        let len = self.len() - (mark.0 + 1);
+        let key = match self.events[mark.0 as usize] {
+            Event::Layer { key, ref mut n_events } => {
+                *n_events = len;
+                key
+            }
+            _ => unreachable!(),
+        };
+        let state = &mut self.layers[key as usize];
+        state.prev_after = self.prev;
+        state.replacement_after = self.replacement;
+    }
+
+    /// Restores the state at the start of the layer.
+    pub(crate) fn enter_layer(&mut self, state: &LayerState) {
+        self.frames.truncate(1);
+        for &call in state.calls.iter() {
+            let args = match self.grammar[call] {
+                Expr::Call(_, ref args) => &args[..],
                 _ => unreachable!(),
+            };
+            let parent = self.frames.len() - 1;
+            self.frames.push(Frame { call: Some(call), args, parent });
+        }
+        self.frame = self.frames.len() - 1;
+        self.contexts = state.contexts.clone();
+        self.prev = state.prev;
+        self.replacement = state.replacement;
+    }
+
+    /// The result of a left-recursive call, if the rule is being grown at `pos`.
+    ///
+    /// The events of the seed are reused in place if they directly precede
+    /// the call, possibly after a `Start` of a node which wraps the seed.
+    /// Otherwise, they are copied.
+    pub(crate) fn replay_seed(&mut self, expr: ExprRef, pos: Pos) -> Option<Option<Pos>> {
+        let key = (expr, pos);
+        let (end, prev) = match self.seeds.get(&key) {
+            Some(seed) => (seed.end, seed.prev),
+            None => return None,
+        };
+        if !self.predicate_mode && end.is_some() {
+            self.copy_pending();
+            let kind = self.replay_kind(key);
+            let first_error = self.first_error;
+            let len = self.events.len() as u32;
+            if let ReplayKind::Copied = kind {
+                let seed = &self.seeds[&key];
+                if let (None, Some(error)) = (self.first_error, seed.first_error) {
+                    self.first_error = Some(len + error - seed.start);
+                }
+                let fix = self.copy_fix(seed);
+                self.pending = Some(PendingCopy { start: seed.start, end: seed.events_end, fix });
+            } else {
+                let seed = self.seeds.get_mut(&key).unwrap();
+                if self.first_error.is_none() {
+                    self.first_error = seed.first_error;
+                }
+                match kind {
+                    ReplayKind::InPlace => seed.n_in_place += 1,
+                    ReplayKind::Linked { from } => {
+                        set_forward_parent(&mut self.events, self.completed.get_mut(), from, Some(len - 1 - from));
+                        seed.n_in_place += 1;
+                        seed.chain.push(len - 1);
+                    }
+                    ReplayKind::Moved { from, lend } => {
+                        set_forward_parent(&mut self.events, self.completed.get_mut(), from, Some(len - 1 - from));
+                        *seed.chain.last_mut().unwrap() = len - 1;
+                        self.replays[lend].kind = ReplayKind::Suspended { from };
+                    }
+                    _ => unreachable!(),
+                }
+            }
+            self.replays.push(Replay { seed: key, kind, first_error });
+        }
+        self.prev = prev;
+        Some(end)
+    }
+
+    fn replay_kind(&self, key: (ExprRef, Pos)) -> ReplayKind {
+        let seed = &self.seeds[&key];
+        let len = self.events.len() as u32;
+        let is_bare_start = |idx: u32| matches!(self.events[idx as usize], Event::Start { forward_parent: None, .. });
+        // A seed which is used in place can't get a forward parent, see `forward_parent`.
+        if self.forward_children.last() == Some(&len) || self.is_moved(seed) {
+            return ReplayKind::Copied;
+        }
+        // An outer seed can be used in place only by the events of the innermost
+        // one, which is then grown with it, see `grow_seed`.
+        let inner = match self.growing.last() {
+            Some(inner) if *inner != key => Some(&self.seeds[inner]),
+            _ => None,
+        };
+        if inner.map_or(false, |inner| inner.own_start != seed.events_end) {
+            return ReplayKind::Copied;
+        }
+        let tail = *seed.chain.last().unwrap();
+        if seed.n_in_place == 0 {
+            if len == seed.events_end {
+                return ReplayKind::InPlace;
+            }
+            if len == seed.events_end + 1 && seed.start < seed.events_end
+                && is_bare_start(tail) && is_bare_start(seed.events_end) {
+                return ReplayKind::Linked { from: tail };
+            }
+        } else if let Some(inner) = inner.filter(|inner| inner.n_in_place == 0) {
+            // The seed is used by the events of the innermost seed only,
+            // which are not used themselves.
+            if let Some(&Replay { seed: lender, kind: ReplayKind::Linked { from }, .. }) = inner.outer.map(|lend| &self.replays[lend]) {
+                if lender == key && len == inner.events_end + 1 && is_bare_start(inner.events_end) {
+                    return ReplayKind::Moved { from, lend: inner.outer.unwrap() };
+                }
+            }
+        }
+        ReplayKind::Copied
+    }
+
+    /// Whether the `outer` seed was moved from the events of the seed.
+    fn is_moved(&self, seed: &Seed) -> bool {
+        matches!(seed.outer.map(|lend| self.replays[lend].kind), Some(ReplayKind::Suspended { .. }))
+    }
+
+    /// Restores the forward parent of the last `Start` of the copied seed
+    /// in the copy: it is not used in place, but it may be linked.
+    fn copy_fix(&self, seed: &Seed) -> Option<(u32, Option<u32>)> {
+        if let Some(ReplayKind::Suspended { from }) = seed.outer.map(|lend| self.replays[lend].kind) {
+            return Some((from, Some(seed.own_start)));
+        }
+        let &last = seed.chain.iter().rev().find(|&&idx| idx < seed.events_end)?;
+        match self.events[last as usize] {
+            Event::Start { forward_parent: Some(offset), .. } if last + offset >= seed.events_end => Some((last, None)),
+            _ => None,
+        }
+    }
+
+    pub(crate) fn plant_seed(&mut self, expr: ExprRef, pos: Pos) {
+        self.copy_pending();
+        let start = self.events.len() as u32;
+        let seed = Seed {
+            end: None,
+            start,
+            own_start: start,
+            events_end: start,
+            chain: vec![start],
+            outer: None,
+            n_in_place: 0,
+            old_replays: self.replays.len(),
+            new_replays: self.replays.len(),
+            after_error: self.first_error.is_some(),
+            first_error: None,
+            prev: self.prev,
+        };
+        self.seeds.insert((expr, pos), seed);
+        self.growing.push((expr, pos));
+    }
+
+    pub(crate) fn seed_end(&self, expr: ExprRef, pos: Pos) -> Option<Pos> {
+        self.seeds[&(expr, pos)].end
+    }
+
+    /// Whether the events of the seed are not a part of the tree,
+    /// until the seed is replayed.
+    fn is_hidden(&self, key: (ExprRef, Pos)) -> bool {
+        self.seeds[&key].n_in_place == 0 || self.seeds.iter().any(|(&inner, seed)| match seed.outer {
+            Some(lend) => match self.replays[lend] {
+                Replay { seed, kind: ReplayKind::InPlace, .. }
+                | Replay { seed, kind: ReplayKind::Linked { .. }, .. } => seed == key && self.is_hidden(inner),
+                _ => false,
+            },
+            None => false,
+        })
+    }
+
+    /// Makes the events since the previous seed the new seed.
+    /// The previous one is dropped, unless it was replayed in place.
+    pub(crate) fn grow_seed(&mut self, expr: ExprRef, pos: Pos, end: Pos) {
+        let key = (expr, pos);
+        if self.predicate_mode {
+            let seed = self.seeds.get_mut(&key).unwrap();
+            seed.end = Some(end);
+            seed.prev = self.prev;
+            return;
+        }
+        self.copy_pending();
+        let mut seed = self.seeds.remove(&key).unwrap();
+        if seed.n_in_place == 0 {
+            self.drop_events(&seed);
+            seed.start = seed.own_start;
+            seed.chain = vec![seed.own_start];
+            seed.outer = None;
+            seed.old_replays = seed.new_replays;
+            // An outer seed used in place at the start of the events is a part of the seed.
+            for (i, replay) in self.replays.iter_mut().enumerate().skip(seed.new_replays) {
+                let outer = match self.seeds.get(&replay.seed) {
+                    Some(outer) => outer,
+                    None => continue,
+                };
+                match replay.kind {
+                    ReplayKind::InPlace | ReplayKind::Linked { .. } => (),
+                    ReplayKind::Moved { from, .. } => replay.kind = ReplayKind::Linked { from },
+                    _ => continue,
+                }
+                seed.start = outer.start;
+                seed.chain = vec![*outer.chain.last().unwrap()];
+                seed.outer = Some(i);
+                break;
+            }
+        }
+        let mut tail = *seed.chain.last().unwrap();
+        while let Some(&Event::Start { forward_parent: Some(offset), .. }) = self.events.get(tail as usize) {
+            tail += offset;
+            seed.chain.push(tail);
+        }
+        seed.end = Some(end);
+        seed.events_end = self.events.len() as u32;
+        seed.n_in_place = 0;
+        seed.new_replays = self.replays.len();
+        seed.prev = self.prev;
+        seed.first_error = if seed.after_error { None } else { self.first_error.take() };
+        self.seeds.insert(key, seed);
+    }
+
+    /// Drops the events of the `seed` which was replaced,
+    /// together with the uses of the seeds they made.
+    fn drop_events(&mut self, seed: &Seed) {
+        let (start, end) = (seed.own_start, seed.events_end);
+        for replay in self.replays[seed.old_replays..seed.new_replays].iter_mut() {
+            let kind = ::std::mem::replace(&mut replay.kind, ReplayKind::Dropped);
+            let outer = self.seeds.get_mut(&replay.seed);
+            match kind {
+                ReplayKind::InPlace => if let Some(outer) = outer {
+                    outer.n_in_place -= 1;
+                },
+                ReplayKind::Linked { from } => {
+                    if from < start {
+                        set_forward_parent(&mut self.events, self.completed.get_mut(), from, None);
+                    }
+                    if let Some(outer) = outer {
+                        outer.n_in_place -= 1;
+                        outer.chain.pop();
+                    }
+                }
+                _ => (),
+            }
+        }
+        self.events.drain(start as usize..end as usize);
+        self.completed.get_mut().invalidate(start);
+        let n_dropped = end - start;
+        let shift = |idx: &mut u32| if *idx >= end {
+            *idx -= n_dropped;
+        };
+        if let Some(ref mut error) = self.first_error {
+            shift(error);
+        }
+        for replay in self.replays[seed.new_replays..].iter_mut() {
+            match replay.kind {
+                ReplayKind::Linked { ref mut from } => shift(from),
+                // The outer seed is linked over the dropped events.
+                ReplayKind::Moved { from, .. } => {
+                    let outer = self.seeds.get_mut(&replay.seed).unwrap();
+                    *outer.chain.last_mut().unwrap() -= n_dropped;
+                    let target = *outer.chain.last().unwrap();
+                    set_forward_parent(&mut self.events, self.completed.get_mut(), from, Some(target - from));
+                }
+                _ => (),
             }
         }
     }
 
-    pub fn reopen(&mut self) {
+    /// Leaves the final seed in place.
+    pub(crate) fn harvest_seed(&mut self, expr: ExprRef, pos: Pos) -> Option<Pos> {
+        self.growing.pop();
+        let seed = self.seeds.remove(&(expr, pos)).unwrap();
+        if self.first_error.is_none() {
+            self.first_error = seed.first_error;
+        }
+        self.prev = seed.prev;
+        seed.end
+    }
+
+    fn undo_replay(&mut self, replay: Replay) {
+        self.first_error = replay.first_error;
+        // The seed is gone if the replay is a part of its final events.
+        let seed = self.seeds.get_mut(&replay.seed);
+        match replay.kind {
+            ReplayKind::InPlace => if let Some(seed) = seed {
+                seed.n_in_place -= 1;
+            },
+            ReplayKind::Linked { from } => {
+                set_forward_parent(&mut self.events, self.completed.get_mut(), from, None);
+                if let Some(seed) = seed {
+                    seed.n_in_place -= 1;
+                    seed.chain.pop();
+                }
+            }
+            ReplayKind::Moved { from, lend } => {
+                let seed = seed.unwrap();
+                set_forward_parent(&mut self.events, self.completed.get_mut(), from, Some(seed.events_end - from));
+                *seed.chain.last_mut().unwrap() = seed.events_end;
+                self.replays[lend].kind = ReplayKind::Linked { from };
+            }
+            ReplayKind::Suspended { .. } | ReplayKind::Copied | ReplayKind::Dropped => (),
+        }
+    }
+
+    /// Adds the pending copy of a seed to the events.
+    pub(crate) fn copy_pending(&mut self) {
+        if let Some(copy) = self.pending.take() {
+            let len = self.events.len() as u32;
+            self.events.extend_from_within(copy.start as usize..copy.end as usize);
+            if let Some((idx, parent)) = copy.fix {
+                let offset = parent.map(|parent| parent - idx);
+                set_forward_parent(&mut self.events, self.completed.get_mut(), len + idx - copy.start, offset);
+            }
+        }
+    }
+
+    /// Number of events, including the pending copy.
+    fn len(&self) -> u32 {
+        self.events.len() as u32 + self.pending.as_ref().map_or(0, |copy| copy.end - copy.start)
+    }
+
+    pub(crate) fn reopen(&mut self) {
         if !self.predicate_mode {
+            self.copy_pending();
             match self.events.pop() {
                 Some(Event::End) => {}
                 _ => unreachable!()
             }
+            self.completed.get_mut().invalidate(self.events.len() as u32);
         }
     }
 
-    pub fn mark(&self) -> Mark {
-        Mark(self.events.len() as u32)
+    pub(crate) fn mark(&self) -> Mark {
+        Mark(self.len(), self.replays.len() as u32)
     }
 
-    pub fn rollback(&mut self, mark: Mark) {
+    pub(crate) fn rollback(&mut self, mark: Mark) {
         fn truncate_fast<T>(xs: &mut Vec<T>, len: usize) {
             assert!(len <= xs.len());
             unsafe { xs.set_len(len) }
         }
 
-        truncate_fast(&mut self.events, mark.0 as usize);
+        while self.replays.len() > mark.1 as usize {
+            let replay = self.replays.pop().unwrap();
+            self.undo_replay(replay);
+        }
+        if mark.0 as usize <= self.events.len() {
+            self.pending = None;
+            truncate_fast(&mut self.events, mark.0 as usize);
+            self.completed.get_mut().invalidate(mark.0);
+        }
+        if self.first_error.map_or(false, |e| e >= mark.0) {
+            self.first_error = None;
+        }
     }
 
-    pub fn replace(&mut self, mark: Mark, ty_idx: NodeTypeRef) {
+    pub(crate) fn replace(&mut self, mark: Mark, ty_idx: NodeTypeRef) {
         let ty = self[ty_idx];
+        self.completed.get_mut().invalidate(mark.0);
         match self.events[mark.0 as usize] {
             Event::Start { ty: ref mut prev, .. } => *prev = ty,
             _ => unreachable!()
         }
     }
 
-    pub fn forward_parent(&mut self, child: Mark, parent: Mark) {
-        match self.events[child.0 as usize] {
-            Event::Start { ref mut forward_parent, .. } =>
-                *forward_parent = Some(parent.0 - child.0),
-            _ => unreachable!(),
-        }
+    /// A mark of the start of a child for `forward_parent`,
+    /// `child_parsed` is called after the child.
+    pub(crate) fn child_mark(&mut self) -> Mark {
+        let mark = self.mark();
+        self.forward_children.push(mark.0);
+        mark
+    }
+
+    pub(crate) fn child_parsed(&mut self) {
+        self.forward_children.pop();
     }
 
-    pub fn bump(&mut self, pos: Pos) -> Option<(NodeType, Pos)> {
+    pub(crate) fn forward_parent(&mut self, child: Mark, parent: Mark) {
+        self.copy_pending();
+        set_forward_parent(&mut self.events, self.completed.get_mut(), child.0, Some(parent.0 - child.0));
+    }
+
+    pub(crate) fn bump(&mut self, pos: Pos) -> Option<(NodeType, Pos)> {
         if pos.is_empty() {
             return None;
         }
@@ -174,7 +985,7 @@ impl<'g> Parser<'g> {
         Some((ty, pos.next()))
     }
 
-    pub fn bump_by_text(&mut self, tokens: Pos, text: &str, ty_idx: NodeTypeRef) -> Option<Pos> {
+    pub(crate) fn bump_by_text(&mut self, tokens: Pos, text: &str, ty_idx: NodeTypeRef) -> Option<Pos> {
         if tokens.is_empty() {
             return None;
         }
@@ -207,12 +1018,31 @@ impl<'g> Parser<'g> {
         Some(pos)
     }
 
-    pub fn cut_suffix(&self, tokens: Pos, suffix: Pos) -> Pos {
+    /// Records a failed attempt to match `token` at `pos`.
+    pub(crate) fn expect(&mut self, pos: Pos, token: ExpectedToken) {
+        if self.predicate_mode {
+            return;
+        }
+        if let Some(&mut (_, ref mut failures)) = self.open_cached.last_mut() {
+            record_failure(failures, pos.0, token.clone());
+        }
+        if self.first_error.is_none() {
+            record_failure(&mut self.expected, pos.0, token);
+        }
+    }
+
+    pub(crate) fn cut_suffix(&self, tokens: Pos, suffix: Pos) -> Pos {
         Pos(tokens.0, suffix.0)
     }
 
+    fn mark_error(&mut self) {
+        if !self.predicate_mode && self.first_error.is_none() {
+            self.first_error = Some(self.len());
+        }
+    }
+
     fn start_ty(&mut self, ty: NodeType) -> Mark {
-        let mark = Mark(self.events.len() as u32);
+        let mark = self.mark();
         self.event(Event::Start { ty, forward_parent: None });
         mark
     }
@@ -223,6 +1053,7 @@ impl<'g> Parser<'g> {
 
     fn event(&mut self, event: Event) {
         if !self.predicate_mode {
+            self.copy_pending();
             self.events.push(event)
         }
     }
@@ -243,3 +1074,49 @@ impl<'g> ::std::ops::Index<NodeTypeRef> for Parser<'g> {
         &self.grammar.node_types[index]
     }
 }
+
+fn record_failure(failures: &mut Failures, idx: u32, token: ExpectedToken) {
+    let (furthest, ref mut tokens) = *failures;
+    if idx < furthest {
+        return;
+    }
+    if idx > furthest {
+        *failures = (idx, vec![token]);
+    } else if !tokens.contains(&token) {
+        tokens.push(token);
+    }
+}
+
+/// Renumbers the keys of `Event::Layer` and `Event::Cached::expected`
+/// in the order of the events, returns the layer states and the
+/// failures for the new keys.
+pub(crate) fn compact<'a>(
+    events: &mut [Event],
+    state: &dyn Fn(u32) -> &'a LayerState,
+    failures: &dyn Fn(u32) -> &'a Failures,
+) -> (Vec<LayerState>, Vec<Failures>) {
+    let mut layers = Vec::new();
+    let mut cached_expected = Vec::new();
+    for event in events.iter_mut() {
+        match *event {
+            Event::Layer { ref mut key, .. } => {
+                layers.push(state(*key).clone());
+                *key = layers.len() as u32 - 1;
+            }
+            Event::Cached { ref mut expected, .. } if *expected != UNKNOWN => {
+                cached_expected.push(failures(*expected).clone());
+                *expected = cached_expected.len() as u32 - 1;
+            }
+            _ => (),
+        }
+    }
+    (layers, cached_expected)
+}
+
+fn set_forward_parent(events: &mut [Event], completed: &mut CompletedNodes, idx: u32, offset: Option<u32>) {
+    completed.invalidate(idx);
+    match events[idx as usize] {
+        Event::Start { ref mut forward_parent, .. } => *forward_parent = offset,
+        _ => unreachable!(),
+    }
+}
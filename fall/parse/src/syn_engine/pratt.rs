@@ -1,45 +1,101 @@
-use crate::PrattTable;
+use crate::{PrattTable, Prefix, Infix, Assoc, ExprRef};
 use crate::syn_engine::parser::{Parser, Pos};
-use super::expr::{parse_or, parse_expr};
+use crate::syn_engine::ErrorKind;
+use super::expr::{parse_or, parse_expr, grow_stack};
+
+/// The operators of a `PrattTable`, borrowed, so that
+/// compiled parsers can keep them in statics.
+#[derive(Clone, Copy)]
+pub(crate) struct Operators<'g> {
+    pub(crate) atoms: &'g [ExprRef],
+    pub(crate) prefixes: &'g [Prefix],
+    pub(crate) infixes: &'g [Infix],
+}
+
+impl<'g> Operators<'g> {
+    fn infixes(self, min_prior: u32) -> impl Iterator<Item=&'g Infix> {
+        self.infixes.iter().filter(move |ix| ix.priority >= min_prior)
+    }
+}
 
 pub(super) fn parse_pratt<'g>(
     p: &mut Parser<'g>,
     table: &'g PrattTable,
     tokens: Pos,
 ) -> Option<Pos> {
-    pratt_go(p, table, tokens, 0)
+    let ops = Operators { atoms: &table.atoms, prefixes: &table.prefixes, infixes: &table.infixes };
+    pratt_go(p, ops, tokens, 0)
+}
+
+pub(crate) fn pratt_with<'g>(
+    p: &mut Parser<'g>,
+    ops: Operators<'g>,
+    tokens: Pos,
+) -> Option<Pos> {
+    pratt_go(p, ops, tokens, 0)
 }
 
 fn pratt_go<'g>(
     p: &mut Parser<'g>,
-    table: &'g PrattTable,
+    ops: Operators<'g>,
     tokens: Pos,
     min_prior: u32
 ) -> Option<Pos> {
-    let mut lhs = p.mark();
-    let mut tokens = match pratt_prefix(p, table, tokens) {
+    p.enter();
+    let result = if p.is_stopped() {
+        Some(tokens)
+    } else {
+        grow_stack(|| pratt_go_inner(p, ops, tokens, min_prior))
+    };
+    p.exit();
+    result
+}
+
+fn pratt_go_inner<'g>(
+    p: &mut Parser<'g>,
+    ops: Operators<'g>,
+    tokens: Pos,
+    min_prior: u32
+) -> Option<Pos> {
+    let mut lhs = p.child_mark();
+    let prefix = pratt_prefix(p, ops, tokens);
+    p.child_parsed();
+    let mut tokens = match prefix {
         Some(ts) => ts,
         _ => return None,
     };
 
+    // Priority of the last operator, if it is non-associative.
+    let mut nonassoc = None;
     'l: loop {
-        for ix in table.infixes(min_prior) {
+        if p.is_stopped() {
+            break;
+        }
+        for ix in ops.infixes(min_prior) {
+            if nonassoc == Some(ix.priority) {
+                continue;
+            }
             let new_lhs = p.mark();
             let mark = p.start(ix.ty);
             if let Some(rest) = parse_expr(p, ix.op, tokens) {
                 p.forward_parent(lhs, mark);
                 tokens = rest;
                 if ix.has_rhs {
-                    if let Some(rest) = pratt_go(p, table, tokens, ix.priority + 1) {
+                    let rhs_prior = match ix.assoc {
+                        Assoc::Right => ix.priority,
+                        Assoc::Left | Assoc::NonAssoc => ix.priority + 1,
+                    };
+                    if let Some(rest) = pratt_go(p, ops, tokens, rhs_prior) {
                         tokens = rest;
                     } else {
-                        p.start_error();
+                        p.start_error(ErrorKind::ExpectedOperand);
                         p.finish();
                     }
                 }
                 let ty = p[ix.ty];
                 p.prev = Some(ty);
                 lhs = new_lhs;
+                nonassoc = if ix.assoc == Assoc::NonAssoc { Some(ix.priority) } else { None };
                 p.finish();
                 continue 'l;
             }
@@ -51,18 +107,18 @@ fn pratt_go<'g>(
     Some(tokens)
 }
 
-fn pratt_prefix<'p>(
-    p: &mut Parser<'p>,
-    table: &'p PrattTable,
+fn pratt_prefix<'g>(
+    p: &mut Parser<'g>,
+    ops: Operators<'g>,
     tokens: Pos,
 ) -> Option<Pos> {
-    if let Some(result) = parse_or(p, &table.atoms, tokens) {
+    if let Some(result) = parse_or(p, ops.atoms, tokens) {
         return Some(result);
     }
-    for prefix in table.prefixes.iter() {
+    for prefix in ops.prefixes.iter() {
         let mark = p.start(prefix.ty);
         if let Some(tokens) = parse_expr(p, prefix.op, tokens) {
-            if let Some(rest) = pratt_go(p, table, tokens, prefix.priority) {
+            if let Some(rest) = pratt_go(p, ops, tokens, prefix.priority) {
                 p.prev = Some(p[prefix.ty]);
                 p.finish();
                 return Some(rest);
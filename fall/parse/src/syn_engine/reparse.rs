@@ -0,0 +1,305 @@
+use fall_tree::{Language, Text, TextEdit, TextEditOp, Expected, ParseOptions, ERROR, tu};
+
+use crate::{Expr, ExprRef};
+use crate::lex_engine::Token;
+use crate::syn_engine::{Grammar, Event, ParseResult, LayerState, Failures, UNKNOWN};
+use crate::syn_engine::parser::{Parser, Pos, compact};
+use crate::syn_engine::expr::{parse_expr_pred, parse_layer_body};
+
+/// The previous parse of the text.
+pub(crate) struct OldParse<'a> {
+    pub tokens: &'a [Token],
+    pub events: &'a [Event],
+    pub layers: &'a [LayerState],
+    pub cached_expected: &'a [Failures],
+    pub expected: &'a Expected,
+}
+
+/// A layer of the old parse which encloses the edit.
+struct Candidate {
+    /// Index of the `Event::Layer`.
+    event: usize,
+    key: u32,
+    n_events: u32,
+    /// Raw tokens between the tokens which precede and follow the layer,
+    /// including the whitespace.
+    start: usize,
+    end: usize,
+}
+
+/// Reparses only the innermost layer which encloses the edit, if the
+/// covers of the layer and of the enclosing layers match the same tokens
+/// after the edit, and splices the result into the old events.
+///
+/// Returns `None` if no layer can be reparsed on its own.
+pub(crate) fn reparse_layer(
+    grammar: Grammar,
+    lang: &Language,
+    old: &OldParse,
+    edit: &TextEdit,
+    text: Text,
+    tokens: &[Token],
+    options: &ParseOptions,
+) -> Option<ParseResult> {
+    let is_ws = |t: Token| lang.node_type_info(t.ty).whitespace_like;
+    let (first_changed, last_changed) = changed_tokens(old.tokens, tokens, edit);
+    let candidates = enclosing_layers(old, &is_ws, first_changed, last_changed);
+    if candidates.is_empty() {
+        return None;
+    }
+
+    // The covers of the enclosing layers may look inside the changed layer,
+    // so they all must match the same tokens as before.
+    let (mut p, all) = Parser::new(None, &grammar, &is_ws, text, tokens, options);
+    let mut first = 0;
+    let mut bounds = Vec::new();
+    for (i, c) in candidates.iter().enumerate() {
+        let state = &old.layers[c.key as usize];
+        let new_end = c.end + tokens.len() - old.tokens.len();
+        let (start, end) = (p.non_ws_index(c.start) as u32, p.non_ws_index(new_end) as u32);
+        p.enter_layer(state);
+        if parse_expr_pred(&mut p, cover_of(grammar, state), Pos(start, all.1)) != Some(Pos(end, all.1)) {
+            first = i + 1;
+        }
+        bounds.push((start, end, new_end));
+    }
+    let mut ticks = p.done().ticks;
+
+    for (c, &(start, end, new_end)) in candidates.iter().zip(bounds.iter()).skip(first) {
+        let state = &old.layers[c.key as usize];
+        let (mut p, all) = Parser::new(None, &grammar, &is_ws, text, tokens, options);
+        p.enter_layer(state);
+        // Parse the cover again, for its effect on `prev`.
+        parse_expr_pred(&mut p, cover_of(grammar, state), Pos(start, all.1));
+        let mark = p.start_layer(state.layer, state.prev, state.replacement).unwrap();
+        parse_layer_body(&mut p, body_of(grammar, state), Pos(start, end));
+        p.finish_layer(mark);
+        if p.is_stopped() {
+            return None;
+        }
+        let same_state = p.prev == state.prev_after && p.replacement == state.replacement_after;
+        let result = p.done();
+        ticks += result.ticks;
+        if !same_state {
+            continue;
+        }
+
+        let expected = splice_expected(old, c, &result, tokens, &is_ws, new_end)?;
+        let (events, layers, cached_expected) = splice(old, c, result);
+        return Some(ParseResult {
+            events,
+            layers,
+            cached_expected,
+            ticks,
+            expected,
+            stopped: None,
+            n_tokens: end - start,
+        });
+    }
+    None
+}
+
+/// Failures are recorded only until the first error, and are collected
+/// again only inside the layer, so this is an approximation of the
+/// full parse. Returns `None` if the result may differ from the full
+/// parse: the failures after the layer are not known, because the edit
+/// fixed the first error, or no failures are known at all.
+fn splice_expected(
+    old: &OldParse,
+    c: &Candidate,
+    result: &ParseResult,
+    tokens: &[Token],
+    is_ws: &dyn Fn(Token) -> bool,
+    new_end: usize,
+) -> Option<Expected> {
+    let is_error = |e: &Event| matches!(*e, Event::Start { ty: ERROR, .. } | Event::Missing { .. });
+    let old_error = old.events.iter().position(is_error);
+    if old_error.map_or(false, |e| e < c.event) {
+        return Some(old.expected.clone()).filter(|e| !e.tokens.is_empty());
+    }
+    let offset = |tokens: &[Token], idx: usize| {
+        tokens[..idx].iter().fold(tu(0), |len, t| len + t.len)
+    };
+    let (start, old_end) = (offset(old.tokens, c.start), offset(old.tokens, c.end));
+
+    let mut parts = Vec::new();
+    if old.expected.offset < start {
+        parts.push(old.expected.clone());
+    }
+    parts.push(result.expected.clone());
+    if !result.events.iter().any(is_error) {
+        if old_error.map_or(false, |e| e <= c.event + c.n_events as usize) {
+            return None;
+        }
+        if old.expected.offset == old_end {
+            return None;
+        }
+        if old.expected.offset > old_end {
+            parts.push(Expected {
+                offset: old.expected.offset - old_end + offset(tokens, new_end),
+                tokens: old.expected.tokens.clone(),
+            });
+        }
+    }
+    let expected = parts.into_iter()
+        .filter(|e| !e.tokens.is_empty())
+        .fold(None, |acc: Option<Expected>, e| match acc {
+            Some(ref acc) if acc.offset > e.offset => Some(acc.clone()),
+            Some(mut acc) if acc.offset == e.offset => {
+                for t in e.tokens {
+                    if !acc.tokens.contains(&t) {
+                        acc.tokens.push(t);
+                    }
+                }
+                Some(acc)
+            }
+            _ => Some(e),
+        })?;
+    // Failures before the layer, at its first token, are not known.
+    let first_token = (c.start..new_end).find(|&i| !is_ws(tokens[i]))
+        .map_or(start, |i| offset(tokens, i));
+    if old.expected.offset >= start && expected.offset <= first_token {
+        return None;
+    }
+    Some(expected)
+}
+
+fn cover_of(grammar: Grammar, state: &LayerState) -> ExprRef {
+    match grammar[state.layer] {
+        Expr::Layer(cover, _) => cover,
+        _ => unreachable!(),
+    }
+}
+
+fn body_of(grammar: Grammar, state: &LayerState) -> ExprRef {
+    match grammar[state.layer] {
+        Expr::Layer(_, body) => body,
+        _ => unreachable!(),
+    }
+}
+
+/// The range of the old raw tokens which are changed by the edit
+/// or relexed differently, as `(first, end)`.
+fn changed_tokens(old: &[Token], new: &[Token], edit: &TextEdit) -> (usize, usize) {
+    let old_len = old.iter().fold(tu(0), |len, t| len + t.len);
+    let (prefix, suffix) = match (edit.ops.first(), edit.ops.last()) {
+        (Some(&TextEditOp::Copy(first)), Some(&TextEditOp::Copy(last))) => (
+            if first.start() == tu(0) { first.len() } else { tu(0) },
+            if last.end() == old_len { last.len() } else { tu(0) },
+        ),
+        (Some(&TextEditOp::Copy(first)), _) if first.start() == tu(0) => (first.len(), tu(0)),
+        (_, Some(&TextEditOp::Copy(last))) if last.end() == old_len => (tu(0), last.len()),
+        _ => (tu(0), tu(0)),
+    };
+    let same = |a: &Token, b: &Token| a.ty == b.ty && a.len == b.len;
+
+    let common = old.len().min(new.len());
+    let mut first = 0;
+    let mut len = tu(0);
+    while first < common && same(&old[first], &new[first]) && len + old[first].len <= prefix {
+        len += old[first].len;
+        first += 1;
+    }
+    let mut n_suffix = 0;
+    let mut len = tu(0);
+    while first + n_suffix < common {
+        let (a, b) = (&old[old.len() - 1 - n_suffix], &new[new.len() - 1 - n_suffix]);
+        if !same(a, b) || len + a.len > suffix {
+            break;
+        }
+        len += a.len;
+        n_suffix += 1;
+    }
+    (first, old.len() - n_suffix)
+}
+
+/// Layers which contain the changed tokens, innermost first.
+fn enclosing_layers(
+    old: &OldParse,
+    is_ws: &dyn Fn(Token) -> bool,
+    first_changed: usize,
+    last_changed: usize,
+) -> Vec<Candidate> {
+    let mut result = Vec::new();
+    let mut open: Vec<Candidate> = Vec::new();
+    let mut raw = 0;
+    for (i, &event) in old.events.iter().enumerate() {
+        match event {
+            Event::Token { n_raw_tokens, .. } => {
+                while is_ws(old.tokens[raw]) {
+                    raw += 1;
+                }
+                raw += n_raw_tokens as usize;
+            }
+            Event::Layer { key, n_events } =>
+                open.push(Candidate { event: i, key, n_events, start: raw, end: 0 }),
+            _ => (),
+        }
+        while open.last().map_or(false, |c| c.event + c.n_events as usize == i) {
+            let mut c = open.pop().unwrap();
+            c.end = raw;
+            while c.end < old.tokens.len() && is_ws(old.tokens[c.end]) {
+                c.end += 1;
+            }
+            if c.start <= first_changed && last_changed <= c.end {
+                result.push(c);
+            }
+        }
+        if raw > last_changed && open.is_empty() {
+            break;
+        }
+    }
+    result
+}
+
+/// Replaces the events of the layer with the `new` ones.
+fn splice(
+    old: &OldParse,
+    c: &Candidate,
+    new: ParseResult,
+) -> (Vec<Event>, Vec<LayerState>, Vec<Failures>) {
+    let (new, new_layers, new_expected) = (new.events, new.layers, new.cached_expected);
+    let old_end = c.event + 1 + c.n_events as usize;
+    let delta = new.len() as i64 - (old_end - c.event) as i64;
+    let shift = |n: u32| (n as i64 + delta) as u32;
+
+    let mut events = Vec::with_capacity(old.events.len() - (old_end - c.event) + new.len());
+    for (i, &event) in old.events[..c.event].iter().enumerate() {
+        // Fix up the segments and forward parents which span the layer.
+        // The failures inside the segments have changed with the layer.
+        let event = match event {
+            Event::Cached { key, n_events, .. } if i + n_events as usize >= c.event =>
+                Event::Cached { key, n_events: shift(n_events), expected: UNKNOWN },
+            Event::Layer { key, n_events } if i + n_events as usize >= c.event =>
+                Event::Layer { key, n_events: shift(n_events) },
+            Event::Start { ty, forward_parent: Some(fwd) } if i + fwd as usize > c.event =>
+                Event::Start { ty, forward_parent: Some(shift(fwd)) },
+            event => event,
+        };
+        events.push(event);
+    }
+    let n_old_layers = old.layers.len() as u32;
+    let n_old_expected = old.cached_expected.len() as u32;
+    events.extend(new.into_iter().map(|event| match event {
+        Event::Layer { key, n_events } => Event::Layer { key: key + n_old_layers, n_events },
+        Event::Cached { key, n_events, expected } =>
+            Event::Cached { key, n_events, expected: expected + n_old_expected },
+        event => event,
+    }));
+    events.extend_from_slice(&old.events[old_end..]);
+
+    let (layers, cached_expected) = compact(
+        &mut events,
+        &|key| if key < n_old_layers {
+            &old.layers[key as usize]
+        } else {
+            &new_layers[(key - n_old_layers) as usize]
+        },
+        &|key| if key < n_old_expected {
+            &old.cached_expected[key as usize]
+        } else {
+            &new_expected[(key - n_old_expected) as usize]
+        },
+    );
+    (events, layers, cached_expected)
+}
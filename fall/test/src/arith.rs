@@ -8,57 +8,162 @@ pub const MINUS: rt::NodeType = rt::NodeType(103);
 pub const STAR: rt::NodeType = rt::NodeType(104);
 pub const SLASH: rt::NodeType = rt::NodeType(105);
 pub const BANG: rt::NodeType = rt::NodeType(106);
-pub const LPAREN: rt::NodeType = rt::NodeType(107);
-pub const RPAREN: rt::NodeType = rt::NodeType(108);
-pub const FILE: rt::NodeType = rt::NodeType(109);
-pub const PRODUCT_EXPR: rt::NodeType = rt::NodeType(110);
-pub const SUM_EXPR: rt::NodeType = rt::NodeType(111);
-pub const CONSTANT_EXPR: rt::NodeType = rt::NodeType(112);
-pub const PAREN_EXPR: rt::NodeType = rt::NodeType(113);
-pub const FACTORIAL_EXPR: rt::NodeType = rt::NodeType(114);
-pub const NEGATE_EXPR: rt::NodeType = rt::NodeType(115);
+pub const CARET: rt::NodeType = rt::NodeType(107);
+pub const EQEQ: rt::NodeType = rt::NodeType(108);
+pub const QUESTION: rt::NodeType = rt::NodeType(109);
+pub const COLON: rt::NodeType = rt::NodeType(110);
+pub const LPAREN: rt::NodeType = rt::NodeType(111);
+pub const RPAREN: rt::NodeType = rt::NodeType(112);
+pub const LBRACK: rt::NodeType = rt::NodeType(113);
+pub const RBRACK: rt::NodeType = rt::NodeType(114);
+pub const AS: rt::NodeType = rt::NodeType(115);
+pub const IF: rt::NodeType = rt::NodeType(116);
+pub const THEN: rt::NodeType = rt::NodeType(117);
+pub const ELSE: rt::NodeType = rt::NodeType(118);
+pub const INT: rt::NodeType = rt::NodeType(119);
+pub const FILE: rt::NodeType = rt::NodeType(120);
+pub const COND_EXPR: rt::NodeType = rt::NodeType(121);
+pub const EQ_EXPR: rt::NodeType = rt::NodeType(122);
+pub const PRODUCT_EXPR: rt::NodeType = rt::NodeType(123);
+pub const SUM_EXPR: rt::NodeType = rt::NodeType(124);
+pub const POWER_EXPR: rt::NodeType = rt::NodeType(125);
+pub const INDEX_EXPR: rt::NodeType = rt::NodeType(126);
+pub const CAST_EXPR: rt::NodeType = rt::NodeType(127);
+pub const TYPE_REF: rt::NodeType = rt::NodeType(128);
+pub const IF_EXPR: rt::NodeType = rt::NodeType(129);
+pub const CONSTANT_EXPR: rt::NodeType = rt::NodeType(130);
+pub const PAREN_EXPR: rt::NodeType = rt::NodeType(131);
+pub const FACTORIAL_EXPR: rt::NodeType = rt::NodeType(132);
+pub const NEGATE_EXPR: rt::NodeType = rt::NodeType(133);
 
 
 pub fn language() -> &'static rt::Language {
-    fn create_lexer() -> rt::RegexLexer {
-        rt::RegexLexer::new(vec![
-            rt::LexRule::new(WHITESPACE, "\\s+", None),
-            rt::LexRule::new(NUMBER, "\\d+", None),
-            rt::LexRule::new(PLUS, "\\+", None),
-            rt::LexRule::new(MINUS, "\\-", None),
-            rt::LexRule::new(STAR, "\\*", None),
-            rt::LexRule::new(SLASH, "/", None),
-            rt::LexRule::new(BANG, "!", None),
-            rt::LexRule::new(LPAREN, "\\(", None),
-            rt::LexRule::new(RPAREN, "\\)", None),
-        ])
+    fn create_lexer() -> rt::DfaLexer {
+        static RULES: &[rt::DfaRule] = &[
+            rt::DfaRule { ty: WHITESPACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: NUMBER, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: PLUS, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: MINUS, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STAR, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: SLASH, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: BANG, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: CARET, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: EQEQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: QUESTION, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: COLON, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LPAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RPAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LBRACK, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RBRACK, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: AS, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: IF, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: THEN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: ELSE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: INT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+        ];
+        static ASCII_CLASSES: [u8; 128] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 2, 0, 0, 0, 0, 0, 0, 3, 4, 5, 6, 0, 7, 0, 8, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 10, 0, 0, 11, 0, 12,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 14, 15, 0,
+            0, 16, 0, 0, 0, 17, 18, 0, 19, 20, 0, 0, 21, 0, 22, 0, 0, 0, 0, 23, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        static UNICODE_CLASSES: &[(u32, u8)] = &[
+            (0x80, 0), (0x85, 1), (0x86, 0), (0xA0, 1), (0xA1, 0), (0x660, 9), (0x66A, 0), (0x6F0, 9),
+            (0x6FA, 0), (0x7C0, 9), (0x7CA, 0), (0x966, 9), (0x970, 0), (0x9E6, 9), (0x9F0, 0), (0xA66, 9),
+            (0xA70, 0), (0xAE6, 9), (0xAF0, 0), (0xB66, 9), (0xB70, 0), (0xBE6, 9), (0xBF0, 0), (0xC66, 9),
+            (0xC70, 0), (0xCE6, 9), (0xCF0, 0), (0xD66, 9), (0xD70, 0), (0xDE6, 9), (0xDF0, 0), (0xE50, 9),
+            (0xE5A, 0), (0xED0, 9), (0xEDA, 0), (0xF20, 9), (0xF2A, 0), (0x1040, 9), (0x104A, 0), (0x1090, 9),
+            (0x109A, 0), (0x1680, 1), (0x1681, 0), (0x17E0, 9), (0x17EA, 0), (0x1810, 9), (0x181A, 0), (0x1946, 9),
+            (0x1950, 0), (0x19D0, 9), (0x19DA, 0), (0x1A80, 9), (0x1A8A, 0), (0x1A90, 9), (0x1A9A, 0), (0x1B50, 9),
+            (0x1B5A, 0), (0x1BB0, 9), (0x1BBA, 0), (0x1C40, 9), (0x1C4A, 0), (0x1C50, 9), (0x1C5A, 0), (0x2000, 1),
+            (0x200B, 0), (0x2028, 1), (0x202A, 0), (0x202F, 1), (0x2030, 0), (0x205F, 1), (0x2060, 0), (0x3000, 1),
+            (0x3001, 0), (0xA620, 9), (0xA62A, 0), (0xA8D0, 9), (0xA8DA, 0), (0xA900, 9), (0xA90A, 0), (0xA9D0, 9),
+            (0xA9DA, 0), (0xA9F0, 9), (0xA9FA, 0), (0xAA50, 9), (0xAA5A, 0), (0xABF0, 9), (0xABFA, 0), (0xFF10, 9),
+            (0xFF1A, 0), (0x104A0, 9), (0x104AA, 0), (0x10D30, 9), (0x10D3A, 0), (0x10D40, 9), (0x10D4A, 0), (0x11066, 9),
+            (0x11070, 0), (0x110F0, 9), (0x110FA, 0), (0x11136, 9), (0x11140, 0), (0x111D0, 9), (0x111DA, 0), (0x112F0, 9),
+            (0x112FA, 0), (0x11450, 9), (0x1145A, 0), (0x114D0, 9), (0x114DA, 0), (0x11650, 9), (0x1165A, 0), (0x116C0, 9),
+            (0x116CA, 0), (0x116D0, 9), (0x116E4, 0), (0x11730, 9), (0x1173A, 0), (0x118E0, 9), (0x118EA, 0), (0x11950, 9),
+            (0x1195A, 0), (0x11BF0, 9), (0x11BFA, 0), (0x11C50, 9), (0x11C5A, 0), (0x11D50, 9), (0x11D5A, 0), (0x11DA0, 9),
+            (0x11DAA, 0), (0x11F50, 9), (0x11F5A, 0), (0x16130, 9), (0x1613A, 0), (0x16A60, 9), (0x16A6A, 0), (0x16AC0, 9),
+            (0x16ACA, 0), (0x16B50, 9), (0x16B5A, 0), (0x16D70, 9), (0x16D7A, 0), (0x1CCF0, 9), (0x1CCFA, 0), (0x1D7CE, 9),
+            (0x1D800, 0), (0x1E140, 9), (0x1E14A, 0), (0x1E2F0, 9), (0x1E2FA, 0), (0x1E4F0, 9), (0x1E4FA, 0), (0x1E5F1, 9),
+            (0x1E5FB, 0), (0x1E950, 9), (0x1E95A, 0), (0x1FBF0, 9), (0x1FBFA, 0),
+        ];
+        static TRANSITIONS: &[u16] = &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 0, 0, 19, 0, 0, 0, 20,
+            0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 25, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 28,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        static ACCEPTS: &[u16] = &[0, 0, 1, 7, 12, 13, 5, 3, 4, 6, 2, 11, 0, 10, 14, 15, 8, 0, 0, 0, 0, 9, 16, 0, 17, 0, 0, 0, 20, 0, 19, 18];
+        static KEYWORDS: &[(&str, u16)] = &[
+        ];
+        rt::DfaLexer {
+            rules: RULES,
+            ascii_classes: &ASCII_CLASSES,
+            unicode_classes: UNICODE_CLASSES,
+            n_classes: 25,
+            transitions: TRANSITIONS,
+            accepts: ACCEPTS,
+            starts: &[1],
+            keyword_seed: 0,
+            keywords: KEYWORDS,
+            layout: None,
+        }
     }
 
     fn create_parser_definition() -> rt::ParserDefinition {
-        let parser_json = r##"[{"Pub":{"ty":10,"body":9,"replaceable":false}},{"Pratt":{"atoms":[4,5],"prefixes":[{"ty":16,"op":21,"priority":999}],"infixes":[{"ty":12,"op":14,"priority":1,"has_rhs":true},{"ty":11,"op":19,"priority":2,"has_rhs":true},{"ty":15,"op":20,"priority":999,"has_rhs":false}]}},{"Pub":{"ty":11,"body":28,"replaceable":false}},{"Pub":{"ty":12,"body":35,"replaceable":false}},{"Pub":{"ty":13,"body":38,"replaceable":false}},{"Pub":{"ty":14,"body":42,"replaceable":false}},{"Pub":{"ty":15,"body":45,"replaceable":false}},{"Pub":{"ty":16,"body":48,"replaceable":false}},{"And":[[1],null]},{"Or":[8]},{"Token":3},{"And":[[10],null]},{"Token":4},{"And":[[12],null]},{"Or":[11,13]},{"Token":5},{"And":[[15],null]},{"Token":6},{"And":[[17],null]},{"Or":[16,18]},{"Token":7},{"Token":4},{"Token":5},{"And":[[22],null]},{"Token":6},{"And":[[24],null]},{"Or":[23,25]},{"And":[[1,26,1],null]},{"Or":[27]},{"Token":3},{"And":[[29],null]},{"Token":4},{"And":[[31],null]},{"Or":[30,32]},{"And":[[1,33,1],null]},{"Or":[34]},{"Token":2},{"And":[[36],null]},{"Or":[37]},{"Token":8},{"Token":9},{"And":[[39,1,40],null]},{"Or":[41]},{"Token":7},{"And":[[1,43],null]},{"Or":[44]},{"Token":4},{"And":[[46,1],null]},{"Or":[47]}]"##;
-
+        let parser_json = r##"[{"Pub":{"ty":21,"body":16,"replaceable":false}},{"Pratt":{"atoms":[11,12],"prefixes":[{"ty":34,"op":38,"priority":999},{"ty":30,"op":42,"priority":1}],"infixes":[{"ty":22,"op":19,"priority":1,"has_rhs":true,"assoc":"Right"},{"ty":23,"op":20,"priority":2,"has_rhs":true,"assoc":"NonAssoc"},{"ty":25,"op":25,"priority":3,"has_rhs":true,"assoc":"Left"},{"ty":24,"op":30,"priority":4,"has_rhs":true,"assoc":"Left"},{"ty":26,"op":31,"priority":5,"has_rhs":true,"assoc":"Right"},{"ty":33,"op":32,"priority":999,"has_rhs":false,"assoc":"Left"},{"ty":27,"op":35,"priority":999,"has_rhs":false,"assoc":"Left"},{"ty":28,"op":37,"priority":999,"has_rhs":false,"assoc":"Left"}]}},{"Pub":{"ty":22,"body":46,"replaceable":false}},{"Pub":{"ty":23,"body":49,"replaceable":false}},{"Pub":{"ty":24,"body":56,"replaceable":false}},{"Pub":{"ty":25,"body":63,"replaceable":false}},{"Pub":{"ty":26,"body":66,"replaceable":false}},{"Pub":{"ty":27,"body":70,"replaceable":false}},{"Pub":{"ty":28,"body":73,"replaceable":false}},{"Pub":{"ty":29,"body":76,"replaceable":false}},{"Pub":{"ty":30,"body":81,"replaceable":false}},{"Pub":{"ty":31,"body":84,"replaceable":false}},{"Pub":{"ty":32,"body":88,"replaceable":false}},{"Pub":{"ty":33,"body":91,"replaceable":false}},{"Pub":{"ty":34,"body":94,"replaceable":false}},{"And":[[1],null]},{"Or":[15]},{"Token":10},{"Token":11},{"And":[[17,1,18],1]},{"Token":9},{"Token":3},{"And":[[21],null]},{"Token":4},{"And":[[23],null]},{"Or":[22,24]},{"Token":5},{"And":[[26],null]},{"Token":6},{"And":[[28],null]},{"Or":[27,29]},{"Token":8},{"Token":7},{"Token":14},{"Token":15},{"And":[[33,1,34],null]},{"Token":16},{"And":[[36,9],null]},{"Token":4},{"Token":17},{"Token":18},{"Token":19},{"And":[[39,1,40,1,41],null]},{"Token":10},{"Token":11},{"And":[[1,43,1,44,1],2]},{"Or":[45]},{"Token":9},{"And":[[1,47,1],null]},{"Or":[48]},{"Token":5},{"And":[[50],null]},{"Token":6},{"And":[[52],null]},{"Or":[51,53]},{"And":[[1,54,1],null]},{"Or":[55]},{"Token":3},{"And":[[57],null]},{"Token":4},{"And":[[59],null]},{"Or":[58,60]},{"And":[[1,61,1],null]},{"Or":[62]},{"Token":8},{"And":[[1,64,1],null]},{"Or":[65]},{"Token":14},{"Token":15},{"And":[[1,67,1,68],null]},{"Or":[69]},{"Token":16},{"And":[[1,71,9],null]},{"Or":[72]},{"Token":20},{"And":[[74],null]},{"Or":[75]},{"Token":17},{"Token":18},{"Token":19},{"And":[[77,1,78,1,79,1],null]},{"Or":[80]},{"Token":2},{"And":[[82],null]},{"Or":[83]},{"Token":12},{"Token":13},{"And":[[85,1,86],null]},{"Or":[87]},{"Token":7},{"And":[[1,89],null]},{"Or":[90]},{"Token":4},{"And":[[92,1],null]},{"Or":[93]}]"##;
         ::fall_parse::ParserDefinition {
             node_types: vec![
                 rt::ERROR,
-                WHITESPACE, NUMBER, PLUS, MINUS, STAR, SLASH, BANG, LPAREN, RPAREN, FILE, PRODUCT_EXPR, SUM_EXPR, CONSTANT_EXPR, PAREN_EXPR, FACTORIAL_EXPR, NEGATE_EXPR,
+                WHITESPACE, NUMBER, PLUS, MINUS, STAR, SLASH, BANG, CARET, EQEQ, QUESTION, COLON, LPAREN, RPAREN, LBRACK, RBRACK, AS, IF, THEN, ELSE, INT, FILE, COND_EXPR, EQ_EXPR, PRODUCT_EXPR, SUM_EXPR, POWER_EXPR, INDEX_EXPR, CAST_EXPR, TYPE_REF, IF_EXPR, CONSTANT_EXPR, PAREN_EXPR, FACTORIAL_EXPR, NEGATE_EXPR, 
             ],
             syntactical_rules: rt::parser_from_str(parser_json),
-            
             .. Default::default()
         }
     }
     use self::rt::*;
     lazy_static! {
         static ref LANG: rt::Language = {
-            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::RegexLexer };
+            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::DfaLexer };
             impl rt::LanguageImpl for Impl {
                 fn parse(
                     &self,
                     text: rt::Text,
+                    options: &rt::ParseOptions,
                     metrics: &rt::Metrics,
                     builder: &mut rt::TreeBuilder,
                 ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
-                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, metrics, builder)
+                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, options, metrics, builder)
                 }
 
                 fn reparse(
@@ -66,10 +171,11 @@ pub fn language() -> &'static rt::Language {
                     incremental_data: &dyn std::any::Any,
                     edit: &rt::TextEdit,
                     new_text: rt::Text,
+                    options: &rt::ParseOptions,
                     metrics: &rt::Metrics,
                     builder: &mut rt::TreeBuilder,
                 ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
-                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, metrics, builder)
+                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, options, metrics, builder)
                 }
 
                 fn node_type_info(&self, ty: rt::NodeType) -> rt::NodeTypeInfo {
@@ -82,11 +188,29 @@ pub fn language() -> &'static rt::Language {
                         STAR => rt::NodeTypeInfo { name: "STAR", whitespace_like: false },
                         SLASH => rt::NodeTypeInfo { name: "SLASH", whitespace_like: false },
                         BANG => rt::NodeTypeInfo { name: "BANG", whitespace_like: false },
+                        CARET => rt::NodeTypeInfo { name: "CARET", whitespace_like: false },
+                        EQEQ => rt::NodeTypeInfo { name: "EQEQ", whitespace_like: false },
+                        QUESTION => rt::NodeTypeInfo { name: "QUESTION", whitespace_like: false },
+                        COLON => rt::NodeTypeInfo { name: "COLON", whitespace_like: false },
                         LPAREN => rt::NodeTypeInfo { name: "LPAREN", whitespace_like: false },
                         RPAREN => rt::NodeTypeInfo { name: "RPAREN", whitespace_like: false },
+                        LBRACK => rt::NodeTypeInfo { name: "LBRACK", whitespace_like: false },
+                        RBRACK => rt::NodeTypeInfo { name: "RBRACK", whitespace_like: false },
+                        AS => rt::NodeTypeInfo { name: "AS", whitespace_like: false },
+                        IF => rt::NodeTypeInfo { name: "IF", whitespace_like: false },
+                        THEN => rt::NodeTypeInfo { name: "THEN", whitespace_like: false },
+                        ELSE => rt::NodeTypeInfo { name: "ELSE", whitespace_like: false },
+                        INT => rt::NodeTypeInfo { name: "INT", whitespace_like: false },
                         FILE => rt::NodeTypeInfo { name: "FILE", whitespace_like: false },
+                        COND_EXPR => rt::NodeTypeInfo { name: "COND_EXPR", whitespace_like: false },
+                        EQ_EXPR => rt::NodeTypeInfo { name: "EQ_EXPR", whitespace_like: false },
                         PRODUCT_EXPR => rt::NodeTypeInfo { name: "PRODUCT_EXPR", whitespace_like: false },
                         SUM_EXPR => rt::NodeTypeInfo { name: "SUM_EXPR", whitespace_like: false },
+                        POWER_EXPR => rt::NodeTypeInfo { name: "POWER_EXPR", whitespace_like: false },
+                        INDEX_EXPR => rt::NodeTypeInfo { name: "INDEX_EXPR", whitespace_like: false },
+                        CAST_EXPR => rt::NodeTypeInfo { name: "CAST_EXPR", whitespace_like: false },
+                        TYPE_REF => rt::NodeTypeInfo { name: "TYPE_REF", whitespace_like: false },
+                        IF_EXPR => rt::NodeTypeInfo { name: "IF_EXPR", whitespace_like: false },
                         CONSTANT_EXPR => rt::NodeTypeInfo { name: "CONSTANT_EXPR", whitespace_like: false },
                         PAREN_EXPR => rt::NodeTypeInfo { name: "PAREN_EXPR", whitespace_like: false },
                         FACTORIAL_EXPR => rt::NodeTypeInfo { name: "FACTORIAL_EXPR", whitespace_like: false },
@@ -108,3 +232,4 @@ pub fn language() -> &'static rt::Language {
 
 
 
+
@@ -0,0 +1,812 @@
+use fall_parse::runtime as rt;
+pub use self::rt::ERROR;
+
+pub const WHITESPACE: rt::NodeType = rt::NodeType(100);
+pub const NUMBER: rt::NodeType = rt::NodeType(101);
+pub const PLUS: rt::NodeType = rt::NodeType(102);
+pub const MINUS: rt::NodeType = rt::NodeType(103);
+pub const STAR: rt::NodeType = rt::NodeType(104);
+pub const SLASH: rt::NodeType = rt::NodeType(105);
+pub const BANG: rt::NodeType = rt::NodeType(106);
+pub const CARET: rt::NodeType = rt::NodeType(107);
+pub const EQEQ: rt::NodeType = rt::NodeType(108);
+pub const QUESTION: rt::NodeType = rt::NodeType(109);
+pub const COLON: rt::NodeType = rt::NodeType(110);
+pub const LPAREN: rt::NodeType = rt::NodeType(111);
+pub const RPAREN: rt::NodeType = rt::NodeType(112);
+pub const LBRACK: rt::NodeType = rt::NodeType(113);
+pub const RBRACK: rt::NodeType = rt::NodeType(114);
+pub const AS: rt::NodeType = rt::NodeType(115);
+pub const IF: rt::NodeType = rt::NodeType(116);
+pub const THEN: rt::NodeType = rt::NodeType(117);
+pub const ELSE: rt::NodeType = rt::NodeType(118);
+pub const INT: rt::NodeType = rt::NodeType(119);
+pub const FILE: rt::NodeType = rt::NodeType(120);
+pub const COND_EXPR: rt::NodeType = rt::NodeType(121);
+pub const EQ_EXPR: rt::NodeType = rt::NodeType(122);
+pub const PRODUCT_EXPR: rt::NodeType = rt::NodeType(123);
+pub const SUM_EXPR: rt::NodeType = rt::NodeType(124);
+pub const POWER_EXPR: rt::NodeType = rt::NodeType(125);
+pub const INDEX_EXPR: rt::NodeType = rt::NodeType(126);
+pub const CAST_EXPR: rt::NodeType = rt::NodeType(127);
+pub const TYPE_REF: rt::NodeType = rt::NodeType(128);
+pub const IF_EXPR: rt::NodeType = rt::NodeType(129);
+pub const CONSTANT_EXPR: rt::NodeType = rt::NodeType(130);
+pub const PAREN_EXPR: rt::NodeType = rt::NodeType(131);
+pub const FACTORIAL_EXPR: rt::NodeType = rt::NodeType(132);
+pub const NEGATE_EXPR: rt::NodeType = rt::NodeType(133);
+
+
+pub fn language() -> &'static rt::Language {
+    fn create_lexer() -> rt::DfaLexer {
+        static RULES: &[rt::DfaRule] = &[
+            rt::DfaRule { ty: WHITESPACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: NUMBER, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: PLUS, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: MINUS, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STAR, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: SLASH, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: BANG, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: CARET, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: EQEQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: QUESTION, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: COLON, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LPAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RPAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LBRACK, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RBRACK, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: AS, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: IF, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: THEN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: ELSE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: INT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+        ];
+        static ASCII_CLASSES: [u8; 128] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 2, 0, 0, 0, 0, 0, 0, 3, 4, 5, 6, 0, 7, 0, 8, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 10, 0, 0, 11, 0, 12,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 14, 15, 0,
+            0, 16, 0, 0, 0, 17, 18, 0, 19, 20, 0, 0, 21, 0, 22, 0, 0, 0, 0, 23, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        static UNICODE_CLASSES: &[(u32, u8)] = &[
+            (0x80, 0), (0x85, 1), (0x86, 0), (0xA0, 1), (0xA1, 0), (0x660, 9), (0x66A, 0), (0x6F0, 9),
+            (0x6FA, 0), (0x7C0, 9), (0x7CA, 0), (0x966, 9), (0x970, 0), (0x9E6, 9), (0x9F0, 0), (0xA66, 9),
+            (0xA70, 0), (0xAE6, 9), (0xAF0, 0), (0xB66, 9), (0xB70, 0), (0xBE6, 9), (0xBF0, 0), (0xC66, 9),
+            (0xC70, 0), (0xCE6, 9), (0xCF0, 0), (0xD66, 9), (0xD70, 0), (0xDE6, 9), (0xDF0, 0), (0xE50, 9),
+            (0xE5A, 0), (0xED0, 9), (0xEDA, 0), (0xF20, 9), (0xF2A, 0), (0x1040, 9), (0x104A, 0), (0x1090, 9),
+            (0x109A, 0), (0x1680, 1), (0x1681, 0), (0x17E0, 9), (0x17EA, 0), (0x1810, 9), (0x181A, 0), (0x1946, 9),
+            (0x1950, 0), (0x19D0, 9), (0x19DA, 0), (0x1A80, 9), (0x1A8A, 0), (0x1A90, 9), (0x1A9A, 0), (0x1B50, 9),
+            (0x1B5A, 0), (0x1BB0, 9), (0x1BBA, 0), (0x1C40, 9), (0x1C4A, 0), (0x1C50, 9), (0x1C5A, 0), (0x2000, 1),
+            (0x200B, 0), (0x2028, 1), (0x202A, 0), (0x202F, 1), (0x2030, 0), (0x205F, 1), (0x2060, 0), (0x3000, 1),
+            (0x3001, 0), (0xA620, 9), (0xA62A, 0), (0xA8D0, 9), (0xA8DA, 0), (0xA900, 9), (0xA90A, 0), (0xA9D0, 9),
+            (0xA9DA, 0), (0xA9F0, 9), (0xA9FA, 0), (0xAA50, 9), (0xAA5A, 0), (0xABF0, 9), (0xABFA, 0), (0xFF10, 9),
+            (0xFF1A, 0), (0x104A0, 9), (0x104AA, 0), (0x10D30, 9), (0x10D3A, 0), (0x10D40, 9), (0x10D4A, 0), (0x11066, 9),
+            (0x11070, 0), (0x110F0, 9), (0x110FA, 0), (0x11136, 9), (0x11140, 0), (0x111D0, 9), (0x111DA, 0), (0x112F0, 9),
+            (0x112FA, 0), (0x11450, 9), (0x1145A, 0), (0x114D0, 9), (0x114DA, 0), (0x11650, 9), (0x1165A, 0), (0x116C0, 9),
+            (0x116CA, 0), (0x116D0, 9), (0x116E4, 0), (0x11730, 9), (0x1173A, 0), (0x118E0, 9), (0x118EA, 0), (0x11950, 9),
+            (0x1195A, 0), (0x11BF0, 9), (0x11BFA, 0), (0x11C50, 9), (0x11C5A, 0), (0x11D50, 9), (0x11D5A, 0), (0x11DA0, 9),
+            (0x11DAA, 0), (0x11F50, 9), (0x11F5A, 0), (0x16130, 9), (0x1613A, 0), (0x16A60, 9), (0x16A6A, 0), (0x16AC0, 9),
+            (0x16ACA, 0), (0x16B50, 9), (0x16B5A, 0), (0x16D70, 9), (0x16D7A, 0), (0x1CCF0, 9), (0x1CCFA, 0), (0x1D7CE, 9),
+            (0x1D800, 0), (0x1E140, 9), (0x1E14A, 0), (0x1E2F0, 9), (0x1E2FA, 0), (0x1E4F0, 9), (0x1E4FA, 0), (0x1E5F1, 9),
+            (0x1E5FB, 0), (0x1E950, 9), (0x1E95A, 0), (0x1FBF0, 9), (0x1FBFA, 0),
+        ];
+        static TRANSITIONS: &[u16] = &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 0, 0, 19, 0, 0, 0, 20,
+            0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 25, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 26, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 28,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 29, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        static ACCEPTS: &[u16] = &[0, 0, 1, 7, 12, 13, 5, 3, 4, 6, 2, 11, 0, 10, 14, 15, 8, 0, 0, 0, 0, 9, 16, 0, 17, 0, 0, 0, 20, 0, 19, 18];
+        static KEYWORDS: &[(&str, u16)] = &[
+        ];
+        rt::DfaLexer {
+            rules: RULES,
+            ascii_classes: &ASCII_CLASSES,
+            unicode_classes: UNICODE_CLASSES,
+            n_classes: 25,
+            transitions: TRANSITIONS,
+            accepts: ACCEPTS,
+            starts: &[1],
+            keyword_seed: 0,
+            keywords: KEYWORDS,
+            layout: None,
+        }
+    }
+
+    fn create_parser_definition() -> rt::ParserDefinition {
+        ::fall_parse::ParserDefinition {
+            node_types: vec![
+                rt::ERROR,
+                WHITESPACE, NUMBER, PLUS, MINUS, STAR, SLASH, BANG, CARET, EQEQ, QUESTION, COLON, LPAREN, RPAREN, LBRACK, RBRACK, AS, IF, THEN, ELSE, INT, FILE, COND_EXPR, EQ_EXPR, PRODUCT_EXPR, SUM_EXPR, POWER_EXPR, INDEX_EXPR, CAST_EXPR, TYPE_REF, IF_EXPR, CONSTANT_EXPR, PAREN_EXPR, FACTORIAL_EXPR, NEGATE_EXPR, 
+            ],
+            syntactical_rules: vec![
+                rt::Expr::Pub { ty: rt::NodeTypeRef(21), body: rt::ExprRef(16), replaceable: false },
+                rt::Expr::Pratt(Box::new(rt::PrattTable { atoms: vec![rt::ExprRef(11), rt::ExprRef(12)], prefixes: vec![rt::Prefix { ty: rt::NodeTypeRef(34), op: rt::ExprRef(38), priority: 999 }, rt::Prefix { ty: rt::NodeTypeRef(30), op: rt::ExprRef(42), priority: 1 }], infixes: vec![rt::Infix { ty: rt::NodeTypeRef(22), op: rt::ExprRef(19), priority: 1, has_rhs: true, assoc: rt::Assoc::Right }, rt::Infix { ty: rt::NodeTypeRef(23), op: rt::ExprRef(20), priority: 2, has_rhs: true, assoc: rt::Assoc::NonAssoc }, rt::Infix { ty: rt::NodeTypeRef(25), op: rt::ExprRef(25), priority: 3, has_rhs: true, assoc: rt::Assoc::Left }, rt::Infix { ty: rt::NodeTypeRef(24), op: rt::ExprRef(30), priority: 4, has_rhs: true, assoc: rt::Assoc::Left }, rt::Infix { ty: rt::NodeTypeRef(26), op: rt::ExprRef(31), priority: 5, has_rhs: true, assoc: rt::Assoc::Right }, rt::Infix { ty: rt::NodeTypeRef(33), op: rt::ExprRef(32), priority: 999, has_rhs: false, assoc: rt::Assoc::Left }, rt::Infix { ty: rt::NodeTypeRef(27), op: rt::ExprRef(35), priority: 999, has_rhs: false, assoc: rt::Assoc::Left }, rt::Infix { ty: rt::NodeTypeRef(28), op: rt::ExprRef(37), priority: 999, has_rhs: false, assoc: rt::Assoc::Left }] })),
+                rt::Expr::Pub { ty: rt::NodeTypeRef(22), body: rt::ExprRef(46), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(23), body: rt::ExprRef(49), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(24), body: rt::ExprRef(56), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(25), body: rt::ExprRef(63), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(26), body: rt::ExprRef(66), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(27), body: rt::ExprRef(70), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(28), body: rt::ExprRef(73), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(29), body: rt::ExprRef(76), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(30), body: rt::ExprRef(81), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(31), body: rt::ExprRef(84), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(32), body: rt::ExprRef(88), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(33), body: rt::ExprRef(91), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(34), body: rt::ExprRef(94), replaceable: false },
+                rt::Expr::And(vec![rt::ExprRef(1)], None),
+                rt::Expr::Or(vec![rt::ExprRef(15)]),
+                rt::Expr::Token(rt::NodeTypeRef(10)),
+                rt::Expr::Token(rt::NodeTypeRef(11)),
+                rt::Expr::And(vec![rt::ExprRef(17), rt::ExprRef(1), rt::ExprRef(18)], Some(1)),
+                rt::Expr::Token(rt::NodeTypeRef(9)),
+                rt::Expr::Token(rt::NodeTypeRef(3)),
+                rt::Expr::And(vec![rt::ExprRef(21)], None),
+                rt::Expr::Token(rt::NodeTypeRef(4)),
+                rt::Expr::And(vec![rt::ExprRef(23)], None),
+                rt::Expr::Or(vec![rt::ExprRef(22), rt::ExprRef(24)]),
+                rt::Expr::Token(rt::NodeTypeRef(5)),
+                rt::Expr::And(vec![rt::ExprRef(26)], None),
+                rt::Expr::Token(rt::NodeTypeRef(6)),
+                rt::Expr::And(vec![rt::ExprRef(28)], None),
+                rt::Expr::Or(vec![rt::ExprRef(27), rt::ExprRef(29)]),
+                rt::Expr::Token(rt::NodeTypeRef(8)),
+                rt::Expr::Token(rt::NodeTypeRef(7)),
+                rt::Expr::Token(rt::NodeTypeRef(14)),
+                rt::Expr::Token(rt::NodeTypeRef(15)),
+                rt::Expr::And(vec![rt::ExprRef(33), rt::ExprRef(1), rt::ExprRef(34)], None),
+                rt::Expr::Token(rt::NodeTypeRef(16)),
+                rt::Expr::And(vec![rt::ExprRef(36), rt::ExprRef(9)], None),
+                rt::Expr::Token(rt::NodeTypeRef(4)),
+                rt::Expr::Token(rt::NodeTypeRef(17)),
+                rt::Expr::Token(rt::NodeTypeRef(18)),
+                rt::Expr::Token(rt::NodeTypeRef(19)),
+                rt::Expr::And(vec![rt::ExprRef(39), rt::ExprRef(1), rt::ExprRef(40), rt::ExprRef(1), rt::ExprRef(41)], None),
+                rt::Expr::Token(rt::NodeTypeRef(10)),
+                rt::Expr::Token(rt::NodeTypeRef(11)),
+                rt::Expr::And(vec![rt::ExprRef(1), rt::ExprRef(43), rt::ExprRef(1), rt::ExprRef(44), rt::ExprRef(1)], Some(2)),
+                rt::Expr::Or(vec![rt::ExprRef(45)]),
+                rt::Expr::Token(rt::NodeTypeRef(9)),
+                rt::Expr::And(vec![rt::ExprRef(1), rt::ExprRef(47), rt::ExprRef(1)], None),
+                rt::Expr::Or(vec![rt::ExprRef(48)]),
+                rt::Expr::Token(rt::NodeTypeRef(5)),
+                rt::Expr::And(vec![rt::ExprRef(50)], None),
+                rt::Expr::Token(rt::NodeTypeRef(6)),
+                rt::Expr::And(vec![rt::ExprRef(52)], None),
+                rt::Expr::Or(vec![rt::ExprRef(51), rt::ExprRef(53)]),
+                rt::Expr::And(vec![rt::ExprRef(1), rt::ExprRef(54), rt::ExprRef(1)], None),
+                rt::Expr::Or(vec![rt::ExprRef(55)]),
+                rt::Expr::Token(rt::NodeTypeRef(3)),
+                rt::Expr::And(vec![rt::ExprRef(57)], None),
+                rt::Expr::Token(rt::NodeTypeRef(4)),
+                rt::Expr::And(vec![rt::ExprRef(59)], None),
+                rt::Expr::Or(vec![rt::ExprRef(58), rt::ExprRef(60)]),
+                rt::Expr::And(vec![rt::ExprRef(1), rt::ExprRef(61), rt::ExprRef(1)], None),
+                rt::Expr::Or(vec![rt::ExprRef(62)]),
+                rt::Expr::Token(rt::NodeTypeRef(8)),
+                rt::Expr::And(vec![rt::ExprRef(1), rt::ExprRef(64), rt::ExprRef(1)], None),
+                rt::Expr::Or(vec![rt::ExprRef(65)]),
+                rt::Expr::Token(rt::NodeTypeRef(14)),
+                rt::Expr::Token(rt::NodeTypeRef(15)),
+                rt::Expr::And(vec![rt::ExprRef(1), rt::ExprRef(67), rt::ExprRef(1), rt::ExprRef(68)], None),
+                rt::Expr::Or(vec![rt::ExprRef(69)]),
+                rt::Expr::Token(rt::NodeTypeRef(16)),
+                rt::Expr::And(vec![rt::ExprRef(1), rt::ExprRef(71), rt::ExprRef(9)], None),
+                rt::Expr::Or(vec![rt::ExprRef(72)]),
+                rt::Expr::Token(rt::NodeTypeRef(20)),
+                rt::Expr::And(vec![rt::ExprRef(74)], None),
+                rt::Expr::Or(vec![rt::ExprRef(75)]),
+                rt::Expr::Token(rt::NodeTypeRef(17)),
+                rt::Expr::Token(rt::NodeTypeRef(18)),
+                rt::Expr::Token(rt::NodeTypeRef(19)),
+                rt::Expr::And(vec![rt::ExprRef(77), rt::ExprRef(1), rt::ExprRef(78), rt::ExprRef(1), rt::ExprRef(79), rt::ExprRef(1)], None),
+                rt::Expr::Or(vec![rt::ExprRef(80)]),
+                rt::Expr::Token(rt::NodeTypeRef(2)),
+                rt::Expr::And(vec![rt::ExprRef(82)], None),
+                rt::Expr::Or(vec![rt::ExprRef(83)]),
+                rt::Expr::Token(rt::NodeTypeRef(12)),
+                rt::Expr::Token(rt::NodeTypeRef(13)),
+                rt::Expr::And(vec![rt::ExprRef(85), rt::ExprRef(1), rt::ExprRef(86)], None),
+                rt::Expr::Or(vec![rt::ExprRef(87)]),
+                rt::Expr::Token(rt::NodeTypeRef(7)),
+                rt::Expr::And(vec![rt::ExprRef(1), rt::ExprRef(89)], None),
+                rt::Expr::Or(vec![rt::ExprRef(90)]),
+                rt::Expr::Token(rt::NodeTypeRef(4)),
+                rt::Expr::And(vec![rt::ExprRef(92), rt::ExprRef(1)], None),
+                rt::Expr::Or(vec![rt::ExprRef(93)]),
+            ],
+                native: Some(native_parser),
+            .. Default::default()
+        }
+    }
+    use self::rt::*;
+    lazy_static! {
+        static ref LANG: rt::Language = {
+            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::DfaLexer };
+            impl rt::LanguageImpl for Impl {
+                fn parse(
+                    &self,
+                    text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, options, metrics, builder)
+                }
+
+                fn reparse(
+                    &self,
+                    incremental_data: &dyn std::any::Any,
+                    edit: &rt::TextEdit,
+                    new_text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, options, metrics, builder)
+                }
+
+                fn node_type_info(&self, ty: rt::NodeType) -> rt::NodeTypeInfo {
+                    match ty {
+                        ERROR => rt::NodeTypeInfo { name: "ERROR", whitespace_like: false },
+                        WHITESPACE => rt::NodeTypeInfo { name: "WHITESPACE", whitespace_like: true },
+                        NUMBER => rt::NodeTypeInfo { name: "NUMBER", whitespace_like: false },
+                        PLUS => rt::NodeTypeInfo { name: "PLUS", whitespace_like: false },
+                        MINUS => rt::NodeTypeInfo { name: "MINUS", whitespace_like: false },
+                        STAR => rt::NodeTypeInfo { name: "STAR", whitespace_like: false },
+                        SLASH => rt::NodeTypeInfo { name: "SLASH", whitespace_like: false },
+                        BANG => rt::NodeTypeInfo { name: "BANG", whitespace_like: false },
+                        CARET => rt::NodeTypeInfo { name: "CARET", whitespace_like: false },
+                        EQEQ => rt::NodeTypeInfo { name: "EQEQ", whitespace_like: false },
+                        QUESTION => rt::NodeTypeInfo { name: "QUESTION", whitespace_like: false },
+                        COLON => rt::NodeTypeInfo { name: "COLON", whitespace_like: false },
+                        LPAREN => rt::NodeTypeInfo { name: "LPAREN", whitespace_like: false },
+                        RPAREN => rt::NodeTypeInfo { name: "RPAREN", whitespace_like: false },
+                        LBRACK => rt::NodeTypeInfo { name: "LBRACK", whitespace_like: false },
+                        RBRACK => rt::NodeTypeInfo { name: "RBRACK", whitespace_like: false },
+                        AS => rt::NodeTypeInfo { name: "AS", whitespace_like: false },
+                        IF => rt::NodeTypeInfo { name: "IF", whitespace_like: false },
+                        THEN => rt::NodeTypeInfo { name: "THEN", whitespace_like: false },
+                        ELSE => rt::NodeTypeInfo { name: "ELSE", whitespace_like: false },
+                        INT => rt::NodeTypeInfo { name: "INT", whitespace_like: false },
+                        FILE => rt::NodeTypeInfo { name: "FILE", whitespace_like: false },
+                        COND_EXPR => rt::NodeTypeInfo { name: "COND_EXPR", whitespace_like: false },
+                        EQ_EXPR => rt::NodeTypeInfo { name: "EQ_EXPR", whitespace_like: false },
+                        PRODUCT_EXPR => rt::NodeTypeInfo { name: "PRODUCT_EXPR", whitespace_like: false },
+                        SUM_EXPR => rt::NodeTypeInfo { name: "SUM_EXPR", whitespace_like: false },
+                        POWER_EXPR => rt::NodeTypeInfo { name: "POWER_EXPR", whitespace_like: false },
+                        INDEX_EXPR => rt::NodeTypeInfo { name: "INDEX_EXPR", whitespace_like: false },
+                        CAST_EXPR => rt::NodeTypeInfo { name: "CAST_EXPR", whitespace_like: false },
+                        TYPE_REF => rt::NodeTypeInfo { name: "TYPE_REF", whitespace_like: false },
+                        IF_EXPR => rt::NodeTypeInfo { name: "IF_EXPR", whitespace_like: false },
+                        CONSTANT_EXPR => rt::NodeTypeInfo { name: "CONSTANT_EXPR", whitespace_like: false },
+                        PAREN_EXPR => rt::NodeTypeInfo { name: "PAREN_EXPR", whitespace_like: false },
+                        FACTORIAL_EXPR => rt::NodeTypeInfo { name: "FACTORIAL_EXPR", whitespace_like: false },
+                        NEGATE_EXPR => rt::NodeTypeInfo { name: "NEGATE_EXPR", whitespace_like: false },
+                        _ => panic!("Unknown rt::NodeType: {:?}", ty)
+                    }
+                }
+            }
+
+            rt::Language::new(Impl {
+                parser_definition: create_parser_definition(),
+                lexer: create_lexer()
+            })
+        };
+    }
+
+    &*LANG
+}
+fn native_parser(p: &mut rt::native::Parser, expr: rt::ExprRef, pos: rt::native::Pos) -> Option<Option<rt::native::Pos>> {
+    let result = match expr.0 {
+        0 => rule_0(p, pos),
+        1 => rule_1(p, pos),
+        2 => rule_2(p, pos),
+        3 => rule_3(p, pos),
+        4 => rule_4(p, pos),
+        5 => rule_5(p, pos),
+        6 => rule_6(p, pos),
+        7 => rule_7(p, pos),
+        8 => rule_8(p, pos),
+        9 => rule_9(p, pos),
+        10 => rule_10(p, pos),
+        11 => rule_11(p, pos),
+        12 => rule_12(p, pos),
+        13 => rule_13(p, pos),
+        14 => rule_14(p, pos),
+        15 => expr_15(p, pos),
+        16 => expr_16(p, pos),
+        17 => expr_17(p, pos),
+        18 => expr_18(p, pos),
+        19 => expr_19(p, pos),
+        20 => expr_20(p, pos),
+        21 => expr_21(p, pos),
+        22 => expr_22(p, pos),
+        23 => expr_23(p, pos),
+        24 => expr_24(p, pos),
+        25 => expr_25(p, pos),
+        26 => expr_26(p, pos),
+        27 => expr_27(p, pos),
+        28 => expr_28(p, pos),
+        29 => expr_29(p, pos),
+        30 => expr_30(p, pos),
+        31 => expr_31(p, pos),
+        32 => expr_32(p, pos),
+        33 => expr_33(p, pos),
+        34 => expr_34(p, pos),
+        35 => expr_35(p, pos),
+        36 => expr_36(p, pos),
+        37 => expr_37(p, pos),
+        38 => expr_38(p, pos),
+        39 => expr_39(p, pos),
+        40 => expr_40(p, pos),
+        41 => expr_41(p, pos),
+        42 => expr_42(p, pos),
+        43 => expr_43(p, pos),
+        44 => expr_44(p, pos),
+        45 => expr_45(p, pos),
+        46 => expr_46(p, pos),
+        47 => expr_47(p, pos),
+        48 => expr_48(p, pos),
+        49 => expr_49(p, pos),
+        50 => expr_50(p, pos),
+        51 => expr_51(p, pos),
+        52 => expr_52(p, pos),
+        53 => expr_53(p, pos),
+        54 => expr_54(p, pos),
+        55 => expr_55(p, pos),
+        56 => expr_56(p, pos),
+        57 => expr_57(p, pos),
+        58 => expr_58(p, pos),
+        59 => expr_59(p, pos),
+        60 => expr_60(p, pos),
+        61 => expr_61(p, pos),
+        62 => expr_62(p, pos),
+        63 => expr_63(p, pos),
+        64 => expr_64(p, pos),
+        65 => expr_65(p, pos),
+        66 => expr_66(p, pos),
+        67 => expr_67(p, pos),
+        68 => expr_68(p, pos),
+        69 => expr_69(p, pos),
+        70 => expr_70(p, pos),
+        71 => expr_71(p, pos),
+        72 => expr_72(p, pos),
+        73 => expr_73(p, pos),
+        74 => expr_74(p, pos),
+        75 => expr_75(p, pos),
+        76 => expr_76(p, pos),
+        77 => expr_77(p, pos),
+        78 => expr_78(p, pos),
+        79 => expr_79(p, pos),
+        80 => expr_80(p, pos),
+        81 => expr_81(p, pos),
+        82 => expr_82(p, pos),
+        83 => expr_83(p, pos),
+        84 => expr_84(p, pos),
+        85 => expr_85(p, pos),
+        86 => expr_86(p, pos),
+        87 => expr_87(p, pos),
+        88 => expr_88(p, pos),
+        89 => expr_89(p, pos),
+        90 => expr_90(p, pos),
+        91 => expr_91(p, pos),
+        92 => expr_92(p, pos),
+        93 => expr_93(p, pos),
+        94 => expr_94(p, pos),
+        _ => return None,
+    };
+    Some(result)
+}
+
+fn rule_0(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 21, false, pos, |p, pos| rt::native::expr(p, 16, pos, expr_16))
+}
+
+fn rule_1(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pratt(p, &[rt::ExprRef(11), rt::ExprRef(12)], &[rt::Prefix { ty: rt::NodeTypeRef(34), op: rt::ExprRef(38), priority: 999 }, rt::Prefix { ty: rt::NodeTypeRef(30), op: rt::ExprRef(42), priority: 1 }], &[rt::Infix { ty: rt::NodeTypeRef(22), op: rt::ExprRef(19), priority: 1, has_rhs: true, assoc: rt::Assoc::Right }, rt::Infix { ty: rt::NodeTypeRef(23), op: rt::ExprRef(20), priority: 2, has_rhs: true, assoc: rt::Assoc::NonAssoc }, rt::Infix { ty: rt::NodeTypeRef(25), op: rt::ExprRef(25), priority: 3, has_rhs: true, assoc: rt::Assoc::Left }, rt::Infix { ty: rt::NodeTypeRef(24), op: rt::ExprRef(30), priority: 4, has_rhs: true, assoc: rt::Assoc::Left }, rt::Infix { ty: rt::NodeTypeRef(26), op: rt::ExprRef(31), priority: 5, has_rhs: true, assoc: rt::Assoc::Right }, rt::Infix { ty: rt::NodeTypeRef(33), op: rt::ExprRef(32), priority: 999, has_rhs: false, assoc: rt::Assoc::Left }, rt::Infix { ty: rt::NodeTypeRef(27), op: rt::ExprRef(35), priority: 999, has_rhs: false, assoc: rt::Assoc::Left }, rt::Infix { ty: rt::NodeTypeRef(28), op: rt::ExprRef(37), priority: 999, has_rhs: false, assoc: rt::Assoc::Left }], pos)
+}
+
+fn rule_2(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 22, false, pos, |p, pos| rt::native::expr(p, 46, pos, expr_46))
+}
+
+fn rule_3(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 23, false, pos, |p, pos| rt::native::expr(p, 49, pos, expr_49))
+}
+
+fn rule_4(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 24, false, pos, |p, pos| rt::native::expr(p, 56, pos, expr_56))
+}
+
+fn rule_5(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 25, false, pos, |p, pos| rt::native::expr(p, 63, pos, expr_63))
+}
+
+fn rule_6(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 26, false, pos, |p, pos| rt::native::expr(p, 66, pos, expr_66))
+}
+
+fn rule_7(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 27, false, pos, |p, pos| rt::native::expr(p, 70, pos, expr_70))
+}
+
+fn rule_8(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 28, false, pos, |p, pos| rt::native::expr(p, 73, pos, expr_73))
+}
+
+fn rule_9(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 29, false, pos, |p, pos| rt::native::expr(p, 76, pos, expr_76))
+}
+
+fn rule_10(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 30, false, pos, |p, pos| rt::native::expr(p, 81, pos, expr_81))
+}
+
+fn rule_11(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 31, false, pos, |p, pos| rt::native::expr(p, 84, pos, expr_84))
+}
+
+fn rule_12(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 32, false, pos, |p, pos| rt::native::expr(p, 88, pos, expr_88))
+}
+
+fn rule_13(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 33, false, pos, |p, pos| rt::native::expr(p, 91, pos, expr_91))
+}
+
+fn rule_14(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 34, false, pos, |p, pos| rt::native::expr(p, 94, pos, expr_94))
+}
+
+fn expr_15(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(1)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 1, pos, rule_1), _ => unreachable!() })
+}
+
+fn expr_16(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 15, pos, expr_15), _ => unreachable!() })
+}
+
+fn expr_17(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 10, pos)
+}
+
+fn expr_18(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 11, pos)
+}
+
+fn expr_19(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(17), rt::ExprRef(1), rt::ExprRef(18)], Some(1), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 17, pos, expr_17), 1 => rt::native::expr(p, 1, pos, rule_1), 2 => rt::native::expr(p, 18, pos, expr_18), _ => unreachable!() })
+}
+
+fn expr_20(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 9, pos)
+}
+
+fn expr_21(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 3, pos)
+}
+
+fn expr_22(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(21)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 21, pos, expr_21), _ => unreachable!() })
+}
+
+fn expr_23(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 4, pos)
+}
+
+fn expr_24(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(23)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 23, pos, expr_23), _ => unreachable!() })
+}
+
+fn expr_25(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 2, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 22, pos, expr_22), 1 => rt::native::expr(p, 24, pos, expr_24), _ => unreachable!() })
+}
+
+fn expr_26(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 5, pos)
+}
+
+fn expr_27(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(26)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 26, pos, expr_26), _ => unreachable!() })
+}
+
+fn expr_28(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 6, pos)
+}
+
+fn expr_29(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(28)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 28, pos, expr_28), _ => unreachable!() })
+}
+
+fn expr_30(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 2, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 27, pos, expr_27), 1 => rt::native::expr(p, 29, pos, expr_29), _ => unreachable!() })
+}
+
+fn expr_31(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 8, pos)
+}
+
+fn expr_32(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 7, pos)
+}
+
+fn expr_33(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 14, pos)
+}
+
+fn expr_34(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 15, pos)
+}
+
+fn expr_35(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(33), rt::ExprRef(1), rt::ExprRef(34)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 33, pos, expr_33), 1 => rt::native::expr(p, 1, pos, rule_1), 2 => rt::native::expr(p, 34, pos, expr_34), _ => unreachable!() })
+}
+
+fn expr_36(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 16, pos)
+}
+
+fn expr_37(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(36), rt::ExprRef(9)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 36, pos, expr_36), 1 => rt::native::expr(p, 9, pos, rule_9), _ => unreachable!() })
+}
+
+fn expr_38(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 4, pos)
+}
+
+fn expr_39(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 17, pos)
+}
+
+fn expr_40(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 18, pos)
+}
+
+fn expr_41(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 19, pos)
+}
+
+fn expr_42(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(39), rt::ExprRef(1), rt::ExprRef(40), rt::ExprRef(1), rt::ExprRef(41)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 39, pos, expr_39), 1 => rt::native::expr(p, 1, pos, rule_1), 2 => rt::native::expr(p, 40, pos, expr_40), 3 => rt::native::expr(p, 1, pos, rule_1), 4 => rt::native::expr(p, 41, pos, expr_41), _ => unreachable!() })
+}
+
+fn expr_43(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 10, pos)
+}
+
+fn expr_44(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 11, pos)
+}
+
+fn expr_45(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(1), rt::ExprRef(43), rt::ExprRef(1), rt::ExprRef(44), rt::ExprRef(1)], Some(2), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 1, pos, rule_1), 1 => rt::native::expr(p, 43, pos, expr_43), 2 => rt::native::expr(p, 1, pos, rule_1), 3 => rt::native::expr(p, 44, pos, expr_44), 4 => rt::native::expr(p, 1, pos, rule_1), _ => unreachable!() })
+}
+
+fn expr_46(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 45, pos, expr_45), _ => unreachable!() })
+}
+
+fn expr_47(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 9, pos)
+}
+
+fn expr_48(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(1), rt::ExprRef(47), rt::ExprRef(1)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 1, pos, rule_1), 1 => rt::native::expr(p, 47, pos, expr_47), 2 => rt::native::expr(p, 1, pos, rule_1), _ => unreachable!() })
+}
+
+fn expr_49(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 48, pos, expr_48), _ => unreachable!() })
+}
+
+fn expr_50(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 5, pos)
+}
+
+fn expr_51(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(50)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 50, pos, expr_50), _ => unreachable!() })
+}
+
+fn expr_52(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 6, pos)
+}
+
+fn expr_53(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(52)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 52, pos, expr_52), _ => unreachable!() })
+}
+
+fn expr_54(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 2, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 51, pos, expr_51), 1 => rt::native::expr(p, 53, pos, expr_53), _ => unreachable!() })
+}
+
+fn expr_55(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(1), rt::ExprRef(54), rt::ExprRef(1)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 1, pos, rule_1), 1 => rt::native::expr(p, 54, pos, expr_54), 2 => rt::native::expr(p, 1, pos, rule_1), _ => unreachable!() })
+}
+
+fn expr_56(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 55, pos, expr_55), _ => unreachable!() })
+}
+
+fn expr_57(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 3, pos)
+}
+
+fn expr_58(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(57)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 57, pos, expr_57), _ => unreachable!() })
+}
+
+fn expr_59(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 4, pos)
+}
+
+fn expr_60(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(59)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 59, pos, expr_59), _ => unreachable!() })
+}
+
+fn expr_61(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 2, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 58, pos, expr_58), 1 => rt::native::expr(p, 60, pos, expr_60), _ => unreachable!() })
+}
+
+fn expr_62(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(1), rt::ExprRef(61), rt::ExprRef(1)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 1, pos, rule_1), 1 => rt::native::expr(p, 61, pos, expr_61), 2 => rt::native::expr(p, 1, pos, rule_1), _ => unreachable!() })
+}
+
+fn expr_63(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 62, pos, expr_62), _ => unreachable!() })
+}
+
+fn expr_64(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 8, pos)
+}
+
+fn expr_65(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(1), rt::ExprRef(64), rt::ExprRef(1)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 1, pos, rule_1), 1 => rt::native::expr(p, 64, pos, expr_64), 2 => rt::native::expr(p, 1, pos, rule_1), _ => unreachable!() })
+}
+
+fn expr_66(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 65, pos, expr_65), _ => unreachable!() })
+}
+
+fn expr_67(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 14, pos)
+}
+
+fn expr_68(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 15, pos)
+}
+
+fn expr_69(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(1), rt::ExprRef(67), rt::ExprRef(1), rt::ExprRef(68)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 1, pos, rule_1), 1 => rt::native::expr(p, 67, pos, expr_67), 2 => rt::native::expr(p, 1, pos, rule_1), 3 => rt::native::expr(p, 68, pos, expr_68), _ => unreachable!() })
+}
+
+fn expr_70(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 69, pos, expr_69), _ => unreachable!() })
+}
+
+fn expr_71(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 16, pos)
+}
+
+fn expr_72(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(1), rt::ExprRef(71), rt::ExprRef(9)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 1, pos, rule_1), 1 => rt::native::expr(p, 71, pos, expr_71), 2 => rt::native::expr(p, 9, pos, rule_9), _ => unreachable!() })
+}
+
+fn expr_73(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 72, pos, expr_72), _ => unreachable!() })
+}
+
+fn expr_74(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 20, pos)
+}
+
+fn expr_75(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(74)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 74, pos, expr_74), _ => unreachable!() })
+}
+
+fn expr_76(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 75, pos, expr_75), _ => unreachable!() })
+}
+
+fn expr_77(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 17, pos)
+}
+
+fn expr_78(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 18, pos)
+}
+
+fn expr_79(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 19, pos)
+}
+
+fn expr_80(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(77), rt::ExprRef(1), rt::ExprRef(78), rt::ExprRef(1), rt::ExprRef(79), rt::ExprRef(1)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 77, pos, expr_77), 1 => rt::native::expr(p, 1, pos, rule_1), 2 => rt::native::expr(p, 78, pos, expr_78), 3 => rt::native::expr(p, 1, pos, rule_1), 4 => rt::native::expr(p, 79, pos, expr_79), 5 => rt::native::expr(p, 1, pos, rule_1), _ => unreachable!() })
+}
+
+fn expr_81(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 80, pos, expr_80), _ => unreachable!() })
+}
+
+fn expr_82(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 2, pos)
+}
+
+fn expr_83(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(82)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 82, pos, expr_82), _ => unreachable!() })
+}
+
+fn expr_84(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 83, pos, expr_83), _ => unreachable!() })
+}
+
+fn expr_85(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 12, pos)
+}
+
+fn expr_86(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 13, pos)
+}
+
+fn expr_87(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(85), rt::ExprRef(1), rt::ExprRef(86)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 85, pos, expr_85), 1 => rt::native::expr(p, 1, pos, rule_1), 2 => rt::native::expr(p, 86, pos, expr_86), _ => unreachable!() })
+}
+
+fn expr_88(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 87, pos, expr_87), _ => unreachable!() })
+}
+
+fn expr_89(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 7, pos)
+}
+
+fn expr_90(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(1), rt::ExprRef(89)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 1, pos, rule_1), 1 => rt::native::expr(p, 89, pos, expr_89), _ => unreachable!() })
+}
+
+fn expr_91(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 90, pos, expr_90), _ => unreachable!() })
+}
+
+fn expr_92(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 4, pos)
+}
+
+fn expr_93(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(92), rt::ExprRef(1)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 92, pos, expr_92), 1 => rt::native::expr(p, 1, pos, rule_1), _ => unreachable!() })
+}
+
+fn expr_94(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 93, pos, expr_93), _ => unreachable!() })
+}
+
+
+
+
@@ -0,0 +1,177 @@
+use fall_parse::runtime as rt;
+pub use self::rt::ERROR;
+
+pub const WHITESPACE: rt::NodeType = rt::NodeType(100);
+pub const COMMENT_START: rt::NodeType = rt::NodeType(101);
+pub const LBRACE: rt::NodeType = rt::NodeType(102);
+pub const RBRACE: rt::NodeType = rt::NodeType(103);
+pub const QUOTE: rt::NodeType = rt::NodeType(104);
+pub const EQ: rt::NodeType = rt::NodeType(105);
+pub const LET: rt::NodeType = rt::NodeType(106);
+pub const NUMBER: rt::NodeType = rt::NodeType(107);
+pub const IDENT: rt::NodeType = rt::NodeType(108);
+pub const STRING_END: rt::NodeType = rt::NodeType(109);
+pub const INTERPOLATION_START: rt::NodeType = rt::NodeType(110);
+pub const STRING_TEXT: rt::NodeType = rt::NodeType(111);
+pub const NESTED_COMMENT_START: rt::NodeType = rt::NodeType(112);
+pub const COMMENT_END: rt::NodeType = rt::NodeType(113);
+pub const COMMENT_TEXT: rt::NodeType = rt::NodeType(114);
+pub const FILE: rt::NodeType = rt::NodeType(115);
+pub const LET_STMT: rt::NodeType = rt::NodeType(116);
+pub const BLOCK: rt::NodeType = rt::NodeType(117);
+pub const STRING: rt::NodeType = rt::NodeType(118);
+pub const INTERPOLATION: rt::NodeType = rt::NodeType(119);
+
+
+pub fn language() -> &'static rt::Language {
+    fn create_lexer() -> rt::DfaLexer {
+        static RULES: &[rt::DfaRule] = &[
+            rt::DfaRule { ty: WHITESPACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: COMMENT_START, mode: 0, transition: rt::ModeTransition::Push(2), f: None },
+            rt::DfaRule { ty: LBRACE, mode: 0, transition: rt::ModeTransition::Push(0), f: None },
+            rt::DfaRule { ty: RBRACE, mode: 0, transition: rt::ModeTransition::Pop, f: None },
+            rt::DfaRule { ty: QUOTE, mode: 0, transition: rt::ModeTransition::Push(1), f: None },
+            rt::DfaRule { ty: EQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LET, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: NUMBER, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: IDENT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STRING_END, mode: 1, transition: rt::ModeTransition::Pop, f: None },
+            rt::DfaRule { ty: INTERPOLATION_START, mode: 1, transition: rt::ModeTransition::Push(0), f: None },
+            rt::DfaRule { ty: STRING_TEXT, mode: 1, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: NESTED_COMMENT_START, mode: 2, transition: rt::ModeTransition::Push(2), f: None },
+            rt::DfaRule { ty: COMMENT_END, mode: 2, transition: rt::ModeTransition::Pop, f: None },
+            rt::DfaRule { ty: COMMENT_TEXT, mode: 2, transition: rt::ModeTransition::Stay, f: None },
+        ];
+        static ASCII_CLASSES: [u8; 128] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 0, 2, 0, 3, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 5, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 0, 0, 0, 7, 0, 0,
+            0, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 0, 0, 0, 0, 8,
+            0, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 8, 9, 0, 10, 0, 0,
+        ];
+        static UNICODE_CLASSES: &[(u32, u8)] = &[
+            (0x80, 0), (0x85, 1), (0x86, 0), (0xA0, 1), (0xA1, 0), (0x1680, 1), (0x1681, 0), (0x2000, 1),
+            (0x200B, 0), (0x2028, 1), (0x202A, 0), (0x202F, 1), (0x2030, 0), (0x205F, 1), (0x2060, 0), (0x3000, 1),
+            (0x3001, 0),
+        ];
+        static TRANSITIONS: &[u16] = &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 4, 5, 0, 0, 6, 7, 8, 9, 10, 11,
+            12, 12, 13, 14, 12, 12, 12, 12, 12, 12, 12,
+            15, 15, 15, 15, 16, 17, 15, 15, 15, 15, 15,
+            0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 9, 0, 9, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            12, 12, 0, 0, 12, 12, 12, 12, 12, 12, 12,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0,
+            15, 15, 15, 15, 0, 0, 15, 15, 15, 15, 15,
+            0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        static ACCEPTS: &[u16] = &[0, 0, 0, 0, 1, 5, 0, 8, 6, 9, 3, 4, 12, 10, 12, 15, 15, 15, 2, 11, 14, 13];
+        static KEYWORDS: &[(&str, u16)] = &[
+            ("", 0),
+            ("", 0),
+            ("let", 6),
+            ("", 0),
+        ];
+        rt::DfaLexer {
+            rules: RULES,
+            ascii_classes: &ASCII_CLASSES,
+            unicode_classes: UNICODE_CLASSES,
+            n_classes: 11,
+            transitions: TRANSITIONS,
+            accepts: ACCEPTS,
+            starts: &[1, 2, 3],
+            keyword_seed: 0,
+            keywords: KEYWORDS,
+            layout: None,
+        }
+    }
+
+    fn create_parser_definition() -> rt::ParserDefinition {
+        let parser_json = r##"[{"Pub":{"ty":16,"body":9,"replaceable":false}},{"Or":[10,11]},{"Pub":{"ty":17,"body":16,"replaceable":false}},{"Or":[18,20,21,22]},{"Pub":{"ty":18,"body":27,"replaceable":false}},{"Pub":{"ty":19,"body":36,"replaceable":false}},{"Pub":{"ty":20,"body":41,"replaceable":false}},{"Rep":1},{"And":[[7],null]},{"Or":[8]},{"And":[[2],null]},{"And":[[3],null]},{"Token":7},{"Token":9},{"Token":6},{"And":[[12,13,14,3],1]},{"Or":[15]},{"Token":9},{"And":[[17],null]},{"Token":8},{"And":[[19],null]},{"And":[[5],null]},{"And":[[4],null]},{"Token":3},{"Rep":1},{"Token":4},{"And":[[23,24,25],null]},{"Or":[26]},{"Token":5},{"Token":12},{"And":[[29],null]},{"And":[[6],null]},{"Or":[30,31]},{"Rep":32},{"Token":10},{"And":[[28,33,34],null]},{"Or":[35]},{"Token":11},{"Rep":1},{"Token":4},{"And":[[37,38,39],1]},{"Or":[40]}]"##;
+
+        ::fall_parse::ParserDefinition {
+            node_types: vec![
+                rt::ERROR,
+                WHITESPACE, COMMENT_START, LBRACE, RBRACE, QUOTE, EQ, LET, NUMBER, IDENT, STRING_END, INTERPOLATION_START, STRING_TEXT, NESTED_COMMENT_START, COMMENT_END, COMMENT_TEXT, FILE, LET_STMT, BLOCK, STRING, INTERPOLATION,
+            ],
+            syntactical_rules: rt::parser_from_str(parser_json),
+            
+            .. Default::default()
+        }
+    }
+    use self::rt::*;
+    lazy_static! {
+        static ref LANG: rt::Language = {
+            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::DfaLexer };
+            impl rt::LanguageImpl for Impl {
+                fn parse(
+                    &self,
+                    text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, options, metrics, builder)
+                }
+
+                fn reparse(
+                    &self,
+                    incremental_data: &dyn std::any::Any,
+                    edit: &rt::TextEdit,
+                    new_text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, options, metrics, builder)
+                }
+
+                fn node_type_info(&self, ty: rt::NodeType) -> rt::NodeTypeInfo {
+                    match ty {
+                        ERROR => rt::NodeTypeInfo { name: "ERROR", whitespace_like: false },
+                        WHITESPACE => rt::NodeTypeInfo { name: "WHITESPACE", whitespace_like: true },
+                        COMMENT_START => rt::NodeTypeInfo { name: "COMMENT_START", whitespace_like: true },
+                        LBRACE => rt::NodeTypeInfo { name: "LBRACE", whitespace_like: false },
+                        RBRACE => rt::NodeTypeInfo { name: "RBRACE", whitespace_like: false },
+                        QUOTE => rt::NodeTypeInfo { name: "QUOTE", whitespace_like: false },
+                        EQ => rt::NodeTypeInfo { name: "EQ", whitespace_like: false },
+                        LET => rt::NodeTypeInfo { name: "LET", whitespace_like: false },
+                        NUMBER => rt::NodeTypeInfo { name: "NUMBER", whitespace_like: false },
+                        IDENT => rt::NodeTypeInfo { name: "IDENT", whitespace_like: false },
+                        STRING_END => rt::NodeTypeInfo { name: "STRING_END", whitespace_like: false },
+                        INTERPOLATION_START => rt::NodeTypeInfo { name: "INTERPOLATION_START", whitespace_like: false },
+                        STRING_TEXT => rt::NodeTypeInfo { name: "STRING_TEXT", whitespace_like: false },
+                        NESTED_COMMENT_START => rt::NodeTypeInfo { name: "NESTED_COMMENT_START", whitespace_like: true },
+                        COMMENT_END => rt::NodeTypeInfo { name: "COMMENT_END", whitespace_like: true },
+                        COMMENT_TEXT => rt::NodeTypeInfo { name: "COMMENT_TEXT", whitespace_like: true },
+                        FILE => rt::NodeTypeInfo { name: "FILE", whitespace_like: false },
+                        LET_STMT => rt::NodeTypeInfo { name: "LET_STMT", whitespace_like: false },
+                        BLOCK => rt::NodeTypeInfo { name: "BLOCK", whitespace_like: false },
+                        STRING => rt::NodeTypeInfo { name: "STRING", whitespace_like: false },
+                        INTERPOLATION => rt::NodeTypeInfo { name: "INTERPOLATION", whitespace_like: false },
+                        _ => panic!("Unknown rt::NodeType: {:?}", ty)
+                    }
+                }
+            }
+
+            rt::Language::new(Impl {
+                parser_definition: create_parser_definition(),
+                lexer: create_lexer()
+            })
+        };
+    }
+
+    &*LANG
+}
@@ -0,0 +1,794 @@
+use fall_parse::runtime as rt;
+pub use self::rt::ERROR;
+
+pub const WHITESPACE: rt::NodeType = rt::NodeType(100);
+pub const LBRACE: rt::NodeType = rt::NodeType(101);
+pub const RBRACE: rt::NodeType = rt::NodeType(102);
+pub const LBRACK: rt::NodeType = rt::NodeType(103);
+pub const RBRACK: rt::NodeType = rt::NodeType(104);
+pub const COLON: rt::NodeType = rt::NodeType(105);
+pub const COMMA: rt::NodeType = rt::NodeType(106);
+pub const NULL: rt::NodeType = rt::NodeType(107);
+pub const BOOL: rt::NodeType = rt::NodeType(108);
+pub const STRING: rt::NodeType = rt::NodeType(109);
+pub const NUMBER: rt::NodeType = rt::NodeType(110);
+pub const FILE: rt::NodeType = rt::NodeType(111);
+pub const OBJECT: rt::NodeType = rt::NodeType(112);
+pub const FIELD: rt::NodeType = rt::NodeType(113);
+pub const ARRAY: rt::NodeType = rt::NodeType(114);
+pub const PRIMITIVE: rt::NodeType = rt::NodeType(115);
+
+
+pub fn language() -> &'static rt::Language {
+    fn create_lexer() -> rt::DfaLexer {
+        static RULES: &[rt::DfaRule] = &[
+            rt::DfaRule { ty: WHITESPACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LBRACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RBRACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LBRACK, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RBRACK, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: COLON, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: COMMA, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: NULL, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: BOOL, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STRING, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: NUMBER, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+        ];
+        static ASCII_CLASSES: [u8; 128] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 0, 0,
+            0, 8, 0, 0, 0, 9, 10, 0, 0, 0, 0, 0, 11, 0, 12, 0, 0, 0, 13, 14, 15, 16, 0, 0, 0, 0, 0, 17, 0, 18, 0, 0,
+        ];
+        static UNICODE_CLASSES: &[(u32, u8)] = &[
+            (0x80, 0), (0x85, 1), (0x86, 0), (0xA0, 1), (0xA1, 0), (0x660, 4), (0x66A, 0), (0x6F0, 4),
+            (0x6FA, 0), (0x7C0, 4), (0x7CA, 0), (0x966, 4), (0x970, 0), (0x9E6, 4), (0x9F0, 0), (0xA66, 4),
+            (0xA70, 0), (0xAE6, 4), (0xAF0, 0), (0xB66, 4), (0xB70, 0), (0xBE6, 4), (0xBF0, 0), (0xC66, 4),
+            (0xC70, 0), (0xCE6, 4), (0xCF0, 0), (0xD66, 4), (0xD70, 0), (0xDE6, 4), (0xDF0, 0), (0xE50, 4),
+            (0xE5A, 0), (0xED0, 4), (0xEDA, 0), (0xF20, 4), (0xF2A, 0), (0x1040, 4), (0x104A, 0), (0x1090, 4),
+            (0x109A, 0), (0x1680, 1), (0x1681, 0), (0x17E0, 4), (0x17EA, 0), (0x1810, 4), (0x181A, 0), (0x1946, 4),
+            (0x1950, 0), (0x19D0, 4), (0x19DA, 0), (0x1A80, 4), (0x1A8A, 0), (0x1A90, 4), (0x1A9A, 0), (0x1B50, 4),
+            (0x1B5A, 0), (0x1BB0, 4), (0x1BBA, 0), (0x1C40, 4), (0x1C4A, 0), (0x1C50, 4), (0x1C5A, 0), (0x2000, 1),
+            (0x200B, 0), (0x2028, 1), (0x202A, 0), (0x202F, 1), (0x2030, 0), (0x205F, 1), (0x2060, 0), (0x3000, 1),
+            (0x3001, 0), (0xA620, 4), (0xA62A, 0), (0xA8D0, 4), (0xA8DA, 0), (0xA900, 4), (0xA90A, 0), (0xA9D0, 4),
+            (0xA9DA, 0), (0xA9F0, 4), (0xA9FA, 0), (0xAA50, 4), (0xAA5A, 0), (0xABF0, 4), (0xABFA, 0), (0xFF10, 4),
+            (0xFF1A, 0), (0x104A0, 4), (0x104AA, 0), (0x10D30, 4), (0x10D3A, 0), (0x10D40, 4), (0x10D4A, 0), (0x11066, 4),
+            (0x11070, 0), (0x110F0, 4), (0x110FA, 0), (0x11136, 4), (0x11140, 0), (0x111D0, 4), (0x111DA, 0), (0x112F0, 4),
+            (0x112FA, 0), (0x11450, 4), (0x1145A, 0), (0x114D0, 4), (0x114DA, 0), (0x11650, 4), (0x1165A, 0), (0x116C0, 4),
+            (0x116CA, 0), (0x116D0, 4), (0x116E4, 0), (0x11730, 4), (0x1173A, 0), (0x118E0, 4), (0x118EA, 0), (0x11950, 4),
+            (0x1195A, 0), (0x11BF0, 4), (0x11BFA, 0), (0x11C50, 4), (0x11C5A, 0), (0x11D50, 4), (0x11D5A, 0), (0x11DA0, 4),
+            (0x11DAA, 0), (0x11F50, 4), (0x11F5A, 0), (0x16130, 4), (0x1613A, 0), (0x16A60, 4), (0x16A6A, 0), (0x16AC0, 4),
+            (0x16ACA, 0), (0x16B50, 4), (0x16B5A, 0), (0x16D70, 4), (0x16D7A, 0), (0x1CCF0, 4), (0x1CCFA, 0), (0x1D7CE, 4),
+            (0x1D800, 0), (0x1E140, 4), (0x1E14A, 0), (0x1E2F0, 4), (0x1E2FA, 0), (0x1E4F0, 4), (0x1E4FA, 0), (0x1E5F1, 4),
+            (0x1E5FB, 0), (0x1E950, 4), (0x1E95A, 0), (0x1FBF0, 4), (0x1FBFA, 0),
+        ];
+        static TRANSITIONS: &[u16] = &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 2, 3, 4, 5, 6, 7, 8, 0, 0, 9, 0, 10, 0, 0, 11, 0, 12, 13,
+            0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            3, 3, 14, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        static ACCEPTS: &[u16] = &[0, 0, 1, 0, 7, 11, 6, 4, 5, 0, 0, 0, 2, 3, 10, 0, 0, 0, 0, 0, 0, 8, 9];
+        static KEYWORDS: &[(&str, u16)] = &[
+        ];
+        rt::DfaLexer {
+            rules: RULES,
+            ascii_classes: &ASCII_CLASSES,
+            unicode_classes: UNICODE_CLASSES,
+            n_classes: 19,
+            transitions: TRANSITIONS,
+            accepts: ACCEPTS,
+            starts: &[1],
+            keyword_seed: 0,
+            keywords: KEYWORDS,
+            layout: None,
+        }
+    }
+
+    fn create_parser_definition() -> rt::ParserDefinition {
+
+        ::fall_parse::ParserDefinition {
+            node_types: vec![
+                rt::ERROR,
+                WHITESPACE, LBRACE, RBRACE, LBRACK, RBRACK, COLON, COMMA, NULL, BOOL, STRING, NUMBER, FILE, OBJECT, FIELD, ARRAY, PRIMITIVE,
+            ],
+            syntactical_rules: vec![
+                rt::Expr::Pub { ty: rt::NodeTypeRef(12), body: rt::ExprRef(14), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(13), body: rt::ExprRef(19), replaceable: false },
+                rt::Expr::Or(vec![rt::ExprRef(32)]),
+                rt::Expr::Pub { ty: rt::NodeTypeRef(14), body: rt::ExprRef(36), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(15), body: rt::ExprRef(41), replaceable: false },
+                rt::Expr::Or(vec![rt::ExprRef(66)]),
+                rt::Expr::Or(vec![rt::ExprRef(67), rt::ExprRef(68), rt::ExprRef(69)]),
+                rt::Expr::Pub { ty: rt::NodeTypeRef(16), body: rt::ExprRef(78), replaceable: false },
+                rt::Expr::Or(vec![rt::ExprRef(80)]),
+                rt::Expr::Or(vec![rt::ExprRef(83), rt::ExprRef(89)]),
+                rt::Expr::Or(vec![rt::ExprRef(91)]),
+                rt::Expr::Or(vec![rt::ExprRef(94), rt::ExprRef(100)]),
+                rt::Expr::And(vec![rt::ExprRef(1)], None),
+                rt::Expr::And(vec![rt::ExprRef(4)], None),
+                rt::Expr::Or(vec![rt::ExprRef(12), rt::ExprRef(13)]),
+                rt::Expr::Token(rt::NodeTypeRef(2)),
+                rt::Expr::Layer(rt::ExprRef(8), rt::ExprRef(2)),
+                rt::Expr::Token(rt::NodeTypeRef(3)),
+                rt::Expr::And(vec![rt::ExprRef(15), rt::ExprRef(16), rt::ExprRef(17)], Some(1)),
+                rt::Expr::Or(vec![rt::ExprRef(18)]),
+                rt::Expr::Token(rt::NodeTypeRef(10)),
+                rt::Expr::Token(rt::NodeTypeRef(7)),
+                rt::Expr::Eof,
+                rt::Expr::Not(rt::ExprRef(22)),
+                rt::Expr::And(vec![rt::ExprRef(21), rt::ExprRef(23)], None),
+                rt::Expr::Eof,
+                rt::Expr::And(vec![rt::ExprRef(25)], None),
+                rt::Expr::Or(vec![rt::ExprRef(24), rt::ExprRef(26)]),
+                rt::Expr::And(vec![rt::ExprRef(3), rt::ExprRef(27)], Some(1)),
+                rt::Expr::Or(vec![rt::ExprRef(28)]),
+                rt::Expr::WithSkip(rt::ExprRef(20), rt::ExprRef(29)),
+                rt::Expr::Rep(rt::ExprRef(30)),
+                rt::Expr::And(vec![rt::ExprRef(31)], None),
+                rt::Expr::Token(rt::NodeTypeRef(10)),
+                rt::Expr::Token(rt::NodeTypeRef(6)),
+                rt::Expr::And(vec![rt::ExprRef(33), rt::ExprRef(34), rt::ExprRef(6)], Some(1)),
+                rt::Expr::Or(vec![rt::ExprRef(35)]),
+                rt::Expr::Token(rt::NodeTypeRef(4)),
+                rt::Expr::Layer(rt::ExprRef(10), rt::ExprRef(5)),
+                rt::Expr::Token(rt::NodeTypeRef(5)),
+                rt::Expr::And(vec![rt::ExprRef(37), rt::ExprRef(38), rt::ExprRef(39)], Some(1)),
+                rt::Expr::Or(vec![rt::ExprRef(40)]),
+                rt::Expr::Token(rt::NodeTypeRef(8)),
+                rt::Expr::And(vec![rt::ExprRef(42)], None),
+                rt::Expr::Token(rt::NodeTypeRef(11)),
+                rt::Expr::And(vec![rt::ExprRef(44)], None),
+                rt::Expr::Token(rt::NodeTypeRef(10)),
+                rt::Expr::And(vec![rt::ExprRef(46)], None),
+                rt::Expr::Token(rt::NodeTypeRef(9)),
+                rt::Expr::And(vec![rt::ExprRef(48)], None),
+                rt::Expr::Token(rt::NodeTypeRef(2)),
+                rt::Expr::And(vec![rt::ExprRef(50)], None),
+                rt::Expr::Token(rt::NodeTypeRef(4)),
+                rt::Expr::And(vec![rt::ExprRef(52)], None),
+                rt::Expr::Or(vec![rt::ExprRef(43), rt::ExprRef(45), rt::ExprRef(47), rt::ExprRef(49), rt::ExprRef(51), rt::ExprRef(53)]),
+                rt::Expr::Token(rt::NodeTypeRef(7)),
+                rt::Expr::Eof,
+                rt::Expr::Not(rt::ExprRef(56)),
+                rt::Expr::And(vec![rt::ExprRef(55), rt::ExprRef(57)], None),
+                rt::Expr::Eof,
+                rt::Expr::And(vec![rt::ExprRef(59)], None),
+                rt::Expr::Or(vec![rt::ExprRef(58), rt::ExprRef(60)]),
+                rt::Expr::And(vec![rt::ExprRef(6), rt::ExprRef(61)], Some(1)),
+                rt::Expr::Or(vec![rt::ExprRef(62)]),
+                rt::Expr::WithSkip(rt::ExprRef(54), rt::ExprRef(63)),
+                rt::Expr::Rep(rt::ExprRef(64)),
+                rt::Expr::And(vec![rt::ExprRef(65)], None),
+                rt::Expr::And(vec![rt::ExprRef(7)], None),
+                rt::Expr::And(vec![rt::ExprRef(1)], None),
+                rt::Expr::And(vec![rt::ExprRef(4)], None),
+                rt::Expr::Token(rt::NodeTypeRef(8)),
+                rt::Expr::And(vec![rt::ExprRef(70)], None),
+                rt::Expr::Token(rt::NodeTypeRef(11)),
+                rt::Expr::And(vec![rt::ExprRef(72)], None),
+                rt::Expr::Token(rt::NodeTypeRef(10)),
+                rt::Expr::And(vec![rt::ExprRef(74)], None),
+                rt::Expr::Token(rt::NodeTypeRef(9)),
+                rt::Expr::And(vec![rt::ExprRef(76)], None),
+                rt::Expr::Or(vec![rt::ExprRef(71), rt::ExprRef(73), rt::ExprRef(75), rt::ExprRef(77)]),
+                rt::Expr::Rep(rt::ExprRef(9)),
+                rt::Expr::And(vec![rt::ExprRef(79)], None),
+                rt::Expr::Token(rt::NodeTypeRef(2)),
+                rt::Expr::Token(rt::NodeTypeRef(3)),
+                rt::Expr::And(vec![rt::ExprRef(81), rt::ExprRef(8), rt::ExprRef(82)], Some(1)),
+                rt::Expr::Token(rt::NodeTypeRef(3)),
+                rt::Expr::Not(rt::ExprRef(84)),
+                rt::Expr::Any,
+                rt::Expr::And(vec![rt::ExprRef(85), rt::ExprRef(86)], None),
+                rt::Expr::Or(vec![rt::ExprRef(87)]),
+                rt::Expr::And(vec![rt::ExprRef(88)], None),
+                rt::Expr::Rep(rt::ExprRef(11)),
+                rt::Expr::And(vec![rt::ExprRef(90)], None),
+                rt::Expr::Token(rt::NodeTypeRef(4)),
+                rt::Expr::Token(rt::NodeTypeRef(5)),
+                rt::Expr::And(vec![rt::ExprRef(92), rt::ExprRef(10), rt::ExprRef(93)], Some(1)),
+                rt::Expr::Token(rt::NodeTypeRef(5)),
+                rt::Expr::Not(rt::ExprRef(95)),
+                rt::Expr::Any,
+                rt::Expr::And(vec![rt::ExprRef(96), rt::ExprRef(97)], None),
+                rt::Expr::Or(vec![rt::ExprRef(98)]),
+                rt::Expr::And(vec![rt::ExprRef(99)], None),
+            ],
+                native: Some(native_parser),
+            .. Default::default()
+        }
+    }
+    use self::rt::*;
+    lazy_static! {
+        static ref LANG: rt::Language = {
+            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::DfaLexer };
+            impl rt::LanguageImpl for Impl {
+                fn parse(
+                    &self,
+                    text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, options, metrics, builder)
+                }
+
+                fn reparse(
+                    &self,
+                    incremental_data: &dyn std::any::Any,
+                    edit: &rt::TextEdit,
+                    new_text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, options, metrics, builder)
+                }
+
+                fn node_type_info(&self, ty: rt::NodeType) -> rt::NodeTypeInfo {
+                    match ty {
+                        ERROR => rt::NodeTypeInfo { name: "ERROR", whitespace_like: false },
+                        WHITESPACE => rt::NodeTypeInfo { name: "WHITESPACE", whitespace_like: true },
+                        LBRACE => rt::NodeTypeInfo { name: "LBRACE", whitespace_like: false },
+                        RBRACE => rt::NodeTypeInfo { name: "RBRACE", whitespace_like: false },
+                        LBRACK => rt::NodeTypeInfo { name: "LBRACK", whitespace_like: false },
+                        RBRACK => rt::NodeTypeInfo { name: "RBRACK", whitespace_like: false },
+                        COLON => rt::NodeTypeInfo { name: "COLON", whitespace_like: false },
+                        COMMA => rt::NodeTypeInfo { name: "COMMA", whitespace_like: false },
+                        NULL => rt::NodeTypeInfo { name: "NULL", whitespace_like: false },
+                        BOOL => rt::NodeTypeInfo { name: "BOOL", whitespace_like: false },
+                        STRING => rt::NodeTypeInfo { name: "STRING", whitespace_like: false },
+                        NUMBER => rt::NodeTypeInfo { name: "NUMBER", whitespace_like: false },
+                        FILE => rt::NodeTypeInfo { name: "FILE", whitespace_like: false },
+                        OBJECT => rt::NodeTypeInfo { name: "OBJECT", whitespace_like: false },
+                        FIELD => rt::NodeTypeInfo { name: "FIELD", whitespace_like: false },
+                        ARRAY => rt::NodeTypeInfo { name: "ARRAY", whitespace_like: false },
+                        PRIMITIVE => rt::NodeTypeInfo { name: "PRIMITIVE", whitespace_like: false },
+                        _ => panic!("Unknown rt::NodeType: {:?}", ty)
+                    }
+                }
+            }
+
+            rt::Language::new(Impl {
+                parser_definition: create_parser_definition(),
+                lexer: create_lexer()
+            })
+        };
+    }
+
+    &*LANG
+}
+fn native_parser(p: &mut rt::native::Parser, expr: rt::ExprRef, pos: rt::native::Pos) -> Option<Option<rt::native::Pos>> {
+    let result = match expr.0 {
+        0 => rule_0(p, pos),
+        1 => rule_1(p, pos),
+        2 => rule_2(p, pos),
+        3 => rule_3(p, pos),
+        4 => rule_4(p, pos),
+        5 => rule_5(p, pos),
+        6 => rule_6(p, pos),
+        7 => rule_7(p, pos),
+        8 => rule_8(p, pos),
+        9 => rule_9(p, pos),
+        10 => rule_10(p, pos),
+        11 => rule_11(p, pos),
+        12 => expr_12(p, pos),
+        13 => expr_13(p, pos),
+        14 => expr_14(p, pos),
+        15 => expr_15(p, pos),
+        16 => expr_16(p, pos),
+        17 => expr_17(p, pos),
+        18 => expr_18(p, pos),
+        19 => expr_19(p, pos),
+        20 => expr_20(p, pos),
+        21 => expr_21(p, pos),
+        22 => expr_22(p, pos),
+        23 => expr_23(p, pos),
+        24 => expr_24(p, pos),
+        25 => expr_25(p, pos),
+        26 => expr_26(p, pos),
+        27 => expr_27(p, pos),
+        28 => expr_28(p, pos),
+        29 => expr_29(p, pos),
+        30 => expr_30(p, pos),
+        31 => expr_31(p, pos),
+        32 => expr_32(p, pos),
+        33 => expr_33(p, pos),
+        34 => expr_34(p, pos),
+        35 => expr_35(p, pos),
+        36 => expr_36(p, pos),
+        37 => expr_37(p, pos),
+        38 => expr_38(p, pos),
+        39 => expr_39(p, pos),
+        40 => expr_40(p, pos),
+        41 => expr_41(p, pos),
+        42 => expr_42(p, pos),
+        43 => expr_43(p, pos),
+        44 => expr_44(p, pos),
+        45 => expr_45(p, pos),
+        46 => expr_46(p, pos),
+        47 => expr_47(p, pos),
+        48 => expr_48(p, pos),
+        49 => expr_49(p, pos),
+        50 => expr_50(p, pos),
+        51 => expr_51(p, pos),
+        52 => expr_52(p, pos),
+        53 => expr_53(p, pos),
+        54 => expr_54(p, pos),
+        55 => expr_55(p, pos),
+        56 => expr_56(p, pos),
+        57 => expr_57(p, pos),
+        58 => expr_58(p, pos),
+        59 => expr_59(p, pos),
+        60 => expr_60(p, pos),
+        61 => expr_61(p, pos),
+        62 => expr_62(p, pos),
+        63 => expr_63(p, pos),
+        64 => expr_64(p, pos),
+        65 => expr_65(p, pos),
+        66 => expr_66(p, pos),
+        67 => expr_67(p, pos),
+        68 => expr_68(p, pos),
+        69 => expr_69(p, pos),
+        70 => expr_70(p, pos),
+        71 => expr_71(p, pos),
+        72 => expr_72(p, pos),
+        73 => expr_73(p, pos),
+        74 => expr_74(p, pos),
+        75 => expr_75(p, pos),
+        76 => expr_76(p, pos),
+        77 => expr_77(p, pos),
+        78 => expr_78(p, pos),
+        79 => expr_79(p, pos),
+        80 => expr_80(p, pos),
+        81 => expr_81(p, pos),
+        82 => expr_82(p, pos),
+        83 => expr_83(p, pos),
+        84 => expr_84(p, pos),
+        85 => expr_85(p, pos),
+        86 => expr_86(p, pos),
+        87 => expr_87(p, pos),
+        88 => expr_88(p, pos),
+        89 => expr_89(p, pos),
+        90 => expr_90(p, pos),
+        91 => expr_91(p, pos),
+        92 => expr_92(p, pos),
+        93 => expr_93(p, pos),
+        94 => expr_94(p, pos),
+        95 => expr_95(p, pos),
+        96 => expr_96(p, pos),
+        97 => expr_97(p, pos),
+        98 => expr_98(p, pos),
+        99 => expr_99(p, pos),
+        100 => expr_100(p, pos),
+        _ => return None,
+    };
+    Some(result)
+}
+
+fn rule_0(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 12, false, pos, |p, pos| rt::native::expr(p, 14, pos, expr_14))
+}
+
+fn rule_1(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 13, false, pos, |p, pos| rt::native::expr(p, 19, pos, expr_19))
+}
+
+fn rule_2(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 32, pos, expr_32), _ => unreachable!() })
+}
+
+fn rule_3(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 14, false, pos, |p, pos| rt::native::expr(p, 36, pos, expr_36))
+}
+
+fn rule_4(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 15, false, pos, |p, pos| rt::native::expr(p, 41, pos, expr_41))
+}
+
+fn rule_5(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 66, pos, expr_66), _ => unreachable!() })
+}
+
+fn rule_6(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 3, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 67, pos, expr_67), 1 => rt::native::expr(p, 68, pos, expr_68), 2 => rt::native::expr(p, 69, pos, expr_69), _ => unreachable!() })
+}
+
+fn rule_7(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 16, false, pos, |p, pos| rt::native::expr(p, 78, pos, expr_78))
+}
+
+fn rule_8(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 80, pos, expr_80), _ => unreachable!() })
+}
+
+fn rule_9(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 2, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 83, pos, expr_83), 1 => rt::native::expr(p, 89, pos, expr_89), _ => unreachable!() })
+}
+
+fn rule_10(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 91, pos, expr_91), _ => unreachable!() })
+}
+
+fn rule_11(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 2, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 94, pos, expr_94), 1 => rt::native::expr(p, 100, pos, expr_100), _ => unreachable!() })
+}
+
+fn expr_12(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(1)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 1, pos, rule_1), _ => unreachable!() })
+}
+
+fn expr_13(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(4)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 4, pos, rule_4), _ => unreachable!() })
+}
+
+fn expr_14(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 2, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 12, pos, expr_12), 1 => rt::native::expr(p, 13, pos, expr_13), _ => unreachable!() })
+}
+
+fn expr_15(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 2, pos)
+}
+
+fn expr_16(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::layer(p, 16, pos, |p, pos| rt::native::expr(p, 8, pos, rule_8), |p, pos| rt::native::expr(p, 2, pos, rule_2))
+}
+
+fn expr_17(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 3, pos)
+}
+
+fn expr_18(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(15), rt::ExprRef(16), rt::ExprRef(17)], Some(1), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 15, pos, expr_15), 1 => rt::native::expr(p, 16, pos, expr_16), 2 => rt::native::expr(p, 17, pos, expr_17), _ => unreachable!() })
+}
+
+fn expr_19(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 18, pos, expr_18), _ => unreachable!() })
+}
+
+fn expr_20(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 10, pos)
+}
+
+fn expr_21(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 7, pos)
+}
+
+fn expr_22(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::eof(p, pos)
+}
+
+fn expr_23(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::not(p, pos, |p, pos| rt::native::expr(p, 22, pos, expr_22))
+}
+
+fn expr_24(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(21), rt::ExprRef(23)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 21, pos, expr_21), 1 => rt::native::expr(p, 23, pos, expr_23), _ => unreachable!() })
+}
+
+fn expr_25(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::eof(p, pos)
+}
+
+fn expr_26(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(25)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 25, pos, expr_25), _ => unreachable!() })
+}
+
+fn expr_27(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 2, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 24, pos, expr_24), 1 => rt::native::expr(p, 26, pos, expr_26), _ => unreachable!() })
+}
+
+fn expr_28(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(3), rt::ExprRef(27)], Some(1), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 3, pos, rule_3), 1 => rt::native::expr(p, 27, pos, expr_27), _ => unreachable!() })
+}
+
+fn expr_29(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 28, pos, expr_28), _ => unreachable!() })
+}
+
+fn expr_30(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::with_skip(p, 20, pos, |p, pos| rt::native::expr(p, 20, pos, expr_20), |p, pos| rt::native::expr(p, 29, pos, expr_29))
+}
+
+fn expr_31(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::rep(p, pos, |p, pos| rt::native::expr(p, 30, pos, expr_30))
+}
+
+fn expr_32(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(31)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 31, pos, expr_31), _ => unreachable!() })
+}
+
+fn expr_33(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 10, pos)
+}
+
+fn expr_34(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 6, pos)
+}
+
+fn expr_35(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(33), rt::ExprRef(34), rt::ExprRef(6)], Some(1), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 33, pos, expr_33), 1 => rt::native::expr(p, 34, pos, expr_34), 2 => rt::native::expr(p, 6, pos, rule_6), _ => unreachable!() })
+}
+
+fn expr_36(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 35, pos, expr_35), _ => unreachable!() })
+}
+
+fn expr_37(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 4, pos)
+}
+
+fn expr_38(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::layer(p, 38, pos, |p, pos| rt::native::expr(p, 10, pos, rule_10), |p, pos| rt::native::expr(p, 5, pos, rule_5))
+}
+
+fn expr_39(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 5, pos)
+}
+
+fn expr_40(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(37), rt::ExprRef(38), rt::ExprRef(39)], Some(1), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 37, pos, expr_37), 1 => rt::native::expr(p, 38, pos, expr_38), 2 => rt::native::expr(p, 39, pos, expr_39), _ => unreachable!() })
+}
+
+fn expr_41(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 40, pos, expr_40), _ => unreachable!() })
+}
+
+fn expr_42(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 8, pos)
+}
+
+fn expr_43(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(42)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 42, pos, expr_42), _ => unreachable!() })
+}
+
+fn expr_44(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 11, pos)
+}
+
+fn expr_45(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(44)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 44, pos, expr_44), _ => unreachable!() })
+}
+
+fn expr_46(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 10, pos)
+}
+
+fn expr_47(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(46)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 46, pos, expr_46), _ => unreachable!() })
+}
+
+fn expr_48(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 9, pos)
+}
+
+fn expr_49(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(48)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 48, pos, expr_48), _ => unreachable!() })
+}
+
+fn expr_50(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 2, pos)
+}
+
+fn expr_51(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(50)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 50, pos, expr_50), _ => unreachable!() })
+}
+
+fn expr_52(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 4, pos)
+}
+
+fn expr_53(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(52)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 52, pos, expr_52), _ => unreachable!() })
+}
+
+fn expr_54(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 6, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 43, pos, expr_43), 1 => rt::native::expr(p, 45, pos, expr_45), 2 => rt::native::expr(p, 47, pos, expr_47), 3 => rt::native::expr(p, 49, pos, expr_49), 4 => rt::native::expr(p, 51, pos, expr_51), 5 => rt::native::expr(p, 53, pos, expr_53), _ => unreachable!() })
+}
+
+fn expr_55(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 7, pos)
+}
+
+fn expr_56(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::eof(p, pos)
+}
+
+fn expr_57(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::not(p, pos, |p, pos| rt::native::expr(p, 56, pos, expr_56))
+}
+
+fn expr_58(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(55), rt::ExprRef(57)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 55, pos, expr_55), 1 => rt::native::expr(p, 57, pos, expr_57), _ => unreachable!() })
+}
+
+fn expr_59(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::eof(p, pos)
+}
+
+fn expr_60(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(59)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 59, pos, expr_59), _ => unreachable!() })
+}
+
+fn expr_61(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 2, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 58, pos, expr_58), 1 => rt::native::expr(p, 60, pos, expr_60), _ => unreachable!() })
+}
+
+fn expr_62(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(6), rt::ExprRef(61)], Some(1), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 6, pos, rule_6), 1 => rt::native::expr(p, 61, pos, expr_61), _ => unreachable!() })
+}
+
+fn expr_63(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 62, pos, expr_62), _ => unreachable!() })
+}
+
+fn expr_64(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::with_skip(p, 54, pos, |p, pos| rt::native::expr(p, 54, pos, expr_54), |p, pos| rt::native::expr(p, 63, pos, expr_63))
+}
+
+fn expr_65(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::rep(p, pos, |p, pos| rt::native::expr(p, 64, pos, expr_64))
+}
+
+fn expr_66(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(65)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 65, pos, expr_65), _ => unreachable!() })
+}
+
+fn expr_67(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(7)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 7, pos, rule_7), _ => unreachable!() })
+}
+
+fn expr_68(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(1)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 1, pos, rule_1), _ => unreachable!() })
+}
+
+fn expr_69(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(4)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 4, pos, rule_4), _ => unreachable!() })
+}
+
+fn expr_70(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 8, pos)
+}
+
+fn expr_71(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(70)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 70, pos, expr_70), _ => unreachable!() })
+}
+
+fn expr_72(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 11, pos)
+}
+
+fn expr_73(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(72)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 72, pos, expr_72), _ => unreachable!() })
+}
+
+fn expr_74(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 10, pos)
+}
+
+fn expr_75(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(74)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 74, pos, expr_74), _ => unreachable!() })
+}
+
+fn expr_76(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 9, pos)
+}
+
+fn expr_77(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(76)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 76, pos, expr_76), _ => unreachable!() })
+}
+
+fn expr_78(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 4, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 71, pos, expr_71), 1 => rt::native::expr(p, 73, pos, expr_73), 2 => rt::native::expr(p, 75, pos, expr_75), 3 => rt::native::expr(p, 77, pos, expr_77), _ => unreachable!() })
+}
+
+fn expr_79(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::rep(p, pos, |p, pos| rt::native::expr(p, 9, pos, rule_9))
+}
+
+fn expr_80(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(79)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 79, pos, expr_79), _ => unreachable!() })
+}
+
+fn expr_81(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 2, pos)
+}
+
+fn expr_82(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 3, pos)
+}
+
+fn expr_83(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(81), rt::ExprRef(8), rt::ExprRef(82)], Some(1), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 81, pos, expr_81), 1 => rt::native::expr(p, 8, pos, rule_8), 2 => rt::native::expr(p, 82, pos, expr_82), _ => unreachable!() })
+}
+
+fn expr_84(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 3, pos)
+}
+
+fn expr_85(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::not(p, pos, |p, pos| rt::native::expr(p, 84, pos, expr_84))
+}
+
+fn expr_86(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::any(p, pos)
+}
+
+fn expr_87(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(85), rt::ExprRef(86)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 85, pos, expr_85), 1 => rt::native::expr(p, 86, pos, expr_86), _ => unreachable!() })
+}
+
+fn expr_88(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 87, pos, expr_87), _ => unreachable!() })
+}
+
+fn expr_89(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(88)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 88, pos, expr_88), _ => unreachable!() })
+}
+
+fn expr_90(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::rep(p, pos, |p, pos| rt::native::expr(p, 11, pos, rule_11))
+}
+
+fn expr_91(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(90)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 90, pos, expr_90), _ => unreachable!() })
+}
+
+fn expr_92(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 4, pos)
+}
+
+fn expr_93(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 5, pos)
+}
+
+fn expr_94(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(92), rt::ExprRef(10), rt::ExprRef(93)], Some(1), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 92, pos, expr_92), 1 => rt::native::expr(p, 10, pos, rule_10), 2 => rt::native::expr(p, 93, pos, expr_93), _ => unreachable!() })
+}
+
+fn expr_95(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 5, pos)
+}
+
+fn expr_96(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::not(p, pos, |p, pos| rt::native::expr(p, 95, pos, expr_95))
+}
+
+fn expr_97(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::any(p, pos)
+}
+
+fn expr_98(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(96), rt::ExprRef(97)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 96, pos, expr_96), 1 => rt::native::expr(p, 97, pos, expr_97), _ => unreachable!() })
+}
+
+fn expr_99(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 98, pos, expr_98), _ => unreachable!() })
+}
+
+fn expr_100(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(99)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 99, pos, expr_99), _ => unreachable!() })
+}
+
+
+
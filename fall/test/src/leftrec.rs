@@ -0,0 +1,172 @@
+use fall_parse::runtime as rt;
+pub use self::rt::ERROR;
+
+pub const WHITESPACE: rt::NodeType = rt::NodeType(100);
+pub const PLUS: rt::NodeType = rt::NodeType(101);
+pub const MINUS: rt::NodeType = rt::NodeType(102);
+pub const STAR: rt::NodeType = rt::NodeType(103);
+pub const COLONCOLON: rt::NodeType = rt::NodeType(104);
+pub const SEMI: rt::NodeType = rt::NodeType(105);
+pub const LPAREN: rt::NodeType = rt::NodeType(106);
+pub const RPAREN: rt::NodeType = rt::NodeType(107);
+pub const NUMBER: rt::NodeType = rt::NodeType(108);
+pub const IDENT: rt::NodeType = rt::NodeType(109);
+pub const FILE: rt::NodeType = rt::NodeType(110);
+pub const EXPR_STMT: rt::NodeType = rt::NodeType(111);
+pub const SUM_EXPR: rt::NodeType = rt::NodeType(112);
+pub const PRODUCT_EXPR: rt::NodeType = rt::NodeType(113);
+pub const LITERAL: rt::NodeType = rt::NodeType(114);
+pub const PAREN_EXPR: rt::NodeType = rt::NodeType(115);
+pub const PATH_EXPR: rt::NodeType = rt::NodeType(116);
+pub const PATH: rt::NodeType = rt::NodeType(117);
+
+
+pub fn language() -> &'static rt::Language {
+    fn create_lexer() -> rt::DfaLexer {
+        static RULES: &[rt::DfaRule] = &[
+            rt::DfaRule { ty: WHITESPACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: PLUS, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: MINUS, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STAR, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: COLONCOLON, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: SEMI, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LPAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RPAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: NUMBER, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: IDENT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+        ];
+        static ASCII_CLASSES: [u8; 128] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 0, 6, 0, 0, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 8, 9, 0, 0, 0, 0,
+            0, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 0, 0, 0, 10,
+            0, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 0, 0, 0, 0,
+        ];
+        static UNICODE_CLASSES: &[(u32, u8)] = &[
+            (0x80, 0), (0x85, 1), (0x86, 0), (0xA0, 1), (0xA1, 0), (0x660, 11), (0x66A, 0), (0x6F0, 11),
+            (0x6FA, 0), (0x7C0, 11), (0x7CA, 0), (0x966, 11), (0x970, 0), (0x9E6, 11), (0x9F0, 0), (0xA66, 11),
+            (0xA70, 0), (0xAE6, 11), (0xAF0, 0), (0xB66, 11), (0xB70, 0), (0xBE6, 11), (0xBF0, 0), (0xC66, 11),
+            (0xC70, 0), (0xCE6, 11), (0xCF0, 0), (0xD66, 11), (0xD70, 0), (0xDE6, 11), (0xDF0, 0), (0xE50, 11),
+            (0xE5A, 0), (0xED0, 11), (0xEDA, 0), (0xF20, 11), (0xF2A, 0), (0x1040, 11), (0x104A, 0), (0x1090, 11),
+            (0x109A, 0), (0x1680, 1), (0x1681, 0), (0x17E0, 11), (0x17EA, 0), (0x1810, 11), (0x181A, 0), (0x1946, 11),
+            (0x1950, 0), (0x19D0, 11), (0x19DA, 0), (0x1A80, 11), (0x1A8A, 0), (0x1A90, 11), (0x1A9A, 0), (0x1B50, 11),
+            (0x1B5A, 0), (0x1BB0, 11), (0x1BBA, 0), (0x1C40, 11), (0x1C4A, 0), (0x1C50, 11), (0x1C5A, 0), (0x2000, 1),
+            (0x200B, 0), (0x2028, 1), (0x202A, 0), (0x202F, 1), (0x2030, 0), (0x205F, 1), (0x2060, 0), (0x3000, 1),
+            (0x3001, 0), (0xA620, 11), (0xA62A, 0), (0xA8D0, 11), (0xA8DA, 0), (0xA900, 11), (0xA90A, 0), (0xA9D0, 11),
+            (0xA9DA, 0), (0xA9F0, 11), (0xA9FA, 0), (0xAA50, 11), (0xAA5A, 0), (0xABF0, 11), (0xABFA, 0), (0xFF10, 11),
+            (0xFF1A, 0), (0x104A0, 11), (0x104AA, 0), (0x10D30, 11), (0x10D3A, 0), (0x10D40, 11), (0x10D4A, 0), (0x11066, 11),
+            (0x11070, 0), (0x110F0, 11), (0x110FA, 0), (0x11136, 11), (0x11140, 0), (0x111D0, 11), (0x111DA, 0), (0x112F0, 11),
+            (0x112FA, 0), (0x11450, 11), (0x1145A, 0), (0x114D0, 11), (0x114DA, 0), (0x11650, 11), (0x1165A, 0), (0x116C0, 11),
+            (0x116CA, 0), (0x116D0, 11), (0x116E4, 0), (0x11730, 11), (0x1173A, 0), (0x118E0, 11), (0x118EA, 0), (0x11950, 11),
+            (0x1195A, 0), (0x11BF0, 11), (0x11BFA, 0), (0x11C50, 11), (0x11C5A, 0), (0x11D50, 11), (0x11D5A, 0), (0x11DA0, 11),
+            (0x11DAA, 0), (0x11F50, 11), (0x11F5A, 0), (0x16130, 11), (0x1613A, 0), (0x16A60, 11), (0x16A6A, 0), (0x16AC0, 11),
+            (0x16ACA, 0), (0x16B50, 11), (0x16B5A, 0), (0x16D70, 11), (0x16D7A, 0), (0x1CCF0, 11), (0x1CCFA, 0), (0x1D7CE, 11),
+            (0x1D800, 0), (0x1E140, 11), (0x1E14A, 0), (0x1E2F0, 11), (0x1E2FA, 0), (0x1E4F0, 11), (0x1E4FA, 0), (0x1E5F1, 11),
+            (0x1E5FB, 0), (0x1E950, 11), (0x1E95A, 0), (0x1FBF0, 11), (0x1FBFA, 0),
+        ];
+        static TRANSITIONS: &[u16] = &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 8,
+            0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 8,
+            0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 11, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        static ACCEPTS: &[u16] = &[0, 0, 1, 7, 8, 4, 2, 3, 9, 0, 6, 10, 5];
+        static KEYWORDS: &[(&str, u16)] = &[
+        ];
+        rt::DfaLexer {
+            rules: RULES,
+            ascii_classes: &ASCII_CLASSES,
+            unicode_classes: UNICODE_CLASSES,
+            n_classes: 12,
+            transitions: TRANSITIONS,
+            accepts: ACCEPTS,
+            starts: &[1],
+            keyword_seed: 0,
+            keywords: KEYWORDS,
+            layout: None,
+        }
+    }
+
+    fn create_parser_definition() -> rt::ParserDefinition {
+        let parser_json = r##"[{"Pub":{"ty":11,"body":13,"replaceable":false}},{"Pub":{"ty":12,"body":16,"replaceable":false}},{"LeftRec":50},{"LeftRec":51},{"LeftRec":52},{"LeftRec":53},{"Or":[31,32,33]},{"Pub":{"ty":15,"body":36,"replaceable":false}},{"Pub":{"ty":16,"body":41,"replaceable":false}},{"Pub":{"ty":17,"body":43,"replaceable":false}},{"LeftRec":54},{"Rep":1},{"And":[[11],null]},{"Or":[12]},{"Token":6},{"And":[[2,14],1]},{"Or":[15]},{"And":[[3],null]},{"And":[[4],null]},{"Token":2},{"And":[[19],null]},{"Token":3},{"And":[[21],null]},{"Or":[20,22]},{"And":[[2,23,4],2]},{"Or":[24]},{"And":[[5],null]},{"And":[[6],null]},{"Token":4},{"And":[[4,28,6],2]},{"Or":[29]},{"And":[[9],null]},{"And":[[7],null]},{"And":[[8],null]},{"Token":9},{"And":[[34],null]},{"Or":[35]},{"Token":7},{"Token":8},{"And":[[37,2,38],null]},{"Or":[39]},{"Cached":40},{"And":[[10],null]},{"Or":[42]},{"Token":5},{"Token":10},{"And":[[10,44,45],2]},{"Token":10},{"And":[[47],null]},{"Or":[46,48]},{"Or":[17,18]},{"Pub":{"ty":13,"body":25,"replaceable":false}},{"Or":[26,27]},{"Pub":{"ty":14,"body":30,"replaceable":false}},{"Pub":{"ty":18,"body":49,"replaceable":false}}]"##;
+
+        ::fall_parse::ParserDefinition {
+            node_types: vec![
+                rt::ERROR,
+                WHITESPACE, PLUS, MINUS, STAR, COLONCOLON, SEMI, LPAREN, RPAREN, NUMBER, IDENT, FILE, EXPR_STMT, SUM_EXPR, PRODUCT_EXPR, LITERAL, PAREN_EXPR, PATH_EXPR, PATH,
+            ],
+            syntactical_rules: rt::parser_from_str(parser_json),
+            
+            .. Default::default()
+        }
+    }
+    use self::rt::*;
+    lazy_static! {
+        static ref LANG: rt::Language = {
+            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::DfaLexer };
+            impl rt::LanguageImpl for Impl {
+                fn parse(
+                    &self,
+                    text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, options, metrics, builder)
+                }
+
+                fn reparse(
+                    &self,
+                    incremental_data: &dyn std::any::Any,
+                    edit: &rt::TextEdit,
+                    new_text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, options, metrics, builder)
+                }
+
+                fn node_type_info(&self, ty: rt::NodeType) -> rt::NodeTypeInfo {
+                    match ty {
+                        ERROR => rt::NodeTypeInfo { name: "ERROR", whitespace_like: false },
+                        WHITESPACE => rt::NodeTypeInfo { name: "WHITESPACE", whitespace_like: true },
+                        PLUS => rt::NodeTypeInfo { name: "PLUS", whitespace_like: false },
+                        MINUS => rt::NodeTypeInfo { name: "MINUS", whitespace_like: false },
+                        STAR => rt::NodeTypeInfo { name: "STAR", whitespace_like: false },
+                        COLONCOLON => rt::NodeTypeInfo { name: "COLONCOLON", whitespace_like: false },
+                        SEMI => rt::NodeTypeInfo { name: "SEMI", whitespace_like: false },
+                        LPAREN => rt::NodeTypeInfo { name: "LPAREN", whitespace_like: false },
+                        RPAREN => rt::NodeTypeInfo { name: "RPAREN", whitespace_like: false },
+                        NUMBER => rt::NodeTypeInfo { name: "NUMBER", whitespace_like: false },
+                        IDENT => rt::NodeTypeInfo { name: "IDENT", whitespace_like: false },
+                        FILE => rt::NodeTypeInfo { name: "FILE", whitespace_like: false },
+                        EXPR_STMT => rt::NodeTypeInfo { name: "EXPR_STMT", whitespace_like: false },
+                        SUM_EXPR => rt::NodeTypeInfo { name: "SUM_EXPR", whitespace_like: false },
+                        PRODUCT_EXPR => rt::NodeTypeInfo { name: "PRODUCT_EXPR", whitespace_like: false },
+                        LITERAL => rt::NodeTypeInfo { name: "LITERAL", whitespace_like: false },
+                        PAREN_EXPR => rt::NodeTypeInfo { name: "PAREN_EXPR", whitespace_like: false },
+                        PATH_EXPR => rt::NodeTypeInfo { name: "PATH_EXPR", whitespace_like: false },
+                        PATH => rt::NodeTypeInfo { name: "PATH", whitespace_like: false },
+                        _ => panic!("Unknown rt::NodeType: {:?}", ty)
+                    }
+                }
+            }
+
+            rt::Language::new(Impl {
+                parser_definition: create_parser_definition(),
+                lexer: create_lexer()
+            })
+        };
+    }
+
+    &*LANG
+}
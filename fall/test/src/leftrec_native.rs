@@ -0,0 +1,508 @@
+use fall_parse::runtime as rt;
+pub use self::rt::ERROR;
+
+pub const WHITESPACE: rt::NodeType = rt::NodeType(100);
+pub const PLUS: rt::NodeType = rt::NodeType(101);
+pub const MINUS: rt::NodeType = rt::NodeType(102);
+pub const STAR: rt::NodeType = rt::NodeType(103);
+pub const COLONCOLON: rt::NodeType = rt::NodeType(104);
+pub const SEMI: rt::NodeType = rt::NodeType(105);
+pub const LPAREN: rt::NodeType = rt::NodeType(106);
+pub const RPAREN: rt::NodeType = rt::NodeType(107);
+pub const NUMBER: rt::NodeType = rt::NodeType(108);
+pub const IDENT: rt::NodeType = rt::NodeType(109);
+pub const FILE: rt::NodeType = rt::NodeType(110);
+pub const EXPR_STMT: rt::NodeType = rt::NodeType(111);
+pub const SUM_EXPR: rt::NodeType = rt::NodeType(112);
+pub const PRODUCT_EXPR: rt::NodeType = rt::NodeType(113);
+pub const LITERAL: rt::NodeType = rt::NodeType(114);
+pub const PAREN_EXPR: rt::NodeType = rt::NodeType(115);
+pub const PATH_EXPR: rt::NodeType = rt::NodeType(116);
+pub const PATH: rt::NodeType = rt::NodeType(117);
+
+
+pub fn language() -> &'static rt::Language {
+    fn create_lexer() -> rt::DfaLexer {
+        static RULES: &[rt::DfaRule] = &[
+            rt::DfaRule { ty: WHITESPACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: PLUS, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: MINUS, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STAR, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: COLONCOLON, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: SEMI, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LPAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RPAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: NUMBER, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: IDENT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+        ];
+        static ASCII_CLASSES: [u8; 128] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 5, 0, 6, 0, 0, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 8, 9, 0, 0, 0, 0,
+            0, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 0, 0, 0, 10,
+            0, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 10, 0, 0, 0, 0, 0,
+        ];
+        static UNICODE_CLASSES: &[(u32, u8)] = &[
+            (0x80, 0), (0x85, 1), (0x86, 0), (0xA0, 1), (0xA1, 0), (0x660, 11), (0x66A, 0), (0x6F0, 11),
+            (0x6FA, 0), (0x7C0, 11), (0x7CA, 0), (0x966, 11), (0x970, 0), (0x9E6, 11), (0x9F0, 0), (0xA66, 11),
+            (0xA70, 0), (0xAE6, 11), (0xAF0, 0), (0xB66, 11), (0xB70, 0), (0xBE6, 11), (0xBF0, 0), (0xC66, 11),
+            (0xC70, 0), (0xCE6, 11), (0xCF0, 0), (0xD66, 11), (0xD70, 0), (0xDE6, 11), (0xDF0, 0), (0xE50, 11),
+            (0xE5A, 0), (0xED0, 11), (0xEDA, 0), (0xF20, 11), (0xF2A, 0), (0x1040, 11), (0x104A, 0), (0x1090, 11),
+            (0x109A, 0), (0x1680, 1), (0x1681, 0), (0x17E0, 11), (0x17EA, 0), (0x1810, 11), (0x181A, 0), (0x1946, 11),
+            (0x1950, 0), (0x19D0, 11), (0x19DA, 0), (0x1A80, 11), (0x1A8A, 0), (0x1A90, 11), (0x1A9A, 0), (0x1B50, 11),
+            (0x1B5A, 0), (0x1BB0, 11), (0x1BBA, 0), (0x1C40, 11), (0x1C4A, 0), (0x1C50, 11), (0x1C5A, 0), (0x2000, 1),
+            (0x200B, 0), (0x2028, 1), (0x202A, 0), (0x202F, 1), (0x2030, 0), (0x205F, 1), (0x2060, 0), (0x3000, 1),
+            (0x3001, 0), (0xA620, 11), (0xA62A, 0), (0xA8D0, 11), (0xA8DA, 0), (0xA900, 11), (0xA90A, 0), (0xA9D0, 11),
+            (0xA9DA, 0), (0xA9F0, 11), (0xA9FA, 0), (0xAA50, 11), (0xAA5A, 0), (0xABF0, 11), (0xABFA, 0), (0xFF10, 11),
+            (0xFF1A, 0), (0x104A0, 11), (0x104AA, 0), (0x10D30, 11), (0x10D3A, 0), (0x10D40, 11), (0x10D4A, 0), (0x11066, 11),
+            (0x11070, 0), (0x110F0, 11), (0x110FA, 0), (0x11136, 11), (0x11140, 0), (0x111D0, 11), (0x111DA, 0), (0x112F0, 11),
+            (0x112FA, 0), (0x11450, 11), (0x1145A, 0), (0x114D0, 11), (0x114DA, 0), (0x11650, 11), (0x1165A, 0), (0x116C0, 11),
+            (0x116CA, 0), (0x116D0, 11), (0x116E4, 0), (0x11730, 11), (0x1173A, 0), (0x118E0, 11), (0x118EA, 0), (0x11950, 11),
+            (0x1195A, 0), (0x11BF0, 11), (0x11BFA, 0), (0x11C50, 11), (0x11C5A, 0), (0x11D50, 11), (0x11D5A, 0), (0x11DA0, 11),
+            (0x11DAA, 0), (0x11F50, 11), (0x11F5A, 0), (0x16130, 11), (0x1613A, 0), (0x16A60, 11), (0x16A6A, 0), (0x16AC0, 11),
+            (0x16ACA, 0), (0x16B50, 11), (0x16B5A, 0), (0x16D70, 11), (0x16D7A, 0), (0x1CCF0, 11), (0x1CCFA, 0), (0x1D7CE, 11),
+            (0x1D800, 0), (0x1E140, 11), (0x1E14A, 0), (0x1E2F0, 11), (0x1E2FA, 0), (0x1E4F0, 11), (0x1E4FA, 0), (0x1E5F1, 11),
+            (0x1E5FB, 0), (0x1E950, 11), (0x1E95A, 0), (0x1FBF0, 11), (0x1FBFA, 0),
+        ];
+        static TRANSITIONS: &[u16] = &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 8,
+            0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 0, 8,
+            0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 11, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        static ACCEPTS: &[u16] = &[0, 0, 1, 7, 8, 4, 2, 3, 9, 0, 6, 10, 5];
+        static KEYWORDS: &[(&str, u16)] = &[
+        ];
+        rt::DfaLexer {
+            rules: RULES,
+            ascii_classes: &ASCII_CLASSES,
+            unicode_classes: UNICODE_CLASSES,
+            n_classes: 12,
+            transitions: TRANSITIONS,
+            accepts: ACCEPTS,
+            starts: &[1],
+            keyword_seed: 0,
+            keywords: KEYWORDS,
+            layout: None,
+        }
+    }
+
+    fn create_parser_definition() -> rt::ParserDefinition {
+
+        ::fall_parse::ParserDefinition {
+            node_types: vec![
+                rt::ERROR,
+                WHITESPACE, PLUS, MINUS, STAR, COLONCOLON, SEMI, LPAREN, RPAREN, NUMBER, IDENT, FILE, EXPR_STMT, SUM_EXPR, PRODUCT_EXPR, LITERAL, PAREN_EXPR, PATH_EXPR, PATH,
+            ],
+            syntactical_rules: vec![
+                rt::Expr::Pub { ty: rt::NodeTypeRef(11), body: rt::ExprRef(13), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(12), body: rt::ExprRef(16), replaceable: false },
+                rt::Expr::LeftRec(rt::ExprRef(50)),
+                rt::Expr::LeftRec(rt::ExprRef(51)),
+                rt::Expr::LeftRec(rt::ExprRef(52)),
+                rt::Expr::LeftRec(rt::ExprRef(53)),
+                rt::Expr::Or(vec![rt::ExprRef(31), rt::ExprRef(32), rt::ExprRef(33)]),
+                rt::Expr::Pub { ty: rt::NodeTypeRef(15), body: rt::ExprRef(36), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(16), body: rt::ExprRef(41), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(17), body: rt::ExprRef(43), replaceable: false },
+                rt::Expr::LeftRec(rt::ExprRef(54)),
+                rt::Expr::Rep(rt::ExprRef(1)),
+                rt::Expr::And(vec![rt::ExprRef(11)], None),
+                rt::Expr::Or(vec![rt::ExprRef(12)]),
+                rt::Expr::Token(rt::NodeTypeRef(6)),
+                rt::Expr::And(vec![rt::ExprRef(2), rt::ExprRef(14)], Some(1)),
+                rt::Expr::Or(vec![rt::ExprRef(15)]),
+                rt::Expr::And(vec![rt::ExprRef(3)], None),
+                rt::Expr::And(vec![rt::ExprRef(4)], None),
+                rt::Expr::Token(rt::NodeTypeRef(2)),
+                rt::Expr::And(vec![rt::ExprRef(19)], None),
+                rt::Expr::Token(rt::NodeTypeRef(3)),
+                rt::Expr::And(vec![rt::ExprRef(21)], None),
+                rt::Expr::Or(vec![rt::ExprRef(20), rt::ExprRef(22)]),
+                rt::Expr::And(vec![rt::ExprRef(2), rt::ExprRef(23), rt::ExprRef(4)], Some(2)),
+                rt::Expr::Or(vec![rt::ExprRef(24)]),
+                rt::Expr::And(vec![rt::ExprRef(5)], None),
+                rt::Expr::And(vec![rt::ExprRef(6)], None),
+                rt::Expr::Token(rt::NodeTypeRef(4)),
+                rt::Expr::And(vec![rt::ExprRef(4), rt::ExprRef(28), rt::ExprRef(6)], Some(2)),
+                rt::Expr::Or(vec![rt::ExprRef(29)]),
+                rt::Expr::And(vec![rt::ExprRef(9)], None),
+                rt::Expr::And(vec![rt::ExprRef(7)], None),
+                rt::Expr::And(vec![rt::ExprRef(8)], None),
+                rt::Expr::Token(rt::NodeTypeRef(9)),
+                rt::Expr::And(vec![rt::ExprRef(34)], None),
+                rt::Expr::Or(vec![rt::ExprRef(35)]),
+                rt::Expr::Token(rt::NodeTypeRef(7)),
+                rt::Expr::Token(rt::NodeTypeRef(8)),
+                rt::Expr::And(vec![rt::ExprRef(37), rt::ExprRef(2), rt::ExprRef(38)], None),
+                rt::Expr::Or(vec![rt::ExprRef(39)]),
+                rt::Expr::Cached(rt::ExprRef(40)),
+                rt::Expr::And(vec![rt::ExprRef(10)], None),
+                rt::Expr::Or(vec![rt::ExprRef(42)]),
+                rt::Expr::Token(rt::NodeTypeRef(5)),
+                rt::Expr::Token(rt::NodeTypeRef(10)),
+                rt::Expr::And(vec![rt::ExprRef(10), rt::ExprRef(44), rt::ExprRef(45)], Some(2)),
+                rt::Expr::Token(rt::NodeTypeRef(10)),
+                rt::Expr::And(vec![rt::ExprRef(47)], None),
+                rt::Expr::Or(vec![rt::ExprRef(46), rt::ExprRef(48)]),
+                rt::Expr::Or(vec![rt::ExprRef(17), rt::ExprRef(18)]),
+                rt::Expr::Pub { ty: rt::NodeTypeRef(13), body: rt::ExprRef(25), replaceable: false },
+                rt::Expr::Or(vec![rt::ExprRef(26), rt::ExprRef(27)]),
+                rt::Expr::Pub { ty: rt::NodeTypeRef(14), body: rt::ExprRef(30), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(18), body: rt::ExprRef(49), replaceable: false },
+            ],
+                native: Some(native_parser),
+            .. Default::default()
+        }
+    }
+    use self::rt::*;
+    lazy_static! {
+        static ref LANG: rt::Language = {
+            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::DfaLexer };
+            impl rt::LanguageImpl for Impl {
+                fn parse(
+                    &self,
+                    text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, options, metrics, builder)
+                }
+
+                fn reparse(
+                    &self,
+                    incremental_data: &dyn std::any::Any,
+                    edit: &rt::TextEdit,
+                    new_text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, options, metrics, builder)
+                }
+
+                fn node_type_info(&self, ty: rt::NodeType) -> rt::NodeTypeInfo {
+                    match ty {
+                        ERROR => rt::NodeTypeInfo { name: "ERROR", whitespace_like: false },
+                        WHITESPACE => rt::NodeTypeInfo { name: "WHITESPACE", whitespace_like: true },
+                        PLUS => rt::NodeTypeInfo { name: "PLUS", whitespace_like: false },
+                        MINUS => rt::NodeTypeInfo { name: "MINUS", whitespace_like: false },
+                        STAR => rt::NodeTypeInfo { name: "STAR", whitespace_like: false },
+                        COLONCOLON => rt::NodeTypeInfo { name: "COLONCOLON", whitespace_like: false },
+                        SEMI => rt::NodeTypeInfo { name: "SEMI", whitespace_like: false },
+                        LPAREN => rt::NodeTypeInfo { name: "LPAREN", whitespace_like: false },
+                        RPAREN => rt::NodeTypeInfo { name: "RPAREN", whitespace_like: false },
+                        NUMBER => rt::NodeTypeInfo { name: "NUMBER", whitespace_like: false },
+                        IDENT => rt::NodeTypeInfo { name: "IDENT", whitespace_like: false },
+                        FILE => rt::NodeTypeInfo { name: "FILE", whitespace_like: false },
+                        EXPR_STMT => rt::NodeTypeInfo { name: "EXPR_STMT", whitespace_like: false },
+                        SUM_EXPR => rt::NodeTypeInfo { name: "SUM_EXPR", whitespace_like: false },
+                        PRODUCT_EXPR => rt::NodeTypeInfo { name: "PRODUCT_EXPR", whitespace_like: false },
+                        LITERAL => rt::NodeTypeInfo { name: "LITERAL", whitespace_like: false },
+                        PAREN_EXPR => rt::NodeTypeInfo { name: "PAREN_EXPR", whitespace_like: false },
+                        PATH_EXPR => rt::NodeTypeInfo { name: "PATH_EXPR", whitespace_like: false },
+                        PATH => rt::NodeTypeInfo { name: "PATH", whitespace_like: false },
+                        _ => panic!("Unknown rt::NodeType: {:?}", ty)
+                    }
+                }
+            }
+
+            rt::Language::new(Impl {
+                parser_definition: create_parser_definition(),
+                lexer: create_lexer()
+            })
+        };
+    }
+
+    &*LANG
+}
+fn native_parser(p: &mut rt::native::Parser, expr: rt::ExprRef, pos: rt::native::Pos) -> Option<Option<rt::native::Pos>> {
+    let result = match expr.0 {
+        0 => rule_0(p, pos),
+        1 => rule_1(p, pos),
+        2 => rule_2(p, pos),
+        3 => rule_3(p, pos),
+        4 => rule_4(p, pos),
+        5 => rule_5(p, pos),
+        6 => rule_6(p, pos),
+        7 => rule_7(p, pos),
+        8 => rule_8(p, pos),
+        9 => rule_9(p, pos),
+        10 => rule_10(p, pos),
+        11 => expr_11(p, pos),
+        12 => expr_12(p, pos),
+        13 => expr_13(p, pos),
+        14 => expr_14(p, pos),
+        15 => expr_15(p, pos),
+        16 => expr_16(p, pos),
+        17 => expr_17(p, pos),
+        18 => expr_18(p, pos),
+        19 => expr_19(p, pos),
+        20 => expr_20(p, pos),
+        21 => expr_21(p, pos),
+        22 => expr_22(p, pos),
+        23 => expr_23(p, pos),
+        24 => expr_24(p, pos),
+        25 => expr_25(p, pos),
+        26 => expr_26(p, pos),
+        27 => expr_27(p, pos),
+        28 => expr_28(p, pos),
+        29 => expr_29(p, pos),
+        30 => expr_30(p, pos),
+        31 => expr_31(p, pos),
+        32 => expr_32(p, pos),
+        33 => expr_33(p, pos),
+        34 => expr_34(p, pos),
+        35 => expr_35(p, pos),
+        36 => expr_36(p, pos),
+        37 => expr_37(p, pos),
+        38 => expr_38(p, pos),
+        39 => expr_39(p, pos),
+        40 => expr_40(p, pos),
+        41 => expr_41(p, pos),
+        42 => expr_42(p, pos),
+        43 => expr_43(p, pos),
+        44 => expr_44(p, pos),
+        45 => expr_45(p, pos),
+        46 => expr_46(p, pos),
+        47 => expr_47(p, pos),
+        48 => expr_48(p, pos),
+        49 => expr_49(p, pos),
+        50 => expr_50(p, pos),
+        51 => expr_51(p, pos),
+        52 => expr_52(p, pos),
+        53 => expr_53(p, pos),
+        54 => expr_54(p, pos),
+        _ => return None,
+    };
+    Some(result)
+}
+
+fn rule_0(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 11, false, pos, |p, pos| rt::native::expr(p, 13, pos, expr_13))
+}
+
+fn rule_1(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 12, false, pos, |p, pos| rt::native::expr(p, 16, pos, expr_16))
+}
+
+fn rule_2(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::left_rec(p, 2, pos, |p, pos| rt::native::expr(p, 50, pos, expr_50))
+}
+
+fn rule_3(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::left_rec(p, 3, pos, |p, pos| rt::native::expr(p, 51, pos, expr_51))
+}
+
+fn rule_4(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::left_rec(p, 4, pos, |p, pos| rt::native::expr(p, 52, pos, expr_52))
+}
+
+fn rule_5(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::left_rec(p, 5, pos, |p, pos| rt::native::expr(p, 53, pos, expr_53))
+}
+
+fn rule_6(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 3, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 31, pos, expr_31), 1 => rt::native::expr(p, 32, pos, expr_32), 2 => rt::native::expr(p, 33, pos, expr_33), _ => unreachable!() })
+}
+
+fn rule_7(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 15, false, pos, |p, pos| rt::native::expr(p, 36, pos, expr_36))
+}
+
+fn rule_8(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 16, false, pos, |p, pos| rt::native::expr(p, 41, pos, expr_41))
+}
+
+fn rule_9(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 17, false, pos, |p, pos| rt::native::expr(p, 43, pos, expr_43))
+}
+
+fn rule_10(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::left_rec(p, 10, pos, |p, pos| rt::native::expr(p, 54, pos, expr_54))
+}
+
+fn expr_11(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::rep(p, pos, |p, pos| rt::native::expr(p, 1, pos, rule_1))
+}
+
+fn expr_12(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(11)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 11, pos, expr_11), _ => unreachable!() })
+}
+
+fn expr_13(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 12, pos, expr_12), _ => unreachable!() })
+}
+
+fn expr_14(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 6, pos)
+}
+
+fn expr_15(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(2), rt::ExprRef(14)], Some(1), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 2, pos, rule_2), 1 => rt::native::expr(p, 14, pos, expr_14), _ => unreachable!() })
+}
+
+fn expr_16(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 15, pos, expr_15), _ => unreachable!() })
+}
+
+fn expr_17(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(3)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 3, pos, rule_3), _ => unreachable!() })
+}
+
+fn expr_18(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(4)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 4, pos, rule_4), _ => unreachable!() })
+}
+
+fn expr_19(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 2, pos)
+}
+
+fn expr_20(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(19)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 19, pos, expr_19), _ => unreachable!() })
+}
+
+fn expr_21(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 3, pos)
+}
+
+fn expr_22(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(21)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 21, pos, expr_21), _ => unreachable!() })
+}
+
+fn expr_23(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 2, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 20, pos, expr_20), 1 => rt::native::expr(p, 22, pos, expr_22), _ => unreachable!() })
+}
+
+fn expr_24(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(2), rt::ExprRef(23), rt::ExprRef(4)], Some(2), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 2, pos, rule_2), 1 => rt::native::expr(p, 23, pos, expr_23), 2 => rt::native::expr(p, 4, pos, rule_4), _ => unreachable!() })
+}
+
+fn expr_25(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 24, pos, expr_24), _ => unreachable!() })
+}
+
+fn expr_26(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(5)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 5, pos, rule_5), _ => unreachable!() })
+}
+
+fn expr_27(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(6)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 6, pos, rule_6), _ => unreachable!() })
+}
+
+fn expr_28(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 4, pos)
+}
+
+fn expr_29(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(4), rt::ExprRef(28), rt::ExprRef(6)], Some(2), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 4, pos, rule_4), 1 => rt::native::expr(p, 28, pos, expr_28), 2 => rt::native::expr(p, 6, pos, rule_6), _ => unreachable!() })
+}
+
+fn expr_30(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 29, pos, expr_29), _ => unreachable!() })
+}
+
+fn expr_31(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(9)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 9, pos, rule_9), _ => unreachable!() })
+}
+
+fn expr_32(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(7)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 7, pos, rule_7), _ => unreachable!() })
+}
+
+fn expr_33(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(8)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 8, pos, rule_8), _ => unreachable!() })
+}
+
+fn expr_34(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 9, pos)
+}
+
+fn expr_35(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(34)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 34, pos, expr_34), _ => unreachable!() })
+}
+
+fn expr_36(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 35, pos, expr_35), _ => unreachable!() })
+}
+
+fn expr_37(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 7, pos)
+}
+
+fn expr_38(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 8, pos)
+}
+
+fn expr_39(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(37), rt::ExprRef(2), rt::ExprRef(38)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 37, pos, expr_37), 1 => rt::native::expr(p, 2, pos, rule_2), 2 => rt::native::expr(p, 38, pos, expr_38), _ => unreachable!() })
+}
+
+fn expr_40(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 39, pos, expr_39), _ => unreachable!() })
+}
+
+fn expr_41(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::cached(p, 40, pos, |p, pos| rt::native::expr(p, 40, pos, expr_40))
+}
+
+fn expr_42(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(10)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 10, pos, rule_10), _ => unreachable!() })
+}
+
+fn expr_43(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 42, pos, expr_42), _ => unreachable!() })
+}
+
+fn expr_44(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 5, pos)
+}
+
+fn expr_45(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 10, pos)
+}
+
+fn expr_46(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(10), rt::ExprRef(44), rt::ExprRef(45)], Some(2), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 10, pos, rule_10), 1 => rt::native::expr(p, 44, pos, expr_44), 2 => rt::native::expr(p, 45, pos, expr_45), _ => unreachable!() })
+}
+
+fn expr_47(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 10, pos)
+}
+
+fn expr_48(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(47)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 47, pos, expr_47), _ => unreachable!() })
+}
+
+fn expr_49(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 2, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 46, pos, expr_46), 1 => rt::native::expr(p, 48, pos, expr_48), _ => unreachable!() })
+}
+
+fn expr_50(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 2, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 17, pos, expr_17), 1 => rt::native::expr(p, 18, pos, expr_18), _ => unreachable!() })
+}
+
+fn expr_51(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 13, false, pos, |p, pos| rt::native::expr(p, 25, pos, expr_25))
+}
+
+fn expr_52(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 2, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 26, pos, expr_26), 1 => rt::native::expr(p, 27, pos, expr_27), _ => unreachable!() })
+}
+
+fn expr_53(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 14, false, pos, |p, pos| rt::native::expr(p, 30, pos, expr_30))
+}
+
+fn expr_54(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 18, false, pos, |p, pos| rt::native::expr(p, 49, pos, expr_49))
+}
@@ -2,8 +2,20 @@ extern crate fall_parse;
 extern crate fall_tree;
 
 pub mod arith;
+pub mod arith_native;
+pub mod inject;
+pub mod interp;
+pub mod json_native;
+pub mod leftrec;
+pub mod leftrec_native;
+pub mod limits;
+pub mod limits_native;
+pub mod missing;
 pub mod sexp;
+pub mod typedef;
+pub mod typedef_native;
 pub mod weird;
+pub mod weird_native;
 
 pub fn match_ast(actual: &str, expected: &str) {
     let actual = actual.trim();
@@ -0,0 +1,331 @@
+use fall_parse::runtime as rt;
+pub use self::rt::ERROR;
+
+pub const WHITESPACE: rt::NodeType = rt::NodeType(100);
+pub const BANG: rt::NodeType = rt::NodeType(101);
+pub const LPAREN: rt::NodeType = rt::NodeType(102);
+pub const RPAREN: rt::NodeType = rt::NodeType(103);
+pub const LBRACK: rt::NodeType = rt::NodeType(104);
+pub const RBRACK: rt::NodeType = rt::NodeType(105);
+pub const IDENT: rt::NodeType = rt::NodeType(106);
+pub const FILE: rt::NodeType = rt::NodeType(107);
+pub const DEEP: rt::NodeType = rt::NodeType(108);
+pub const GROUP: rt::NodeType = rt::NodeType(109);
+pub const ROW: rt::NodeType = rt::NodeType(110);
+
+
+pub fn language() -> &'static rt::Language {
+    fn create_lexer() -> rt::DfaLexer {
+        static RULES: &[rt::DfaRule] = &[
+            rt::DfaRule { ty: WHITESPACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: BANG, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LPAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RPAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LBRACK, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RBRACK, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: IDENT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+        ];
+        static ASCII_CLASSES: [u8; 128] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 2, 0, 0, 0, 0, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 0, 0, 0, 0, 0,
+            0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 6, 0, 7, 0, 5,
+            0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 0, 0, 0, 0,
+        ];
+        static UNICODE_CLASSES: &[(u32, u8)] = &[
+            (0x80, 0), (0x85, 1), (0x86, 0), (0xA0, 1), (0xA1, 0), (0xAA, 5), (0xAB, 0), (0xB5, 5),
+            (0xB6, 0), (0xBA, 5), (0xBB, 0), (0xC0, 5), (0xD7, 0), (0xD8, 5), (0xF7, 0), (0xF8, 5),
+            (0x2C2, 0), (0x2C6, 5), (0x2D2, 0), (0x2E0, 5), (0x2E5, 0), (0x2EC, 5), (0x2ED, 0), (0x2EE, 5),
+            (0x2EF, 0), (0x300, 5), (0x375, 0), (0x376, 5), (0x378, 0), (0x37A, 5), (0x37E, 0), (0x37F, 5),
+            (0x380, 0), (0x386, 5), (0x387, 0), (0x388, 5), (0x38B, 0), (0x38C, 5), (0x38D, 0), (0x38E, 5),
+            (0x3A2, 0), (0x3A3, 5), (0x3F6, 0), (0x3F7, 5), (0x482, 0), (0x483, 5), (0x530, 0), (0x531, 5),
+            (0x557, 0), (0x559, 5), (0x55A, 0), (0x560, 5), (0x589, 0), (0x591, 5), (0x5BE, 0), (0x5BF, 5),
+            (0x5C0, 0), (0x5C1, 5), (0x5C3, 0), (0x5C4, 5), (0x5C6, 0), (0x5C7, 5), (0x5C8, 0), (0x5D0, 5),
+            (0x5EB, 0), (0x5EF, 5), (0x5F3, 0), (0x610, 5), (0x61B, 0), (0x620, 5), (0x66A, 0), (0x66E, 5),
+            (0x6D4, 0), (0x6D5, 5), (0x6DD, 0), (0x6DF, 5), (0x6E9, 0), (0x6EA, 5), (0x6FD, 0), (0x6FF, 5),
+            (0x700, 0), (0x710, 5), (0x74B, 0), (0x74D, 5), (0x7B2, 0), (0x7C0, 5), (0x7F6, 0), (0x7FA, 5),
+            (0x7FB, 0), (0x7FD, 5), (0x7FE, 0), (0x800, 5), (0x82E, 0), (0x840, 5), (0x85C, 0), (0x860, 5),
+            (0x86B, 0), (0x870, 5), (0x888, 0), (0x889, 5), (0x88F, 0), (0x897, 5), (0x8E2, 0), (0x8E3, 5),
+            (0x964, 0), (0x966, 5), (0x970, 0), (0x971, 5), (0x984, 0), (0x985, 5), (0x98D, 0), (0x98F, 5),
+            (0x991, 0), (0x993, 5), (0x9A9, 0), (0x9AA, 5), (0x9B1, 0), (0x9B2, 5), (0x9B3, 0), (0x9B6, 5),
+            (0x9BA, 0), (0x9BC, 5), (0x9C5, 0), (0x9C7, 5), (0x9C9, 0), (0x9CB, 5), (0x9CF, 0), (0x9D7, 5),
+            (0x9D8, 0), (0x9DC, 5), (0x9DE, 0), (0x9DF, 5), (0x9E4, 0), (0x9E6, 5), (0x9F2, 0), (0x9FC, 5),
+            (0x9FD, 0), (0x9FE, 5), (0x9FF, 0), (0xA01, 5), (0xA04, 0), (0xA05, 5), (0xA0B, 0), (0xA0F, 5),
+            (0xA11, 0), (0xA13, 5), (0xA29, 0), (0xA2A, 5), (0xA31, 0), (0xA32, 5), (0xA34, 0), (0xA35, 5),
+            (0xA37, 0), (0xA38, 5), (0xA3A, 0), (0xA3C, 5), (0xA3D, 0), (0xA3E, 5), (0xA43, 0), (0xA47, 5),
+            (0xA49, 0), (0xA4B, 5), (0xA4E, 0), (0xA51, 5), (0xA52, 0), (0xA59, 5), (0xA5D, 0), (0xA5E, 5),
+            (0xA5F, 0), (0xA66, 5), (0xA76, 0), (0xA81, 5), (0xA84, 0), (0xA85, 5), (0xA8E, 0), (0xA8F, 5),
+            (0xA92, 0), (0xA93, 5), (0xAA9, 0), (0xAAA, 5), (0xAB1, 0), (0xAB2, 5), (0xAB4, 0), (0xAB5, 5),
+            (0xABA, 0), (0xABC, 5), (0xAC6, 0), (0xAC7, 5), (0xACA, 0), (0xACB, 5), (0xACE, 0), (0xAD0, 5),
+            (0xAD1, 0), (0xAE0, 5), (0xAE4, 0), (0xAE6, 5), (0xAF0, 0), (0xAF9, 5), (0xB00, 0), (0xB01, 5),
+            (0xB04, 0), (0xB05, 5), (0xB0D, 0), (0xB0F, 5), (0xB11, 0), (0xB13, 5), (0xB29, 0), (0xB2A, 5),
+            (0xB31, 0), (0xB32, 5), (0xB34, 0), (0xB35, 5), (0xB3A, 0), (0xB3C, 5), (0xB45, 0), (0xB47, 5),
+            (0xB49, 0), (0xB4B, 5), (0xB4E, 0), (0xB55, 5), (0xB58, 0), (0xB5C, 5), (0xB5E, 0), (0xB5F, 5),
+            (0xB64, 0), (0xB66, 5), (0xB70, 0), (0xB71, 5), (0xB72, 0), (0xB82, 5), (0xB84, 0), (0xB85, 5),
+            (0xB8B, 0), (0xB8E, 5), (0xB91, 0), (0xB92, 5), (0xB96, 0), (0xB99, 5), (0xB9B, 0), (0xB9C, 5),
+            (0xB9D, 0), (0xB9E, 5), (0xBA0, 0), (0xBA3, 5), (0xBA5, 0), (0xBA8, 5), (0xBAB, 0), (0xBAE, 5),
+            (0xBBA, 0), (0xBBE, 5), (0xBC3, 0), (0xBC6, 5), (0xBC9, 0), (0xBCA, 5), (0xBCE, 0), (0xBD0, 5),
+            (0xBD1, 0), (0xBD7, 5), (0xBD8, 0), (0xBE6, 5), (0xBF0, 0), (0xC00, 5), (0xC0D, 0), (0xC0E, 5),
+            (0xC11, 0), (0xC12, 5), (0xC29, 0), (0xC2A, 5), (0xC3A, 0), (0xC3C, 5), (0xC45, 0), (0xC46, 5),
+            (0xC49, 0), (0xC4A, 5), (0xC4E, 0), (0xC55, 5), (0xC57, 0), (0xC58, 5), (0xC5B, 0), (0xC5D, 5),
+            (0xC5E, 0), (0xC60, 5), (0xC64, 0), (0xC66, 5), (0xC70, 0), (0xC80, 5), (0xC84, 0), (0xC85, 5),
+            (0xC8D, 0), (0xC8E, 5), (0xC91, 0), (0xC92, 5), (0xCA9, 0), (0xCAA, 5), (0xCB4, 0), (0xCB5, 5),
+            (0xCBA, 0), (0xCBC, 5), (0xCC5, 0), (0xCC6, 5), (0xCC9, 0), (0xCCA, 5), (0xCCE, 0), (0xCD5, 5),
+            (0xCD7, 0), (0xCDD, 5), (0xCDF, 0), (0xCE0, 5), (0xCE4, 0), (0xCE6, 5), (0xCF0, 0), (0xCF1, 5),
+            (0xCF4, 0), (0xD00, 5), (0xD0D, 0), (0xD0E, 5), (0xD11, 0), (0xD12, 5), (0xD45, 0), (0xD46, 5),
+            (0xD49, 0), (0xD4A, 5), (0xD4F, 0), (0xD54, 5), (0xD58, 0), (0xD5F, 5), (0xD64, 0), (0xD66, 5),
+            (0xD70, 0), (0xD7A, 5), (0xD80, 0), (0xD81, 5), (0xD84, 0), (0xD85, 5), (0xD97, 0), (0xD9A, 5),
+            (0xDB2, 0), (0xDB3, 5), (0xDBC, 0), (0xDBD, 5), (0xDBE, 0), (0xDC0, 5), (0xDC7, 0), (0xDCA, 5),
+            (0xDCB, 0), (0xDCF, 5), (0xDD5, 0), (0xDD6, 5), (0xDD7, 0), (0xDD8, 5), (0xDE0, 0), (0xDE6, 5),
+            (0xDF0, 0), (0xDF2, 5), (0xDF4, 0), (0xE01, 5), (0xE3B, 0), (0xE40, 5), (0xE4F, 0), (0xE50, 5),
+            (0xE5A, 0), (0xE81, 5), (0xE83, 0), (0xE84, 5), (0xE85, 0), (0xE86, 5), (0xE8B, 0), (0xE8C, 5),
+            (0xEA4, 0), (0xEA5, 5), (0xEA6, 0), (0xEA7, 5), (0xEBE, 0), (0xEC0, 5), (0xEC5, 0), (0xEC6, 5),
+            (0xEC7, 0), (0xEC8, 5), (0xECF, 0), (0xED0, 5), (0xEDA, 0), (0xEDC, 5), (0xEE0, 0), (0xF00, 5),
+            (0xF01, 0), (0xF18, 5), (0xF1A, 0), (0xF20, 5), (0xF2A, 0), (0xF35, 5), (0xF36, 0), (0xF37, 5),
+            (0xF38, 0), (0xF39, 5), (0xF3A, 0), (0xF3E, 5), (0xF48, 0), (0xF49, 5), (0xF6D, 0), (0xF71, 5),
+            (0xF85, 0), (0xF86, 5), (0xF98, 0), (0xF99, 5), (0xFBD, 0), (0xFC6, 5), (0xFC7, 0), (0x1000, 5),
+            (0x104A, 0), (0x1050, 5), (0x109E, 0), (0x10A0, 5), (0x10C6, 0), (0x10C7, 5), (0x10C8, 0), (0x10CD, 5),
+            (0x10CE, 0), (0x10D0, 5), (0x10FB, 0), (0x10FC, 5), (0x1249, 0), (0x124A, 5), (0x124E, 0), (0x1250, 5),
+            (0x1257, 0), (0x1258, 5), (0x1259, 0), (0x125A, 5), (0x125E, 0), (0x1260, 5), (0x1289, 0), (0x128A, 5),
+            (0x128E, 0), (0x1290, 5), (0x12B1, 0), (0x12B2, 5), (0x12B6, 0), (0x12B8, 5), (0x12BF, 0), (0x12C0, 5),
+            (0x12C1, 0), (0x12C2, 5), (0x12C6, 0), (0x12C8, 5), (0x12D7, 0), (0x12D8, 5), (0x1311, 0), (0x1312, 5),
+            (0x1316, 0), (0x1318, 5), (0x135B, 0), (0x135D, 5), (0x1360, 0), (0x1380, 5), (0x1390, 0), (0x13A0, 5),
+            (0x13F6, 0), (0x13F8, 5), (0x13FE, 0), (0x1401, 5), (0x166D, 0), (0x166F, 5), (0x1680, 1), (0x1681, 5),
+            (0x169B, 0), (0x16A0, 5), (0x16EB, 0), (0x16EE, 5), (0x16F9, 0), (0x1700, 5), (0x1716, 0), (0x171F, 5),
+            (0x1735, 0), (0x1740, 5), (0x1754, 0), (0x1760, 5), (0x176D, 0), (0x176E, 5), (0x1771, 0), (0x1772, 5),
+            (0x1774, 0), (0x1780, 5), (0x17D4, 0), (0x17D7, 5), (0x17D8, 0), (0x17DC, 5), (0x17DE, 0), (0x17E0, 5),
+            (0x17EA, 0), (0x180B, 5), (0x180E, 0), (0x180F, 5), (0x181A, 0), (0x1820, 5), (0x1879, 0), (0x1880, 5),
+            (0x18AB, 0), (0x18B0, 5), (0x18F6, 0), (0x1900, 5), (0x191F, 0), (0x1920, 5), (0x192C, 0), (0x1930, 5),
+            (0x193C, 0), (0x1946, 5), (0x196E, 0), (0x1970, 5), (0x1975, 0), (0x1980, 5), (0x19AC, 0), (0x19B0, 5),
+            (0x19CA, 0), (0x19D0, 5), (0x19DA, 0), (0x1A00, 5), (0x1A1C, 0), (0x1A20, 5), (0x1A5F, 0), (0x1A60, 5),
+            (0x1A7D, 0), (0x1A7F, 5), (0x1A8A, 0), (0x1A90, 5), (0x1A9A, 0), (0x1AA7, 5), (0x1AA8, 0), (0x1AB0, 5),
+            (0x1ACF, 0), (0x1B00, 5), (0x1B4D, 0), (0x1B50, 5), (0x1B5A, 0), (0x1B6B, 5), (0x1B74, 0), (0x1B80, 5),
+            (0x1BF4, 0), (0x1C00, 5), (0x1C38, 0), (0x1C40, 5), (0x1C4A, 0), (0x1C4D, 5), (0x1C7E, 0), (0x1C80, 5),
+            (0x1C8B, 0), (0x1C90, 5), (0x1CBB, 0), (0x1CBD, 5), (0x1CC0, 0), (0x1CD0, 5), (0x1CD3, 0), (0x1CD4, 5),
+            (0x1CFB, 0), (0x1D00, 5), (0x1F16, 0), (0x1F18, 5), (0x1F1E, 0), (0x1F20, 5), (0x1F46, 0), (0x1F48, 5),
+            (0x1F4E, 0), (0x1F50, 5), (0x1F58, 0), (0x1F59, 5), (0x1F5A, 0), (0x1F5B, 5), (0x1F5C, 0), (0x1F5D, 5),
+            (0x1F5E, 0), (0x1F5F, 5), (0x1F7E, 0), (0x1F80, 5), (0x1FB5, 0), (0x1FB6, 5), (0x1FBD, 0), (0x1FBE, 5),
+            (0x1FBF, 0), (0x1FC2, 5), (0x1FC5, 0), (0x1FC6, 5), (0x1FCD, 0), (0x1FD0, 5), (0x1FD4, 0), (0x1FD6, 5),
+            (0x1FDC, 0), (0x1FE0, 5), (0x1FED, 0), (0x1FF2, 5), (0x1FF5, 0), (0x1FF6, 5), (0x1FFD, 0), (0x2000, 1),
+            (0x200B, 0), (0x200C, 5), (0x200E, 0), (0x2028, 1), (0x202A, 0), (0x202F, 1), (0x2030, 0), (0x203F, 5),
+            (0x2041, 0), (0x2054, 5), (0x2055, 0), (0x205F, 1), (0x2060, 0), (0x2071, 5), (0x2072, 0), (0x207F, 5),
+            (0x2080, 0), (0x2090, 5), (0x209D, 0), (0x20D0, 5), (0x20F1, 0), (0x2102, 5), (0x2103, 0), (0x2107, 5),
+            (0x2108, 0), (0x210A, 5), (0x2114, 0), (0x2115, 5), (0x2116, 0), (0x2119, 5), (0x211E, 0), (0x2124, 5),
+            (0x2125, 0), (0x2126, 5), (0x2127, 0), (0x2128, 5), (0x2129, 0), (0x212A, 5), (0x212E, 0), (0x212F, 5),
+            (0x213A, 0), (0x213C, 5), (0x2140, 0), (0x2145, 5), (0x214A, 0), (0x214E, 5), (0x214F, 0), (0x2160, 5),
+            (0x2189, 0), (0x24B6, 5), (0x24EA, 0), (0x2C00, 5), (0x2CE5, 0), (0x2CEB, 5), (0x2CF4, 0), (0x2D00, 5),
+            (0x2D26, 0), (0x2D27, 5), (0x2D28, 0), (0x2D2D, 5), (0x2D2E, 0), (0x2D30, 5), (0x2D68, 0), (0x2D6F, 5),
+            (0x2D70, 0), (0x2D7F, 5), (0x2D97, 0), (0x2DA0, 5), (0x2DA7, 0), (0x2DA8, 5), (0x2DAF, 0), (0x2DB0, 5),
+            (0x2DB7, 0), (0x2DB8, 5), (0x2DBF, 0), (0x2DC0, 5), (0x2DC7, 0), (0x2DC8, 5), (0x2DCF, 0), (0x2DD0, 5),
+            (0x2DD7, 0), (0x2DD8, 5), (0x2DDF, 0), (0x2DE0, 5), (0x2E00, 0), (0x2E2F, 5), (0x2E30, 0), (0x3000, 1),
+            (0x3001, 0), (0x3005, 5), (0x3008, 0), (0x3021, 5), (0x3030, 0), (0x3031, 5), (0x3036, 0), (0x3038, 5),
+            (0x303D, 0), (0x3041, 5), (0x3097, 0), (0x3099, 5), (0x309B, 0), (0x309D, 5), (0x30A0, 0), (0x30A1, 5),
+            (0x30FB, 0), (0x30FC, 5), (0x3100, 0), (0x3105, 5), (0x3130, 0), (0x3131, 5), (0x318F, 0), (0x31A0, 5),
+            (0x31C0, 0), (0x31F0, 5), (0x3200, 0), (0x3400, 5), (0x4DC0, 0), (0x4E00, 5), (0xA48D, 0), (0xA4D0, 5),
+            (0xA4FE, 0), (0xA500, 5), (0xA60D, 0), (0xA610, 5), (0xA62C, 0), (0xA640, 5), (0xA673, 0), (0xA674, 5),
+            (0xA67E, 0), (0xA67F, 5), (0xA6F2, 0), (0xA717, 5), (0xA720, 0), (0xA722, 5), (0xA789, 0), (0xA78B, 5),
+            (0xA7CE, 0), (0xA7D0, 5), (0xA7D2, 0), (0xA7D3, 5), (0xA7D4, 0), (0xA7D5, 5), (0xA7DD, 0), (0xA7F2, 5),
+            (0xA828, 0), (0xA82C, 5), (0xA82D, 0), (0xA840, 5), (0xA874, 0), (0xA880, 5), (0xA8C6, 0), (0xA8D0, 5),
+            (0xA8DA, 0), (0xA8E0, 5), (0xA8F8, 0), (0xA8FB, 5), (0xA8FC, 0), (0xA8FD, 5), (0xA92E, 0), (0xA930, 5),
+            (0xA954, 0), (0xA960, 5), (0xA97D, 0), (0xA980, 5), (0xA9C1, 0), (0xA9CF, 5), (0xA9DA, 0), (0xA9E0, 5),
+            (0xA9FF, 0), (0xAA00, 5), (0xAA37, 0), (0xAA40, 5), (0xAA4E, 0), (0xAA50, 5), (0xAA5A, 0), (0xAA60, 5),
+            (0xAA77, 0), (0xAA7A, 5), (0xAAC3, 0), (0xAADB, 5), (0xAADE, 0), (0xAAE0, 5), (0xAAF0, 0), (0xAAF2, 5),
+            (0xAAF7, 0), (0xAB01, 5), (0xAB07, 0), (0xAB09, 5), (0xAB0F, 0), (0xAB11, 5), (0xAB17, 0), (0xAB20, 5),
+            (0xAB27, 0), (0xAB28, 5), (0xAB2F, 0), (0xAB30, 5), (0xAB5B, 0), (0xAB5C, 5), (0xAB6A, 0), (0xAB70, 5),
+            (0xABEB, 0), (0xABEC, 5), (0xABEE, 0), (0xABF0, 5), (0xABFA, 0), (0xAC00, 5), (0xD7A4, 0), (0xD7B0, 5),
+            (0xD7C7, 0), (0xD7CB, 5), (0xD7FC, 0), (0xF900, 5), (0xFA6E, 0), (0xFA70, 5), (0xFADA, 0), (0xFB00, 5),
+            (0xFB07, 0), (0xFB13, 5), (0xFB18, 0), (0xFB1D, 5), (0xFB29, 0), (0xFB2A, 5), (0xFB37, 0), (0xFB38, 5),
+            (0xFB3D, 0), (0xFB3E, 5), (0xFB3F, 0), (0xFB40, 5), (0xFB42, 0), (0xFB43, 5), (0xFB45, 0), (0xFB46, 5),
+            (0xFBB2, 0), (0xFBD3, 5), (0xFD3E, 0), (0xFD50, 5), (0xFD90, 0), (0xFD92, 5), (0xFDC8, 0), (0xFDF0, 5),
+            (0xFDFC, 0), (0xFE00, 5), (0xFE10, 0), (0xFE20, 5), (0xFE30, 0), (0xFE33, 5), (0xFE35, 0), (0xFE4D, 5),
+            (0xFE50, 0), (0xFE70, 5), (0xFE75, 0), (0xFE76, 5), (0xFEFD, 0), (0xFF10, 5), (0xFF1A, 0), (0xFF21, 5),
+            (0xFF3B, 0), (0xFF3F, 5), (0xFF40, 0), (0xFF41, 5), (0xFF5B, 0), (0xFF66, 5), (0xFFBF, 0), (0xFFC2, 5),
+            (0xFFC8, 0), (0xFFCA, 5), (0xFFD0, 0), (0xFFD2, 5), (0xFFD8, 0), (0xFFDA, 5), (0xFFDD, 0), (0x10000, 5),
+            (0x1000C, 0), (0x1000D, 5), (0x10027, 0), (0x10028, 5), (0x1003B, 0), (0x1003C, 5), (0x1003E, 0), (0x1003F, 5),
+            (0x1004E, 0), (0x10050, 5), (0x1005E, 0), (0x10080, 5), (0x100FB, 0), (0x10140, 5), (0x10175, 0), (0x101FD, 5),
+            (0x101FE, 0), (0x10280, 5), (0x1029D, 0), (0x102A0, 5), (0x102D1, 0), (0x102E0, 5), (0x102E1, 0), (0x10300, 5),
+            (0x10320, 0), (0x1032D, 5), (0x1034B, 0), (0x10350, 5), (0x1037B, 0), (0x10380, 5), (0x1039E, 0), (0x103A0, 5),
+            (0x103C4, 0), (0x103C8, 5), (0x103D0, 0), (0x103D1, 5), (0x103D6, 0), (0x10400, 5), (0x1049E, 0), (0x104A0, 5),
+            (0x104AA, 0), (0x104B0, 5), (0x104D4, 0), (0x104D8, 5), (0x104FC, 0), (0x10500, 5), (0x10528, 0), (0x10530, 5),
+            (0x10564, 0), (0x10570, 5), (0x1057B, 0), (0x1057C, 5), (0x1058B, 0), (0x1058C, 5), (0x10593, 0), (0x10594, 5),
+            (0x10596, 0), (0x10597, 5), (0x105A2, 0), (0x105A3, 5), (0x105B2, 0), (0x105B3, 5), (0x105BA, 0), (0x105BB, 5),
+            (0x105BD, 0), (0x105C0, 5), (0x105F4, 0), (0x10600, 5), (0x10737, 0), (0x10740, 5), (0x10756, 0), (0x10760, 5),
+            (0x10768, 0), (0x10780, 5), (0x10786, 0), (0x10787, 5), (0x107B1, 0), (0x107B2, 5), (0x107BB, 0), (0x10800, 5),
+            (0x10806, 0), (0x10808, 5), (0x10809, 0), (0x1080A, 5), (0x10836, 0), (0x10837, 5), (0x10839, 0), (0x1083C, 5),
+            (0x1083D, 0), (0x1083F, 5), (0x10856, 0), (0x10860, 5), (0x10877, 0), (0x10880, 5), (0x1089F, 0), (0x108E0, 5),
+            (0x108F3, 0), (0x108F4, 5), (0x108F6, 0), (0x10900, 5), (0x10916, 0), (0x10920, 5), (0x1093A, 0), (0x10980, 5),
+            (0x109B8, 0), (0x109BE, 5), (0x109C0, 0), (0x10A00, 5), (0x10A04, 0), (0x10A05, 5), (0x10A07, 0), (0x10A0C, 5),
+            (0x10A14, 0), (0x10A15, 5), (0x10A18, 0), (0x10A19, 5), (0x10A36, 0), (0x10A38, 5), (0x10A3B, 0), (0x10A3F, 5),
+            (0x10A40, 0), (0x10A60, 5), (0x10A7D, 0), (0x10A80, 5), (0x10A9D, 0), (0x10AC0, 5), (0x10AC8, 0), (0x10AC9, 5),
+            (0x10AE7, 0), (0x10B00, 5), (0x10B36, 0), (0x10B40, 5), (0x10B56, 0), (0x10B60, 5), (0x10B73, 0), (0x10B80, 5),
+            (0x10B92, 0), (0x10C00, 5), (0x10C49, 0), (0x10C80, 5), (0x10CB3, 0), (0x10CC0, 5), (0x10CF3, 0), (0x10D00, 5),
+            (0x10D28, 0), (0x10D30, 5), (0x10D3A, 0), (0x10D40, 5), (0x10D66, 0), (0x10D69, 5), (0x10D6E, 0), (0x10D6F, 5),
+            (0x10D86, 0), (0x10E80, 5), (0x10EAA, 0), (0x10EAB, 5), (0x10EAD, 0), (0x10EB0, 5), (0x10EB2, 0), (0x10EC2, 5),
+            (0x10EC5, 0), (0x10EFC, 5), (0x10F1D, 0), (0x10F27, 5), (0x10F28, 0), (0x10F30, 5), (0x10F51, 0), (0x10F70, 5),
+            (0x10F86, 0), (0x10FB0, 5), (0x10FC5, 0), (0x10FE0, 5), (0x10FF7, 0), (0x11000, 5), (0x11047, 0), (0x11066, 5),
+            (0x11076, 0), (0x1107F, 5), (0x110BB, 0), (0x110C2, 5), (0x110C3, 0), (0x110D0, 5), (0x110E9, 0), (0x110F0, 5),
+            (0x110FA, 0), (0x11100, 5), (0x11135, 0), (0x11136, 5), (0x11140, 0), (0x11144, 5), (0x11148, 0), (0x11150, 5),
+            (0x11174, 0), (0x11176, 5), (0x11177, 0), (0x11180, 5), (0x111C5, 0), (0x111C9, 5), (0x111CD, 0), (0x111CE, 5),
+            (0x111DB, 0), (0x111DC, 5), (0x111DD, 0), (0x11200, 5), (0x11212, 0), (0x11213, 5), (0x11238, 0), (0x1123E, 5),
+            (0x11242, 0), (0x11280, 5), (0x11287, 0), (0x11288, 5), (0x11289, 0), (0x1128A, 5), (0x1128E, 0), (0x1128F, 5),
+            (0x1129E, 0), (0x1129F, 5), (0x112A9, 0), (0x112B0, 5), (0x112EB, 0), (0x112F0, 5), (0x112FA, 0), (0x11300, 5),
+            (0x11304, 0), (0x11305, 5), (0x1130D, 0), (0x1130F, 5), (0x11311, 0), (0x11313, 5), (0x11329, 0), (0x1132A, 5),
+            (0x11331, 0), (0x11332, 5), (0x11334, 0), (0x11335, 5), (0x1133A, 0), (0x1133B, 5), (0x11345, 0), (0x11347, 5),
+            (0x11349, 0), (0x1134B, 5), (0x1134E, 0), (0x11350, 5), (0x11351, 0), (0x11357, 5), (0x11358, 0), (0x1135D, 5),
+            (0x11364, 0), (0x11366, 5), (0x1136D, 0), (0x11370, 5), (0x11375, 0), (0x11380, 5), (0x1138A, 0), (0x1138B, 5),
+            (0x1138C, 0), (0x1138E, 5), (0x1138F, 0), (0x11390, 5), (0x113B6, 0), (0x113B7, 5), (0x113C1, 0), (0x113C2, 5),
+            (0x113C3, 0), (0x113C5, 5), (0x113C6, 0), (0x113C7, 5), (0x113CB, 0), (0x113CC, 5), (0x113D4, 0), (0x113E1, 5),
+            (0x113E3, 0), (0x11400, 5), (0x1144B, 0), (0x11450, 5), (0x1145A, 0), (0x1145E, 5), (0x11462, 0), (0x11480, 5),
+            (0x114C6, 0), (0x114C7, 5), (0x114C8, 0), (0x114D0, 5), (0x114DA, 0), (0x11580, 5), (0x115B6, 0), (0x115B8, 5),
+            (0x115C1, 0), (0x115D8, 5), (0x115DE, 0), (0x11600, 5), (0x11641, 0), (0x11644, 5), (0x11645, 0), (0x11650, 5),
+            (0x1165A, 0), (0x11680, 5), (0x116B9, 0), (0x116C0, 5), (0x116CA, 0), (0x116D0, 5), (0x116E4, 0), (0x11700, 5),
+            (0x1171B, 0), (0x1171D, 5), (0x1172C, 0), (0x11730, 5), (0x1173A, 0), (0x11740, 5), (0x11747, 0), (0x11800, 5),
+            (0x1183B, 0), (0x118A0, 5), (0x118EA, 0), (0x118FF, 5), (0x11907, 0), (0x11909, 5), (0x1190A, 0), (0x1190C, 5),
+            (0x11914, 0), (0x11915, 5), (0x11917, 0), (0x11918, 5), (0x11936, 0), (0x11937, 5), (0x11939, 0), (0x1193B, 5),
+            (0x11944, 0), (0x11950, 5), (0x1195A, 0), (0x119A0, 5), (0x119A8, 0), (0x119AA, 5), (0x119D8, 0), (0x119DA, 5),
+            (0x119E2, 0), (0x119E3, 5), (0x119E5, 0), (0x11A00, 5), (0x11A3F, 0), (0x11A47, 5), (0x11A48, 0), (0x11A50, 5),
+            (0x11A9A, 0), (0x11A9D, 5), (0x11A9E, 0), (0x11AB0, 5), (0x11AF9, 0), (0x11BC0, 5), (0x11BE1, 0), (0x11BF0, 5),
+            (0x11BFA, 0), (0x11C00, 5), (0x11C09, 0), (0x11C0A, 5), (0x11C37, 0), (0x11C38, 5), (0x11C41, 0), (0x11C50, 5),
+            (0x11C5A, 0), (0x11C72, 5), (0x11C90, 0), (0x11C92, 5), (0x11CA8, 0), (0x11CA9, 5), (0x11CB7, 0), (0x11D00, 5),
+            (0x11D07, 0), (0x11D08, 5), (0x11D0A, 0), (0x11D0B, 5), (0x11D37, 0), (0x11D3A, 5), (0x11D3B, 0), (0x11D3C, 5),
+            (0x11D3E, 0), (0x11D3F, 5), (0x11D48, 0), (0x11D50, 5), (0x11D5A, 0), (0x11D60, 5), (0x11D66, 0), (0x11D67, 5),
+            (0x11D69, 0), (0x11D6A, 5), (0x11D8F, 0), (0x11D90, 5), (0x11D92, 0), (0x11D93, 5), (0x11D99, 0), (0x11DA0, 5),
+            (0x11DAA, 0), (0x11EE0, 5), (0x11EF7, 0), (0x11F00, 5), (0x11F11, 0), (0x11F12, 5), (0x11F3B, 0), (0x11F3E, 5),
+            (0x11F43, 0), (0x11F50, 5), (0x11F5B, 0), (0x11FB0, 5), (0x11FB1, 0), (0x12000, 5), (0x1239A, 0), (0x12400, 5),
+            (0x1246F, 0), (0x12480, 5), (0x12544, 0), (0x12F90, 5), (0x12FF1, 0), (0x13000, 5), (0x13430, 0), (0x13440, 5),
+            (0x13456, 0), (0x13460, 5), (0x143FB, 0), (0x14400, 5), (0x14647, 0), (0x16100, 5), (0x1613A, 0), (0x16800, 5),
+            (0x16A39, 0), (0x16A40, 5), (0x16A5F, 0), (0x16A60, 5), (0x16A6A, 0), (0x16A70, 5), (0x16ABF, 0), (0x16AC0, 5),
+            (0x16ACA, 0), (0x16AD0, 5), (0x16AEE, 0), (0x16AF0, 5), (0x16AF5, 0), (0x16B00, 5), (0x16B37, 0), (0x16B40, 5),
+            (0x16B44, 0), (0x16B50, 5), (0x16B5A, 0), (0x16B63, 5), (0x16B78, 0), (0x16B7D, 5), (0x16B90, 0), (0x16D40, 5),
+            (0x16D6D, 0), (0x16D70, 5), (0x16D7A, 0), (0x16E40, 5), (0x16E80, 0), (0x16F00, 5), (0x16F4B, 0), (0x16F4F, 5),
+            (0x16F88, 0), (0x16F8F, 5), (0x16FA0, 0), (0x16FE0, 5), (0x16FE2, 0), (0x16FE3, 5), (0x16FE5, 0), (0x16FF0, 5),
+            (0x16FF2, 0), (0x17000, 5), (0x187F8, 0), (0x18800, 5), (0x18CD6, 0), (0x18CFF, 5), (0x18D09, 0), (0x1AFF0, 5),
+            (0x1AFF4, 0), (0x1AFF5, 5), (0x1AFFC, 0), (0x1AFFD, 5), (0x1AFFF, 0), (0x1B000, 5), (0x1B123, 0), (0x1B132, 5),
+            (0x1B133, 0), (0x1B150, 5), (0x1B153, 0), (0x1B155, 5), (0x1B156, 0), (0x1B164, 5), (0x1B168, 0), (0x1B170, 5),
+            (0x1B2FC, 0), (0x1BC00, 5), (0x1BC6B, 0), (0x1BC70, 5), (0x1BC7D, 0), (0x1BC80, 5), (0x1BC89, 0), (0x1BC90, 5),
+            (0x1BC9A, 0), (0x1BC9D, 5), (0x1BC9F, 0), (0x1CCF0, 5), (0x1CCFA, 0), (0x1CF00, 5), (0x1CF2E, 0), (0x1CF30, 5),
+            (0x1CF47, 0), (0x1D165, 5), (0x1D16A, 0), (0x1D16D, 5), (0x1D173, 0), (0x1D17B, 5), (0x1D183, 0), (0x1D185, 5),
+            (0x1D18C, 0), (0x1D1AA, 5), (0x1D1AE, 0), (0x1D242, 5), (0x1D245, 0), (0x1D400, 5), (0x1D455, 0), (0x1D456, 5),
+            (0x1D49D, 0), (0x1D49E, 5), (0x1D4A0, 0), (0x1D4A2, 5), (0x1D4A3, 0), (0x1D4A5, 5), (0x1D4A7, 0), (0x1D4A9, 5),
+            (0x1D4AD, 0), (0x1D4AE, 5), (0x1D4BA, 0), (0x1D4BB, 5), (0x1D4BC, 0), (0x1D4BD, 5), (0x1D4C4, 0), (0x1D4C5, 5),
+            (0x1D506, 0), (0x1D507, 5), (0x1D50B, 0), (0x1D50D, 5), (0x1D515, 0), (0x1D516, 5), (0x1D51D, 0), (0x1D51E, 5),
+            (0x1D53A, 0), (0x1D53B, 5), (0x1D53F, 0), (0x1D540, 5), (0x1D545, 0), (0x1D546, 5), (0x1D547, 0), (0x1D54A, 5),
+            (0x1D551, 0), (0x1D552, 5), (0x1D6A6, 0), (0x1D6A8, 5), (0x1D6C1, 0), (0x1D6C2, 5), (0x1D6DB, 0), (0x1D6DC, 5),
+            (0x1D6FB, 0), (0x1D6FC, 5), (0x1D715, 0), (0x1D716, 5), (0x1D735, 0), (0x1D736, 5), (0x1D74F, 0), (0x1D750, 5),
+            (0x1D76F, 0), (0x1D770, 5), (0x1D789, 0), (0x1D78A, 5), (0x1D7A9, 0), (0x1D7AA, 5), (0x1D7C3, 0), (0x1D7C4, 5),
+            (0x1D7CC, 0), (0x1D7CE, 5), (0x1D800, 0), (0x1DA00, 5), (0x1DA37, 0), (0x1DA3B, 5), (0x1DA6D, 0), (0x1DA75, 5),
+            (0x1DA76, 0), (0x1DA84, 5), (0x1DA85, 0), (0x1DA9B, 5), (0x1DAA0, 0), (0x1DAA1, 5), (0x1DAB0, 0), (0x1DF00, 5),
+            (0x1DF1F, 0), (0x1DF25, 5), (0x1DF2B, 0), (0x1E000, 5), (0x1E007, 0), (0x1E008, 5), (0x1E019, 0), (0x1E01B, 5),
+            (0x1E022, 0), (0x1E023, 5), (0x1E025, 0), (0x1E026, 5), (0x1E02B, 0), (0x1E030, 5), (0x1E06E, 0), (0x1E08F, 5),
+            (0x1E090, 0), (0x1E100, 5), (0x1E12D, 0), (0x1E130, 5), (0x1E13E, 0), (0x1E140, 5), (0x1E14A, 0), (0x1E14E, 5),
+            (0x1E14F, 0), (0x1E290, 5), (0x1E2AF, 0), (0x1E2C0, 5), (0x1E2FA, 0), (0x1E4D0, 5), (0x1E4FA, 0), (0x1E5D0, 5),
+            (0x1E5FB, 0), (0x1E7E0, 5), (0x1E7E7, 0), (0x1E7E8, 5), (0x1E7EC, 0), (0x1E7ED, 5), (0x1E7EF, 0), (0x1E7F0, 5),
+            (0x1E7FF, 0), (0x1E800, 5), (0x1E8C5, 0), (0x1E8D0, 5), (0x1E8D7, 0), (0x1E900, 5), (0x1E94C, 0), (0x1E950, 5),
+            (0x1E95A, 0), (0x1EE00, 5), (0x1EE04, 0), (0x1EE05, 5), (0x1EE20, 0), (0x1EE21, 5), (0x1EE23, 0), (0x1EE24, 5),
+            (0x1EE25, 0), (0x1EE27, 5), (0x1EE28, 0), (0x1EE29, 5), (0x1EE33, 0), (0x1EE34, 5), (0x1EE38, 0), (0x1EE39, 5),
+            (0x1EE3A, 0), (0x1EE3B, 5), (0x1EE3C, 0), (0x1EE42, 5), (0x1EE43, 0), (0x1EE47, 5), (0x1EE48, 0), (0x1EE49, 5),
+            (0x1EE4A, 0), (0x1EE4B, 5), (0x1EE4C, 0), (0x1EE4D, 5), (0x1EE50, 0), (0x1EE51, 5), (0x1EE53, 0), (0x1EE54, 5),
+            (0x1EE55, 0), (0x1EE57, 5), (0x1EE58, 0), (0x1EE59, 5), (0x1EE5A, 0), (0x1EE5B, 5), (0x1EE5C, 0), (0x1EE5D, 5),
+            (0x1EE5E, 0), (0x1EE5F, 5), (0x1EE60, 0), (0x1EE61, 5), (0x1EE63, 0), (0x1EE64, 5), (0x1EE65, 0), (0x1EE67, 5),
+            (0x1EE6B, 0), (0x1EE6C, 5), (0x1EE73, 0), (0x1EE74, 5), (0x1EE78, 0), (0x1EE79, 5), (0x1EE7D, 0), (0x1EE7E, 5),
+            (0x1EE7F, 0), (0x1EE80, 5), (0x1EE8A, 0), (0x1EE8B, 5), (0x1EE9C, 0), (0x1EEA1, 5), (0x1EEA4, 0), (0x1EEA5, 5),
+            (0x1EEAA, 0), (0x1EEAB, 5), (0x1EEBC, 0), (0x1F130, 5), (0x1F14A, 0), (0x1F150, 5), (0x1F16A, 0), (0x1F170, 5),
+            (0x1F18A, 0), (0x1FBF0, 5), (0x1FBFA, 0), (0x20000, 5), (0x2A6E0, 0), (0x2A700, 5), (0x2B73A, 0), (0x2B740, 5),
+            (0x2B81E, 0), (0x2B820, 5), (0x2CEA2, 0), (0x2CEB0, 5), (0x2EBE1, 0), (0x2EBF0, 5), (0x2EE5E, 0), (0x2F800, 5),
+            (0x2FA1E, 0), (0x30000, 5), (0x3134B, 0), (0x31350, 5), (0x323B0, 0), (0xE0100, 5), (0xE01F0, 0),
+        ];
+        static TRANSITIONS: &[u16] = &[
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 2, 3, 4, 5, 6, 7, 8,
+            0, 2, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 6, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        static ACCEPTS: &[u16] = &[0, 0, 1, 2, 3, 4, 7, 5, 6];
+        static KEYWORDS: &[(&str, u16)] = &[
+        ];
+        rt::DfaLexer {
+            rules: RULES,
+            ascii_classes: &ASCII_CLASSES,
+            unicode_classes: UNICODE_CLASSES,
+            n_classes: 8,
+            transitions: TRANSITIONS,
+            accepts: ACCEPTS,
+            starts: &[1],
+            keyword_seed: 0,
+            keywords: KEYWORDS,
+            layout: None,
+        }
+    }
+
+    fn create_parser_definition() -> rt::ParserDefinition {
+        let parser_json = r##"[{"Pub":{"ty":8,"body":27,"replaceable":false}},{"Or":[28,29,30]},{"Pub":{"ty":9,"body":37,"replaceable":false}},{"Pub":{"ty":10,"body":41,"replaceable":false}},{"Or":[49,51]},{"Pub":{"ty":11,"body":72,"replaceable":false}},{"Or":[89]},{"Rep":1},{"Exit":[0,7]},{"Enter":[16,8]},{"Enter":[15,9]},{"Enter":[14,10]},{"Enter":[13,11]},{"Enter":[12,12]},{"Enter":[11,13]},{"Enter":[10,14]},{"Enter":[9,15]},{"Enter":[8,16]},{"Enter":[7,17]},{"Enter":[6,18]},{"Enter":[5,19]},{"Enter":[4,20]},{"Enter":[3,21]},{"Enter":[2,22]},{"Enter":[1,23]},{"Enter":[0,24]},{"And":[[25],null]},{"Or":[26]},{"And":[[2],null]},{"And":[[3],null]},{"And":[[5],null]},{"Token":2},{"IsIn":16},{"IsIn":0},{"Not":33},{"Token":7},{"And":[[31,32,34,35],null]},{"Or":[36]},{"Token":7},{"Call":[4,[[0,38]]]},{"And":[[39],null]},{"Or":[40]},{"Token":3},{"Var":0},{"Var":0},{"And":[[43,44],null]},{"Or":[45]},{"Call":[4,[[0,46]]]},{"Token":4},{"And":[[42,47,48],1]},{"Var":0},{"And":[[50],null]},{"Token":5},{"Token":7},{"Token":7},{"Token":7},{"Token":7},{"Token":7},{"Token":7},{"Token":7},{"Token":7},{"Token":7},{"Token":7},{"Token":7},{"Token":7},{"Token":7},{"Token":7},{"Token":7},{"Token":7},{"Call":[6,[[1,53],[2,54],[3,55],[4,56],[5,57],[6,58],[7,59],[8,60],[9,61],[10,62],[11,63],[12,64],[13,65],[14,66],[15,67],[16,68]]]},{"Token":6},{"And":[[52,69,70],null]},{"Or":[71]},{"Var":1},{"Var":2},{"Var":3},{"Var":4},{"Var":5},{"Var":6},{"Var":7},{"Var":8},{"Var":9},{"Var":10},{"Var":11},{"Var":12},{"Var":13},{"Var":14},{"Var":15},{"Var":16},{"And":[[73,74,75,76,77,78,79,80,81,82,83,84,85,86,87,88],null]}]"##;
+
+        ::fall_parse::ParserDefinition {
+            node_types: vec![
+                rt::ERROR,
+                WHITESPACE, BANG, LPAREN, RPAREN, LBRACK, RBRACK, IDENT, FILE, DEEP, GROUP, ROW,
+            ],
+            syntactical_rules: rt::parser_from_str(parser_json),
+
+            .. Default::default()
+        }
+    }
+    use self::rt::*;
+    lazy_static! {
+        static ref LANG: rt::Language = {
+            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::DfaLexer };
+            impl rt::LanguageImpl for Impl {
+                fn parse(
+                    &self,
+                    text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, options, metrics, builder)
+                }
+
+                fn reparse(
+                    &self,
+                    incremental_data: &dyn std::any::Any,
+                    edit: &rt::TextEdit,
+                    new_text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, options, metrics, builder)
+                }
+
+                fn node_type_info(&self, ty: rt::NodeType) -> rt::NodeTypeInfo {
+                    match ty {
+                        ERROR => rt::NodeTypeInfo { name: "ERROR", whitespace_like: false },
+                        WHITESPACE => rt::NodeTypeInfo { name: "WHITESPACE", whitespace_like: true },
+                        BANG => rt::NodeTypeInfo { name: "BANG", whitespace_like: false },
+                        LPAREN => rt::NodeTypeInfo { name: "LPAREN", whitespace_like: false },
+                        RPAREN => rt::NodeTypeInfo { name: "RPAREN", whitespace_like: false },
+                        LBRACK => rt::NodeTypeInfo { name: "LBRACK", whitespace_like: false },
+                        RBRACK => rt::NodeTypeInfo { name: "RBRACK", whitespace_like: false },
+                        IDENT => rt::NodeTypeInfo { name: "IDENT", whitespace_like: false },
+                        FILE => rt::NodeTypeInfo { name: "FILE", whitespace_like: false },
+                        DEEP => rt::NodeTypeInfo { name: "DEEP", whitespace_like: false },
+                        GROUP => rt::NodeTypeInfo { name: "GROUP", whitespace_like: false },
+                        ROW => rt::NodeTypeInfo { name: "ROW", whitespace_like: false },
+                        _ => panic!("Unknown rt::NodeType: {:?}", ty)
+                    }
+                }
+            }
+
+            rt::Language::new(Impl {
+                parser_definition: create_parser_definition(),
+                lexer: create_lexer()
+            })
+        };
+    }
+
+    &*LANG
+}
@@ -10,14 +10,244 @@ pub const LIST: rt::NodeType = rt::NodeType(105);
 
 
 pub fn language() -> &'static rt::Language {
-    fn create_lexer() -> rt::RegexLexer {
-        rt::RegexLexer::new(vec![
-            rt::LexRule::new(WHITESPACE, "\\s+", None),
-            rt::LexRule::new(LPAREN, "\\(", None),
-            rt::LexRule::new(RPAREN, "\\)", None),
-            rt::LexRule::new(ATOM, "\\w+", None),
-
-        ])
+    fn create_lexer() -> rt::DfaLexer {
+        static RULES: &[rt::DfaRule] = &[
+            rt::DfaRule { ty: WHITESPACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LPAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RPAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: ATOM, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+        ];
+        static ASCII_CLASSES: [u8; 128] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 3, 0, 0, 0, 0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 0, 0, 0, 0,
+            0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 0, 0, 4,
+            0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 0, 0, 0, 0, 0,
+        ];
+        static UNICODE_CLASSES: &[(u32, u8)] = &[
+            (0x80, 0), (0x85, 1), (0x86, 0), (0xA0, 1), (0xA1, 0), (0xAA, 4), (0xAB, 0), (0xB5, 4),
+            (0xB6, 0), (0xBA, 4), (0xBB, 0), (0xC0, 4), (0xD7, 0), (0xD8, 4), (0xF7, 0), (0xF8, 4),
+            (0x2C2, 0), (0x2C6, 4), (0x2D2, 0), (0x2E0, 4), (0x2E5, 0), (0x2EC, 4), (0x2ED, 0), (0x2EE, 4),
+            (0x2EF, 0), (0x300, 4), (0x375, 0), (0x376, 4), (0x378, 0), (0x37A, 4), (0x37E, 0), (0x37F, 4),
+            (0x380, 0), (0x386, 4), (0x387, 0), (0x388, 4), (0x38B, 0), (0x38C, 4), (0x38D, 0), (0x38E, 4),
+            (0x3A2, 0), (0x3A3, 4), (0x3F6, 0), (0x3F7, 4), (0x482, 0), (0x483, 4), (0x530, 0), (0x531, 4),
+            (0x557, 0), (0x559, 4), (0x55A, 0), (0x560, 4), (0x589, 0), (0x591, 4), (0x5BE, 0), (0x5BF, 4),
+            (0x5C0, 0), (0x5C1, 4), (0x5C3, 0), (0x5C4, 4), (0x5C6, 0), (0x5C7, 4), (0x5C8, 0), (0x5D0, 4),
+            (0x5EB, 0), (0x5EF, 4), (0x5F3, 0), (0x610, 4), (0x61B, 0), (0x620, 4), (0x66A, 0), (0x66E, 4),
+            (0x6D4, 0), (0x6D5, 4), (0x6DD, 0), (0x6DF, 4), (0x6E9, 0), (0x6EA, 4), (0x6FD, 0), (0x6FF, 4),
+            (0x700, 0), (0x710, 4), (0x74B, 0), (0x74D, 4), (0x7B2, 0), (0x7C0, 4), (0x7F6, 0), (0x7FA, 4),
+            (0x7FB, 0), (0x7FD, 4), (0x7FE, 0), (0x800, 4), (0x82E, 0), (0x840, 4), (0x85C, 0), (0x860, 4),
+            (0x86B, 0), (0x870, 4), (0x888, 0), (0x889, 4), (0x88F, 0), (0x897, 4), (0x8E2, 0), (0x8E3, 4),
+            (0x964, 0), (0x966, 4), (0x970, 0), (0x971, 4), (0x984, 0), (0x985, 4), (0x98D, 0), (0x98F, 4),
+            (0x991, 0), (0x993, 4), (0x9A9, 0), (0x9AA, 4), (0x9B1, 0), (0x9B2, 4), (0x9B3, 0), (0x9B6, 4),
+            (0x9BA, 0), (0x9BC, 4), (0x9C5, 0), (0x9C7, 4), (0x9C9, 0), (0x9CB, 4), (0x9CF, 0), (0x9D7, 4),
+            (0x9D8, 0), (0x9DC, 4), (0x9DE, 0), (0x9DF, 4), (0x9E4, 0), (0x9E6, 4), (0x9F2, 0), (0x9FC, 4),
+            (0x9FD, 0), (0x9FE, 4), (0x9FF, 0), (0xA01, 4), (0xA04, 0), (0xA05, 4), (0xA0B, 0), (0xA0F, 4),
+            (0xA11, 0), (0xA13, 4), (0xA29, 0), (0xA2A, 4), (0xA31, 0), (0xA32, 4), (0xA34, 0), (0xA35, 4),
+            (0xA37, 0), (0xA38, 4), (0xA3A, 0), (0xA3C, 4), (0xA3D, 0), (0xA3E, 4), (0xA43, 0), (0xA47, 4),
+            (0xA49, 0), (0xA4B, 4), (0xA4E, 0), (0xA51, 4), (0xA52, 0), (0xA59, 4), (0xA5D, 0), (0xA5E, 4),
+            (0xA5F, 0), (0xA66, 4), (0xA76, 0), (0xA81, 4), (0xA84, 0), (0xA85, 4), (0xA8E, 0), (0xA8F, 4),
+            (0xA92, 0), (0xA93, 4), (0xAA9, 0), (0xAAA, 4), (0xAB1, 0), (0xAB2, 4), (0xAB4, 0), (0xAB5, 4),
+            (0xABA, 0), (0xABC, 4), (0xAC6, 0), (0xAC7, 4), (0xACA, 0), (0xACB, 4), (0xACE, 0), (0xAD0, 4),
+            (0xAD1, 0), (0xAE0, 4), (0xAE4, 0), (0xAE6, 4), (0xAF0, 0), (0xAF9, 4), (0xB00, 0), (0xB01, 4),
+            (0xB04, 0), (0xB05, 4), (0xB0D, 0), (0xB0F, 4), (0xB11, 0), (0xB13, 4), (0xB29, 0), (0xB2A, 4),
+            (0xB31, 0), (0xB32, 4), (0xB34, 0), (0xB35, 4), (0xB3A, 0), (0xB3C, 4), (0xB45, 0), (0xB47, 4),
+            (0xB49, 0), (0xB4B, 4), (0xB4E, 0), (0xB55, 4), (0xB58, 0), (0xB5C, 4), (0xB5E, 0), (0xB5F, 4),
+            (0xB64, 0), (0xB66, 4), (0xB70, 0), (0xB71, 4), (0xB72, 0), (0xB82, 4), (0xB84, 0), (0xB85, 4),
+            (0xB8B, 0), (0xB8E, 4), (0xB91, 0), (0xB92, 4), (0xB96, 0), (0xB99, 4), (0xB9B, 0), (0xB9C, 4),
+            (0xB9D, 0), (0xB9E, 4), (0xBA0, 0), (0xBA3, 4), (0xBA5, 0), (0xBA8, 4), (0xBAB, 0), (0xBAE, 4),
+            (0xBBA, 0), (0xBBE, 4), (0xBC3, 0), (0xBC6, 4), (0xBC9, 0), (0xBCA, 4), (0xBCE, 0), (0xBD0, 4),
+            (0xBD1, 0), (0xBD7, 4), (0xBD8, 0), (0xBE6, 4), (0xBF0, 0), (0xC00, 4), (0xC0D, 0), (0xC0E, 4),
+            (0xC11, 0), (0xC12, 4), (0xC29, 0), (0xC2A, 4), (0xC3A, 0), (0xC3C, 4), (0xC45, 0), (0xC46, 4),
+            (0xC49, 0), (0xC4A, 4), (0xC4E, 0), (0xC55, 4), (0xC57, 0), (0xC58, 4), (0xC5B, 0), (0xC5D, 4),
+            (0xC5E, 0), (0xC60, 4), (0xC64, 0), (0xC66, 4), (0xC70, 0), (0xC80, 4), (0xC84, 0), (0xC85, 4),
+            (0xC8D, 0), (0xC8E, 4), (0xC91, 0), (0xC92, 4), (0xCA9, 0), (0xCAA, 4), (0xCB4, 0), (0xCB5, 4),
+            (0xCBA, 0), (0xCBC, 4), (0xCC5, 0), (0xCC6, 4), (0xCC9, 0), (0xCCA, 4), (0xCCE, 0), (0xCD5, 4),
+            (0xCD7, 0), (0xCDD, 4), (0xCDF, 0), (0xCE0, 4), (0xCE4, 0), (0xCE6, 4), (0xCF0, 0), (0xCF1, 4),
+            (0xCF4, 0), (0xD00, 4), (0xD0D, 0), (0xD0E, 4), (0xD11, 0), (0xD12, 4), (0xD45, 0), (0xD46, 4),
+            (0xD49, 0), (0xD4A, 4), (0xD4F, 0), (0xD54, 4), (0xD58, 0), (0xD5F, 4), (0xD64, 0), (0xD66, 4),
+            (0xD70, 0), (0xD7A, 4), (0xD80, 0), (0xD81, 4), (0xD84, 0), (0xD85, 4), (0xD97, 0), (0xD9A, 4),
+            (0xDB2, 0), (0xDB3, 4), (0xDBC, 0), (0xDBD, 4), (0xDBE, 0), (0xDC0, 4), (0xDC7, 0), (0xDCA, 4),
+            (0xDCB, 0), (0xDCF, 4), (0xDD5, 0), (0xDD6, 4), (0xDD7, 0), (0xDD8, 4), (0xDE0, 0), (0xDE6, 4),
+            (0xDF0, 0), (0xDF2, 4), (0xDF4, 0), (0xE01, 4), (0xE3B, 0), (0xE40, 4), (0xE4F, 0), (0xE50, 4),
+            (0xE5A, 0), (0xE81, 4), (0xE83, 0), (0xE84, 4), (0xE85, 0), (0xE86, 4), (0xE8B, 0), (0xE8C, 4),
+            (0xEA4, 0), (0xEA5, 4), (0xEA6, 0), (0xEA7, 4), (0xEBE, 0), (0xEC0, 4), (0xEC5, 0), (0xEC6, 4),
+            (0xEC7, 0), (0xEC8, 4), (0xECF, 0), (0xED0, 4), (0xEDA, 0), (0xEDC, 4), (0xEE0, 0), (0xF00, 4),
+            (0xF01, 0), (0xF18, 4), (0xF1A, 0), (0xF20, 4), (0xF2A, 0), (0xF35, 4), (0xF36, 0), (0xF37, 4),
+            (0xF38, 0), (0xF39, 4), (0xF3A, 0), (0xF3E, 4), (0xF48, 0), (0xF49, 4), (0xF6D, 0), (0xF71, 4),
+            (0xF85, 0), (0xF86, 4), (0xF98, 0), (0xF99, 4), (0xFBD, 0), (0xFC6, 4), (0xFC7, 0), (0x1000, 4),
+            (0x104A, 0), (0x1050, 4), (0x109E, 0), (0x10A0, 4), (0x10C6, 0), (0x10C7, 4), (0x10C8, 0), (0x10CD, 4),
+            (0x10CE, 0), (0x10D0, 4), (0x10FB, 0), (0x10FC, 4), (0x1249, 0), (0x124A, 4), (0x124E, 0), (0x1250, 4),
+            (0x1257, 0), (0x1258, 4), (0x1259, 0), (0x125A, 4), (0x125E, 0), (0x1260, 4), (0x1289, 0), (0x128A, 4),
+            (0x128E, 0), (0x1290, 4), (0x12B1, 0), (0x12B2, 4), (0x12B6, 0), (0x12B8, 4), (0x12BF, 0), (0x12C0, 4),
+            (0x12C1, 0), (0x12C2, 4), (0x12C6, 0), (0x12C8, 4), (0x12D7, 0), (0x12D8, 4), (0x1311, 0), (0x1312, 4),
+            (0x1316, 0), (0x1318, 4), (0x135B, 0), (0x135D, 4), (0x1360, 0), (0x1380, 4), (0x1390, 0), (0x13A0, 4),
+            (0x13F6, 0), (0x13F8, 4), (0x13FE, 0), (0x1401, 4), (0x166D, 0), (0x166F, 4), (0x1680, 1), (0x1681, 4),
+            (0x169B, 0), (0x16A0, 4), (0x16EB, 0), (0x16EE, 4), (0x16F9, 0), (0x1700, 4), (0x1716, 0), (0x171F, 4),
+            (0x1735, 0), (0x1740, 4), (0x1754, 0), (0x1760, 4), (0x176D, 0), (0x176E, 4), (0x1771, 0), (0x1772, 4),
+            (0x1774, 0), (0x1780, 4), (0x17D4, 0), (0x17D7, 4), (0x17D8, 0), (0x17DC, 4), (0x17DE, 0), (0x17E0, 4),
+            (0x17EA, 0), (0x180B, 4), (0x180E, 0), (0x180F, 4), (0x181A, 0), (0x1820, 4), (0x1879, 0), (0x1880, 4),
+            (0x18AB, 0), (0x18B0, 4), (0x18F6, 0), (0x1900, 4), (0x191F, 0), (0x1920, 4), (0x192C, 0), (0x1930, 4),
+            (0x193C, 0), (0x1946, 4), (0x196E, 0), (0x1970, 4), (0x1975, 0), (0x1980, 4), (0x19AC, 0), (0x19B0, 4),
+            (0x19CA, 0), (0x19D0, 4), (0x19DA, 0), (0x1A00, 4), (0x1A1C, 0), (0x1A20, 4), (0x1A5F, 0), (0x1A60, 4),
+            (0x1A7D, 0), (0x1A7F, 4), (0x1A8A, 0), (0x1A90, 4), (0x1A9A, 0), (0x1AA7, 4), (0x1AA8, 0), (0x1AB0, 4),
+            (0x1ACF, 0), (0x1B00, 4), (0x1B4D, 0), (0x1B50, 4), (0x1B5A, 0), (0x1B6B, 4), (0x1B74, 0), (0x1B80, 4),
+            (0x1BF4, 0), (0x1C00, 4), (0x1C38, 0), (0x1C40, 4), (0x1C4A, 0), (0x1C4D, 4), (0x1C7E, 0), (0x1C80, 4),
+            (0x1C8B, 0), (0x1C90, 4), (0x1CBB, 0), (0x1CBD, 4), (0x1CC0, 0), (0x1CD0, 4), (0x1CD3, 0), (0x1CD4, 4),
+            (0x1CFB, 0), (0x1D00, 4), (0x1F16, 0), (0x1F18, 4), (0x1F1E, 0), (0x1F20, 4), (0x1F46, 0), (0x1F48, 4),
+            (0x1F4E, 0), (0x1F50, 4), (0x1F58, 0), (0x1F59, 4), (0x1F5A, 0), (0x1F5B, 4), (0x1F5C, 0), (0x1F5D, 4),
+            (0x1F5E, 0), (0x1F5F, 4), (0x1F7E, 0), (0x1F80, 4), (0x1FB5, 0), (0x1FB6, 4), (0x1FBD, 0), (0x1FBE, 4),
+            (0x1FBF, 0), (0x1FC2, 4), (0x1FC5, 0), (0x1FC6, 4), (0x1FCD, 0), (0x1FD0, 4), (0x1FD4, 0), (0x1FD6, 4),
+            (0x1FDC, 0), (0x1FE0, 4), (0x1FED, 0), (0x1FF2, 4), (0x1FF5, 0), (0x1FF6, 4), (0x1FFD, 0), (0x2000, 1),
+            (0x200B, 0), (0x200C, 4), (0x200E, 0), (0x2028, 1), (0x202A, 0), (0x202F, 1), (0x2030, 0), (0x203F, 4),
+            (0x2041, 0), (0x2054, 4), (0x2055, 0), (0x205F, 1), (0x2060, 0), (0x2071, 4), (0x2072, 0), (0x207F, 4),
+            (0x2080, 0), (0x2090, 4), (0x209D, 0), (0x20D0, 4), (0x20F1, 0), (0x2102, 4), (0x2103, 0), (0x2107, 4),
+            (0x2108, 0), (0x210A, 4), (0x2114, 0), (0x2115, 4), (0x2116, 0), (0x2119, 4), (0x211E, 0), (0x2124, 4),
+            (0x2125, 0), (0x2126, 4), (0x2127, 0), (0x2128, 4), (0x2129, 0), (0x212A, 4), (0x212E, 0), (0x212F, 4),
+            (0x213A, 0), (0x213C, 4), (0x2140, 0), (0x2145, 4), (0x214A, 0), (0x214E, 4), (0x214F, 0), (0x2160, 4),
+            (0x2189, 0), (0x24B6, 4), (0x24EA, 0), (0x2C00, 4), (0x2CE5, 0), (0x2CEB, 4), (0x2CF4, 0), (0x2D00, 4),
+            (0x2D26, 0), (0x2D27, 4), (0x2D28, 0), (0x2D2D, 4), (0x2D2E, 0), (0x2D30, 4), (0x2D68, 0), (0x2D6F, 4),
+            (0x2D70, 0), (0x2D7F, 4), (0x2D97, 0), (0x2DA0, 4), (0x2DA7, 0), (0x2DA8, 4), (0x2DAF, 0), (0x2DB0, 4),
+            (0x2DB7, 0), (0x2DB8, 4), (0x2DBF, 0), (0x2DC0, 4), (0x2DC7, 0), (0x2DC8, 4), (0x2DCF, 0), (0x2DD0, 4),
+            (0x2DD7, 0), (0x2DD8, 4), (0x2DDF, 0), (0x2DE0, 4), (0x2E00, 0), (0x2E2F, 4), (0x2E30, 0), (0x3000, 1),
+            (0x3001, 0), (0x3005, 4), (0x3008, 0), (0x3021, 4), (0x3030, 0), (0x3031, 4), (0x3036, 0), (0x3038, 4),
+            (0x303D, 0), (0x3041, 4), (0x3097, 0), (0x3099, 4), (0x309B, 0), (0x309D, 4), (0x30A0, 0), (0x30A1, 4),
+            (0x30FB, 0), (0x30FC, 4), (0x3100, 0), (0x3105, 4), (0x3130, 0), (0x3131, 4), (0x318F, 0), (0x31A0, 4),
+            (0x31C0, 0), (0x31F0, 4), (0x3200, 0), (0x3400, 4), (0x4DC0, 0), (0x4E00, 4), (0xA48D, 0), (0xA4D0, 4),
+            (0xA4FE, 0), (0xA500, 4), (0xA60D, 0), (0xA610, 4), (0xA62C, 0), (0xA640, 4), (0xA673, 0), (0xA674, 4),
+            (0xA67E, 0), (0xA67F, 4), (0xA6F2, 0), (0xA717, 4), (0xA720, 0), (0xA722, 4), (0xA789, 0), (0xA78B, 4),
+            (0xA7CE, 0), (0xA7D0, 4), (0xA7D2, 0), (0xA7D3, 4), (0xA7D4, 0), (0xA7D5, 4), (0xA7DD, 0), (0xA7F2, 4),
+            (0xA828, 0), (0xA82C, 4), (0xA82D, 0), (0xA840, 4), (0xA874, 0), (0xA880, 4), (0xA8C6, 0), (0xA8D0, 4),
+            (0xA8DA, 0), (0xA8E0, 4), (0xA8F8, 0), (0xA8FB, 4), (0xA8FC, 0), (0xA8FD, 4), (0xA92E, 0), (0xA930, 4),
+            (0xA954, 0), (0xA960, 4), (0xA97D, 0), (0xA980, 4), (0xA9C1, 0), (0xA9CF, 4), (0xA9DA, 0), (0xA9E0, 4),
+            (0xA9FF, 0), (0xAA00, 4), (0xAA37, 0), (0xAA40, 4), (0xAA4E, 0), (0xAA50, 4), (0xAA5A, 0), (0xAA60, 4),
+            (0xAA77, 0), (0xAA7A, 4), (0xAAC3, 0), (0xAADB, 4), (0xAADE, 0), (0xAAE0, 4), (0xAAF0, 0), (0xAAF2, 4),
+            (0xAAF7, 0), (0xAB01, 4), (0xAB07, 0), (0xAB09, 4), (0xAB0F, 0), (0xAB11, 4), (0xAB17, 0), (0xAB20, 4),
+            (0xAB27, 0), (0xAB28, 4), (0xAB2F, 0), (0xAB30, 4), (0xAB5B, 0), (0xAB5C, 4), (0xAB6A, 0), (0xAB70, 4),
+            (0xABEB, 0), (0xABEC, 4), (0xABEE, 0), (0xABF0, 4), (0xABFA, 0), (0xAC00, 4), (0xD7A4, 0), (0xD7B0, 4),
+            (0xD7C7, 0), (0xD7CB, 4), (0xD7FC, 0), (0xF900, 4), (0xFA6E, 0), (0xFA70, 4), (0xFADA, 0), (0xFB00, 4),
+            (0xFB07, 0), (0xFB13, 4), (0xFB18, 0), (0xFB1D, 4), (0xFB29, 0), (0xFB2A, 4), (0xFB37, 0), (0xFB38, 4),
+            (0xFB3D, 0), (0xFB3E, 4), (0xFB3F, 0), (0xFB40, 4), (0xFB42, 0), (0xFB43, 4), (0xFB45, 0), (0xFB46, 4),
+            (0xFBB2, 0), (0xFBD3, 4), (0xFD3E, 0), (0xFD50, 4), (0xFD90, 0), (0xFD92, 4), (0xFDC8, 0), (0xFDF0, 4),
+            (0xFDFC, 0), (0xFE00, 4), (0xFE10, 0), (0xFE20, 4), (0xFE30, 0), (0xFE33, 4), (0xFE35, 0), (0xFE4D, 4),
+            (0xFE50, 0), (0xFE70, 4), (0xFE75, 0), (0xFE76, 4), (0xFEFD, 0), (0xFF10, 4), (0xFF1A, 0), (0xFF21, 4),
+            (0xFF3B, 0), (0xFF3F, 4), (0xFF40, 0), (0xFF41, 4), (0xFF5B, 0), (0xFF66, 4), (0xFFBF, 0), (0xFFC2, 4),
+            (0xFFC8, 0), (0xFFCA, 4), (0xFFD0, 0), (0xFFD2, 4), (0xFFD8, 0), (0xFFDA, 4), (0xFFDD, 0), (0x10000, 4),
+            (0x1000C, 0), (0x1000D, 4), (0x10027, 0), (0x10028, 4), (0x1003B, 0), (0x1003C, 4), (0x1003E, 0), (0x1003F, 4),
+            (0x1004E, 0), (0x10050, 4), (0x1005E, 0), (0x10080, 4), (0x100FB, 0), (0x10140, 4), (0x10175, 0), (0x101FD, 4),
+            (0x101FE, 0), (0x10280, 4), (0x1029D, 0), (0x102A0, 4), (0x102D1, 0), (0x102E0, 4), (0x102E1, 0), (0x10300, 4),
+            (0x10320, 0), (0x1032D, 4), (0x1034B, 0), (0x10350, 4), (0x1037B, 0), (0x10380, 4), (0x1039E, 0), (0x103A0, 4),
+            (0x103C4, 0), (0x103C8, 4), (0x103D0, 0), (0x103D1, 4), (0x103D6, 0), (0x10400, 4), (0x1049E, 0), (0x104A0, 4),
+            (0x104AA, 0), (0x104B0, 4), (0x104D4, 0), (0x104D8, 4), (0x104FC, 0), (0x10500, 4), (0x10528, 0), (0x10530, 4),
+            (0x10564, 0), (0x10570, 4), (0x1057B, 0), (0x1057C, 4), (0x1058B, 0), (0x1058C, 4), (0x10593, 0), (0x10594, 4),
+            (0x10596, 0), (0x10597, 4), (0x105A2, 0), (0x105A3, 4), (0x105B2, 0), (0x105B3, 4), (0x105BA, 0), (0x105BB, 4),
+            (0x105BD, 0), (0x105C0, 4), (0x105F4, 0), (0x10600, 4), (0x10737, 0), (0x10740, 4), (0x10756, 0), (0x10760, 4),
+            (0x10768, 0), (0x10780, 4), (0x10786, 0), (0x10787, 4), (0x107B1, 0), (0x107B2, 4), (0x107BB, 0), (0x10800, 4),
+            (0x10806, 0), (0x10808, 4), (0x10809, 0), (0x1080A, 4), (0x10836, 0), (0x10837, 4), (0x10839, 0), (0x1083C, 4),
+            (0x1083D, 0), (0x1083F, 4), (0x10856, 0), (0x10860, 4), (0x10877, 0), (0x10880, 4), (0x1089F, 0), (0x108E0, 4),
+            (0x108F3, 0), (0x108F4, 4), (0x108F6, 0), (0x10900, 4), (0x10916, 0), (0x10920, 4), (0x1093A, 0), (0x10980, 4),
+            (0x109B8, 0), (0x109BE, 4), (0x109C0, 0), (0x10A00, 4), (0x10A04, 0), (0x10A05, 4), (0x10A07, 0), (0x10A0C, 4),
+            (0x10A14, 0), (0x10A15, 4), (0x10A18, 0), (0x10A19, 4), (0x10A36, 0), (0x10A38, 4), (0x10A3B, 0), (0x10A3F, 4),
+            (0x10A40, 0), (0x10A60, 4), (0x10A7D, 0), (0x10A80, 4), (0x10A9D, 0), (0x10AC0, 4), (0x10AC8, 0), (0x10AC9, 4),
+            (0x10AE7, 0), (0x10B00, 4), (0x10B36, 0), (0x10B40, 4), (0x10B56, 0), (0x10B60, 4), (0x10B73, 0), (0x10B80, 4),
+            (0x10B92, 0), (0x10C00, 4), (0x10C49, 0), (0x10C80, 4), (0x10CB3, 0), (0x10CC0, 4), (0x10CF3, 0), (0x10D00, 4),
+            (0x10D28, 0), (0x10D30, 4), (0x10D3A, 0), (0x10D40, 4), (0x10D66, 0), (0x10D69, 4), (0x10D6E, 0), (0x10D6F, 4),
+            (0x10D86, 0), (0x10E80, 4), (0x10EAA, 0), (0x10EAB, 4), (0x10EAD, 0), (0x10EB0, 4), (0x10EB2, 0), (0x10EC2, 4),
+            (0x10EC5, 0), (0x10EFC, 4), (0x10F1D, 0), (0x10F27, 4), (0x10F28, 0), (0x10F30, 4), (0x10F51, 0), (0x10F70, 4),
+            (0x10F86, 0), (0x10FB0, 4), (0x10FC5, 0), (0x10FE0, 4), (0x10FF7, 0), (0x11000, 4), (0x11047, 0), (0x11066, 4),
+            (0x11076, 0), (0x1107F, 4), (0x110BB, 0), (0x110C2, 4), (0x110C3, 0), (0x110D0, 4), (0x110E9, 0), (0x110F0, 4),
+            (0x110FA, 0), (0x11100, 4), (0x11135, 0), (0x11136, 4), (0x11140, 0), (0x11144, 4), (0x11148, 0), (0x11150, 4),
+            (0x11174, 0), (0x11176, 4), (0x11177, 0), (0x11180, 4), (0x111C5, 0), (0x111C9, 4), (0x111CD, 0), (0x111CE, 4),
+            (0x111DB, 0), (0x111DC, 4), (0x111DD, 0), (0x11200, 4), (0x11212, 0), (0x11213, 4), (0x11238, 0), (0x1123E, 4),
+            (0x11242, 0), (0x11280, 4), (0x11287, 0), (0x11288, 4), (0x11289, 0), (0x1128A, 4), (0x1128E, 0), (0x1128F, 4),
+            (0x1129E, 0), (0x1129F, 4), (0x112A9, 0), (0x112B0, 4), (0x112EB, 0), (0x112F0, 4), (0x112FA, 0), (0x11300, 4),
+            (0x11304, 0), (0x11305, 4), (0x1130D, 0), (0x1130F, 4), (0x11311, 0), (0x11313, 4), (0x11329, 0), (0x1132A, 4),
+            (0x11331, 0), (0x11332, 4), (0x11334, 0), (0x11335, 4), (0x1133A, 0), (0x1133B, 4), (0x11345, 0), (0x11347, 4),
+            (0x11349, 0), (0x1134B, 4), (0x1134E, 0), (0x11350, 4), (0x11351, 0), (0x11357, 4), (0x11358, 0), (0x1135D, 4),
+            (0x11364, 0), (0x11366, 4), (0x1136D, 0), (0x11370, 4), (0x11375, 0), (0x11380, 4), (0x1138A, 0), (0x1138B, 4),
+            (0x1138C, 0), (0x1138E, 4), (0x1138F, 0), (0x11390, 4), (0x113B6, 0), (0x113B7, 4), (0x113C1, 0), (0x113C2, 4),
+            (0x113C3, 0), (0x113C5, 4), (0x113C6, 0), (0x113C7, 4), (0x113CB, 0), (0x113CC, 4), (0x113D4, 0), (0x113E1, 4),
+            (0x113E3, 0), (0x11400, 4), (0x1144B, 0), (0x11450, 4), (0x1145A, 0), (0x1145E, 4), (0x11462, 0), (0x11480, 4),
+            (0x114C6, 0), (0x114C7, 4), (0x114C8, 0), (0x114D0, 4), (0x114DA, 0), (0x11580, 4), (0x115B6, 0), (0x115B8, 4),
+            (0x115C1, 0), (0x115D8, 4), (0x115DE, 0), (0x11600, 4), (0x11641, 0), (0x11644, 4), (0x11645, 0), (0x11650, 4),
+            (0x1165A, 0), (0x11680, 4), (0x116B9, 0), (0x116C0, 4), (0x116CA, 0), (0x116D0, 4), (0x116E4, 0), (0x11700, 4),
+            (0x1171B, 0), (0x1171D, 4), (0x1172C, 0), (0x11730, 4), (0x1173A, 0), (0x11740, 4), (0x11747, 0), (0x11800, 4),
+            (0x1183B, 0), (0x118A0, 4), (0x118EA, 0), (0x118FF, 4), (0x11907, 0), (0x11909, 4), (0x1190A, 0), (0x1190C, 4),
+            (0x11914, 0), (0x11915, 4), (0x11917, 0), (0x11918, 4), (0x11936, 0), (0x11937, 4), (0x11939, 0), (0x1193B, 4),
+            (0x11944, 0), (0x11950, 4), (0x1195A, 0), (0x119A0, 4), (0x119A8, 0), (0x119AA, 4), (0x119D8, 0), (0x119DA, 4),
+            (0x119E2, 0), (0x119E3, 4), (0x119E5, 0), (0x11A00, 4), (0x11A3F, 0), (0x11A47, 4), (0x11A48, 0), (0x11A50, 4),
+            (0x11A9A, 0), (0x11A9D, 4), (0x11A9E, 0), (0x11AB0, 4), (0x11AF9, 0), (0x11BC0, 4), (0x11BE1, 0), (0x11BF0, 4),
+            (0x11BFA, 0), (0x11C00, 4), (0x11C09, 0), (0x11C0A, 4), (0x11C37, 0), (0x11C38, 4), (0x11C41, 0), (0x11C50, 4),
+            (0x11C5A, 0), (0x11C72, 4), (0x11C90, 0), (0x11C92, 4), (0x11CA8, 0), (0x11CA9, 4), (0x11CB7, 0), (0x11D00, 4),
+            (0x11D07, 0), (0x11D08, 4), (0x11D0A, 0), (0x11D0B, 4), (0x11D37, 0), (0x11D3A, 4), (0x11D3B, 0), (0x11D3C, 4),
+            (0x11D3E, 0), (0x11D3F, 4), (0x11D48, 0), (0x11D50, 4), (0x11D5A, 0), (0x11D60, 4), (0x11D66, 0), (0x11D67, 4),
+            (0x11D69, 0), (0x11D6A, 4), (0x11D8F, 0), (0x11D90, 4), (0x11D92, 0), (0x11D93, 4), (0x11D99, 0), (0x11DA0, 4),
+            (0x11DAA, 0), (0x11EE0, 4), (0x11EF7, 0), (0x11F00, 4), (0x11F11, 0), (0x11F12, 4), (0x11F3B, 0), (0x11F3E, 4),
+            (0x11F43, 0), (0x11F50, 4), (0x11F5B, 0), (0x11FB0, 4), (0x11FB1, 0), (0x12000, 4), (0x1239A, 0), (0x12400, 4),
+            (0x1246F, 0), (0x12480, 4), (0x12544, 0), (0x12F90, 4), (0x12FF1, 0), (0x13000, 4), (0x13430, 0), (0x13440, 4),
+            (0x13456, 0), (0x13460, 4), (0x143FB, 0), (0x14400, 4), (0x14647, 0), (0x16100, 4), (0x1613A, 0), (0x16800, 4),
+            (0x16A39, 0), (0x16A40, 4), (0x16A5F, 0), (0x16A60, 4), (0x16A6A, 0), (0x16A70, 4), (0x16ABF, 0), (0x16AC0, 4),
+            (0x16ACA, 0), (0x16AD0, 4), (0x16AEE, 0), (0x16AF0, 4), (0x16AF5, 0), (0x16B00, 4), (0x16B37, 0), (0x16B40, 4),
+            (0x16B44, 0), (0x16B50, 4), (0x16B5A, 0), (0x16B63, 4), (0x16B78, 0), (0x16B7D, 4), (0x16B90, 0), (0x16D40, 4),
+            (0x16D6D, 0), (0x16D70, 4), (0x16D7A, 0), (0x16E40, 4), (0x16E80, 0), (0x16F00, 4), (0x16F4B, 0), (0x16F4F, 4),
+            (0x16F88, 0), (0x16F8F, 4), (0x16FA0, 0), (0x16FE0, 4), (0x16FE2, 0), (0x16FE3, 4), (0x16FE5, 0), (0x16FF0, 4),
+            (0x16FF2, 0), (0x17000, 4), (0x187F8, 0), (0x18800, 4), (0x18CD6, 0), (0x18CFF, 4), (0x18D09, 0), (0x1AFF0, 4),
+            (0x1AFF4, 0), (0x1AFF5, 4), (0x1AFFC, 0), (0x1AFFD, 4), (0x1AFFF, 0), (0x1B000, 4), (0x1B123, 0), (0x1B132, 4),
+            (0x1B133, 0), (0x1B150, 4), (0x1B153, 0), (0x1B155, 4), (0x1B156, 0), (0x1B164, 4), (0x1B168, 0), (0x1B170, 4),
+            (0x1B2FC, 0), (0x1BC00, 4), (0x1BC6B, 0), (0x1BC70, 4), (0x1BC7D, 0), (0x1BC80, 4), (0x1BC89, 0), (0x1BC90, 4),
+            (0x1BC9A, 0), (0x1BC9D, 4), (0x1BC9F, 0), (0x1CCF0, 4), (0x1CCFA, 0), (0x1CF00, 4), (0x1CF2E, 0), (0x1CF30, 4),
+            (0x1CF47, 0), (0x1D165, 4), (0x1D16A, 0), (0x1D16D, 4), (0x1D173, 0), (0x1D17B, 4), (0x1D183, 0), (0x1D185, 4),
+            (0x1D18C, 0), (0x1D1AA, 4), (0x1D1AE, 0), (0x1D242, 4), (0x1D245, 0), (0x1D400, 4), (0x1D455, 0), (0x1D456, 4),
+            (0x1D49D, 0), (0x1D49E, 4), (0x1D4A0, 0), (0x1D4A2, 4), (0x1D4A3, 0), (0x1D4A5, 4), (0x1D4A7, 0), (0x1D4A9, 4),
+            (0x1D4AD, 0), (0x1D4AE, 4), (0x1D4BA, 0), (0x1D4BB, 4), (0x1D4BC, 0), (0x1D4BD, 4), (0x1D4C4, 0), (0x1D4C5, 4),
+            (0x1D506, 0), (0x1D507, 4), (0x1D50B, 0), (0x1D50D, 4), (0x1D515, 0), (0x1D516, 4), (0x1D51D, 0), (0x1D51E, 4),
+            (0x1D53A, 0), (0x1D53B, 4), (0x1D53F, 0), (0x1D540, 4), (0x1D545, 0), (0x1D546, 4), (0x1D547, 0), (0x1D54A, 4),
+            (0x1D551, 0), (0x1D552, 4), (0x1D6A6, 0), (0x1D6A8, 4), (0x1D6C1, 0), (0x1D6C2, 4), (0x1D6DB, 0), (0x1D6DC, 4),
+            (0x1D6FB, 0), (0x1D6FC, 4), (0x1D715, 0), (0x1D716, 4), (0x1D735, 0), (0x1D736, 4), (0x1D74F, 0), (0x1D750, 4),
+            (0x1D76F, 0), (0x1D770, 4), (0x1D789, 0), (0x1D78A, 4), (0x1D7A9, 0), (0x1D7AA, 4), (0x1D7C3, 0), (0x1D7C4, 4),
+            (0x1D7CC, 0), (0x1D7CE, 4), (0x1D800, 0), (0x1DA00, 4), (0x1DA37, 0), (0x1DA3B, 4), (0x1DA6D, 0), (0x1DA75, 4),
+            (0x1DA76, 0), (0x1DA84, 4), (0x1DA85, 0), (0x1DA9B, 4), (0x1DAA0, 0), (0x1DAA1, 4), (0x1DAB0, 0), (0x1DF00, 4),
+            (0x1DF1F, 0), (0x1DF25, 4), (0x1DF2B, 0), (0x1E000, 4), (0x1E007, 0), (0x1E008, 4), (0x1E019, 0), (0x1E01B, 4),
+            (0x1E022, 0), (0x1E023, 4), (0x1E025, 0), (0x1E026, 4), (0x1E02B, 0), (0x1E030, 4), (0x1E06E, 0), (0x1E08F, 4),
+            (0x1E090, 0), (0x1E100, 4), (0x1E12D, 0), (0x1E130, 4), (0x1E13E, 0), (0x1E140, 4), (0x1E14A, 0), (0x1E14E, 4),
+            (0x1E14F, 0), (0x1E290, 4), (0x1E2AF, 0), (0x1E2C0, 4), (0x1E2FA, 0), (0x1E4D0, 4), (0x1E4FA, 0), (0x1E5D0, 4),
+            (0x1E5FB, 0), (0x1E7E0, 4), (0x1E7E7, 0), (0x1E7E8, 4), (0x1E7EC, 0), (0x1E7ED, 4), (0x1E7EF, 0), (0x1E7F0, 4),
+            (0x1E7FF, 0), (0x1E800, 4), (0x1E8C5, 0), (0x1E8D0, 4), (0x1E8D7, 0), (0x1E900, 4), (0x1E94C, 0), (0x1E950, 4),
+            (0x1E95A, 0), (0x1EE00, 4), (0x1EE04, 0), (0x1EE05, 4), (0x1EE20, 0), (0x1EE21, 4), (0x1EE23, 0), (0x1EE24, 4),
+            (0x1EE25, 0), (0x1EE27, 4), (0x1EE28, 0), (0x1EE29, 4), (0x1EE33, 0), (0x1EE34, 4), (0x1EE38, 0), (0x1EE39, 4),
+            (0x1EE3A, 0), (0x1EE3B, 4), (0x1EE3C, 0), (0x1EE42, 4), (0x1EE43, 0), (0x1EE47, 4), (0x1EE48, 0), (0x1EE49, 4),
+            (0x1EE4A, 0), (0x1EE4B, 4), (0x1EE4C, 0), (0x1EE4D, 4), (0x1EE50, 0), (0x1EE51, 4), (0x1EE53, 0), (0x1EE54, 4),
+            (0x1EE55, 0), (0x1EE57, 4), (0x1EE58, 0), (0x1EE59, 4), (0x1EE5A, 0), (0x1EE5B, 4), (0x1EE5C, 0), (0x1EE5D, 4),
+            (0x1EE5E, 0), (0x1EE5F, 4), (0x1EE60, 0), (0x1EE61, 4), (0x1EE63, 0), (0x1EE64, 4), (0x1EE65, 0), (0x1EE67, 4),
+            (0x1EE6B, 0), (0x1EE6C, 4), (0x1EE73, 0), (0x1EE74, 4), (0x1EE78, 0), (0x1EE79, 4), (0x1EE7D, 0), (0x1EE7E, 4),
+            (0x1EE7F, 0), (0x1EE80, 4), (0x1EE8A, 0), (0x1EE8B, 4), (0x1EE9C, 0), (0x1EEA1, 4), (0x1EEA4, 0), (0x1EEA5, 4),
+            (0x1EEAA, 0), (0x1EEAB, 4), (0x1EEBC, 0), (0x1F130, 4), (0x1F14A, 0), (0x1F150, 4), (0x1F16A, 0), (0x1F170, 4),
+            (0x1F18A, 0), (0x1FBF0, 4), (0x1FBFA, 0), (0x20000, 4), (0x2A6E0, 0), (0x2A700, 4), (0x2B73A, 0), (0x2B740, 4),
+            (0x2B81E, 0), (0x2B820, 4), (0x2CEA2, 0), (0x2CEB0, 4), (0x2EBE1, 0), (0x2EBF0, 4), (0x2EE5E, 0), (0x2F800, 4),
+            (0x2FA1E, 0), (0x30000, 4), (0x3134B, 0), (0x31350, 4), (0x323B0, 0), (0xE0100, 4), (0xE01F0, 0),
+        ];
+        static TRANSITIONS: &[u16] = &[
+            0, 0, 0, 0, 0,
+            0, 2, 3, 4, 5,
+            0, 2, 0, 0, 0,
+            0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0,
+            0, 0, 0, 0, 5,
+        ];
+        static ACCEPTS: &[u16] = &[0, 0, 1, 2, 3, 4];
+        static KEYWORDS: &[(&str, u16)] = &[
+        ];
+        rt::DfaLexer {
+            rules: RULES,
+            ascii_classes: &ASCII_CLASSES,
+            unicode_classes: UNICODE_CLASSES,
+            n_classes: 5,
+            transitions: TRANSITIONS,
+            accepts: ACCEPTS,
+            starts: &[1],
+            keyword_seed: 0,
+            keywords: KEYWORDS,
+            layout: None,
+        }
     }
 
     fn create_parser_definition() -> rt::ParserDefinition {
@@ -36,15 +266,16 @@ pub fn language() -> &'static rt::Language {
     use self::rt::*;
     lazy_static! {
         static ref LANG: rt::Language = {
-            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::RegexLexer };
+            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::DfaLexer };
             impl rt::LanguageImpl for Impl {
                 fn parse(
                     &self,
                     text: rt::Text,
+                    options: &rt::ParseOptions,
                     metrics: &rt::Metrics,
                     builder: &mut rt::TreeBuilder,
                 ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
-                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, metrics, builder)
+                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, options, metrics, builder)
                 }
 
                 fn reparse(
@@ -52,10 +283,11 @@ pub fn language() -> &'static rt::Language {
                     incremental_data: &dyn std::any::Any,
                     edit: &rt::TextEdit,
                     new_text: rt::Text,
+                    options: &rt::ParseOptions,
                     metrics: &rt::Metrics,
                     builder: &mut rt::TreeBuilder,
                 ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
-                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, metrics, builder)
+                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, options, metrics, builder)
                 }
 
                 fn node_type_info(&self, ty: rt::NodeType) -> rt::NodeTypeInfo {
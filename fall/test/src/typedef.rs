@@ -0,0 +1,209 @@
+use fall_parse::runtime as rt;
+pub use self::rt::ERROR;
+
+
+pub const WHITESPACE: rt::NodeType = rt::NodeType(100);
+
+pub const TYPEDEF: rt::NodeType = rt::NodeType(101);
+
+pub const STAR: rt::NodeType = rt::NodeType(102);
+
+pub const SEMI: rt::NodeType = rt::NodeType(103);
+
+pub const LPAREN: rt::NodeType = rt::NodeType(104);
+
+pub const RPAREN: rt::NodeType = rt::NodeType(105);
+
+pub const IDENT: rt::NodeType = rt::NodeType(106);
+
+pub const FILE: rt::NodeType = rt::NodeType(107);
+
+pub const TYPEDEF_DEF: rt::NodeType = rt::NodeType(108);
+
+pub const TYPE_NAME: rt::NodeType = rt::NodeType(109);
+
+pub const VAR_DECL: rt::NodeType = rt::NodeType(110);
+
+pub const TYPE_REF: rt::NodeType = rt::NodeType(111);
+
+pub const EXPR_STMT: rt::NodeType = rt::NodeType(112);
+
+pub const PRODUCT_EXPR: rt::NodeType = rt::NodeType(113);
+
+pub const CAST_EXPR: rt::NodeType = rt::NodeType(114);
+
+pub const PAREN_EXPR: rt::NodeType = rt::NodeType(115);
+
+pub const NAME_REF: rt::NodeType = rt::NodeType(116);
+
+
+
+pub fn language() -> &'static rt::Language {
+    fn create_lexer() -> rt::DfaLexer {
+        static RULES: &[rt::DfaRule] = &[
+            rt::DfaRule { ty: WHITESPACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: TYPEDEF, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STAR, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: SEMI, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LPAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RPAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: IDENT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+        ];
+        static ASCII_CLASSES: [u8; 128] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 0, 0, 0, 0, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 6, 0, 0, 0, 0,
+            0, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 0, 0, 0, 0, 7,
+            0, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 0, 0, 0, 0, 0,
+        ];
+        static UNICODE_CLASSES: &[(u32, u8)] = &[
+            (0x80, 0), (0x85, 1), (0x86, 0), (0xA0, 1), (0xA1, 0), (0x1680, 1), (0x1681, 0), (0x2000, 1),
+            (0x200B, 0), (0x2028, 1), (0x202A, 0), (0x202F, 1), (0x2030, 0), (0x205F, 1), (0x2060, 0), (0x3000, 1),
+            (0x3001, 0),
+        ];
+        static TRANSITIONS: &[u16] = &[
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 2, 3, 4, 5, 0, 6, 7,
+            0, 2, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 7, 0, 7,
+        ];
+        static ACCEPTS: &[u16] = &[0, 0, 1, 5, 6, 3, 4, 7];
+        static KEYWORDS: &[(&str, u16)] = &[
+            ("typedef", 1),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+        ];
+        rt::DfaLexer {
+            rules: RULES,
+            ascii_classes: &ASCII_CLASSES,
+            unicode_classes: UNICODE_CLASSES,
+            n_classes: 8,
+            transitions: TRANSITIONS,
+            accepts: ACCEPTS,
+            starts: &[1],
+            keyword_seed: 0,
+            keywords: KEYWORDS,
+            layout: None,
+        }
+    }
+
+    fn create_parser_definition() -> rt::ParserDefinition {
+        
+        let parser_json = r##"[{"Pub":{"ty":8,"body":15,"replaceable":false}},{"Or":[16,17,18]},{"Pub":{"ty":9,"body":22,"replaceable":false}},{"Pub":{"ty":10,"body":25,"replaceable":false}},{"Pub":{"ty":11,"body":32,"replaceable":false}},{"Pub":{"ty":12,"body":36,"replaceable":false}},{"Pub":{"ty":13,"body":39,"replaceable":false}},{"Or":[40,41]},{"Pub":{"ty":14,"body":44,"replaceable":false}},{"Or":[45,46,47]},{"Pub":{"ty":15,"body":51,"replaceable":false}},{"Pub":{"ty":16,"body":55,"replaceable":false}},{"Pub":{"ty":17,"body":58,"replaceable":false}},{"Rep":1},{"And":[[13],null]},{"Or":[14]},{"And":[[2],null]},{"And":[[4],null]},{"And":[[6],null]},{"Token":2},{"Token":4},{"And":[[19,5,3,20],1]},{"Or":[21]},{"Token":7},{"And":[[23],null]},{"Or":[24]},{"Token":3},{"Rep":26},{"Token":7},{"Token":4},{"And":[[5,27,28,29],3]},{"Or":[30]},{"Cached":31},{"Pred":0},{"Token":7},{"And":[[33,34],null]},{"Or":[35]},{"Token":4},{"And":[[7,37],1]},{"Or":[38]},{"And":[[8],null]},{"And":[[9],null]},{"Token":3},{"And":[[9,42,7],2]},{"Or":[43]},{"And":[[10],null]},{"And":[[11],null]},{"And":[[12],null]},{"Token":5},{"Token":6},{"And":[[48,5,49,9],3]},{"Or":[50]},{"Token":5},{"Token":6},{"And":[[52,7,53],null]},{"Or":[54]},{"Token":7},{"And":[[56],null]},{"Or":[57]}]"##;
+        
+
+        ::fall_parse::ParserDefinition {
+            node_types: vec![
+                rt::ERROR,
+                WHITESPACE, TYPEDEF, STAR, SEMI, LPAREN, RPAREN, IDENT, FILE, TYPEDEF_DEF, TYPE_NAME, VAR_DECL, TYPE_REF, EXPR_STMT, PRODUCT_EXPR, CAST_EXPR, PAREN_EXPR, NAME_REF, 
+            ],
+            
+            syntactical_rules: rt::parser_from_str(parser_json),
+            
+            
+            
+            
+            
+                predicates: vec![is_type as rt::SemanticPredicate, ],
+            
+            .. Default::default()
+        }
+    }
+    use self::rt::*;
+    lazy_static! {
+        static ref LANG: rt::Language = {
+            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::DfaLexer };
+            impl rt::LanguageImpl for Impl {
+                fn parse(
+                    &self,
+                    text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, options, metrics, builder)
+                }
+
+                fn reparse(
+                    &self,
+                    incremental_data: &dyn std::any::Any,
+                    edit: &rt::TextEdit,
+                    new_text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, options, metrics, builder)
+                }
+
+                fn node_type_info(&self, ty: rt::NodeType) -> rt::NodeTypeInfo {
+                    match ty {
+                        ERROR => rt::NodeTypeInfo { name: "ERROR", whitespace_like: false },
+                        
+                        WHITESPACE => rt::NodeTypeInfo { name: "WHITESPACE", whitespace_like: true },
+                        
+                        TYPEDEF => rt::NodeTypeInfo { name: "TYPEDEF", whitespace_like: false },
+                        
+                        STAR => rt::NodeTypeInfo { name: "STAR", whitespace_like: false },
+                        
+                        SEMI => rt::NodeTypeInfo { name: "SEMI", whitespace_like: false },
+                        
+                        LPAREN => rt::NodeTypeInfo { name: "LPAREN", whitespace_like: false },
+                        
+                        RPAREN => rt::NodeTypeInfo { name: "RPAREN", whitespace_like: false },
+                        
+                        IDENT => rt::NodeTypeInfo { name: "IDENT", whitespace_like: false },
+                        
+                        FILE => rt::NodeTypeInfo { name: "FILE", whitespace_like: false },
+                        
+                        TYPEDEF_DEF => rt::NodeTypeInfo { name: "TYPEDEF_DEF", whitespace_like: false },
+                        
+                        TYPE_NAME => rt::NodeTypeInfo { name: "TYPE_NAME", whitespace_like: false },
+                        
+                        VAR_DECL => rt::NodeTypeInfo { name: "VAR_DECL", whitespace_like: false },
+                        
+                        TYPE_REF => rt::NodeTypeInfo { name: "TYPE_REF", whitespace_like: false },
+                        
+                        EXPR_STMT => rt::NodeTypeInfo { name: "EXPR_STMT", whitespace_like: false },
+                        
+                        PRODUCT_EXPR => rt::NodeTypeInfo { name: "PRODUCT_EXPR", whitespace_like: false },
+                        
+                        CAST_EXPR => rt::NodeTypeInfo { name: "CAST_EXPR", whitespace_like: false },
+                        
+                        PAREN_EXPR => rt::NodeTypeInfo { name: "PAREN_EXPR", whitespace_like: false },
+                        
+                        NAME_REF => rt::NodeTypeInfo { name: "NAME_REF", whitespace_like: false },
+                        
+                        _ => panic!("Unknown rt::NodeType: {:?}", ty)
+                    }
+                }
+                
+            }
+
+            rt::Language::new(Impl {
+                parser_definition: create_parser_definition(),
+                lexer: create_lexer()
+            })
+        };
+    }
+
+    &*LANG
+}
+
+
+
+
+fn is_type(p: &rt::ParserView) -> bool {
+    let name = match p.nth_token(0) {
+        Some((ty, name)) if ty == IDENT => name,
+        _ => return false,
+    };
+    name == "int" || p.completed_nodes().iter()
+        .any(|&(ty, text)| ty == TYPE_NAME && text == name)
+}
+
+
@@ -0,0 +1,572 @@
+use fall_parse::runtime as rt;
+pub use self::rt::ERROR;
+
+
+pub const WHITESPACE: rt::NodeType = rt::NodeType(100);
+
+pub const TYPEDEF: rt::NodeType = rt::NodeType(101);
+
+pub const STAR: rt::NodeType = rt::NodeType(102);
+
+pub const SEMI: rt::NodeType = rt::NodeType(103);
+
+pub const LPAREN: rt::NodeType = rt::NodeType(104);
+
+pub const RPAREN: rt::NodeType = rt::NodeType(105);
+
+pub const IDENT: rt::NodeType = rt::NodeType(106);
+
+pub const FILE: rt::NodeType = rt::NodeType(107);
+
+pub const TYPEDEF_DEF: rt::NodeType = rt::NodeType(108);
+
+pub const TYPE_NAME: rt::NodeType = rt::NodeType(109);
+
+pub const VAR_DECL: rt::NodeType = rt::NodeType(110);
+
+pub const TYPE_REF: rt::NodeType = rt::NodeType(111);
+
+pub const EXPR_STMT: rt::NodeType = rt::NodeType(112);
+
+pub const PRODUCT_EXPR: rt::NodeType = rt::NodeType(113);
+
+pub const CAST_EXPR: rt::NodeType = rt::NodeType(114);
+
+pub const PAREN_EXPR: rt::NodeType = rt::NodeType(115);
+
+pub const NAME_REF: rt::NodeType = rt::NodeType(116);
+
+
+
+pub fn language() -> &'static rt::Language {
+    fn create_lexer() -> rt::DfaLexer {
+        static RULES: &[rt::DfaRule] = &[
+            rt::DfaRule { ty: WHITESPACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: TYPEDEF, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STAR, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: SEMI, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LPAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RPAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: IDENT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+        ];
+        static ASCII_CLASSES: [u8; 128] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 0, 0, 0, 0, 0, 0, 0, 2, 3, 4, 0, 0, 0, 0, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 0, 6, 0, 0, 0, 0,
+            0, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 0, 0, 0, 0, 7,
+            0, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 7, 0, 0, 0, 0, 0,
+        ];
+        static UNICODE_CLASSES: &[(u32, u8)] = &[
+            (0x80, 0), (0x85, 1), (0x86, 0), (0xA0, 1), (0xA1, 0), (0x1680, 1), (0x1681, 0), (0x2000, 1),
+            (0x200B, 0), (0x2028, 1), (0x202A, 0), (0x202F, 1), (0x2030, 0), (0x205F, 1), (0x2060, 0), (0x3000, 1),
+            (0x3001, 0),
+        ];
+        static TRANSITIONS: &[u16] = &[
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 2, 3, 4, 5, 0, 6, 7,
+            0, 2, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 7, 0, 7,
+        ];
+        static ACCEPTS: &[u16] = &[0, 0, 1, 5, 6, 3, 4, 7];
+        static KEYWORDS: &[(&str, u16)] = &[
+            ("typedef", 1),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+        ];
+        rt::DfaLexer {
+            rules: RULES,
+            ascii_classes: &ASCII_CLASSES,
+            unicode_classes: UNICODE_CLASSES,
+            n_classes: 8,
+            transitions: TRANSITIONS,
+            accepts: ACCEPTS,
+            starts: &[1],
+            keyword_seed: 0,
+            keywords: KEYWORDS,
+            layout: None,
+        }
+    }
+
+    fn create_parser_definition() -> rt::ParserDefinition {
+        
+
+        ::fall_parse::ParserDefinition {
+            node_types: vec![
+                rt::ERROR,
+                WHITESPACE, TYPEDEF, STAR, SEMI, LPAREN, RPAREN, IDENT, FILE, TYPEDEF_DEF, TYPE_NAME, VAR_DECL, TYPE_REF, EXPR_STMT, PRODUCT_EXPR, CAST_EXPR, PAREN_EXPR, NAME_REF, 
+            ],
+            
+            syntactical_rules: vec![
+                rt::Expr::Pub { ty: rt::NodeTypeRef(8), body: rt::ExprRef(15), replaceable: false },
+                rt::Expr::Or(vec![rt::ExprRef(16), rt::ExprRef(17), rt::ExprRef(18)]),
+                rt::Expr::Pub { ty: rt::NodeTypeRef(9), body: rt::ExprRef(22), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(10), body: rt::ExprRef(25), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(11), body: rt::ExprRef(32), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(12), body: rt::ExprRef(36), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(13), body: rt::ExprRef(39), replaceable: false },
+                rt::Expr::Or(vec![rt::ExprRef(40), rt::ExprRef(41)]),
+                rt::Expr::Pub { ty: rt::NodeTypeRef(14), body: rt::ExprRef(44), replaceable: false },
+                rt::Expr::Or(vec![rt::ExprRef(45), rt::ExprRef(46), rt::ExprRef(47)]),
+                rt::Expr::Pub { ty: rt::NodeTypeRef(15), body: rt::ExprRef(51), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(16), body: rt::ExprRef(55), replaceable: false },
+                rt::Expr::Pub { ty: rt::NodeTypeRef(17), body: rt::ExprRef(58), replaceable: false },
+                rt::Expr::Rep(rt::ExprRef(1)),
+                rt::Expr::And(vec![rt::ExprRef(13)], None),
+                rt::Expr::Or(vec![rt::ExprRef(14)]),
+                rt::Expr::And(vec![rt::ExprRef(2)], None),
+                rt::Expr::And(vec![rt::ExprRef(4)], None),
+                rt::Expr::And(vec![rt::ExprRef(6)], None),
+                rt::Expr::Token(rt::NodeTypeRef(2)),
+                rt::Expr::Token(rt::NodeTypeRef(4)),
+                rt::Expr::And(vec![rt::ExprRef(19), rt::ExprRef(5), rt::ExprRef(3), rt::ExprRef(20)], Some(1)),
+                rt::Expr::Or(vec![rt::ExprRef(21)]),
+                rt::Expr::Token(rt::NodeTypeRef(7)),
+                rt::Expr::And(vec![rt::ExprRef(23)], None),
+                rt::Expr::Or(vec![rt::ExprRef(24)]),
+                rt::Expr::Token(rt::NodeTypeRef(3)),
+                rt::Expr::Rep(rt::ExprRef(26)),
+                rt::Expr::Token(rt::NodeTypeRef(7)),
+                rt::Expr::Token(rt::NodeTypeRef(4)),
+                rt::Expr::And(vec![rt::ExprRef(5), rt::ExprRef(27), rt::ExprRef(28), rt::ExprRef(29)], Some(3)),
+                rt::Expr::Or(vec![rt::ExprRef(30)]),
+                rt::Expr::Cached(rt::ExprRef(31)),
+                rt::Expr::Pred(rt::PredicateRef(0)),
+                rt::Expr::Token(rt::NodeTypeRef(7)),
+                rt::Expr::And(vec![rt::ExprRef(33), rt::ExprRef(34)], None),
+                rt::Expr::Or(vec![rt::ExprRef(35)]),
+                rt::Expr::Token(rt::NodeTypeRef(4)),
+                rt::Expr::And(vec![rt::ExprRef(7), rt::ExprRef(37)], Some(1)),
+                rt::Expr::Or(vec![rt::ExprRef(38)]),
+                rt::Expr::And(vec![rt::ExprRef(8)], None),
+                rt::Expr::And(vec![rt::ExprRef(9)], None),
+                rt::Expr::Token(rt::NodeTypeRef(3)),
+                rt::Expr::And(vec![rt::ExprRef(9), rt::ExprRef(42), rt::ExprRef(7)], Some(2)),
+                rt::Expr::Or(vec![rt::ExprRef(43)]),
+                rt::Expr::And(vec![rt::ExprRef(10)], None),
+                rt::Expr::And(vec![rt::ExprRef(11)], None),
+                rt::Expr::And(vec![rt::ExprRef(12)], None),
+                rt::Expr::Token(rt::NodeTypeRef(5)),
+                rt::Expr::Token(rt::NodeTypeRef(6)),
+                rt::Expr::And(vec![rt::ExprRef(48), rt::ExprRef(5), rt::ExprRef(49), rt::ExprRef(9)], Some(3)),
+                rt::Expr::Or(vec![rt::ExprRef(50)]),
+                rt::Expr::Token(rt::NodeTypeRef(5)),
+                rt::Expr::Token(rt::NodeTypeRef(6)),
+                rt::Expr::And(vec![rt::ExprRef(52), rt::ExprRef(7), rt::ExprRef(53)], None),
+                rt::Expr::Or(vec![rt::ExprRef(54)]),
+                rt::Expr::Token(rt::NodeTypeRef(7)),
+                rt::Expr::And(vec![rt::ExprRef(56)], None),
+                rt::Expr::Or(vec![rt::ExprRef(57)]),
+            ],
+            
+            
+            
+            
+                native: Some(native_parser),
+            
+            
+                predicates: vec![is_type as rt::SemanticPredicate, ],
+            
+            .. Default::default()
+        }
+    }
+    use self::rt::*;
+    lazy_static! {
+        static ref LANG: rt::Language = {
+            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::DfaLexer };
+            impl rt::LanguageImpl for Impl {
+                fn parse(
+                    &self,
+                    text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, options, metrics, builder)
+                }
+
+                fn reparse(
+                    &self,
+                    incremental_data: &dyn std::any::Any,
+                    edit: &rt::TextEdit,
+                    new_text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, options, metrics, builder)
+                }
+
+                fn node_type_info(&self, ty: rt::NodeType) -> rt::NodeTypeInfo {
+                    match ty {
+                        ERROR => rt::NodeTypeInfo { name: "ERROR", whitespace_like: false },
+                        
+                        WHITESPACE => rt::NodeTypeInfo { name: "WHITESPACE", whitespace_like: true },
+                        
+                        TYPEDEF => rt::NodeTypeInfo { name: "TYPEDEF", whitespace_like: false },
+                        
+                        STAR => rt::NodeTypeInfo { name: "STAR", whitespace_like: false },
+                        
+                        SEMI => rt::NodeTypeInfo { name: "SEMI", whitespace_like: false },
+                        
+                        LPAREN => rt::NodeTypeInfo { name: "LPAREN", whitespace_like: false },
+                        
+                        RPAREN => rt::NodeTypeInfo { name: "RPAREN", whitespace_like: false },
+                        
+                        IDENT => rt::NodeTypeInfo { name: "IDENT", whitespace_like: false },
+                        
+                        FILE => rt::NodeTypeInfo { name: "FILE", whitespace_like: false },
+                        
+                        TYPEDEF_DEF => rt::NodeTypeInfo { name: "TYPEDEF_DEF", whitespace_like: false },
+                        
+                        TYPE_NAME => rt::NodeTypeInfo { name: "TYPE_NAME", whitespace_like: false },
+                        
+                        VAR_DECL => rt::NodeTypeInfo { name: "VAR_DECL", whitespace_like: false },
+                        
+                        TYPE_REF => rt::NodeTypeInfo { name: "TYPE_REF", whitespace_like: false },
+                        
+                        EXPR_STMT => rt::NodeTypeInfo { name: "EXPR_STMT", whitespace_like: false },
+                        
+                        PRODUCT_EXPR => rt::NodeTypeInfo { name: "PRODUCT_EXPR", whitespace_like: false },
+                        
+                        CAST_EXPR => rt::NodeTypeInfo { name: "CAST_EXPR", whitespace_like: false },
+                        
+                        PAREN_EXPR => rt::NodeTypeInfo { name: "PAREN_EXPR", whitespace_like: false },
+                        
+                        NAME_REF => rt::NodeTypeInfo { name: "NAME_REF", whitespace_like: false },
+                        
+                        _ => panic!("Unknown rt::NodeType: {:?}", ty)
+                    }
+                }
+                
+            }
+
+            rt::Language::new(Impl {
+                parser_definition: create_parser_definition(),
+                lexer: create_lexer()
+            })
+        };
+    }
+
+    &*LANG
+}
+
+
+fn native_parser(p: &mut rt::native::Parser, expr: rt::ExprRef, pos: rt::native::Pos) -> Option<Option<rt::native::Pos>> {
+    let result = match expr.0 {
+        0 => rule_0(p, pos),
+        1 => rule_1(p, pos),
+        2 => rule_2(p, pos),
+        3 => rule_3(p, pos),
+        4 => rule_4(p, pos),
+        5 => rule_5(p, pos),
+        6 => rule_6(p, pos),
+        7 => rule_7(p, pos),
+        8 => rule_8(p, pos),
+        9 => rule_9(p, pos),
+        10 => rule_10(p, pos),
+        11 => rule_11(p, pos),
+        12 => rule_12(p, pos),
+        13 => expr_13(p, pos),
+        14 => expr_14(p, pos),
+        15 => expr_15(p, pos),
+        16 => expr_16(p, pos),
+        17 => expr_17(p, pos),
+        18 => expr_18(p, pos),
+        19 => expr_19(p, pos),
+        20 => expr_20(p, pos),
+        21 => expr_21(p, pos),
+        22 => expr_22(p, pos),
+        23 => expr_23(p, pos),
+        24 => expr_24(p, pos),
+        25 => expr_25(p, pos),
+        26 => expr_26(p, pos),
+        27 => expr_27(p, pos),
+        28 => expr_28(p, pos),
+        29 => expr_29(p, pos),
+        30 => expr_30(p, pos),
+        31 => expr_31(p, pos),
+        32 => expr_32(p, pos),
+        33 => expr_33(p, pos),
+        34 => expr_34(p, pos),
+        35 => expr_35(p, pos),
+        36 => expr_36(p, pos),
+        37 => expr_37(p, pos),
+        38 => expr_38(p, pos),
+        39 => expr_39(p, pos),
+        40 => expr_40(p, pos),
+        41 => expr_41(p, pos),
+        42 => expr_42(p, pos),
+        43 => expr_43(p, pos),
+        44 => expr_44(p, pos),
+        45 => expr_45(p, pos),
+        46 => expr_46(p, pos),
+        47 => expr_47(p, pos),
+        48 => expr_48(p, pos),
+        49 => expr_49(p, pos),
+        50 => expr_50(p, pos),
+        51 => expr_51(p, pos),
+        52 => expr_52(p, pos),
+        53 => expr_53(p, pos),
+        54 => expr_54(p, pos),
+        55 => expr_55(p, pos),
+        56 => expr_56(p, pos),
+        57 => expr_57(p, pos),
+        58 => expr_58(p, pos),
+        _ => return None,
+    };
+    Some(result)
+}
+
+fn rule_0(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 8, false, pos, |p, pos| rt::native::expr(p, 15, pos, expr_15))
+}
+
+fn rule_1(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 3, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 16, pos, expr_16), 1 => rt::native::expr(p, 17, pos, expr_17), 2 => rt::native::expr(p, 18, pos, expr_18), _ => unreachable!() })
+}
+
+fn rule_2(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 9, false, pos, |p, pos| rt::native::expr(p, 22, pos, expr_22))
+}
+
+fn rule_3(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 10, false, pos, |p, pos| rt::native::expr(p, 25, pos, expr_25))
+}
+
+fn rule_4(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 11, false, pos, |p, pos| rt::native::expr(p, 32, pos, expr_32))
+}
+
+fn rule_5(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 12, false, pos, |p, pos| rt::native::expr(p, 36, pos, expr_36))
+}
+
+fn rule_6(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 13, false, pos, |p, pos| rt::native::expr(p, 39, pos, expr_39))
+}
+
+fn rule_7(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 2, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 40, pos, expr_40), 1 => rt::native::expr(p, 41, pos, expr_41), _ => unreachable!() })
+}
+
+fn rule_8(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 14, false, pos, |p, pos| rt::native::expr(p, 44, pos, expr_44))
+}
+
+fn rule_9(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 3, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 45, pos, expr_45), 1 => rt::native::expr(p, 46, pos, expr_46), 2 => rt::native::expr(p, 47, pos, expr_47), _ => unreachable!() })
+}
+
+fn rule_10(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 15, false, pos, |p, pos| rt::native::expr(p, 51, pos, expr_51))
+}
+
+fn rule_11(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 16, false, pos, |p, pos| rt::native::expr(p, 55, pos, expr_55))
+}
+
+fn rule_12(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pub_node(p, 17, false, pos, |p, pos| rt::native::expr(p, 58, pos, expr_58))
+}
+
+fn expr_13(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::rep(p, pos, |p, pos| rt::native::expr(p, 1, pos, rule_1))
+}
+
+fn expr_14(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(13)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 13, pos, expr_13), _ => unreachable!() })
+}
+
+fn expr_15(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 14, pos, expr_14), _ => unreachable!() })
+}
+
+fn expr_16(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(2)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 2, pos, rule_2), _ => unreachable!() })
+}
+
+fn expr_17(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(4)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 4, pos, rule_4), _ => unreachable!() })
+}
+
+fn expr_18(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(6)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 6, pos, rule_6), _ => unreachable!() })
+}
+
+fn expr_19(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 2, pos)
+}
+
+fn expr_20(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 4, pos)
+}
+
+fn expr_21(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(19), rt::ExprRef(5), rt::ExprRef(3), rt::ExprRef(20)], Some(1), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 19, pos, expr_19), 1 => rt::native::expr(p, 5, pos, rule_5), 2 => rt::native::expr(p, 3, pos, rule_3), 3 => rt::native::expr(p, 20, pos, expr_20), _ => unreachable!() })
+}
+
+fn expr_22(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 21, pos, expr_21), _ => unreachable!() })
+}
+
+fn expr_23(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 7, pos)
+}
+
+fn expr_24(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(23)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 23, pos, expr_23), _ => unreachable!() })
+}
+
+fn expr_25(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 24, pos, expr_24), _ => unreachable!() })
+}
+
+fn expr_26(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 3, pos)
+}
+
+fn expr_27(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::rep(p, pos, |p, pos| rt::native::expr(p, 26, pos, expr_26))
+}
+
+fn expr_28(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 7, pos)
+}
+
+fn expr_29(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 4, pos)
+}
+
+fn expr_30(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(5), rt::ExprRef(27), rt::ExprRef(28), rt::ExprRef(29)], Some(3), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 5, pos, rule_5), 1 => rt::native::expr(p, 27, pos, expr_27), 2 => rt::native::expr(p, 28, pos, expr_28), 3 => rt::native::expr(p, 29, pos, expr_29), _ => unreachable!() })
+}
+
+fn expr_31(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 30, pos, expr_30), _ => unreachable!() })
+}
+
+fn expr_32(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::cached(p, 31, pos, |p, pos| rt::native::expr(p, 31, pos, expr_31))
+}
+
+fn expr_33(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::pred(p, 0, pos)
+}
+
+fn expr_34(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 7, pos)
+}
+
+fn expr_35(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(33), rt::ExprRef(34)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 33, pos, expr_33), 1 => rt::native::expr(p, 34, pos, expr_34), _ => unreachable!() })
+}
+
+fn expr_36(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 35, pos, expr_35), _ => unreachable!() })
+}
+
+fn expr_37(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 4, pos)
+}
+
+fn expr_38(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(7), rt::ExprRef(37)], Some(1), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 7, pos, rule_7), 1 => rt::native::expr(p, 37, pos, expr_37), _ => unreachable!() })
+}
+
+fn expr_39(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 38, pos, expr_38), _ => unreachable!() })
+}
+
+fn expr_40(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(8)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 8, pos, rule_8), _ => unreachable!() })
+}
+
+fn expr_41(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(9)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 9, pos, rule_9), _ => unreachable!() })
+}
+
+fn expr_42(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 3, pos)
+}
+
+fn expr_43(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(9), rt::ExprRef(42), rt::ExprRef(7)], Some(2), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 9, pos, rule_9), 1 => rt::native::expr(p, 42, pos, expr_42), 2 => rt::native::expr(p, 7, pos, rule_7), _ => unreachable!() })
+}
+
+fn expr_44(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 43, pos, expr_43), _ => unreachable!() })
+}
+
+fn expr_45(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(10)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 10, pos, rule_10), _ => unreachable!() })
+}
+
+fn expr_46(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(11)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 11, pos, rule_11), _ => unreachable!() })
+}
+
+fn expr_47(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(12)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 12, pos, rule_12), _ => unreachable!() })
+}
+
+fn expr_48(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 5, pos)
+}
+
+fn expr_49(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 6, pos)
+}
+
+fn expr_50(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(48), rt::ExprRef(5), rt::ExprRef(49), rt::ExprRef(9)], Some(3), pos, |p, i, pos| match i { 0 => rt::native::expr(p, 48, pos, expr_48), 1 => rt::native::expr(p, 5, pos, rule_5), 2 => rt::native::expr(p, 49, pos, expr_49), 3 => rt::native::expr(p, 9, pos, rule_9), _ => unreachable!() })
+}
+
+fn expr_51(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 50, pos, expr_50), _ => unreachable!() })
+}
+
+fn expr_52(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 5, pos)
+}
+
+fn expr_53(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 6, pos)
+}
+
+fn expr_54(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(52), rt::ExprRef(7), rt::ExprRef(53)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 52, pos, expr_52), 1 => rt::native::expr(p, 7, pos, rule_7), 2 => rt::native::expr(p, 53, pos, expr_53), _ => unreachable!() })
+}
+
+fn expr_55(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 54, pos, expr_54), _ => unreachable!() })
+}
+
+fn expr_56(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::token(p, 7, pos)
+}
+
+fn expr_57(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::and(p, &[rt::ExprRef(56)], None, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 56, pos, expr_56), _ => unreachable!() })
+}
+
+fn expr_58(p: &mut rt::native::Parser, pos: rt::native::Pos) -> Option<rt::native::Pos> {
+    rt::native::or(p, 1, pos, |p, i, pos| match i { 0 => rt::native::expr(p, 57, pos, expr_57), _ => unreachable!() })
+}
+
+
+
+
+fn is_type(p: &rt::ParserView) -> bool {
+    let name = match p.nth_token(0) {
+        Some((ty, name)) if ty == IDENT => name,
+        _ => return false,
+    };
+    name == "int" || p.completed_nodes().iter()
+        .any(|&(ty, text)| ty == TYPE_NAME && text == name)
+}
+
+
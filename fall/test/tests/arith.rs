@@ -179,3 +179,138 @@ FILE
         BANG "!"
     "#)
 }
+
+#[test]
+fn right_associativity() {
+    match_ast(&ast("2 ^ 3 ^ 4"), r#"
+FILE
+  POWER_EXPR
+    CONSTANT_EXPR
+      NUMBER "2"
+    CARET "^"
+    POWER_EXPR
+      CONSTANT_EXPR
+        NUMBER "3"
+      CARET "^"
+      CONSTANT_EXPR
+        NUMBER "4"
+"#);
+}
+
+#[test]
+fn non_associativity() {
+    match_ast(&ast("1 == 2 + 3"), r#"
+FILE
+  EQ_EXPR
+    CONSTANT_EXPR
+      NUMBER "1"
+    EQEQ "=="
+    SUM_EXPR
+      CONSTANT_EXPR
+        NUMBER "2"
+      PLUS "+"
+      CONSTANT_EXPR
+        NUMBER "3"
+"#);
+    match_ast(&ast("1 == 2 == 3"), r#"
+FILE
+  EQ_EXPR
+    CONSTANT_EXPR
+      NUMBER "1"
+    EQEQ "=="
+    CONSTANT_EXPR
+      NUMBER "2"
+  ERROR
+    EQEQ "=="
+    NUMBER "3"
+"#);
+}
+
+#[test]
+fn ternary() {
+    match_ast(&ast("1 + 2 ? 3 : 4 ? 5 : 6"), r#"
+FILE
+  COND_EXPR
+    SUM_EXPR
+      CONSTANT_EXPR
+        NUMBER "1"
+      PLUS "+"
+      CONSTANT_EXPR
+        NUMBER "2"
+    QUESTION "?"
+    CONSTANT_EXPR
+      NUMBER "3"
+    COLON ":"
+    COND_EXPR
+      CONSTANT_EXPR
+        NUMBER "4"
+      QUESTION "?"
+      CONSTANT_EXPR
+        NUMBER "5"
+      COLON ":"
+      CONSTANT_EXPR
+        NUMBER "6"
+"#);
+    match_ast(&ast("1 ? 2"), r#"
+FILE
+  COND_EXPR
+    CONSTANT_EXPR
+      NUMBER "1"
+    QUESTION "?"
+    CONSTANT_EXPR
+      NUMBER "2"
+    ERROR ""
+    ERROR ""
+"#);
+}
+
+#[test]
+fn mixfix_postfix() {
+    match_ast(&ast("1[2 + 3] as int * 4"), r#"
+FILE
+  PRODUCT_EXPR
+    CAST_EXPR
+      INDEX_EXPR
+        CONSTANT_EXPR
+          NUMBER "1"
+        LBRACK "["
+        SUM_EXPR
+          CONSTANT_EXPR
+            NUMBER "2"
+          PLUS "+"
+          CONSTANT_EXPR
+            NUMBER "3"
+        RBRACK "]"
+      AS "as"
+      TYPE_REF
+        INT "int"
+    STAR "*"
+    CONSTANT_EXPR
+      NUMBER "4"
+"#);
+}
+
+#[test]
+fn mixfix_prefix() {
+    match_ast(&ast("1 + if 2 then 3 else 4 + 5"), r#"
+FILE
+  SUM_EXPR
+    CONSTANT_EXPR
+      NUMBER "1"
+    PLUS "+"
+    IF_EXPR
+      IF "if"
+      CONSTANT_EXPR
+        NUMBER "2"
+      THEN "then"
+      CONSTANT_EXPR
+        NUMBER "3"
+      ELSE "else"
+      SUM_EXPR
+        CONSTANT_EXPR
+          NUMBER "4"
+        PLUS "+"
+        CONSTANT_EXPR
+          NUMBER "5"
+"#);
+}
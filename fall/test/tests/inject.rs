@@ -0,0 +1,125 @@
+extern crate fall_test;
+extern crate fall_tree;
+extern crate fall_editor;
+
+use fall_test::{inject, sexp, arith, match_ast};
+use fall_tree::{File, TextEdit, TextRange, dump_file, tu};
+use fall_tree::test_util::extract_range;
+use fall_editor::EditorFileImpl;
+use fall_editor::hl::{self, Highlights};
+
+
+fn parse(code: &str) -> File {
+    inject::language().parse(code.to_owned())
+}
+
+#[test]
+fn injected_files() {
+    let file = parse(r#"x = sexp "(a b)"; y = arith "1 + 2";"#);
+    let injections = file.injections();
+    assert_eq!(injections.len(), 2);
+
+    let (s, a) = (&injections[0], &injections[1]);
+    assert_eq!(s.range, TextRange::from_to(tu(10), tu(15)));
+    assert_eq!(file.text().slice(s.range).to_string(), "(a b)");
+    assert!(*s.file.language() == *sexp::language());
+    match_ast(&dump_file(&s.file), r#"
+FILE
+  LIST
+    LPAREN "("
+    ATOM "a"
+    ATOM "b"
+    RPAREN ")"
+"#);
+
+    assert_eq!(file.text().slice(a.range).to_string(), "1 + 2");
+    assert!(*a.file.language() == *arith::language());
+    match_ast(&dump_file(&a.file), r#"
+FILE
+  SUM_EXPR
+    CONSTANT_EXPR
+      NUMBER "1"
+    PLUS "+"
+    CONSTANT_EXPR
+      NUMBER "2"
+"#);
+}
+
+#[test]
+fn offsets_are_mapped_to_host() {
+    let file = parse(r#"x = sexp "(a b)";"#);
+    let injected = &file.injections()[0];
+    let b = TextRange::from_len(tu(3), tu(1));
+    assert_eq!(injected.to_host(b), TextRange::from_len(tu(13), tu(1)));
+    assert_eq!(injected.from_host(TextRange::from_len(tu(13), tu(1))), Some(b));
+    assert_eq!(injected.from_host(TextRange::from_len(tu(0), tu(1))), None);
+}
+
+#[test]
+fn errors_stay_in_injected_file() {
+    let file = parse(r#"x = sexp "(a";"#);
+    assert!(file.errors().is_empty());
+    assert_eq!(file.injections()[0].file.errors().len(), 1);
+}
+
+#[test]
+fn injections_are_parsed_once() {
+    let file = parse(r#"x = sexp "(a b)";"#);
+    assert!(::std::ptr::eq(&file.injections()[0].file, &file.injections()[0].file));
+}
+
+struct InjectFile {
+    file: File,
+}
+
+impl EditorFileImpl for InjectFile {
+    fn parse(text: &str) -> Self {
+        InjectFile { file: parse(text) }
+    }
+
+    fn edit(&self, edit: &TextEdit) -> Self {
+        InjectFile { file: self.file.edit(edit) }
+    }
+
+    fn syntax_tree(&self) -> String {
+        dump_file(&self.file)
+    }
+
+    fn file(&self) -> &File {
+        &self.file
+    }
+}
+
+#[test]
+fn extend_selection_descends_into_injections() {
+    let (text, range) = extract_range(r#"x = sexp "(a ^^(b c))";"#, "^");
+    let file = InjectFile::parse(&text);
+    let mut selections = Vec::new();
+    let mut range = range;
+    while let Some(next) = file.extend_selection(range) {
+        selections.push(text[next].to_string());
+        range = next;
+    }
+    assert_eq!(selections, vec![
+        "(", "(b c)", "(a (b c))", r#""(a (b c))""#,
+        r#"sexp "(a (b c))""#, r#"x = sexp "(a (b c))";"#,
+    ]);
+}
+
+#[test]
+fn highlighting_descends_into_injections() {
+    fn highlight_sexp(file: &File) -> Highlights {
+        fall_tree::search::subtree(file.root())
+            .filter(|node| node.ty() == sexp::ATOM)
+            .map(|node| (node.range(), hl::LITERAL))
+            .collect()
+    }
+
+    let file = parse(r#"x = sexp "(a b)"; y = arith "1";"#);
+    let highlights = hl::injections(&file, &highlight_sexp);
+    let ranges = highlights.iter()
+        .map(|&(range, _)| file.text().slice(range).to_string())
+        .collect::<Vec<_>>();
+    assert_eq!(ranges, vec!["a", "b"]);
+    assert_eq!(highlights[0].0, TextRange::from_len(tu(11), tu(1)));
+}
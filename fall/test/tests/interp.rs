@@ -0,0 +1,138 @@
+extern crate fall_test;
+extern crate fall_tree;
+
+use fall_test::{interp, match_ast};
+use fall_tree::{dump_file_ws, TextBuf, TextEditBuilder, TextRange, tu};
+use fall_tree::test_util::report_diff;
+
+
+fn ast(code: &str) -> String {
+    dump_file_ws(&interp::language().parse(code.to_owned()))
+}
+
+#[test]
+fn string_interpolation() {
+    match_ast(&ast(r#""a ${x} b""#), r#"
+FILE
+  STRING
+    QUOTE "\""
+    STRING_TEXT "a "
+    INTERPOLATION
+      INTERPOLATION_START "${"
+      IDENT "x"
+      RBRACE "}"
+    STRING_TEXT " b"
+    STRING_END "\""
+"#)
+}
+
+#[test]
+fn braces_inside_interpolation() {
+    match_ast(&ast(r#""${ {x} }""#), r#"
+FILE
+  STRING
+    QUOTE "\""
+    INTERPOLATION
+      INTERPOLATION_START "${"
+      WHITESPACE " "
+      BLOCK
+        LBRACE "{"
+        IDENT "x"
+        RBRACE "}"
+      WHITESPACE " "
+      RBRACE "}"
+    STRING_END "\""
+"#)
+}
+
+#[test]
+fn nested_strings() {
+    match_ast(&ast(r#""a ${"b ${c}"}""#), r#"
+FILE
+  STRING
+    QUOTE "\""
+    STRING_TEXT "a "
+    INTERPOLATION
+      INTERPOLATION_START "${"
+      STRING
+        QUOTE "\""
+        STRING_TEXT "b "
+        INTERPOLATION
+          INTERPOLATION_START "${"
+          IDENT "c"
+          RBRACE "}"
+        STRING_END "\""
+      RBRACE "}"
+    STRING_END "\""
+"#)
+}
+
+#[test]
+fn nested_comments() {
+    match_ast(&ast("/* a /* b */ c */ x"), r#"
+FILE
+  COMMENT_START "/*"
+  COMMENT_TEXT " a "
+  NESTED_COMMENT_START "/*"
+  COMMENT_TEXT " b "
+  COMMENT_END "*/"
+  COMMENT_TEXT " c "
+  COMMENT_END "*/"
+  WHITESPACE " "
+  IDENT "x"
+"#)
+}
+
+#[test]
+fn unbalanced_pop() {
+    match_ast(&ast("} x"), r#"
+FILE
+  ERROR
+    RBRACE "}"
+    WHITESPACE " "
+    IDENT "x"
+"#)
+}
+
+#[test]
+fn keywords_are_per_mode() {
+    match_ast(&ast(r#"let x = "let""#), r#"
+FILE
+  LET_STMT
+    LET "let"
+    WHITESPACE " "
+    IDENT "x"
+    WHITESPACE " "
+    EQ "="
+    WHITESPACE " "
+    STRING
+      QUOTE "\""
+      STRING_TEXT "let"
+      STRING_END "\""
+"#)
+}
+
+#[test]
+fn relex_tracks_modes() {
+    let mut text: TextBuf = r#"x "a ${b}" /* c */ d"#.into();
+    let mut file = interp::language().parse(text.to_string());
+
+    let edits: Vec<fn(&mut TextEditBuilder)> = vec![
+        |b| b.insert(tu(0), "\"".to_string()),
+        |b| b.delete(TextRange::from_len(tu(0), tu(1))),
+        |b| b.replace(TextRange::from_len(tu(4), tu(1)), "/*".to_string()),
+        |b| b.insert(tu(8), "}".to_string()),
+        |b| b.insert(tu(12), "z".to_string()),
+    ];
+    for edit in edits {
+        let edit = {
+            let mut b = TextEditBuilder::new(text.as_text());
+            edit(&mut b);
+            b.build()
+        };
+        text = edit.apply(text.as_text());
+        file = file.edit(&edit);
+        let fresh_file = interp::language().parse(text.to_string());
+        report_diff(&dump_file_ws(&fresh_file), &dump_file_ws(&file));
+    }
+}
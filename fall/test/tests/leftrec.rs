@@ -0,0 +1,144 @@
+extern crate fall_test;
+extern crate fall_tree;
+
+use fall_test::{leftrec, match_ast};
+use fall_tree::{dump_file, dump_file_ws, TextBuf, TextEditBuilder, TextRange, tu};
+use fall_tree::test_util::report_diff;
+
+
+fn ast(code: &str) -> String {
+    dump_file(&leftrec::language().parse(code.to_owned()))
+}
+
+#[test]
+fn left_nested() {
+    match_ast(&ast("1 + 2 - 3;"), r#"
+FILE
+  EXPR_STMT
+    SUM_EXPR
+      SUM_EXPR
+        LITERAL
+          NUMBER "1"
+        PLUS "+"
+        LITERAL
+          NUMBER "2"
+      MINUS "-"
+      LITERAL
+        NUMBER "3"
+    SEMI ";"
+"#);
+}
+
+#[test]
+fn precedence() {
+    match_ast(&ast("1 * 2 + 3 * 4 * 5;"), r#"
+FILE
+  EXPR_STMT
+    SUM_EXPR
+      PRODUCT_EXPR
+        LITERAL
+          NUMBER "1"
+        STAR "*"
+        LITERAL
+          NUMBER "2"
+      PLUS "+"
+      PRODUCT_EXPR
+        PRODUCT_EXPR
+          LITERAL
+            NUMBER "3"
+          STAR "*"
+          LITERAL
+            NUMBER "4"
+        STAR "*"
+        LITERAL
+          NUMBER "5"
+    SEMI ";"
+"#);
+    match_ast(&ast("1 - (2 - 3);"), r#"
+FILE
+  EXPR_STMT
+    SUM_EXPR
+      LITERAL
+        NUMBER "1"
+      MINUS "-"
+      PAREN_EXPR
+        LPAREN "("
+        SUM_EXPR
+          LITERAL
+            NUMBER "2"
+          MINUS "-"
+          LITERAL
+            NUMBER "3"
+        RPAREN ")"
+    SEMI ";"
+"#);
+}
+
+#[test]
+fn direct_recursion() {
+    match_ast(&ast("a::b::c;"), r#"
+FILE
+  EXPR_STMT
+    PATH_EXPR
+      PATH
+        PATH
+          PATH
+            IDENT "a"
+          COLONCOLON "::"
+          IDENT "b"
+        COLONCOLON "::"
+        IDENT "c"
+    SEMI ";"
+"#);
+}
+
+#[test]
+fn missing_operand() {
+    match_ast(&ast("1 + ; 2;"), r#"
+FILE
+  EXPR_STMT
+    SUM_EXPR
+      LITERAL
+        NUMBER "1"
+      PLUS "+"
+      ERROR ""
+    SEMI ";"
+  EXPR_STMT
+    LITERAL
+      NUMBER "2"
+    SEMI ";"
+"#);
+}
+
+#[test]
+fn incremental_reparse() {
+    let mut text: TextBuf = "1 + (2 * 3) - a::b;\n(4 - 5) * 6;".into();
+    let mut file = leftrec::language().parse(text.to_string());
+
+    let edits: Vec<fn(&mut TextEditBuilder)> = vec![
+        |b| b.insert(tu(9), " * 7".to_string()),
+        |b| b.replace(TextRange::from_len(tu(2), tu(1)), "*".to_string()),
+        |b| b.insert(tu(0), "0 - ".to_string()),
+        |b| b.delete(TextRange::from_len(tu(8), tu(1))),
+        |b| b.insert(tu(27), "::c".to_string()),
+    ];
+    for edit in edits {
+        let edit = {
+            let mut b = TextEditBuilder::new(text.as_text());
+            edit(&mut b);
+            b.build()
+        };
+        text = edit.apply(text.as_text());
+        file = file.edit(&edit);
+        let fresh_file = leftrec::language().parse(text.to_string());
+        report_diff(&dump_file_ws(&fresh_file), &dump_file_ws(&file));
+    }
+}
+
+#[test]
+fn long_chain() {
+    let n = 1_000;
+    let file = leftrec::language().parse(format!("{}1;", "1 + ".repeat(n)));
+    assert!(file.errors().is_empty());
+    assert_eq!(dump_file(&file).matches("SUM_EXPR").count(), n);
+}
@@ -0,0 +1,67 @@
+extern crate fall_test;
+extern crate fall_tree;
+
+use fall_test::{limits, match_ast};
+use fall_tree::dump_file;
+
+
+fn ast(code: &str) -> String {
+    dump_file(&limits::language().parse(code.to_owned()))
+}
+
+#[test]
+fn many_contexts() {
+    match_ast(&ast("!a"), r#"
+FILE
+  DEEP
+    BANG "!"
+    IDENT "a"
+"#);
+}
+
+#[test]
+fn many_parameters() {
+    match_ast(&ast("[a b c d e f g h i j k l m n o p]"), r#"
+FILE
+  ROW
+    LBRACK "["
+    IDENT "a"
+    IDENT "b"
+    IDENT "c"
+    IDENT "d"
+    IDENT "e"
+    IDENT "f"
+    IDENT "g"
+    IDENT "h"
+    IDENT "i"
+    IDENT "j"
+    IDENT "k"
+    IDENT "l"
+    IDENT "m"
+    IDENT "n"
+    IDENT "o"
+    IDENT "p"
+    RBRACK "]"
+"#);
+}
+
+#[test]
+fn arguments_are_parsed_in_the_caller_frame() {
+    match_ast(&ast("((a b c d)) (a b)"), r#"
+FILE
+  GROUP
+    LPAREN "("
+    LPAREN "("
+    IDENT "a"
+    IDENT "b"
+    IDENT "c"
+    IDENT "d"
+    RPAREN ")"
+    RPAREN ")"
+  GROUP
+    LPAREN "("
+    IDENT "a"
+    IDENT "b"
+    RPAREN ")"
+"#);
+}
@@ -0,0 +1,61 @@
+extern crate fall_test;
+extern crate fall_tree;
+
+use fall_test::{missing, match_ast};
+use fall_tree::{dump_file, tu, TextRange};
+
+
+fn ast(code: &str) -> String {
+    dump_file(&missing::language().parse(code.to_owned()))
+}
+
+#[test]
+fn complete_statement() {
+    match_ast(&ast("let x = y;"), r#"
+FILE
+  LET_STMT
+    LET "let"
+    IDENT "x"
+    EQ "="
+    IDENT "y"
+    SEMI ";"
+"#);
+}
+
+#[test]
+fn missing_tail() {
+    match_ast(&ast("let x let z = y;"), r#"
+FILE
+  LET_STMT
+    LET "let"
+    IDENT "x"
+    MISSING EQ
+    MISSING IDENT
+    MISSING SEMI
+  LET_STMT
+    LET "let"
+    IDENT "z"
+    EQ "="
+    IDENT "y"
+    SEMI ";"
+"#);
+}
+
+#[test]
+fn missing_in_the_middle() {
+    match_ast(&ast("let x y;"), r#"
+FILE
+  LET_STMT
+    LET "let"
+    IDENT "x"
+    MISSING EQ
+    IDENT "y"
+    SEMI ";"
+"#);
+}
+
+#[test]
+fn missing_tokens_are_errors() {
+    let file = missing::language().parse("let x y;".to_owned());
+    assert_eq!(file.errors(), vec![(TextRange::from_len(tu(5), tu(0)), "EQ expected".to_owned())]);
+}
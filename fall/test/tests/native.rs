@@ -0,0 +1,148 @@
+extern crate fall_test;
+extern crate fall_tree;
+extern crate lang_json;
+
+use std::time::Instant;
+
+use fall_test::{arith, arith_native, json_native, leftrec, leftrec_native, limits, limits_native,
+                typedef, typedef_native, weird, weird_native};
+use fall_tree::{dump_file_ws, File, Language, TextEditBuilder, TextRange, tu};
+
+
+fn assert_same(interpreted: &File, native: &File) {
+    assert_eq!(dump_file_ws(interpreted), dump_file_ws(native));
+    assert_eq!(interpreted.errors(), native.errors());
+    assert_eq!(interpreted.expected(), native.expected());
+    assert_eq!(
+        interpreted.metrics().get("parsing ticks"),
+        native.metrics().get("parsing ticks"),
+    );
+}
+
+/// Parses each text with both parsers and checks
+/// that they produce exactly the same files.
+fn check(interpreted: &Language, native: &Language, texts: &[&str]) {
+    for &text in texts {
+        let (a, b) = (interpreted.parse(text.to_owned()), native.parse(text.to_owned()));
+        assert_same(&a, &b);
+    }
+}
+
+/// Applies the same edits to the files of both parsers,
+/// so that the compiled parser is checked on reparsing as well.
+fn check_edits(interpreted: &Language, native: &Language, text: &str, edits: &[(u32, u32, &str)]) {
+    let mut a = interpreted.parse(text.to_owned());
+    let mut b = native.parse(text.to_owned());
+    for &(offset, len, insert) in edits {
+        let edit = {
+            let mut builder = TextEditBuilder::new(a.text());
+            builder.replace(TextRange::from_len(tu(offset), tu(len)), insert.to_owned());
+            builder.build()
+        };
+        a = a.edit(&edit);
+        b = b.edit(&edit);
+        assert_same(&a, &b);
+    }
+}
+
+#[test]
+fn arith_is_the_same() {
+    check(arith::language(), arith_native::language(), &[
+        "1 + 2 * 3 + 4",
+        "1 + --1! - -2!",
+        "2 ^ 3 ^ 4 == 1 == 2",
+        "1 + 2 ? 3 : 4 ? 5 : 6",
+        "1[2 + 3] as int * 4",
+        "1 + if 2 then 3 else 4 + 5",
+        "1 ? 2 + * 3 ] as",
+    ]);
+}
+
+#[test]
+fn weird_is_the_same() {
+    check(weird::language(), weird_native::language(), &[
+        r###"_1 r##"f#"o"#o"## "###,
+        "_2 hello ",
+        "_3 foo bar",
+        "_3 foo foo",
+        "_4 { foo { {foo} foo {bar}} bar }",
+        "_4 { foo { {} bar",
+    ]);
+}
+
+#[test]
+fn limits_is_the_same() {
+    check(limits::language(), limits_native::language(), &[
+        "!a ((a a a a)) [a a a a a a a a a a a a a a a a]",
+        "((a b c d)) (a b) [a b c",
+        &"(".repeat(200),
+    ]);
+}
+
+#[test]
+fn leftrec_is_the_same() {
+    check(leftrec::language(), leftrec_native::language(), &[
+        "1 + 2 * 3 - a::b::c;\n(1 - 2) - 3 * 4 * 5;",
+        "1 - (2 - 3); a::b::;",
+        "1 + ; 2;",
+    ]);
+    check_edits(leftrec::language(), leftrec_native::language(), "1 + 2;\na::b;\n3 * 4;", &[
+        (2, 1, "-"),
+        (8, 1, "c"),
+        (4, 0, " + 5"),
+        (0, 6, ""),
+    ]);
+}
+
+#[test]
+fn typedef_is_the_same() {
+    check(typedef::language(), typedef_native::language(), &[
+        "typedef int T; T * x; a * b;",
+        "typedef int T; (T) a; (a) * b;",
+        "T * x; typedef int T; T * x;",
+        "typedef T; * ;",
+    ]);
+}
+
+#[test]
+fn json_is_the_same() {
+    check(lang_json::lang_json(), json_native::language(), &[
+        r#"{"a": [1, {"b": null}, true, "c"], "d": {}}"#,
+        r#"{"a": [1, , {"b" 1}], 92 "d": {"#,
+    ]);
+    check_edits(lang_json::lang_json(), json_native::language(), r#"{"a": [1, 2], "b": {"c": 3}}"#, &[
+        (7, 1, "[10, 11]"),
+        (20, 0, "\"e\": 4, "),
+        (1, 3, ""),
+    ]);
+}
+
+#[test]
+fn performance_test() {
+    if !::std::env::var("slow_tests").is_ok() {
+        return;
+    }
+    fn measure(name: &str, interpreted: &Language, native: &Language, text: &str) {
+        let start = Instant::now();
+        let a = interpreted.parse(text.to_owned());
+        let interpreted_time = start.elapsed();
+        let start = Instant::now();
+        let b = native.parse(text.to_owned());
+        let native_time = start.elapsed();
+        assert_same(&a, &b);
+        println!("{}: interpreted {:?}, native {:?}", name, interpreted_time, native_time);
+    }
+
+    // A balanced expression, so that the tree is not too deep to dump.
+    let mut arith_text = "1 + 2 * -3 ^ 4 ? 5 : 6 - 7!".to_string();
+    for _ in 0..13 {
+        arith_text = format!("({}) * ({})", arith_text, arith_text);
+    }
+    measure("arith", arith::language(), arith_native::language(), &arith_text);
+
+    let json_text = format!(
+        "{{\"items\": [{}]}}",
+        vec![r#"{"a": [1, 2.5, true, null], "b": {"c": "d"}}"#; 20_000].join(", "),
+    );
+    measure("json", lang_json::lang_json(), json_native::language(), &json_text);
+}
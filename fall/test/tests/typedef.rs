@@ -0,0 +1,159 @@
+extern crate fall_test;
+extern crate fall_tree;
+
+use fall_test::{typedef, match_ast};
+use fall_tree::{dump_file, dump_file_ws, TextBuf, TextEditBuilder, TextRange, tu};
+use fall_tree::test_util::report_diff;
+
+
+fn ast(code: &str) -> String {
+    dump_file(&typedef::language().parse(code.to_owned()))
+}
+
+#[test]
+fn declaration_or_product() {
+    match_ast(&ast("typedef int T; T * x; a * b;"), r#"
+FILE
+  TYPEDEF_DEF
+    TYPEDEF "typedef"
+    TYPE_REF
+      IDENT "int"
+    TYPE_NAME
+      IDENT "T"
+    SEMI ";"
+  VAR_DECL
+    TYPE_REF
+      IDENT "T"
+    STAR "*"
+    IDENT "x"
+    SEMI ";"
+  EXPR_STMT
+    PRODUCT_EXPR
+      NAME_REF
+        IDENT "a"
+      STAR "*"
+      NAME_REF
+        IDENT "b"
+    SEMI ";"
+"#);
+}
+
+#[test]
+fn cast_or_parens() {
+    match_ast(&ast("typedef int T; (T) a; (a) * b;"), r#"
+FILE
+  TYPEDEF_DEF
+    TYPEDEF "typedef"
+    TYPE_REF
+      IDENT "int"
+    TYPE_NAME
+      IDENT "T"
+    SEMI ";"
+  EXPR_STMT
+    CAST_EXPR
+      LPAREN "("
+      TYPE_REF
+        IDENT "T"
+      RPAREN ")"
+      NAME_REF
+        IDENT "a"
+    SEMI ";"
+  EXPR_STMT
+    PRODUCT_EXPR
+      PAREN_EXPR
+        LPAREN "("
+        NAME_REF
+          IDENT "a"
+        RPAREN ")"
+      STAR "*"
+      NAME_REF
+        IDENT "b"
+    SEMI ";"
+"#);
+}
+
+#[test]
+fn typedef_must_come_first() {
+    match_ast(&ast("T * x; typedef int T; T * x;"), r#"
+FILE
+  EXPR_STMT
+    PRODUCT_EXPR
+      NAME_REF
+        IDENT "T"
+      STAR "*"
+      NAME_REF
+        IDENT "x"
+    SEMI ";"
+  TYPEDEF_DEF
+    TYPEDEF "typedef"
+    TYPE_REF
+      IDENT "int"
+    TYPE_NAME
+      IDENT "T"
+    SEMI ";"
+  VAR_DECL
+    TYPE_REF
+      IDENT "T"
+    STAR "*"
+    IDENT "x"
+    SEMI ";"
+"#);
+}
+
+#[test]
+fn typedefs_accumulate() {
+    match_ast(&ast("typedef int A; A * a; typedef A B; (B) a; B * b;"), r#"
+FILE
+  TYPEDEF_DEF
+    TYPEDEF "typedef"
+    TYPE_REF
+      IDENT "int"
+    TYPE_NAME
+      IDENT "A"
+    SEMI ";"
+  VAR_DECL
+    TYPE_REF
+      IDENT "A"
+    STAR "*"
+    IDENT "a"
+    SEMI ";"
+  TYPEDEF_DEF
+    TYPEDEF "typedef"
+    TYPE_REF
+      IDENT "A"
+    TYPE_NAME
+      IDENT "B"
+    SEMI ";"
+  EXPR_STMT
+    CAST_EXPR
+      LPAREN "("
+      TYPE_REF
+        IDENT "B"
+      RPAREN ")"
+      NAME_REF
+        IDENT "a"
+    SEMI ";"
+  VAR_DECL
+    TYPE_REF
+      IDENT "B"
+    STAR "*"
+    IDENT "b"
+    SEMI ";"
+"#);
+}
+
+#[test]
+fn reparse_after_renaming_a_typedef() {
+    let text: TextBuf = "typedef int T;\nT * x;".into();
+    let file = typedef::language().parse(text.to_string());
+    let edit = {
+        let mut b = TextEditBuilder::new(text.as_text());
+        b.replace(TextRange::from_len(tu(12), tu(1)), "U".to_string());
+        b.build()
+    };
+    let text = edit.apply(text.as_text());
+    let file = file.edit(&edit);
+    let fresh_file = typedef::language().parse(text.to_string());
+    assert!(dump_file(&fresh_file).contains("EXPR_STMT"));
+    report_diff(&dump_file_ws(&fresh_file), &dump_file_ws(&file));
+}
@@ -0,0 +1,272 @@
+use std::fmt;
+use std::ops::Range;
+
+use crate::{Text, TextBuf, TextEdit, TextEditOp, TextRange, TextUnit, tu};
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Encoding {
+    Utf8,
+    /// Used for input which is not valid UTF-8: each byte is a char.
+    Latin1,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LineEnding {
+    Lf,
+    CrLf,
+}
+
+/// Text decoded from raw bytes and normalized for parsing: the BOM is
+/// stripped, `\r\n` becomes `\n` and the text is converted to UTF-8.
+///
+/// Keeps enough information to map offsets in the normalized text
+/// back to byte offsets in the original input, and to translate
+/// edits of the normalized text into edits of the original bytes.
+pub struct DecodedText {
+    text: TextBuf,
+    encoding: Encoding,
+    has_bom: bool,
+    line_ending: LineEnding,
+    /// Places where the normalized text differs from the original one,
+    /// sorted by position. Text between them differs only by a shift.
+    replacements: Vec<(TextRange, Range<usize>)>,
+}
+
+/// Replacement of a range of the original bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ByteEdit {
+    pub delete: Range<usize>,
+    pub insert: Vec<u8>,
+}
+
+/// A character which can't be represented in the original encoding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EncodeError(pub char);
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "character {:?} can't be encoded as Latin-1", self.0)
+    }
+}
+
+impl ::std::error::Error for EncodeError {}
+
+impl DecodedText {
+    pub fn decode(bytes: &[u8]) -> DecodedText {
+        let mut replacements = Vec::new();
+        let has_bom = bytes.starts_with(UTF8_BOM);
+        let body = if has_bom {
+            replacements.push((TextRange::empty(), 0..UTF8_BOM.len()));
+            &bytes[UTF8_BOM.len()..]
+        } else {
+            bytes
+        };
+        let (encoding, decoded) = match ::std::str::from_utf8(body) {
+            Ok(s) => (Encoding::Utf8, ::std::borrow::Cow::Borrowed(s)),
+            Err(_) => (Encoding::Latin1, ::std::borrow::Cow::Owned(body.iter().map(|&b| b as char).collect::<String>())),
+        };
+
+        let base = bytes.len() - body.len();
+        let mut text = String::with_capacity(decoded.len());
+        let mut original = base;
+        let (mut crlf, mut lf) = (0, 0);
+        let mut chars = decoded.chars().peekable();
+        while let Some(c) = chars.next() {
+            let offset = tu(text.len() as u32);
+            let original_len = match encoding {
+                Encoding::Utf8 => c.len_utf8(),
+                Encoding::Latin1 => 1,
+            };
+            if c == '\r' && chars.peek() == Some(&'\n') {
+                chars.next();
+                crlf += 1;
+                replacements.push((TextRange::from_len(offset, tu(1)), original..original + 2));
+                text.push('\n');
+                original += 2;
+                continue;
+            }
+            if c == '\n' {
+                lf += 1;
+            }
+            if original_len != c.len_utf8() {
+                replacements.push((
+                    TextRange::from_len(offset, tu(c.len_utf8() as u32)),
+                    original..original + original_len,
+                ));
+            }
+            text.push(c);
+            original += original_len;
+        }
+
+        DecodedText {
+            text: TextBuf::from(text),
+            encoding,
+            has_bom,
+            line_ending: if crlf > lf { LineEnding::CrLf } else { LineEnding::Lf },
+            replacements,
+        }
+    }
+
+    pub fn text(&self) -> Text {
+        self.text.as_text()
+    }
+
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    pub fn has_bom(&self) -> bool {
+        self.has_bom
+    }
+
+    /// The dominant line ending of the original text.
+    pub fn line_ending(&self) -> LineEnding {
+        self.line_ending
+    }
+
+    /// Byte offset in the original input. Offsets inside a normalized
+    /// sequence (like the middle of a `\r\n`) map to its start.
+    pub fn original_offset(&self, offset: TextUnit) -> usize {
+        let idx = self.replacements.partition_point(|&(ref norm, _)| norm.start() <= offset);
+        match idx.checked_sub(1).map(|i| &self.replacements[i]) {
+            None => offset.utf8_len(),
+            Some(&(norm, ref orig)) if offset < norm.end() => orig.start,
+            Some(&(norm, ref orig)) => orig.end + (offset - norm.end()).utf8_len(),
+        }
+    }
+
+    pub fn original_range(&self, range: TextRange) -> Range<usize> {
+        self.original_offset(range.start())..self.original_offset(range.end())
+    }
+
+    /// Offset in the normalized text for a byte offset in the original input.
+    pub fn normalized_offset(&self, offset: usize) -> TextUnit {
+        let idx = self.replacements.partition_point(|&(_, ref orig)| orig.start <= offset);
+        match idx.checked_sub(1).map(|i| &self.replacements[i]) {
+            None => tu(offset as u32),
+            Some(&(norm, ref orig)) if offset < orig.end => norm.start(),
+            Some(&(norm, ref orig)) => norm.end() + tu((offset - orig.end) as u32),
+        }
+    }
+
+    /// Encodes a fragment of normalized text using the encoding and
+    /// the line ending of the original input.
+    pub fn encode(&self, text: &str) -> Result<Vec<u8>, EncodeError> {
+        let mut result = Vec::with_capacity(text.len());
+        for c in text.chars() {
+            if c == '\n' && self.line_ending == LineEnding::CrLf {
+                result.push(b'\r');
+            }
+            match self.encoding {
+                Encoding::Utf8 => {
+                    let mut buf = [0; 4];
+                    result.extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+                }
+                Encoding::Latin1 => {
+                    if (c as u32) > 0xFF {
+                        return Err(EncodeError(c));
+                    }
+                    result.push(c as u8);
+                }
+            }
+        }
+        Ok(result)
+    }
+
+    /// Translates an edit of the normalized text into edits of the original
+    /// bytes. Bytes outside of the changed ranges are left untouched,
+    /// so mixed line endings elsewhere in the file are preserved.
+    pub fn original_edits(&self, edit: &TextEdit) -> Result<Vec<ByteEdit>, EncodeError> {
+        let mut result: Vec<ByteEdit> = Vec::new();
+        let mut offset = tu(0);
+        let mut push = |range: TextRange, text: &str| -> Result<(), EncodeError> {
+            let delete = self.original_range(range);
+            let insert = self.encode(text)?;
+            match result.last_mut() {
+                Some(last) if last.delete.end == delete.start => {
+                    last.delete.end = delete.end;
+                    last.insert.extend(insert);
+                }
+                _ => result.push(ByteEdit { delete, insert }),
+            }
+            Ok(())
+        };
+        for op in edit.ops.iter() {
+            match *op {
+                TextEditOp::Copy(range) => {
+                    if offset < range.start() {
+                        push(TextRange::from_to(offset, range.start()), "")?;
+                    }
+                    offset = range.end();
+                }
+                TextEditOp::Insert(ref buf) => {
+                    push(TextRange::from_len(offset, tu(0)), &buf.as_text().to_cow())?;
+                }
+            }
+        }
+        let len = self.text.as_text().len();
+        if offset < len {
+            push(TextRange::from_to(offset, len), "")?;
+        }
+        Ok(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TextEditBuilder;
+
+    fn apply(bytes: &[u8], edits: &[ByteEdit]) -> Vec<u8> {
+        let mut result = bytes.to_vec();
+        for e in edits.iter().rev() {
+            result.splice(e.delete.clone(), e.insert.iter().cloned());
+        }
+        result
+    }
+
+    #[test]
+    fn test_crlf_and_bom() {
+        let bytes = b"\xEF\xBB\xBFfoo\r\nbar\nbaz\r\n";
+        let decoded = DecodedText::decode(bytes);
+        assert_eq!(decoded.text(), "foo\nbar\nbaz\n");
+        assert!(decoded.has_bom());
+        assert_eq!(decoded.encoding(), Encoding::Utf8);
+        assert_eq!(decoded.line_ending(), LineEnding::CrLf);
+
+        assert_eq!(decoded.original_offset(tu(0)), 3);
+        assert_eq!(decoded.original_offset(tu(3)), 6);
+        assert_eq!(decoded.original_offset(tu(4)), 8);
+        assert_eq!(decoded.original_offset(tu(12)), 17);
+        assert_eq!(decoded.normalized_offset(0), tu(0));
+        assert_eq!(decoded.normalized_offset(7), tu(3));
+        assert_eq!(decoded.normalized_offset(8), tu(4));
+        assert_eq!(decoded.normalized_offset(13), tu(9));
+
+        let edit = {
+            let mut e = TextEditBuilder::new(decoded.text());
+            e.replace(TextRange::from_to(tu(2), tu(5)), "o\nquux\nb");
+            e.delete(TextRange::from_len(tu(8), tu(4)));
+            e.build()
+        };
+        let new_text = edit.apply(decoded.text());
+        let edits = decoded.original_edits(&edit).unwrap();
+        let new_bytes = apply(bytes, &edits);
+        assert_eq!(new_bytes, b"\xEF\xBB\xBFfoo\r\nquux\r\nbar\n".to_vec());
+        assert_eq!(DecodedText::decode(&new_bytes).text(), new_text.as_text());
+    }
+
+    #[test]
+    fn test_latin1() {
+        let bytes = b"caf\xE9 = 1\n";
+        let decoded = DecodedText::decode(bytes);
+        assert_eq!(decoded.encoding(), Encoding::Latin1);
+        assert_eq!(decoded.text(), "café = 1\n");
+        assert_eq!(decoded.original_offset(tu(6)), 5);
+        assert_eq!(decoded.normalized_offset(5), tu(6));
+        assert_eq!(decoded.encode("é\n").unwrap(), b"\xE9\n".to_vec());
+        assert_eq!(decoded.encode("ы"), Err(EncodeError('ы')));
+    }
+}
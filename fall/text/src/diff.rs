@@ -0,0 +1,168 @@
+use crate::{TextEdit, TextEditBuilder, TextBuf, TextRange, TextUnit, tu};
+
+/// Diffs with more than this many changed lines are not searched
+/// for a minimal edit and become a single replacement.
+const MAX_CHANGED_LINES: usize = 1024;
+
+pub(crate) fn diff(old: &str, new: &str) -> TextEdit {
+    let mut builder = TextEditBuilder::new(TextBuf::from(old).as_text());
+
+    let prefix = common_prefix(old, new);
+    let suffix = common_suffix(&old[prefix..], &new[prefix..]);
+    let old_mid = &old[prefix..old.len() - suffix];
+    let new_mid = &new[prefix..new.len() - suffix];
+
+    let old_lines: Vec<&str> = old_mid.split_inclusive('\n').collect();
+    let new_lines: Vec<&str> = new_mid.split_inclusive('\n').collect();
+    let old_offsets = line_offsets(prefix, &old_lines);
+    let new_offsets = line_offsets(prefix, &new_lines);
+
+    let mut hunk = |old_range: (usize, usize), new_range: (usize, usize)| {
+        let old_start = old_offsets[old_range.0];
+        let old_end = old_offsets[old_range.1];
+        let new_start = new_offsets[new_range.0];
+        let new_end = new_offsets[new_range.1];
+        replace_trimmed(&mut builder, old_start, &old[old_start..old_end], &new[new_start..new_end]);
+    };
+
+    let (mut i, mut j) = (0, 0);
+    for (old_line, new_line) in matching_lines(&old_lines, &new_lines) {
+        if i < old_line || j < new_line {
+            hunk((i, old_line), (j, new_line));
+        }
+        i = old_line + 1;
+        j = new_line + 1;
+    }
+    if i < old_lines.len() || j < new_lines.len() {
+        hunk((i, old_lines.len()), (j, new_lines.len()));
+    }
+
+    builder.build()
+}
+
+fn line_offsets(start: usize, lines: &[&str]) -> Vec<usize> {
+    let mut result = Vec::with_capacity(lines.len() + 1);
+    let mut offset = start;
+    result.push(offset);
+    for line in lines {
+        offset += line.len();
+        result.push(offset);
+    }
+    result
+}
+
+fn replace_trimmed(builder: &mut TextEditBuilder, offset: usize, old: &str, new: &str) {
+    let prefix = common_prefix(old, new);
+    let suffix = common_suffix(&old[prefix..], &new[prefix..]);
+    let range = TextRange::from_to(
+        unit(offset + prefix),
+        unit(offset + old.len() - suffix),
+    );
+    builder.replace(range, &new[prefix..new.len() - suffix]);
+}
+
+fn common_prefix(a: &str, b: &str) -> usize {
+    let mut len = a.bytes().zip(b.bytes()).take_while(|&(x, y)| x == y).count();
+    while !a.is_char_boundary(len) {
+        len -= 1;
+    }
+    len
+}
+
+fn common_suffix(a: &str, b: &str) -> usize {
+    let mut len = a.bytes().rev().zip(b.bytes().rev()).take_while(|&(x, y)| x == y).count();
+    while !a.is_char_boundary(a.len() - len) {
+        len -= 1;
+    }
+    len
+}
+
+fn unit(offset: usize) -> TextUnit {
+    tu(offset as u32)
+}
+
+/// Pairs of indices of equal lines in the longest common subsequence,
+/// computed with Myers' O(ND) algorithm.
+fn matching_lines(a: &[&str], b: &[&str]) -> Vec<(usize, usize)> {
+    let (n, m) = (a.len() as isize, b.len() as isize);
+    let max_d = ::std::cmp::min(n + m, MAX_CHANGED_LINES as isize);
+    let offset = max_d + 1;
+    let mut v = vec![0isize; 2 * max_d as usize + 3];
+    let mut trace: Vec<Vec<isize>> = Vec::new();
+
+    for d in 0..max_d + 1 {
+        trace.push(v.clone());
+        for k in (-d..d + 1).step_by(2) {
+            let idx = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) {
+                v[idx + 1]
+            } else {
+                v[idx - 1] + 1
+            };
+            let mut y = x - k;
+            while x < n && y < m && a[x as usize] == b[y as usize] {
+                x += 1;
+                y += 1;
+            }
+            v[idx] = x;
+            if x >= n && y >= m {
+                return backtrack(&trace, offset, n, m);
+            }
+        }
+    }
+    Vec::new()
+}
+
+fn backtrack(trace: &[Vec<isize>], offset: isize, n: isize, m: isize) -> Vec<(usize, usize)> {
+    let mut result = Vec::new();
+    let (mut x, mut y) = (n, m);
+    for (d, v) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        let k = x - y;
+        let idx = (k + offset) as usize;
+        let prev_k = if k == -d || (k != d && v[idx - 1] < v[idx + 1]) { k + 1 } else { k - 1 };
+        let prev_x = v[(prev_k + offset) as usize];
+        let prev_y = prev_x - prev_k;
+        while x > prev_x && y > prev_y {
+            x -= 1;
+            y -= 1;
+            result.push((x as usize, y as usize));
+        }
+        if d > 0 {
+            x = prev_x;
+            y = prev_y;
+        }
+    }
+    result.reverse();
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TextEditOp;
+
+    fn check(old: &str, new: &str, expected_inserts: &[&str]) {
+        let edit = diff(old, new);
+        assert_eq!(edit.apply(TextBuf::from(old).as_text()), new);
+        let inserts: Vec<String> = edit.ops.iter()
+            .filter_map(|op| match *op {
+                TextEditOp::Insert(ref buf) => Some(buf.to_string()),
+                TextEditOp::Copy(_) => None,
+            })
+            .collect();
+        assert_eq!(inserts, expected_inserts);
+    }
+
+    #[test]
+    fn test_diff() {
+        check("", "", &[]);
+        check("foo", "foo", &[]);
+        check("", "foo", &["foo"]);
+        check("foo", "", &[]);
+        check("rule foo { x|y }", "rule foo { x | y }", &[" | "]);
+        check("a\nb\nc\nd\n", "a\nc\nd\ne\n", &["\ne"]);
+        check("a\nbar\nc\nbaz\n", "a\nbor\nc\nbuz\n", &["o", "u"]);
+        check("жук\n", "жёлудь\n", &["ёлудь"]);
+    }
+}
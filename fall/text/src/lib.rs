@@ -1,4 +1,6 @@
 extern crate serde;
+#[macro_use]
+extern crate serde_derive;
 extern crate rand;
 extern crate itertools;
 extern crate proptest;
@@ -9,12 +11,18 @@ mod text_buf;
 mod text;
 mod text_edit;
 mod text_slice;
+mod rope;
+mod diff;
+mod line_index;
+mod decode;
 pub mod prop;
 
 pub use crate::text_unit::{TextUnit, tu};
 pub use crate::text_range::TextRange;
 pub use crate::text_buf::TextBuf;
 pub use crate::text::Text;
-pub use crate::text_edit::{TextEdit, TextEditOp, TextEditBuilder};
+pub use crate::text_edit::{TextEdit, TextEditOp, TextEditBuilder, Bias, EditError};
 pub use crate::text_slice::TextSuffix;
+pub use crate::line_index::{LineIndex, LineCol};
+pub use crate::decode::{DecodedText, Encoding, LineEnding, ByteEdit, EncodeError};
 
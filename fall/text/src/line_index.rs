@@ -0,0 +1,228 @@
+use crate::{Text, TextEdit, TextEditOp, TextRange, TextUnit, tu};
+
+/// Maps offsets to line/column pairs and back.
+///
+/// Columns are available both in UTF-8 bytes, which is what `TextUnit`
+/// counts, and in UTF-16 code units, which is what LSP and VS Code use.
+#[derive(Clone, Debug)]
+pub struct LineIndex {
+    /// Offsets of the starts of all lines, the first one is always zero.
+    line_starts: Vec<TextUnit>,
+    /// Non-ASCII characters, the only ones whose UTF-8 and UTF-16
+    /// lengths differ.
+    wide_chars: Vec<WideChar>,
+    len: TextUnit,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct LineCol {
+    pub line: u32,
+    pub col: u32,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct WideChar {
+    start: TextUnit,
+    utf8_len: u8,
+}
+
+impl WideChar {
+    fn end(&self) -> TextUnit {
+        self.start + self.utf8_len as u32
+    }
+
+    /// How many more UTF-8 bytes than UTF-16 code units this char takes.
+    fn excess(&self) -> u32 {
+        let utf16_len = if self.utf8_len == 4 { 2 } else { 1 };
+        self.utf8_len as u32 - utf16_len
+    }
+}
+
+impl LineIndex {
+    pub fn new(text: Text) -> LineIndex {
+        let mut index = LineIndex {
+            line_starts: vec![tu(0)],
+            wide_chars: Vec::new(),
+            len: tu(0),
+        };
+        for chunk in text.chunks() {
+            index.push_str(chunk);
+        }
+        index
+    }
+
+    /// Returns the index for the text after `edit`, scanning
+    /// only the inserted fragments.
+    pub fn apply_edit(&self, edit: &TextEdit) -> LineIndex {
+        let mut result = LineIndex {
+            line_starts: vec![tu(0)],
+            wide_chars: Vec::new(),
+            len: tu(0),
+        };
+        for op in edit.ops.iter() {
+            match *op {
+                TextEditOp::Insert(ref buf) => {
+                    for chunk in buf.as_text().chunks() {
+                        result.push_str(chunk);
+                    }
+                }
+                TextEditOp::Copy(range) => {
+                    let base = result.len;
+                    let shift = |offset: TextUnit| offset - range.start() + base;
+                    // A line start equal to `range.start()` is a newline before the range.
+                    let lines = &self.line_starts[
+                        self.line_starts.partition_point(|&s| s <= range.start())
+                            ..self.line_starts.partition_point(|&s| s <= range.end())
+                    ];
+                    result.line_starts.extend(lines.iter().map(|&s| shift(s)));
+                    let wide_chars = &self.wide_chars[
+                        self.wide_chars.partition_point(|c| c.start < range.start())
+                            ..self.wide_chars.partition_point(|c| c.start < range.end())
+                    ];
+                    result.wide_chars.extend(wide_chars.iter().map(|&c| WideChar { start: shift(c.start), ..c }));
+                    result.len += range.len();
+                }
+            }
+        }
+        result
+    }
+
+    pub fn line_col(&self, offset: TextUnit) -> LineCol {
+        let line = self.line_starts.partition_point(|&s| s <= offset) - 1;
+        let col = offset - self.line_starts[line];
+        LineCol { line: line as u32, col: col.into() }
+    }
+
+    pub fn line_col_utf16(&self, offset: TextUnit) -> LineCol {
+        let LineCol { line, col } = self.line_col(offset);
+        let excess: u32 = self.wide_chars_in(TextRange::from_to(offset - col, offset))
+            .iter()
+            .map(WideChar::excess)
+            .sum();
+        LineCol { line, col: col - excess }
+    }
+
+    /// Columns past the end of the line are clamped to the line end,
+    /// lines past the end of the text are clamped to the text end.
+    pub fn offset(&self, line_col: LineCol) -> TextUnit {
+        let line = match self.line_range(line_col.line) {
+            None => return self.len,
+            Some(range) => range,
+        };
+        ::std::cmp::min(line.start() + line_col.col, line.end())
+    }
+
+    pub fn offset_utf16(&self, line_col: LineCol) -> TextUnit {
+        let line = match self.line_range(line_col.line) {
+            None => return self.len,
+            Some(range) => range,
+        };
+        let mut col = line_col.col;
+        for c in self.wide_chars_in(line) {
+            let utf16_col = u32::from(c.start - line.start()) - (col - line_col.col);
+            if utf16_col >= line_col.col {
+                break;
+            }
+            col += c.excess();
+        }
+        ::std::cmp::min(line.start() + col, line.end())
+    }
+
+    /// Range of the line, excluding the trailing newline.
+    fn line_range(&self, line: u32) -> Option<TextRange> {
+        let line = line as usize;
+        let start = *self.line_starts.get(line)?;
+        let end = match self.line_starts.get(line + 1) {
+            Some(&next_start) => next_start - 1,
+            None => self.len,
+        };
+        Some(TextRange::from_to(start, end))
+    }
+
+    fn wide_chars_in(&self, range: TextRange) -> &[WideChar] {
+        let start = self.wide_chars.partition_point(|c| c.start < range.start());
+        let end = self.wide_chars.partition_point(|c| c.end() <= range.end());
+        &self.wide_chars[start..::std::cmp::max(start, end)]
+    }
+
+    fn push_str(&mut self, text: &str) {
+        for (offset, c) in text.char_indices() {
+            let offset = self.len + offset as u32;
+            if c == '\n' {
+                self.line_starts.push(offset + 1);
+            } else if !c.is_ascii() {
+                self.wide_chars.push(WideChar { start: offset, utf8_len: c.len_utf8() as u8 });
+            }
+        }
+        self.len += tu(text.len() as u32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TextBuf, TextEditBuilder};
+
+    #[test]
+    fn test_line_index() {
+        let text = "hello\nworld";
+        let text = TextBuf::from(text);
+        let index = LineIndex::new(text.as_text());
+        assert_eq!(index.line_col(tu(0)), LineCol { line: 0, col: 0});
+        assert_eq!(index.line_col(tu(1)), LineCol { line: 0, col: 1});
+        assert_eq!(index.line_col(tu(5)), LineCol { line: 0, col: 5});
+        assert_eq!(index.line_col(tu(6)), LineCol { line: 1, col: 0});
+        assert_eq!(index.line_col(tu(7)), LineCol { line: 1, col: 1});
+        assert_eq!(index.line_col(tu(8)), LineCol { line: 1, col: 2});
+        assert_eq!(index.line_col(tu(10)), LineCol { line: 1, col: 4});
+        assert_eq!(index.line_col(tu(11)), LineCol { line: 1, col: 5});
+        assert_eq!(index.line_col(tu(12)), LineCol { line: 1, col: 6});
+
+        let text = "\nhello\nworld";
+        let text = TextBuf::from(text);
+        let index = LineIndex::new(text.as_text());
+        assert_eq!(index.line_col(tu(0)), LineCol { line: 0, col: 0});
+        assert_eq!(index.line_col(tu(1)), LineCol { line: 1, col: 0});
+        assert_eq!(index.line_col(tu(2)), LineCol { line: 1, col: 1});
+        assert_eq!(index.line_col(tu(6)), LineCol { line: 1, col: 5});
+        assert_eq!(index.line_col(tu(7)), LineCol { line: 2, col: 0});
+        assert_eq!(index.offset(LineCol { line: 1, col: 2 }), tu(3));
+        assert_eq!(index.offset(LineCol { line: 1, col: 92 }), tu(6));
+        assert_eq!(index.offset(LineCol { line: 92, col: 0 }), tu(12));
+    }
+
+    #[test]
+    fn test_utf16() {
+        let text = TextBuf::from("fn main() {\n    let x = \"ы😀z\";\n}\n");
+        let index = LineIndex::new(text.as_text());
+        // `ы😀` is 6 bytes, but only 3 UTF-16 code units.
+        let z = text.as_text().find("z\"").unwrap();
+        assert_eq!(index.line_col(z), LineCol { line: 1, col: 19 });
+        assert_eq!(index.line_col_utf16(z), LineCol { line: 1, col: 16 });
+        assert_eq!(index.offset_utf16(LineCol { line: 1, col: 16 }), z);
+        assert_eq!(index.offset_utf16(LineCol { line: 1, col: 14 }), z - 4);
+        assert_eq!(index.offset_utf16(LineCol { line: 1, col: 2 }), tu(14));
+        assert_eq!(index.line_col_utf16(tu(35)), LineCol { line: 2, col: 0 });
+    }
+
+    #[test]
+    fn test_apply_edit() {
+        let text = TextBuf::from("ы\nhello\nwörld\n");
+        let index = LineIndex::new(text.as_text());
+        let edit = {
+            let mut e = TextEditBuilder::new(text.as_text());
+            e.replace(TextRange::from_len(tu(4), tu(2)), "ÿ\n\n");
+            e.delete(TextRange::from_len(tu(10), tu(2)));
+            e.build()
+        };
+        let new_text = edit.apply(text.as_text());
+        let incremental = index.apply_edit(&edit);
+        let fresh = LineIndex::new(new_text.as_text());
+        assert_eq!(incremental.line_starts, fresh.line_starts);
+        assert_eq!(incremental.len, fresh.len);
+        let wide = |index: &LineIndex| index.wide_chars.iter()
+            .map(|c| (c.start, c.utf8_len))
+            .collect::<Vec<_>>();
+        assert_eq!(wide(&incremental), wide(&fresh));
+    }
+}
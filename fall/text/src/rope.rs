@@ -0,0 +1,228 @@
+use std::sync::Arc;
+
+use crate::{TextUnit, TextRange, tu};
+
+/// Leaves are split to be no longer than this many bytes.
+const MAX_CHUNK: usize = 1024;
+
+/// A node of a persistent, height-balanced rope.
+///
+/// Nodes are immutable and shared between `TextBuf`s via `Arc`,
+/// so slicing and concatenation only allocate `O(log n)` new nodes.
+pub(crate) struct Node {
+    len: TextUnit,
+    height: u8,
+    kind: Kind,
+}
+
+enum Kind {
+    Leaf(String),
+    Branch(Arc<Node>, Arc<Node>),
+}
+
+impl Node {
+    pub(crate) fn len(&self) -> TextUnit {
+        self.len
+    }
+
+    fn children(&self) -> (&Arc<Node>, &Arc<Node>) {
+        match self.kind {
+            Kind::Branch(ref l, ref r) => (l, r),
+            Kind::Leaf(_) => unreachable!("leaf has no children"),
+        }
+    }
+}
+
+pub(crate) fn from_string(text: String) -> Arc<Node> {
+    if text.len() <= MAX_CHUNK {
+        return leaf(text);
+    }
+    let mut leaves = Vec::new();
+    let mut rest = text.as_str();
+    while rest.len() > MAX_CHUNK {
+        let mut split = MAX_CHUNK;
+        while !rest.is_char_boundary(split) {
+            split -= 1;
+        }
+        leaves.push(leaf(rest[..split].to_owned()));
+        rest = &rest[split..];
+    }
+    leaves.push(leaf(rest.to_owned()));
+    balanced(&leaves)
+}
+
+pub(crate) fn concat(left: Arc<Node>, right: Arc<Node>) -> Arc<Node> {
+    if right.len == tu(0) {
+        return left;
+    }
+    if left.len == tu(0) {
+        return right;
+    }
+    join(left, right)
+}
+
+pub(crate) fn slice(node: &Arc<Node>, range: TextRange) -> Arc<Node> {
+    assert!(range.end() <= node.len);
+    if range.start() == tu(0) && range.end() == node.len {
+        return node.clone();
+    }
+    match node.kind {
+        Kind::Leaf(ref s) => leaf(s[range].to_owned()),
+        Kind::Branch(ref l, ref r) => {
+            let mid = l.len;
+            if range.end() <= mid {
+                slice(l, range)
+            } else if range.start() >= mid {
+                slice(r, TextRange::from_to(range.start() - mid, range.end() - mid))
+            } else {
+                concat(
+                    slice(l, TextRange::from_to(range.start(), mid)),
+                    slice(r, TextRange::from_to(tu(0), range.end() - mid)),
+                )
+            }
+        }
+    }
+}
+
+/// Collects the pieces of leaves covered by `range`, in order.
+pub(crate) fn chunks<'a>(node: &'a Node, range: TextRange, acc: &mut Vec<&'a str>) {
+    if range.is_empty() {
+        return;
+    }
+    match node.kind {
+        Kind::Leaf(ref s) => acc.push(&s[range]),
+        Kind::Branch(ref l, ref r) => {
+            let mid = l.len;
+            if range.start() < mid {
+                let end = if range.end() < mid { range.end() } else { mid };
+                chunks(l, TextRange::from_to(range.start(), end), acc);
+            }
+            if range.end() > mid {
+                let start = if range.start() > mid { range.start() - mid } else { tu(0) };
+                chunks(r, TextRange::from_to(start, range.end() - mid), acc);
+            }
+        }
+    }
+}
+
+pub(crate) fn is_char_boundary(node: &Node, offset: TextUnit) -> bool {
+    match node.kind {
+        Kind::Leaf(ref s) => s.is_char_boundary(offset.0 as usize),
+        Kind::Branch(ref l, ref r) => if offset < l.len {
+            is_char_boundary(l, offset)
+        } else {
+            is_char_boundary(r, offset - l.len)
+        },
+    }
+}
+
+fn leaf(text: String) -> Arc<Node> {
+    Arc::new(Node {
+        len: tu(text.len() as u32),
+        height: 0,
+        kind: Kind::Leaf(text),
+    })
+}
+
+fn branch(left: Arc<Node>, right: Arc<Node>) -> Arc<Node> {
+    Arc::new(Node {
+        len: left.len + right.len,
+        height: 1 + ::std::cmp::max(left.height, right.height),
+        kind: Kind::Branch(left, right),
+    })
+}
+
+fn balanced(nodes: &[Arc<Node>]) -> Arc<Node> {
+    if nodes.len() == 1 {
+        return nodes[0].clone();
+    }
+    let (l, r) = nodes.split_at(nodes.len() / 2);
+    branch(balanced(l), balanced(r))
+}
+
+/// AVL join: descends the spine of the taller tree until heights
+/// match and rebalances on the way back up.
+fn join(left: Arc<Node>, right: Arc<Node>) -> Arc<Node> {
+    if let (&Kind::Leaf(ref l), &Kind::Leaf(ref r)) = (&left.kind, &right.kind) {
+        if l.len() + r.len() <= MAX_CHUNK {
+            return leaf(l.clone() + r);
+        }
+    }
+    if left.height > right.height + 1 {
+        let (ll, lr) = left.children();
+        rebalance(ll.clone(), join(lr.clone(), right))
+    } else if right.height > left.height + 1 {
+        let (rl, rr) = right.children();
+        rebalance(join(left, rl.clone()), rr.clone())
+    } else {
+        branch(left, right)
+    }
+}
+
+fn rebalance(left: Arc<Node>, right: Arc<Node>) -> Arc<Node> {
+    if right.height > left.height + 1 {
+        let (rl, rr) = right.children();
+        if rl.height > rr.height {
+            let (rll, rlr) = rl.children();
+            branch(branch(left, rll.clone()), branch(rlr.clone(), rr.clone()))
+        } else {
+            branch(branch(left, rl.clone()), rr.clone())
+        }
+    } else if left.height > right.height + 1 {
+        let (ll, lr) = left.children();
+        if lr.height > ll.height {
+            let (lrl, lrr) = lr.children();
+            branch(branch(ll.clone(), lrl.clone()), branch(lrr.clone(), right))
+        } else {
+            branch(ll.clone(), branch(lr.clone(), right))
+        }
+    } else {
+        branch(left, right)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_balanced(node: &Node) -> u8 {
+        match node.kind {
+            Kind::Leaf(_) => 0,
+            Kind::Branch(ref l, ref r) => {
+                let (hl, hr) = (check_balanced(l), check_balanced(r));
+                assert!((hl as i32 - hr as i32).abs() <= 1);
+                assert_eq!(node.height, 1 + ::std::cmp::max(hl, hr));
+                assert_eq!(node.len, l.len + r.len);
+                node.height
+            }
+        }
+    }
+
+    fn to_string(node: &Node) -> String {
+        let mut acc = Vec::new();
+        chunks(node, TextRange::from_len(tu(0), node.len), &mut acc);
+        acc.concat()
+    }
+
+    #[test]
+    fn test_slice_and_concat() {
+        let text: String = (0..10_000).map(|i| if i % 7 == 0 { 'ы' } else { 'x' }).collect();
+        let rope = from_string(text.clone());
+        check_balanced(&rope);
+
+        let mut rope = rope;
+        let mut expected = text;
+        for i in 0..100u32 {
+            let offset = tu(i * 97 % rope.len.0);
+            let offset = (0..4).map(|d| offset + d)
+                .find(|&o| expected.is_char_boundary(o.0 as usize))
+                .unwrap();
+            let prefix = slice(&rope, TextRange::from_to(tu(0), offset));
+            let suffix = slice(&rope, TextRange::from_to(offset, rope.len));
+            rope = concat(concat(prefix, from_string("hello".to_owned())), suffix);
+            expected.insert_str(offset.0 as usize, "hello");
+            check_balanced(&rope);
+        }
+        assert_eq!(to_string(&rope), expected);
+    }
+}
@@ -3,12 +3,15 @@ use std::borrow::Cow;
 
 use serde::{Serialize, Serializer};
 
-use crate::{TextRange, TextUnit, TextBuf};
+use std::sync::Arc;
+
+use crate::{TextRange, TextUnit, TextBuf, TextSuffix};
 use crate::text_slice::TextSlice;
+use crate::rope::{self, Node};
 
-#[derive(Clone, Copy, Eq)]
+#[derive(Clone, Copy)]
 pub struct Text<'f> {
-    pub(crate) owned: &'f String,
+    pub(crate) rope: &'f Arc<Node>,
     pub(crate) range: TextRange
 }
 
@@ -25,101 +28,188 @@ impl<'f> Text<'f> {
         let start = self.range.start() + r.start();
         assert!(start <= self.range.end());
         Text {
-            owned: self.owned,
+            rope: self.rope,
             range: TextRange::from_to(start, start + (r.end() - r.start())),
         }
     }
 
+    /// Contiguous pieces of the underlying rope which make up this text.
+    pub fn chunks(&self) -> ::std::vec::IntoIter<&'f str> {
+        let mut acc = Vec::new();
+        rope::chunks(self.rope, self.range, &mut acc);
+        acc.into_iter()
+    }
+
+    pub fn is_char_boundary(&self, offset: TextUnit) -> bool {
+        assert!(offset <= self.len());
+        rope::is_char_boundary(self.rope, self.range.start() + offset)
+    }
+
     pub fn starts_with(&self, prefix: &str) -> bool {
-        self.as_str().starts_with(prefix)
+        let len = TextUnit(prefix.len() as u32);
+        len <= self.len() && self.slice(TextRange::from_len(TextUnit(0), len)) == prefix
     }
 
     pub fn ends_with(&self, suffix: &str) -> bool {
-        self.as_str().ends_with(suffix)
+        let len = TextUnit(suffix.len() as u32);
+        len <= self.len() && self.slice(TextSuffix::from(self.len() - len)) == suffix
     }
 
     pub fn contains(&self, needle: &str) -> bool {
-        self.as_str().contains(needle)
+        self.find(needle).is_some()
     }
 
     pub fn find(&self, needle: &str) -> Option<TextUnit> {
-        self.as_str().find(needle).map(|off| TextUnit(off as u32))
+        if needle.is_empty() {
+            return Some(TextUnit(0));
+        }
+        let mut offset = 0;
+        let mut carry: Vec<u8> = Vec::new();
+        for chunk in self.chunks() {
+            if !carry.is_empty() {
+                let head = &chunk.as_bytes()[..min(needle.len() - 1, chunk.len())];
+                let window = [carry.as_slice(), head].concat();
+                if let Some(pos) = find_bytes(&window, needle.as_bytes()) {
+                    return Some(TextUnit((offset - carry.len() + pos) as u32));
+                }
+            }
+            if let Some(pos) = chunk.find(needle) {
+                return Some(TextUnit((offset + pos) as u32));
+            }
+            carry.extend_from_slice(chunk.as_bytes());
+            let excess = carry.len().saturating_sub(needle.len() - 1);
+            carry.drain(..excess);
+            offset += chunk.len();
+        }
+        None
     }
 
     pub fn rfind(&self, needle: &str) -> Option<TextUnit> {
-        self.as_str().rfind(needle).map(|off| TextUnit(off as u32))
+        if needle.is_empty() {
+            return Some(self.len());
+        }
+        let mut end = self.len().utf8_len();
+        let mut carry: Vec<u8> = Vec::new();
+        for chunk in self.chunks().rev() {
+            let offset = end - chunk.len();
+            if !carry.is_empty() {
+                let tail = &chunk.as_bytes()[chunk.len() - min(needle.len() - 1, chunk.len())..];
+                let window = [tail, carry.as_slice()].concat();
+                if let Some(pos) = rfind_bytes(&window, needle.as_bytes()) {
+                    return Some(TextUnit((end - tail.len() + pos) as u32));
+                }
+            }
+            if let Some(pos) = chunk.rfind(needle) {
+                return Some(TextUnit((offset + pos) as u32));
+            }
+            let keep = min(needle.len() - 1, carry.len() + chunk.len());
+            carry = [chunk.as_bytes(), carry.as_slice()].concat();
+            carry.truncate(keep);
+            end = offset;
+        }
+        None
     }
 
     pub fn trim(&self) -> Text<'f> {
-        fn non_ws(c: char) -> bool {
-            !c.is_whitespace()
+        let left: usize = self.chars()
+            .take_while(|c| c.is_whitespace())
+            .map(char::len_utf8)
+            .sum();
+        let right: usize = self.chars().rev()
+            .take_while(|c| c.is_whitespace())
+            .map(char::len_utf8)
+            .sum();
+        let len = self.len().utf8_len();
+        if left == len {
+            return self.slice(TextRange::from_len(TextUnit(len as u32), TextUnit(0)));
         }
-
-        let left = self.as_str()
-            .find(non_ws)
-            .unwrap_or(self.as_str().len());
-        let s = &self.as_str()[left..];
-        let right = s
-            .rfind(non_ws)
-            .map(|last| last + s[last..].chars().next().unwrap().len_utf8())
-            .unwrap_or(0);
         self.slice(TextRange::from_to(
             TextUnit(left as u32),
-            TextUnit((left + right) as u32)
+            TextUnit((len - right) as u32)
         ))
     }
 
     pub fn to_string(&self) -> String {
-        self.as_str().to_owned()
+        self.chunks().collect()
     }
 
+    /// Borrows the text if it lies within a single chunk of the rope,
+    /// and copies it otherwise.
     pub fn to_cow(&self) -> Cow<'f, str> {
-        Cow::Borrowed(self.as_str())
+        let mut chunks = self.chunks();
+        match chunks.len() {
+            0 => Cow::Borrowed(""),
+            1 => Cow::Borrowed(chunks.next().unwrap()),
+            _ => Cow::Owned(chunks.collect()),
+        }
     }
 
     pub fn to_text_buf(&self) -> TextBuf {
-        TextBuf::from(self.as_str())
+        TextBuf::from_rope(rope::slice(self.rope, self.range))
     }
 
-    pub fn chars(&self) -> ::std::str::Chars {
-        self.as_str().chars()
+    pub fn chars(&self) -> impl DoubleEndedIterator<Item=char> + 'f {
+        self.chunks().flat_map(str::chars)
     }
 
-    fn as_str(&self) -> &'f str {
-        &self.owned[self.range]
+    fn eq_str(&self, other: &str) -> bool {
+        if self.len().utf8_len() != other.len() {
+            return false;
+        }
+        let mut rest = other.as_bytes();
+        for chunk in self.chunks() {
+            let (head, tail) = rest.split_at(chunk.len());
+            if head != chunk.as_bytes() {
+                return false;
+            }
+            rest = tail;
+        }
+        true
     }
 }
 
+fn min(a: usize, b: usize) -> usize {
+    ::std::cmp::min(a, b)
+}
+
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+fn rfind_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).rposition(|w| w == needle)
+}
+
 impl<'f> Serialize for Text<'f> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
         where S: Serializer {
-        self.as_str().serialize(serializer)
+        self.to_cow().serialize(serializer)
     }
 }
 
 impl<'f> fmt::Display for Text<'f> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.as_str().fmt(f)
+        self.to_cow().fmt(f)
     }
 }
 
 impl<'f> fmt::Debug for Text<'f> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        self.as_str().fmt(f)
+        self.to_cow().fmt(f)
     }
 }
 
 
 impl<'a, 'b> PartialEq<Text<'b>> for Text<'a> {
     fn eq(&self, other: &Text<'b>) -> bool {
-        self.as_str() == other.as_str()
+        self.len() == other.len() && self.eq_str(&other.to_cow())
     }
 }
 
 
 impl<'f, 's> PartialEq<&'s str> for Text<'f> {
     fn eq(&self, other: &&str) -> bool {
-        &self.owned[self.range] == *other
+        self.eq_str(other)
     }
 }
 
@@ -130,29 +220,57 @@ impl<'f, 's> PartialEq<Text<'f>> for &'s str {
 }
 
 
+impl<'f> Eq for Text<'f> {}
+
+
 impl<'a, 'b> PartialOrd<Text<'b>> for Text<'a> {
     fn partial_cmp(&self, other: &Text<'b>) -> Option<::std::cmp::Ordering> {
-        self.as_str().partial_cmp(other.as_str())
+        self.to_cow().partial_cmp(&other.to_cow())
     }
 }
 
 
 impl<'f, 's> PartialOrd<&'s str> for Text<'f> {
     fn partial_cmp(&self, other: &&str) -> Option<::std::cmp::Ordering> {
-        self.owned[self.range].partial_cmp(*other)
+        self.to_cow().as_ref().partial_cmp(*other)
     }
 }
 
 
 impl<'a, 'b> Ord for Text<'a> {
     fn cmp(&self, other: &Text<'a>) -> ::std::cmp::Ordering {
-        self.as_str().cmp(other.as_str())
+        self.to_cow().cmp(&other.to_cow())
     }
 }
 
 
 impl<'f> ::std::hash::Hash for Text<'f> {
     fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
-        self.as_str().hash(state)
+        self.to_cow().hash(state)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{TextBuf, TextRange, tu};
+
+    #[test]
+    fn test_multi_chunk_text() {
+        let s: String = (0..3000).map(|i| format!("{} ы ", i)).collect();
+        let buf = TextBuf::from(s.clone());
+        let text = buf.as_text();
+        assert!(text.chunks().len() > 1);
+        assert_eq!(text, s.as_str());
+        assert_eq!(text.to_string(), s);
+        for needle in &["0 ы", "341 ы 342", "2999 ы ", "1 ы 2 ы", "nope"] {
+            assert_eq!(text.find(needle), s.find(needle).map(|o| tu(o as u32)));
+            assert_eq!(text.rfind(needle), s.rfind(needle).map(|o| tu(o as u32)));
+        }
+        let range = TextRange::from_to(tu(1020), tu(2090));
+        let slice = text.slice(range);
+        assert_eq!(slice, &s[range]);
+        assert_eq!(slice.trim(), s[range].trim());
+        assert!(slice.starts_with(&s[TextRange::from_to(tu(1020), tu(1030))]));
+        assert!(slice.ends_with(&s[TextRange::from_to(tu(2000), tu(2090))]));
     }
 }
@@ -1,36 +1,51 @@
-use crate::{Text, TextRange, tu};
 use std::fmt;
+use std::sync::Arc;
+
+use crate::{Text, TextRange, tu};
+use crate::rope::{self, Node};
 
+/// An owned, immutable text, stored as a balanced rope.
+///
+/// Cloning and slicing a `TextBuf` is cheap: the underlying chunks are shared.
 #[derive(Clone)]
 pub struct TextBuf {
-    data: String
+    rope: Arc<Node>
 }
 
 impl From<String> for TextBuf {
     fn from(s: String) -> Self {
-        TextBuf { data: s }
+        TextBuf::from_rope(rope::from_string(s))
     }
 }
 
 impl<'s> From<&'s str> for TextBuf {
     fn from(s: &'s str) -> Self {
-        TextBuf { data: s.to_owned() }
+        TextBuf::from(s.to_owned())
     }
 }
 
 impl<'s> From<Text<'s>> for TextBuf {
     fn from(s: Text<'s>) -> Self {
-        TextBuf { data: s.to_string() }
+        s.to_text_buf()
     }
 }
 
 impl TextBuf {
     pub fn as_text(&self) -> Text {
         Text {
-            owned: &self.data,
-            range: TextRange::from_len(tu(0), tu(self.data.len() as u32)),
+            rope: &self.rope,
+            range: TextRange::from_len(tu(0), self.rope.len()),
         }
     }
+
+    pub(crate) fn from_rope(rope: Arc<Node>) -> TextBuf {
+        TextBuf { rope }
+    }
+
+    pub(crate) fn append(&mut self, other: TextBuf) {
+        let rope = ::std::mem::replace(&mut self.rope, rope::from_string(String::new()));
+        self.rope = rope::concat(rope, other.rope);
+    }
 }
 
 impl PartialEq<str> for TextBuf {
@@ -1,95 +1,318 @@
+use std::fmt;
+use std::sync::Arc;
+
 use crate::{TextUnit, TextRange, TextBuf, Text, tu};
-use std::cmp::Ordering;
+use crate::rope::{self, Node};
 
 #[derive(Clone, Debug)]
 pub struct TextEdit {
     pub ops: Vec<TextEditOp>,
 }
 
+/// `Copy` ranges of an edit are expected to be ordered and disjoint,
+/// `TextEdit::new` checks this.
 #[derive(Clone, Debug)]
 pub enum TextEditOp {
-    Copy(TextRange), // TODO: check for disjoint ranges
+    Copy(TextRange),
     Insert(TextBuf),
 }
 
+/// Describes why a set of edit operations is invalid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EditError {
+    /// The range extends past the end of the text.
+    OutOfBounds { range: TextRange, text_len: TextUnit },
+    /// Two ranges overlap.
+    Overlap(TextRange, TextRange),
+    /// The second copied range precedes the first one.
+    Unordered(TextRange, TextRange),
+    /// The offset is inside of a multi-byte character.
+    NotCharBoundary(TextUnit),
+}
+
+impl fmt::Display for EditError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            EditError::OutOfBounds { range, text_len } =>
+                write!(f, "range {} is out of bounds of text of length {}", range, text_len),
+            EditError::Overlap(first, second) =>
+                write!(f, "ranges {} and {} overlap", first, second),
+            EditError::Unordered(first, second) =>
+                write!(f, "range {} goes after {}", second, first),
+            EditError::NotCharBoundary(offset) =>
+                write!(f, "offset {} is not a char boundary", offset),
+        }
+    }
+}
+
+impl ::std::error::Error for EditError {}
+
+
+/// Which side an offset sticks to when text is inserted
+/// or deleted right at it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Bias {
+    /// Stick to the character before the offset.
+    Left,
+    /// Stick to the character after the offset.
+    Right,
+}
 
 impl TextEdit {
+    /// Creates an edit of a text of length `old_len`, checking that copied
+    /// ranges are in bounds, ordered and disjoint. Char boundaries are not
+    /// checked, because the text is not known, `TextEditBuilder` checks them.
+    pub fn new(ops: Vec<TextEditOp>, old_len: TextUnit) -> Result<TextEdit, EditError> {
+        let mut prev: Option<TextRange> = None;
+        for op in ops.iter() {
+            let range = match *op {
+                TextEditOp::Copy(range) => range,
+                TextEditOp::Insert(_) => continue,
+            };
+            if range.end() > old_len {
+                return Err(EditError::OutOfBounds { range, text_len: old_len });
+            }
+            if let Some(prev) = prev {
+                if range.start() < prev.end() {
+                    return Err(if range.end() <= prev.start() {
+                        EditError::Unordered(prev, range)
+                    } else {
+                        EditError::Overlap(prev, range)
+                    });
+                }
+            }
+            prev = Some(range);
+        }
+        Ok(TextEdit { ops })
+    }
+
+    /// Computes a small edit which turns `old` into `new`.
+    ///
+    /// Texts are compared line by line, and each changed group of lines
+    /// is trimmed down to the characters which actually differ.
+    pub fn diff(old: Text, new: Text) -> TextEdit {
+        crate::diff::diff(&old.to_cow(), &new.to_cow())
+    }
+
+    /// Merges two successive edits: applying the result is the same as
+    /// applying `first` and then `second`.
+    pub fn compose(first: &TextEdit, second: &TextEdit) -> TextEdit {
+        let mut segments = Vec::with_capacity(first.ops.len());
+        let mut mid_offset = tu(0);
+        for op in first.ops.iter() {
+            let len = op.len();
+            segments.push((TextRange::from_len(mid_offset, len), op));
+            mid_offset += len;
+        }
+
+        let mut ops = Vec::new();
+        for op in second.ops.iter() {
+            let range = match *op {
+                TextEditOp::Insert(ref buf) => {
+                    push_op(&mut ops, TextEditOp::Insert(buf.clone()));
+                    continue;
+                }
+                TextEditOp::Copy(range) => range,
+            };
+            let first_segment = segments.partition_point(|&(mid, _)| mid.end() <= range.start());
+            for &(mid, op) in segments[first_segment..].iter() {
+                if mid.start() >= range.end() {
+                    break;
+                }
+                let start = ::std::cmp::max(mid.start(), range.start()) - mid.start();
+                let end = ::std::cmp::min(mid.end(), range.end()) - mid.start();
+                let op = match *op {
+                    TextEditOp::Copy(old) =>
+                        TextEditOp::Copy(TextRange::from_to(old.start() + start, old.start() + end)),
+                    TextEditOp::Insert(ref buf) =>
+                        TextEditOp::Insert(buf.as_text().slice(TextRange::from_to(start, end)).to_text_buf()),
+                };
+                push_op(&mut ops, op);
+            }
+        }
+        TextEdit { ops }
+    }
+
+    /// Returns an edit which undoes this one.
+    ///
+    /// `old_text` is the text this edit was applied to. Copied ranges
+    /// must be ordered and disjoint.
+    pub fn invert(&self, old_text: Text) -> TextEdit {
+        let mut ops = Vec::new();
+        let mut old_offset = tu(0);
+        let mut new_offset = tu(0);
+        for op in self.ops.iter() {
+            if let TextEditOp::Copy(range) = *op {
+                if old_offset < range.start() {
+                    let deleted = old_text.slice(TextRange::from_to(old_offset, range.start()));
+                    push_op(&mut ops, TextEditOp::Insert(deleted.to_text_buf()));
+                }
+                push_op(&mut ops, TextEditOp::Copy(TextRange::from_len(new_offset, range.len())));
+                old_offset = range.end();
+            }
+            new_offset += op.len();
+        }
+        if old_offset < old_text.len() {
+            let deleted = old_text.slice(TextRange::from_to(old_offset, old_text.len()));
+            push_op(&mut ops, TextEditOp::Insert(deleted.to_text_buf()));
+        }
+        TextEdit { ops }
+    }
+
+    /// Moves an offset in the old text to the corresponding offset in the new one.
+    ///
+    /// With `Bias::Left` the offset follows the character before it,
+    /// with `Bias::Right` it follows the character after it. If that character
+    /// was deleted, the nearest surviving one in the same direction is used.
+    pub fn map_offset(&self, offset: TextUnit, bias: Bias) -> TextUnit {
+        let mut new_offset = tu(0);
+        let mut result = None;
+        for op in self.ops.iter() {
+            if let TextEditOp::Copy(range) = *op {
+                match bias {
+                    Bias::Left => {
+                        if range.start() < offset && offset <= range.end() {
+                            return new_offset + (offset - range.start());
+                        }
+                        if range.end() < offset {
+                            result = Some(new_offset + range.len());
+                        }
+                    }
+                    Bias::Right => {
+                        if range.start() <= offset && offset < range.end() {
+                            return new_offset + (offset - range.start());
+                        }
+                        if offset < range.start() && result.is_none() {
+                            result = Some(new_offset);
+                        }
+                    }
+                }
+            }
+            new_offset += op.len();
+        }
+        result.unwrap_or(match bias {
+            Bias::Left => tu(0),
+            Bias::Right => new_offset,
+        })
+    }
+
+    /// Moves both ends of the range with `map_offset`.
+    pub fn map_range(&self, range: TextRange, bias: Bias) -> TextRange {
+        TextRange::from_to(
+            self.map_offset(range.start(), bias),
+            self.map_offset(range.end(), bias),
+        )
+    }
+
     pub fn apply(&self, text: Text) -> TextBuf {
-        let mut result = String::new();
+        let mut result = TextBuf::from(String::new());
         for s in self.ops.iter() {
             match *s {
-                TextEditOp::Copy(range) => result += &text.slice(range).to_cow(),
-                TextEditOp::Insert(ref i) => result += &i.as_text().to_cow(),
+                TextEditOp::Copy(range) => result.append(text.slice(range).to_text_buf()),
+                TextEditOp::Insert(ref i) => result.append(i.clone()),
             }
         }
+        result
+    }
+}
+
+impl TextEditOp {
+    /// Length of the text this op contributes to the result.
+    fn len(&self) -> TextUnit {
+        match *self {
+            TextEditOp::Copy(range) => range.len(),
+            TextEditOp::Insert(ref buf) => buf.as_text().len(),
+        }
+    }
+}
 
-        result.into()
+fn push_op(ops: &mut Vec<TextEditOp>, op: TextEditOp) {
+    if op.len() == tu(0) {
+        return;
+    }
+    match (ops.last_mut(), op) {
+        (Some(&mut TextEditOp::Copy(ref mut prev)), TextEditOp::Copy(range)) if prev.end() == range.start() =>
+            *prev = prev.glue(range),
+        (Some(&mut TextEditOp::Insert(ref mut prev)), TextEditOp::Insert(buf)) =>
+            prev.append(buf),
+        (_, op) => ops.push(op),
     }
 }
 
+/// Collects replacements in any order and turns them into a `TextEdit`.
+///
+/// Replacements are sorted by their position; overlapping ones are
+/// reported as an error by `try_build`.
 pub struct TextEditBuilder {
-    segments: Vec<TextEditOp>,
-    last_offset: TextUnit,
+    replacements: Vec<(TextRange, TextBuf)>,
     text_len: TextUnit,
-
+    /// The rope of the text and the start of the text in it,
+    /// to check the char boundaries.
+    rope: Arc<Node>,
+    text_start: TextUnit,
 }
 
 impl TextEditBuilder {
     pub fn new(text: Text) -> TextEditBuilder {
         TextEditBuilder {
-            segments: Vec::new(),
-            last_offset: tu(0),
-            text_len: text.len()
+            replacements: Vec::new(),
+            text_len: text.len(),
+            rope: text.rope.clone(),
+            text_start: text.range.start(),
         }
     }
 
-    pub fn build(mut self) -> TextEdit {
-        let len = self.text_len;
-        self.advance_to(len);
-        TextEdit { ops: self.segments }
-    }
-
-    pub fn insert<T: Into<TextBuf>>(&mut self, offset: TextUnit, text: T) {
-        self.advance_to(offset);
-        self.insert_(text.into());
-    }
-
-    pub fn delete(&mut self, range: TextRange) {
-        self.advance_to(range.start());
-        self.delete_len(range.len());
-    }
-
-    pub fn replace<T: Into<TextBuf>>(&mut self, range: TextRange, text: T) {
-        self.advance_to(range.start());
-        self.insert_(text.into());
-        self.delete_len(range.len());
-    }
-
-    fn advance_to(&mut self, offset: TextUnit) {
-        match self.last_offset.cmp(&offset) {
-            Ordering::Less => self.copy_up_to(offset),
-            Ordering::Equal => (),
-            Ordering::Greater => panic!("Invalid edit"),
+    /// Panics if the replacements overlap, are out of bounds or split
+    /// a character, use `try_build` for untrusted input.
+    pub fn build(self) -> TextEdit {
+        match self.try_build() {
+            Ok(edit) => edit,
+            Err(e) => panic!("Invalid edit: {}", e),
         }
     }
 
-    fn copy_up_to(&mut self, offset: TextUnit) {
-        let len = offset - self.last_offset;
-        self.copy_len(len)
+    pub fn try_build(mut self) -> Result<TextEdit, EditError> {
+        // Stable sort: inserts at the same offset keep their order.
+        self.replacements.sort_by_key(|&(range, _)| (range.start(), range.end()));
+        let mut ops = Vec::new();
+        let mut last: Option<TextRange> = None;
+        for (range, text) in self.replacements {
+            if range.end() > self.text_len {
+                return Err(EditError::OutOfBounds { range, text_len: self.text_len });
+            }
+            for &offset in [range.start(), range.end()].iter() {
+                if !rope::is_char_boundary(&self.rope, self.text_start + offset) {
+                    return Err(EditError::NotCharBoundary(offset));
+                }
+            }
+            let last_offset = match last {
+                Some(prev) => {
+                    if prev.start() < range.end() && range.start() < prev.end() {
+                        return Err(EditError::Overlap(prev, range));
+                    }
+                    prev.end()
+                }
+                None => tu(0),
+            };
+            push_op(&mut ops, TextEditOp::Copy(TextRange::from_to(last_offset, range.start())));
+            push_op(&mut ops, TextEditOp::Insert(text));
+            last = Some(range);
+        }
+        let last_offset = last.map(|r| r.end()).unwrap_or(tu(0));
+        push_op(&mut ops, TextEditOp::Copy(TextRange::from_to(last_offset, self.text_len)));
+        Ok(TextEdit { ops })
     }
 
-    fn copy_len(&mut self, len: TextUnit) {
-        let range = TextRange::from_len(self.last_offset, len);
-        self.segments.push(TextEditOp::Copy(range));
-        self.last_offset += len
+    pub fn insert<T: Into<TextBuf>>(&mut self, offset: TextUnit, text: T) {
+        self.replace(TextRange::from_len(offset, tu(0)), text)
     }
 
-    fn insert_(&mut self, text: TextBuf) {
-        self.segments.push(TextEditOp::Insert(text))
+    pub fn delete(&mut self, range: TextRange) {
+        self.replace(range, "")
     }
 
-    fn delete_len(&mut self, len: TextUnit) {
-        self.last_offset += len
+    pub fn replace<T: Into<TextBuf>>(&mut self, range: TextRange, text: T) {
+        self.replacements.push((range, text.into()))
     }
 }
 
@@ -110,4 +333,89 @@ mod tests {
         let new_text = edit.apply(text.as_text());
         assert_eq!(new_text, "Goodbye, cruel World");
     }
+
+    #[test]
+    fn test_unordered_edits() {
+        let text: TextBuf = "Hello, World!".into();
+        let mut e = TextEditBuilder::new(text.as_text());
+        e.delete(TextRange::from_len(tu(12), tu(1)));
+        e.insert(tu(7), "cruel ");
+        e.replace(TextRange::from_len(tu(0), tu(5)), "Goodbye");
+        let new_text = e.build().apply(text.as_text());
+        assert_eq!(new_text, "Goodbye, cruel World");
+
+        let mut e = TextEditBuilder::new(text.as_text());
+        e.replace(TextRange::from_len(tu(0), tu(5)), "Goodbye");
+        e.insert(tu(3), "!");
+        assert_eq!(
+            e.try_build().unwrap_err(),
+            EditError::Overlap(TextRange::from_len(tu(0), tu(5)), TextRange::from_len(tu(3), tu(0)))
+        );
+
+        let mut e = TextEditBuilder::new(text.as_text());
+        e.delete(TextRange::from_len(tu(10), tu(5)));
+        assert_eq!(
+            e.try_build().unwrap_err(),
+            EditError::OutOfBounds { range: TextRange::from_len(tu(10), tu(5)), text_len: tu(13) }
+        );
+    }
+
+    #[test]
+    fn test_not_char_boundary() {
+        let text: TextBuf = "привет, мир".into();
+        let mut e = TextEditBuilder::new(text.as_text());
+        e.replace(TextRange::from_len(tu(0), tu(12)), "пока");
+        e.delete(TextRange::from_len(tu(14), tu(3)));
+        assert_eq!(e.try_build().unwrap_err(), EditError::NotCharBoundary(tu(17)));
+
+        let mut e = TextEditBuilder::new(text.as_text().slice(TextRange::from_len(tu(14), tu(6))));
+        e.insert(tu(1), "!");
+        assert_eq!(e.try_build().unwrap_err(), EditError::NotCharBoundary(tu(1)));
+
+        let mut e = TextEditBuilder::new(text.as_text());
+        e.replace(TextRange::from_len(tu(0), tu(12)), "пока");
+        assert_eq!(e.build().apply(text.as_text()), "пока, мир");
+    }
+
+    #[test]
+    fn test_checked_edit() {
+        let len = tu(10);
+        let copy = |s, e| TextEditOp::Copy(TextRange::from_to(tu(s), tu(e)));
+        assert!(TextEdit::new(vec![copy(0, 2), TextEditOp::Insert("x".into()), copy(2, 10)], len).is_ok());
+        assert_eq!(
+            TextEdit::new(vec![copy(0, 5), copy(3, 10)], len).unwrap_err(),
+            EditError::Overlap(TextRange::from_to(tu(0), tu(5)), TextRange::from_to(tu(3), tu(10)))
+        );
+        assert_eq!(
+            TextEdit::new(vec![copy(5, 10), copy(0, 2)], len).unwrap_err(),
+            EditError::Unordered(TextRange::from_to(tu(5), tu(10)), TextRange::from_to(tu(0), tu(2)))
+        );
+        assert_eq!(
+            TextEdit::new(vec![copy(5, 11)], len).unwrap_err(),
+            EditError::OutOfBounds { range: TextRange::from_to(tu(5), tu(11)), text_len: len }
+        );
+    }
+
+    #[test]
+    fn test_map_offset() {
+        let text: TextBuf = "Hello, World!".into();
+        let edit = {
+            let mut e = TextEditBuilder::new(text.as_text());
+            e.insert(tu(0), ">> ");
+            e.replace(TextRange::from_len(tu(5), tu(2)), " ");
+            e.build()
+        };
+        assert_eq!(edit.apply(text.as_text()), ">> Hello World!");
+
+        assert_eq!(edit.map_offset(tu(0), Bias::Left), tu(0));
+        assert_eq!(edit.map_offset(tu(0), Bias::Right), tu(3));
+        assert_eq!(edit.map_offset(tu(5), Bias::Left), tu(8));
+        assert_eq!(edit.map_offset(tu(6), Bias::Left), tu(8));
+        assert_eq!(edit.map_offset(tu(6), Bias::Right), tu(9));
+        assert_eq!(edit.map_offset(tu(13), Bias::Right), tu(15));
+        assert_eq!(
+            edit.map_range(TextRange::from_to(tu(7), tu(12)), Bias::Right),
+            TextRange::from_to(tu(9), tu(14))
+        );
+    }
 }
@@ -6,7 +6,7 @@ use serde::{Serialize, Serializer, Deserialize, Deserializer};
 
 use super::{TextUnit, tu};
 
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct TextRange {
     start: TextUnit,
     end: TextUnit,
@@ -32,7 +32,11 @@ impl Serialize for TextRange {
 
 impl<'de> Deserialize<'de> for TextRange {
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        let (start, end) = Deserialize::deserialize(deserializer)?;
+        let (start, end): (TextUnit, TextUnit) = Deserialize::deserialize(deserializer)?;
+        if start > end {
+            let msg = format!("invalid text range [{}; {})", start, end);
+            return Err(<D::Error as ::serde::de::Error>::custom(msg));
+        }
         Ok(TextRange { start, end })
     }
 }
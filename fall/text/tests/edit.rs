@@ -0,0 +1,87 @@
+#[macro_use]
+extern crate proptest;
+extern crate fall_text;
+
+use proptest::prelude::*;
+
+use fall_text::{TextBuf, TextEdit, TextEditOp, Bias, tu};
+use fall_text::prop::arb_text_edit;
+
+fn arb_text() -> BoxedStrategy<String> {
+    prop::collection::vec(prop::num::u8::ANY, 0..64)
+        .prop_map(|bytes| {
+            bytes.into_iter()
+                .map(|b| ['a', 'b', 'c', '\n'][(b % 4) as usize])
+                .collect()
+        })
+        .boxed()
+}
+
+proptest! {
+    #[test]
+    fn compose_applies_edits_in_sequence(
+        ref text in arb_text(),
+        ref first in arb_text_edit(),
+        ref second in arb_text_edit()
+    ) {
+        let text = TextBuf::from(text.as_str());
+        let first = first.as_text_edit(text.as_text());
+        let mid = first.apply(text.as_text());
+        let second = second.as_text_edit(mid.as_text());
+        let expected = second.apply(mid.as_text());
+
+        let composed = TextEdit::compose(&first, &second);
+        assert_eq!(composed.apply(text.as_text()).to_string(), expected.to_string());
+    }
+
+    #[test]
+    fn invert_undoes_edit(ref text in arb_text(), ref edit in arb_text_edit()) {
+        let text = TextBuf::from(text.as_str());
+        let edit = edit.as_text_edit(text.as_text());
+        let new_text = edit.apply(text.as_text());
+
+        let undo = edit.invert(text.as_text());
+        assert_eq!(undo.apply(new_text.as_text()).to_string(), text.to_string());
+    }
+
+    #[test]
+    fn map_offset_follows_copied_text(ref text in arb_text(), ref edit in arb_text_edit()) {
+        let text = TextBuf::from(text.as_str());
+        let edit = edit.as_text_edit(text.as_text());
+        let new_text = edit.apply(text.as_text());
+
+        let (mut prev_left, mut prev_right) = (tu(0), tu(0));
+        for offset in 0..text.as_text().len().utf8_len() as u32 + 1 {
+            let offset = tu(offset);
+            let left = edit.map_offset(offset, Bias::Left);
+            let right = edit.map_offset(offset, Bias::Right);
+            assert!(prev_left <= left && prev_right <= right);
+            assert!(left <= right && right <= new_text.as_text().len());
+            prev_left = left;
+            prev_right = right;
+        }
+        let mut new_offset = tu(0);
+        for op in edit.ops.iter() {
+            if let TextEditOp::Copy(range) = *op {
+                if range.is_empty() {
+                    continue;
+                }
+                assert_eq!(edit.map_offset(range.start(), Bias::Right), new_offset);
+                assert_eq!(edit.map_offset(range.end(), Bias::Left), new_offset + range.len());
+                new_offset += range.len();
+            } else if let TextEditOp::Insert(ref buf) = *op {
+                new_offset += buf.as_text().len();
+            }
+        }
+    }
+}
+
+proptest! {
+    #[test]
+    fn diff_turns_old_text_into_new(ref old in arb_text(), ref new in arb_text()) {
+        let old = TextBuf::from(old.as_str());
+        let new = TextBuf::from(new.as_str());
+        let edit = TextEdit::diff(old.as_text(), new.as_text());
+        assert_eq!(edit.apply(old.as_text()).to_string(), new.to_string());
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{TextEdit, TextEditBuilder, Node, File, TextRange, TextEditOp, tu};
+use crate::{TextEdit, TextEditBuilder, TextBuf, Node, File, TextRange, TextEditOp, tu};
 use crate::search::find_covering_node;
 
 pub struct FileEdit<'f> {
@@ -66,7 +66,7 @@ impl<'f> FileEdit<'f> {
         }
 
         if let Some(&(_, ref replacement)) = self.replaced.iter().find(|&&(n, _)| n == node) {
-            edit_builder.replace(node.range(), replacement.as_ref());
+            replace_minimal(node, replacement, edit_builder);
             return;
         }
 
@@ -79,3 +79,27 @@ impl<'f> FileEdit<'f> {
         }
     }
 }
+
+/// Replaces only the parts of the node's text which actually change,
+/// so that the rest of the node survives incremental reparsing.
+fn replace_minimal(node: Node, replacement: &str, edit_builder: &mut TextEditBuilder) {
+    let replacement = TextBuf::from(replacement);
+    let diff = TextEdit::diff(node.text(), replacement.as_text());
+    let start = node.range().start();
+    let mut offset = tu(0);
+    for op in diff.ops {
+        match op {
+            TextEditOp::Copy(range) => {
+                if offset < range.start() {
+                    edit_builder.delete(TextRange::from_to(offset, range.start()).shift_right(start));
+                }
+                offset = range.end();
+            }
+            TextEditOp::Insert(text) => edit_builder.insert(start + offset, text),
+        }
+    }
+    let len = node.range().len();
+    if offset < len {
+        edit_builder.delete(TextRange::from_to(offset, len).shift_right(start));
+    }
+}
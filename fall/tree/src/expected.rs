@@ -0,0 +1,37 @@
+use crate::{NodeType, TextUnit, Language};
+
+/// A token the parser has tried to match.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ExpectedToken {
+    Token(NodeType),
+    /// A contextual keyword, matched by text.
+    Contextual(String),
+    /// The end of file.
+    Eof,
+}
+
+/// Tokens the parser has tried at the furthest offset where matching failed.
+///
+/// If the file has syntax errors, this is usually the place of the first one,
+/// and `tokens` are the ones which would have allowed parsing to continue.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Expected {
+    pub offset: TextUnit,
+    pub tokens: Vec<ExpectedToken>,
+}
+
+impl Expected {
+    /// Human-readable list of the expected tokens, like `SEMI or 'where'`.
+    pub fn describe(&self, lang: &Language) -> String {
+        let names: Vec<String> = self.tokens.iter().map(|t| match *t {
+            ExpectedToken::Token(ty) => lang.node_type_info(ty).name.to_owned(),
+            ExpectedToken::Contextual(ref text) => format!("'{}'", text),
+            ExpectedToken::Eof => "end of file".to_owned(),
+        }).collect();
+        match names.split_last() {
+            None => String::new(),
+            Some((last, [])) => last.clone(),
+            Some((last, init)) => format!("{} or {}", init.join(", "), last),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use crate::{File, Language, Node, NodeType, TextRange};
+use crate::search::traversal;
+
+/// Declares that the contents of nodes of type `ty` are written
+/// in another language.
+///
+/// `range` selects the part of the node which holds the embedded text
+/// (for example, a string literal without quotes). Returning `None`
+/// skips the node.
+#[derive(Clone, Copy)]
+pub struct Injection {
+    pub ty: NodeType,
+    pub lang: fn() -> &'static Language,
+    pub range: fn(Node) -> Option<TextRange>,
+}
+
+/// A region of the host file parsed with an injected language.
+///
+/// Offsets inside `file` are relative to the start of `range`.
+pub struct InjectedFile {
+    pub range: TextRange,
+    pub file: File,
+}
+
+impl InjectedFile {
+    pub fn to_host(&self, range: TextRange) -> TextRange {
+        range.shift_right(self.range.start())
+    }
+
+    pub fn from_host(&self, range: TextRange) -> Option<TextRange> {
+        if !range.is_subrange_of(self.range) {
+            return None;
+        }
+        Some(TextRange::from_len(range.start() - self.range.start(), range.len()))
+    }
+}
+
+pub(crate) fn injections(file: &File) -> Vec<InjectedFile> {
+    let injections = file.language().injections();
+    if injections.is_empty() {
+        return Vec::new();
+    }
+    let mut result = Vec::new();
+    traversal::top_down(file.root(), |node| {
+        let nested = result.last()
+            .map_or(false, |prev: &InjectedFile| node.range().is_subrange_of(prev.range));
+        if nested {
+            return;
+        }
+        let injection = match injections.iter().find(|i| i.ty == node.ty()) {
+            Some(injection) => injection,
+            None => return,
+        };
+        let range = match (injection.range)(node) {
+            Some(range) if range.is_subrange_of(node.range()) => range,
+            _ => return,
+        };
+        let text = file.text().slice(range).to_string();
+        result.push(InjectedFile { range, file: (injection.lang)().parse(text) });
+    });
+    result
+}
@@ -1,11 +1,13 @@
 use std::any::Any;
 use std::sync::Arc;
-use crate::{Text, TextBuf, TextEdit, File, NodeType, NodeTypeInfo, Metrics, TreeBuilder};
+use std::sync::atomic::{AtomicBool, Ordering};
+use crate::{Text, TextBuf, TextEdit, File, NodeType, NodeTypeInfo, Metrics, TreeBuilder, NodeMap, Injection};
 
 pub trait LanguageImpl: 'static + Send + Sync {
     fn parse(
         &self,
         text: Text,
+        options: &ParseOptions,
         metrics: &Metrics,
         builder: &mut TreeBuilder,
     ) -> Option<Box<dyn Any + Sync + Send>>;
@@ -15,11 +17,49 @@ pub trait LanguageImpl: 'static + Send + Sync {
         incremental_data: &dyn Any,
         edit: &TextEdit,
         new_text: Text,
+        options: &ParseOptions,
         metrics: &Metrics,
         builder: &mut TreeBuilder,
     ) -> Option<Box<dyn Any + Sync + Send>>;
 
     fn node_type_info(&self, ty: NodeType) -> NodeTypeInfo;
+
+    /// Node types whose contents are parsed with another language.
+    fn injections(&self) -> Vec<Injection> {
+        Vec::new()
+    }
+}
+
+/// Limits the amount of work the parser may do.
+///
+/// When a limit is hit, the parser stops and wraps the rest of the input
+/// into an `ERROR` node. The `"parse budget exhausted"` or the
+/// `"nesting limit exceeded"` metric is recorded then.
+#[derive(Clone)]
+pub struct ParseOptions {
+    /// Maximum number of parser steps.
+    pub tick_budget: Option<u64>,
+    /// Set this flag from another thread to stop parsing.
+    pub cancelled: Option<Arc<AtomicBool>>,
+    /// Maximum nesting of grammar expressions. The parser grows its stack
+    /// as needed, so this bounds the memory used on deeply nested input.
+    pub max_depth: u32,
+}
+
+impl Default for ParseOptions {
+    fn default() -> ParseOptions {
+        ParseOptions {
+            tick_budget: None,
+            cancelled: None,
+            max_depth: 100_000,
+        }
+    }
+}
+
+impl ParseOptions {
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.as_ref().map_or(false, |flag| flag.load(Ordering::Relaxed))
+    }
 }
 
 #[derive(Clone)]
@@ -33,21 +73,38 @@ impl Language {
     }
 
     pub fn parse<T: Into<TextBuf>>(&self, text: T) -> File {
+        self.parse_with_options(text, &ParseOptions::default())
+    }
+
+    pub fn parse_with_options<T: Into<TextBuf>>(&self, text: T, options: &ParseOptions) -> File {
         let text: TextBuf = text.into();
         let metrics = Metrics::new();
         let mut builder = TreeBuilder::new();
-        let incremental = self.imp.parse(text.as_text(), &metrics, &mut builder);
+        let incremental = self.imp.parse(text.as_text(), options, &metrics, &mut builder);
         File::new(self.clone(), text, metrics, incremental, builder)
     }
 
+    /// Parses the edited `file`, reusing the unchanged parts of it.
     pub fn reparse(&self, file: &File, edit: &TextEdit) -> File {
+        self.reparse_with_options(file, edit, &ParseOptions::default())
+    }
+
+    /// Like `reparse`, but also maps the nodes of the old file
+    /// to the new ones which replace them unchanged.
+    pub fn reparse_with_map(&self, file: &File, edit: &TextEdit) -> (File, NodeMap) {
+        let new_file = self.reparse(file, edit);
+        let map = NodeMap::new(file, &new_file);
+        (new_file, map)
+    }
+
+    pub fn reparse_with_options(&self, file: &File, edit: &TextEdit, options: &ParseOptions) -> File {
         let new_text = edit.apply(file.text());
         let metrics = Metrics::new();
-        let mut builder = TreeBuilder::new();
+        let mut builder = TreeBuilder::reusing(file, edit);
         let incremental = if let Some(incremental) = file.incremental_data() {
-            self.imp.reparse(incremental, edit, new_text.as_text(), &metrics, &mut builder)
+            self.imp.reparse(incremental, edit, new_text.as_text(), options, &metrics, &mut builder)
         } else {
-            self.imp.parse(new_text.as_text(), &metrics, &mut builder)
+            self.imp.parse(new_text.as_text(), options, &metrics, &mut builder)
         };
         File::new(self.clone(), new_text, metrics, incremental, builder)
     }
@@ -55,4 +112,16 @@ impl Language {
     pub fn node_type_info(&self, ty: NodeType) -> NodeTypeInfo {
         self.imp.node_type_info(ty)
     }
+
+    pub fn injections(&self) -> Vec<Injection> {
+        self.imp.injections()
+    }
+}
+
+impl PartialEq for Language {
+    fn eq(&self, other: &Language) -> bool {
+        Arc::ptr_eq(&self.imp, &other.imp)
+    }
 }
+
+impl Eq for Language {}
@@ -9,10 +9,12 @@ extern crate file;
 mod node_type;
 mod node;
 mod edit;
+mod expected;
 
 mod ast;
 mod util;
 mod lang;
+mod injection;
 
 pub mod visitor;
 pub mod search;
@@ -21,9 +23,11 @@ mod metrics;
 
 pub use fall_text::*;
 pub use crate::node_type::{NodeType, NodeTypeInfo, ERROR};
-pub use crate::node::{File, Node, TreeBuilder};
+pub use crate::node::{File, Node, NodeId, NodeMap, TreeBuilder, SyntaxNode, NodePtr};
 pub use crate::edit::FileEdit;
-pub use crate::lang::{Language, LanguageImpl};
+pub use crate::expected::{Expected, ExpectedToken};
+pub use crate::lang::{Language, LanguageImpl, ParseOptions};
+pub use crate::injection::{Injection, InjectedFile};
 pub use crate::ast::{AstNode, AstChildren};
 pub use crate::util::{dump_file, dump_file_ws, walk_tree};
 pub use crate::metrics::{Metric, Metrics};
@@ -0,0 +1,178 @@
+use std::mem;
+use std::ops::Deref;
+use std::sync::Arc;
+
+use crate::{NodeType, TextUnit, TextRange, TextEdit, TextEditOp, tu};
+
+/// An immutable node, which knows its type, length and children, but not
+/// its offset or parent, so it can be shared between the trees of different
+/// versions of a file.
+#[derive(Clone)]
+pub(crate) struct GreenNode(Arc<GreenData>);
+
+pub(crate) struct GreenData {
+    pub(crate) ty: NodeType,
+    pub(crate) len: TextUnit,
+    pub(crate) children: Box<[GreenNode]>,
+    /// The number of nodes with children in the subtree, including this one.
+    pub(crate) n_branches: u32,
+    pub(crate) error: Option<Box<str>>,
+    pub(crate) missing: bool,
+}
+
+impl GreenNode {
+    pub(crate) fn leaf(ty: NodeType, len: TextUnit, missing: bool) -> GreenNode {
+        GreenNode(Arc::new(GreenData {
+            ty,
+            len,
+            children: Box::new([]),
+            n_branches: 0,
+            error: None,
+            missing,
+        }))
+    }
+
+    pub(crate) fn new(ty: NodeType, children: Vec<GreenNode>, error: Option<String>) -> GreenNode {
+        let len = children.iter().fold(tu(0), |len, child| len + child.len);
+        let n_branches = children.iter().map(|child| child.n_branches).sum::<u32>()
+            + if children.is_empty() { 0 } else { 1 };
+        GreenNode(Arc::new(GreenData {
+            ty,
+            len,
+            children: children.into_boxed_slice(),
+            n_branches,
+            error: error.map(String::into_boxed_str),
+            missing: false,
+        }))
+    }
+
+    pub(crate) fn ptr_eq(&self, other: &GreenNode) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Deref for GreenNode {
+    type Target = GreenData;
+
+    fn deref(&self) -> &GreenData {
+        &self.0
+    }
+}
+
+/// Drops the children with an explicit stack, so arbitrary deep trees are fine.
+impl Drop for GreenData {
+    fn drop(&mut self) {
+        if self.children.is_empty() {
+            return;
+        }
+        let mut stack = mem::take(&mut self.children).into_vec();
+        while let Some(mut node) = stack.pop() {
+            // A node which is still shared keeps its children.
+            if let Some(data) = Arc::get_mut(&mut node.0) {
+                stack.extend(mem::take(&mut data.children).into_vec());
+            }
+        }
+    }
+}
+
+/// Finds the nodes of the old tree which survived an edit.
+pub(crate) struct Reuse {
+    /// The copied parts of the old text, as `(start in the new text, old range)`.
+    copied: Vec<(TextUnit, TextRange)>,
+    /// The path from the root to the last visited node, as
+    /// `(node, offset, index and offset of the child to look at first)`.
+    path: Vec<(GreenNode, TextUnit, usize, TextUnit)>,
+}
+
+impl Reuse {
+    pub(crate) fn new(root: GreenNode, edit: &TextEdit) -> Reuse {
+        let mut copied = Vec::new();
+        let mut pos = tu(0);
+        for op in edit.ops.iter() {
+            match *op {
+                TextEditOp::Copy(range) => {
+                    copied.push((pos, range));
+                    pos += range.len();
+                }
+                TextEditOp::Insert(ref text) => pos += text.as_text().len(),
+            }
+        }
+        Reuse { copied, path: vec![(root, tu(0), 0, tu(0))] }
+    }
+
+    /// Finds an old node of type `ty` for a node at `range` of the new text,
+    /// which is equal to the node with the `children`.
+    pub(crate) fn find(
+        &mut self,
+        range: TextRange,
+        ty: NodeType,
+        children: &[GreenNode],
+        error: Option<&str>,
+    ) -> Option<GreenNode> {
+        let range = self.old_range(range)?;
+        let same = |node: &GreenNode| {
+            node.ty == ty
+                && node.error.as_deref() == error
+                && node.children.len() == children.len()
+                && node.children.iter().zip(children).all(|(a, b)| a.ptr_eq(b))
+        };
+        let contains = |&(ref node, offset, _, _): &(GreenNode, TextUnit, usize, TextUnit)| {
+            offset <= range.start() && range.end() <= offset + node.len
+        };
+
+        while self.path.len() > 1 && !contains(self.path.last().unwrap()) {
+            self.path.pop();
+        }
+        // Nodes with a single child share its range.
+        for &(ref node, offset, _, _) in self.path.iter().rev() {
+            if offset != range.start() || node.len != range.len() {
+                break;
+            }
+            if same(node) {
+                return Some(node.clone());
+            }
+        }
+        loop {
+            let (node, offset, hint, hint_offset) = self.path.last().unwrap().clone();
+            let (mut idx, mut child_offset) = if hint_offset <= range.start() {
+                (hint, hint_offset)
+            } else {
+                (0, offset)
+            };
+            loop {
+                let child = node.children.get(idx)?;
+                let end = child_offset + child.len;
+                if child_offset <= range.start() && range.end() <= end {
+                    break;
+                }
+                if range.start() < child_offset {
+                    return None;
+                }
+                idx += 1;
+                child_offset = end;
+            }
+            {
+                let last = self.path.last_mut().unwrap();
+                last.2 = idx;
+                last.3 = child_offset;
+            }
+            let child = node.children[idx].clone();
+            let found = child_offset == range.start() && child.len == range.len() && same(&child);
+            self.path.push((child.clone(), child_offset, 0, child_offset));
+            if found {
+                return Some(child);
+            }
+        }
+    }
+
+    fn old_range(&self, range: TextRange) -> Option<TextRange> {
+        let idx = match self.copied.binary_search_by_key(&range.start(), |&(start, _)| start) {
+            Ok(idx) => idx,
+            Err(0) => return None,
+            Err(idx) => idx - 1,
+        };
+        let (start, old) = self.copied[idx];
+        let shifted = TextRange::from_len(old.start() + (range.start() - start), range.len());
+        if shifted.end() <= old.end() { Some(shifted) } else { None }
+    }
+}
@@ -1,20 +1,32 @@
-use std::ops::Index;
-
-use crate::{TextBuf, Text, TextRange, NodeType, Language, Metrics};
+use crate::{TextBuf, Text, TextRange, TextUnit, NodeType, Language, Metrics, Expected, ERROR, tu};
 use super::Node;
-use crate::node::tree_builder::{TreeBuilder, NodeData, NodeId};
+use crate::node::green::{GreenNode, GreenData};
+use crate::node::tree_builder::{TreeBuilder, Leaves};
+use crate::search::traversal;
 
 pub struct FileImpl {
     pub lang: Language,
     metrics: Metrics,
     text: TextBuf,
-    root: NodeId,
-    nodes: Vec<NodeData>,
+    green: GreenNode,
+    leaves: Leaves,
+    /// The root and the nodes with children, in preorder.
+    branches: Vec<Branch>,
+    expected: Option<Expected>,
+}
+
+/// Positions a green node with children in the file.
+pub(super) struct Branch {
+    pub(super) green: GreenNode,
+    parent: u32,
+    pub(super) offset: TextUnit,
 }
 
+pub(super) const NONE: u32 = !0;
+
 impl FileImpl {
     pub fn root<'i, 'f: 'i>(&'i self, file: &'f super::File) -> Node<'f> {
-        Node(NodeImpl { id: self.root, file })
+        Node(NodeImpl::branch(file, 0))
     }
 
     pub fn text(&self) -> Text {
@@ -24,12 +36,55 @@ impl FileImpl {
     pub fn metrics(&self) -> &Metrics {
         &self.metrics
     }
+
+    pub fn errors(&self, file: &super::File) -> Vec<(TextRange, String)> {
+        let mut result = Vec::new();
+        traversal::top_down(file.root(), |node| {
+            if node.is_missing() {
+                let name = self.lang.node_type_info(node.ty()).name;
+                result.push((node.range(), format!("{} expected", name)));
+                return;
+            }
+            if node.ty() != ERROR || node.parent().map(|p| p.ty()) == Some(ERROR) {
+                return;
+            }
+            let message = match node.error_message() {
+                Some(message) => message.to_owned(),
+                None if node.children().next().is_none() => "unexpected token".to_owned(),
+                None => "syntax error".to_owned(),
+            };
+            result.push((node.range(), message));
+        });
+        result
+    }
+
+    pub fn expected(&self) -> Option<&Expected> {
+        self.expected.as_ref()
+    }
+
+    pub(crate) fn green(&self) -> &GreenNode {
+        &self.green
+    }
+
+    pub(crate) fn leaves(&self) -> &Leaves {
+        &self.leaves
+    }
+
+    pub(super) fn branches(&self) -> &[Branch] {
+        &self.branches
+    }
 }
 
+/// A green node together with its position in the file.
 #[derive(Clone, Copy)]
 pub struct NodeImpl<'f> {
-    id: NodeId,
     file: &'f super::File,
+    green: &'f GreenData,
+    offset: TextUnit,
+    /// The index of the branch of this node, or of the parent for a leaf.
+    branch: u32,
+    /// The index of a leaf in the parent, `NONE` for a branch.
+    leaf: u32,
 }
 
 impl<'f> ::std::cmp::PartialEq for NodeImpl<'f> {
@@ -48,11 +103,11 @@ impl<'f> ::std::hash::Hash for NodeImpl<'f> {
 
 impl<'f> NodeImpl<'f> {
     pub fn ty(&self) -> NodeType {
-        self.data().ty
+        self.green.ty
     }
 
     pub fn range(&self) -> TextRange {
-        self.data().range
+        TextRange::from_len(self.offset, self.green.len)
     }
 
     pub fn text(&self) -> Text<'f> {
@@ -62,54 +117,153 @@ impl<'f> NodeImpl<'f> {
     pub fn file(&self) -> &'f super::File { self.file }
 
     pub fn parent(&self) -> Option<Node<'f>> {
-        self.data().parent.map(|id| Node(NodeImpl { id, file: self.file }))
+        let parent = if self.leaf != NONE {
+            self.branch
+        } else {
+            self.file.imp.branches[self.branch as usize].parent
+        };
+        if parent == NONE {
+            return None;
+        }
+        Some(Node(NodeImpl::branch(self.file, parent)))
     }
 
     pub fn children(&self) -> NodeChildren<'f> {
-        NodeChildren { file: self.file, inner: self.data().children.iter() }
+        NodeChildren {
+            file: self.file,
+            inner: self.green.children.iter(),
+            parent: self.branch,
+            next_branch: self.branch + 1,
+            offset: self.offset,
+            idx: 0,
+        }
+    }
+
+    pub fn error_message(&self) -> Option<&'f str> {
+        self.green.error.as_deref()
+    }
+
+    pub fn is_missing(&self) -> bool {
+        self.green.missing
+    }
+
+    pub fn same_subtree(&self, other: &NodeImpl) -> bool {
+        ::std::ptr::eq(self.green, other.green)
     }
 
     pub fn debug(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
         write!(f, "Node({})", self.file.imp.lang.node_type_info(self.ty()).name)
     }
 
-    fn data(&self) -> &'f NodeData {
-        &self.file.imp[self.id]
+    pub fn id(&self) -> NodeId {
+        NodeId { branch: self.branch, leaf: self.leaf, offset: self.offset }
+    }
+
+    pub fn from_id(file: &'f super::File, id: NodeId) -> NodeImpl<'f> {
+        let branch = &file.imp.branches[id.branch as usize];
+        let green = if id.leaf == NONE {
+            &branch.green
+        } else {
+            &branch.green.children[id.leaf as usize]
+        };
+        NodeImpl { file, green, offset: id.offset, branch: id.branch, leaf: id.leaf }
+    }
+
+    /// Like `from_id`, but checks that the file has a node with the `id`.
+    pub fn try_from_id(file: &'f super::File, id: NodeId) -> Option<NodeImpl<'f>> {
+        let branch = file.imp.branches.get(id.branch as usize)?;
+        let offset = if id.leaf == NONE {
+            branch.offset
+        } else {
+            let siblings = &branch.green.children;
+            if !siblings.get(id.leaf as usize)?.children.is_empty() {
+                return None;
+            }
+            siblings[..id.leaf as usize].iter()
+                .fold(branch.offset, |offset, sibling| offset + sibling.len)
+        };
+        if offset != id.offset {
+            return None;
+        }
+        Some(NodeImpl::from_id(file, id))
+    }
+
+    fn branch(file: &'f super::File, idx: u32) -> NodeImpl<'f> {
+        let branch = &file.imp.branches[idx as usize];
+        NodeImpl { file, green: &branch.green, offset: branch.offset, branch: idx, leaf: NONE }
     }
 
-    fn key(&self) -> (*const super::File, NodeId) {
-        (self.file as *const super::File, self.id)
+    fn key(&self) -> (*const super::File, u32, u32) {
+        (self.file as *const super::File, self.branch, self.leaf)
     }
 }
 
+/// Identifies a node within a file.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct NodeId {
+    pub(super) branch: u32,
+    pub(super) leaf: u32,
+    pub(super) offset: TextUnit,
+}
+
 #[derive(Clone)]
 pub struct NodeChildren<'f> {
     file: &'f super::File,
-    inner: ::std::slice::Iter<'f, NodeId>,
+    inner: ::std::slice::Iter<'f, GreenNode>,
+    parent: u32,
+    next_branch: u32,
+    offset: TextUnit,
+    idx: u32,
 }
 
 impl<'f> Iterator for NodeChildren<'f> {
     type Item = Node<'f>;
 
     fn next(&mut self) -> Option<Node<'f>> {
-        self.inner.next().map(|&id| Node(NodeImpl { id, file: self.file }))
-    }
-}
-
-impl Index<NodeId> for FileImpl {
-    type Output = NodeData;
-    fn index(&self, index: NodeId) -> &Self::Output {
-        &self.nodes[index.0 as usize]
+        let green = self.inner.next()?;
+        let offset = self.offset;
+        let node = if green.children.is_empty() {
+            NodeImpl { file: self.file, green, offset, branch: self.parent, leaf: self.idx }
+        } else {
+            let branch = self.next_branch;
+            self.next_branch += green.n_branches;
+            NodeImpl { file: self.file, green, offset, branch, leaf: NONE }
+        };
+        self.offset += green.len;
+        self.idx += 1;
+        Some(Node(node))
     }
 }
 
 
 pub fn new_file(lang: Language, text: TextBuf, metrics: Metrics, builder: TreeBuilder) -> FileImpl {
+    let tree = builder.finish();
     FileImpl {
         lang,
         metrics,
         text,
-        root: NodeId(0),
-        nodes: builder.finish(),
+        branches: branches(&tree.root),
+        green: tree.root,
+        leaves: tree.leaves,
+        expected: tree.expected,
+    }
+}
+
+/// Lays out the nodes with children in preorder, so that the branches
+/// of the subtree follow the branch of its root.
+fn branches(root: &GreenNode) -> Vec<Branch> {
+    let mut result = Vec::with_capacity(root.n_branches.max(1) as usize);
+    let mut stack = vec![(root.clone(), NONE, tu(0))];
+    while let Some((green, parent, offset)) = stack.pop() {
+        let idx = result.len() as u32;
+        let mut child_offset = offset + green.len;
+        for child in green.children.iter().rev() {
+            child_offset -= child.len;
+            if !child.children.is_empty() {
+                stack.push((child.clone(), idx, child_offset));
+            }
+        }
+        result.push(Branch { green, parent, offset });
     }
+    result
 }
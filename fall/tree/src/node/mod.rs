@@ -1,17 +1,25 @@
 use std::any::Any;
+use std::sync::OnceLock;
 
-use crate::{TextEdit, TextBuf, Text, TextRange, NodeType, Language, Metrics};
+use crate::{TextEdit, TextBuf, Text, TextRange, NodeType, Language, Metrics, Expected, InjectedFile};
 
+mod green;
 mod imp;
 mod tree_builder;
+mod syntax_node;
+mod node_map;
 
-pub use self::imp::NodeChildren;
+pub use self::imp::{NodeChildren, NodeId};
 pub use self::tree_builder::TreeBuilder;
+pub use self::syntax_node::{SyntaxNode, NodePtr};
+pub use self::node_map::NodeMap;
 
 
 pub struct File {
     imp: imp::FileImpl,
     incremental_data: Option<Box<dyn Any + Sync + Send>>,
+    /// Parsed on the first call of `injections`.
+    injections: OnceLock<Vec<InjectedFile>>,
 }
 
 impl File {
@@ -25,6 +33,7 @@ impl File {
         File {
             imp: imp::new_file(lang, text.into(), metrics, builder),
             incremental_data,
+            injections: OnceLock::new(),
         }
     }
 
@@ -48,6 +57,32 @@ impl File {
         self.language().reparse(self, edit)
     }
 
+    /// Ranges of the `ERROR` nodes together with the messages explaining them.
+    /// Errors nested inside other errors are not reported.
+    pub fn errors(&self) -> Vec<(TextRange, String)> {
+        self.imp.errors(self)
+    }
+
+    /// Tokens the parser has tried at the furthest point of failure,
+    /// if the language records them.
+    pub fn expected(&self) -> Option<&Expected> {
+        self.imp.expected()
+    }
+
+    /// Regions of this file written in other languages, each parsed
+    /// into a separate file, in the order of appearance.
+    /// They are parsed once, when first asked for.
+    pub fn injections(&self) -> &[InjectedFile] {
+        self.injections.get_or_init(|| crate::injection::injections(self))
+    }
+
+    /// The node with the `id` obtained from a node of this file.
+    /// Returns `None` if there is no such node. An id of another
+    /// file may name an unrelated node of this one.
+    pub fn node(&self, id: NodeId) -> Option<Node> {
+        imp::NodeImpl::try_from_id(self, id).map(Node)
+    }
+
     pub fn incremental_data(&self) -> Option<&(dyn Any + Send + Sync)> {
         self.incremental_data.as_ref().map(|r| r.as_ref())
     }
@@ -84,4 +119,27 @@ impl<'f> Node<'f> {
     pub fn children(&self) -> NodeChildren<'f> {
         self.0.children()
     }
+
+    /// The message recorded by the parser for an `ERROR` node.
+    pub fn error_message(&self) -> Option<&'f str> {
+        self.0.error_message()
+    }
+
+    /// Is this a zero-width leaf synthesized by the parser
+    /// in place of a token absent from the text?
+    pub fn is_missing(&self) -> bool {
+        self.0.is_missing()
+    }
+
+    /// Identifies the node within its file, see `File::node`.
+    pub fn id(&self) -> NodeId {
+        self.0.id()
+    }
+
+    /// Do the nodes, possibly from different files, share the same
+    /// immutable subtree? After an incremental reparse, this is the case
+    /// for most of the nodes which were not touched by the edit.
+    pub fn same_subtree(&self, other: Node) -> bool {
+        self.0.same_subtree(&other.0)
+    }
 }
@@ -0,0 +1,60 @@
+use std::collections::HashMap;
+
+use crate::TextUnit;
+use super::File;
+use super::imp::NodeId;
+
+/// Maps the nodes of a file to the nodes of its reparsed version,
+/// for the subtrees which were reused unchanged, possibly at another offset.
+pub struct NodeMap {
+    /// Reused subtrees, as `(old branch, new branch, number of branches,
+    /// old offset, new offset)` of their roots, ordered by the old branch.
+    subtrees: Vec<(u32, u32, u32, TextUnit, TextUnit)>,
+}
+
+impl NodeMap {
+    pub(crate) fn new(old: &File, new: &File) -> NodeMap {
+        let old_branches = old.imp.branches();
+        let by_green: HashMap<*const _, u32> = old_branches.iter()
+            .enumerate()
+            .map(|(idx, branch)| (&*branch.green as *const _, idx as u32))
+            .collect();
+
+        let mut subtrees = Vec::new();
+        let new_branches = new.imp.branches();
+        let mut idx = 0;
+        while idx < new_branches.len() {
+            let branch = &new_branches[idx];
+            // The root of an empty file is a branch without children.
+            let n_branches = branch.green.n_branches.max(1);
+            match by_green.get(&(&*branch.green as *const _)) {
+                Some(&old_idx) => {
+                    let old_offset = old_branches[old_idx as usize].offset;
+                    subtrees.push((old_idx, idx as u32, n_branches, old_offset, branch.offset));
+                    idx += n_branches as usize;
+                }
+                None => idx += 1,
+            }
+        }
+        subtrees.sort_by_key(|&(old_idx, _, _, _, _)| old_idx);
+        NodeMap { subtrees }
+    }
+
+    /// The new node corresponding to the `old` one, if it was reused.
+    pub fn get(&self, old: NodeId) -> Option<NodeId> {
+        let idx = match self.subtrees.binary_search_by_key(&old.branch, |&(old_idx, _, _, _, _)| old_idx) {
+            Ok(idx) => idx,
+            Err(0) => return None,
+            Err(idx) => idx - 1,
+        };
+        let (old_idx, new_idx, n_branches, old_offset, new_offset) = self.subtrees[idx];
+        if old.branch >= old_idx + n_branches {
+            return None;
+        }
+        Some(NodeId {
+            branch: new_idx + (old.branch - old_idx),
+            leaf: old.leaf,
+            offset: new_offset + (old.offset - old_offset),
+        })
+    }
+}
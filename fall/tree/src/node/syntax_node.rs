@@ -0,0 +1,133 @@
+use std::sync::Arc;
+
+use crate::{Text, TextRange, TextEdit, Bias, NodeType};
+use crate::search::{ancestors, find_covering_node};
+use super::{File, Node};
+use super::imp::{NodeImpl, NodeId};
+
+/// A node which owns its file, so it can be stored without borrowing.
+/// Navigates the tree the same way as `Node`.
+#[derive(Clone)]
+pub struct SyntaxNode {
+    file: Arc<File>,
+    id: NodeId,
+}
+
+impl SyntaxNode {
+    pub fn root(file: Arc<File>) -> SyntaxNode {
+        let id = file.root().0.id();
+        SyntaxNode { file, id }
+    }
+
+    /// Wraps a `node` of the `file`.
+    pub fn new(file: &Arc<File>, node: Node) -> SyntaxNode {
+        assert!(::std::ptr::eq(&**file, node.file()), "node belongs to a different file");
+        SyntaxNode { file: file.clone(), id: node.0.id() }
+    }
+
+    pub fn node(&self) -> Node {
+        Node(NodeImpl::from_id(&self.file, self.id))
+    }
+
+    pub fn file(&self) -> &Arc<File> {
+        &self.file
+    }
+
+    pub fn ty(&self) -> NodeType {
+        self.node().ty()
+    }
+
+    pub fn range(&self) -> TextRange {
+        self.node().range()
+    }
+
+    pub fn text(&self) -> Text {
+        self.node().text()
+    }
+
+    pub fn parent(&self) -> Option<SyntaxNode> {
+        self.node().parent().map(|node| self.wrap(node))
+    }
+
+    pub fn children<'a>(&'a self) -> impl Iterator<Item=SyntaxNode> + 'a {
+        self.node().children().map(move |node| self.wrap(node))
+    }
+
+    pub fn error_message(&self) -> Option<&str> {
+        self.node().error_message()
+    }
+
+    pub fn is_missing(&self) -> bool {
+        self.node().is_missing()
+    }
+
+    pub fn ptr(&self) -> NodePtr {
+        NodePtr::new(self.node())
+    }
+
+    fn wrap(&self, node: Node) -> SyntaxNode {
+        SyntaxNode { file: self.file.clone(), id: node.0.id() }
+    }
+}
+
+impl PartialEq for SyntaxNode {
+    fn eq(&self, other: &SyntaxNode) -> bool {
+        Arc::ptr_eq(&self.file, &other.file) && self.id == other.id
+    }
+}
+
+impl Eq for SyntaxNode {}
+
+impl ::std::hash::Hash for SyntaxNode {
+    fn hash<H: ::std::hash::Hasher>(&self, state: &mut H) {
+        (&*self.file as *const File).hash(state);
+        self.id.hash(state)
+    }
+}
+
+impl ::std::fmt::Debug for SyntaxNode {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        self.node().fmt(f)
+    }
+}
+
+/// Identifies a node by its type and range, so that it can be found again
+/// in a newer version of the file, if the edits have not touched it.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct NodePtr {
+    ty: NodeType,
+    range: TextRange,
+}
+
+impl NodePtr {
+    pub fn new(node: Node) -> NodePtr {
+        NodePtr { ty: node.ty(), range: node.range() }
+    }
+
+    pub fn ty(&self) -> NodeType {
+        self.ty
+    }
+
+    pub fn range(&self) -> TextRange {
+        self.range
+    }
+
+    /// Moves the range through the `edit`. Text inserted at the borders
+    /// of the node is not considered a part of it.
+    pub fn map(&self, edit: &TextEdit) -> NodePtr {
+        let start = edit.map_offset(self.range.start(), Bias::Right);
+        let end = edit.map_offset(self.range.end(), Bias::Left).max(start);
+        NodePtr { ty: self.ty, range: TextRange::from_to(start, end) }
+    }
+
+    /// Finds the innermost node of the type with exactly this range.
+    pub fn resolve<'f>(&self, file: &'f File) -> Option<Node<'f>> {
+        let root = file.root();
+        if !self.range.is_subrange_of(root.range()) {
+            return None;
+        }
+        ancestors(find_covering_node(root, self.range))
+            .take_while(|node| node.range() == self.range)
+            .find(|node| node.ty() == self.ty)
+    }
+}
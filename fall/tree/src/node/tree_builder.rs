@@ -1,101 +1,109 @@
-use crate::{NodeType, TextUnit, TextRange, tu};
+use std::collections::HashMap;
+
+use crate::{NodeType, TextUnit, TextRange, TextEdit, Expected, tu};
+use crate::node::File;
+use crate::node::green::{GreenNode, Reuse};
 
 pub struct TreeBuilder {
-    nodes: Vec<NodeData>,
-    in_progress: Vec<NodeId>,
+    /// Finished children of the nodes which are being built.
+    children: Vec<GreenNode>,
+    /// Types, errors, first children and start offsets of the nodes which are being built.
+    in_progress: Vec<(NodeType, Option<String>, usize, TextUnit)>,
     pos: TextUnit,
+    leaves: Leaves,
+    reuse: Option<Reuse>,
+    expected: Option<Expected>,
 }
 
+/// Leaves are shared by type and length, within a file and with
+/// the next versions of the file.
+pub(crate) type Leaves = HashMap<(NodeType, TextUnit, bool), GreenNode>;
+
 impl TreeBuilder {
     pub fn leaf(&mut self, ty: NodeType, len: TextUnit) {
-        let leaf = NodeData {
-            ty,
-            parent: None,
-            children: Vec::new(),
-            range: TextRange::from_len(self.pos, len),
-        };
-        self.pos += len;
-        let id = self.push_child(leaf);
-        self.add_len(id);
+        self.push_leaf(ty, len, false)
     }
 
-    pub fn start_internal(&mut self, ty: NodeType) {
-        let node = NodeData {
-            ty,
-            parent: None,
-            children: Vec::new(),
-            range: TextRange::from_len(self.pos, tu(0)),
-        };
-        let id = if self.in_progress.is_empty() {
-            self.new_node(node)
-        } else {
-            self.push_child(node)
-        };
-        self.in_progress.push(id)
+    /// Adds a zero-width leaf for a token which is absent from the text.
+    pub fn missing(&mut self, ty: NodeType) {
+        self.push_leaf(ty, tu(0), true)
     }
 
-    pub fn finish_internal(&mut self) {
-        let id = self.in_progress.pop().unwrap();
-        if !self.in_progress.is_empty() {
-            self.add_len(id);
-        }
+    pub fn start_internal(&mut self, ty: NodeType) {
+        self.in_progress.push((ty, None, self.children.len(), self.pos))
     }
 
-    pub(crate) fn new() -> TreeBuilder {
-        TreeBuilder {
-            nodes: Vec::new(),
-            in_progress: Vec::new(),
-            pos: tu(0),
+    /// Attaches an error message to the node which is being built.
+    pub fn error(&mut self, message: String) {
+        let error = &mut self.in_progress.last_mut().unwrap().1;
+        if error.is_none() {
+            *error = Some(message)
         }
     }
 
-    pub(crate) fn finish(self) -> Vec<NodeData> {
-        assert!(self.in_progress.is_empty());
-        self.nodes
+    pub fn set_expected(&mut self, expected: Expected) {
+        self.expected = Some(expected)
     }
 
-    fn new_node(&mut self, data: NodeData) -> NodeId {
-        let id = NodeId(self.nodes.len() as u32);
-        self.nodes.push(data);
-        id
+    pub fn finish_internal(&mut self) {
+        let (ty, error, first_child, start) = self.in_progress.pop().unwrap();
+        let range = TextRange::from_to(start, self.pos);
+        let children = &self.children[first_child..];
+        let old = self.reuse.as_mut().and_then(|reuse| {
+            reuse.find(range, ty, children, error.as_deref())
+        });
+        let node = match old {
+            Some(node) => {
+                self.children.truncate(first_child);
+                node
+            }
+            None => GreenNode::new(ty, self.children.split_off(first_child), error),
+        };
+        self.children.push(node)
     }
 
-    fn push_child(&mut self, mut child: NodeData) -> NodeId {
-        child.parent = Some(self.current_id());
-        let id = self.new_node(child);
-        self.current().children.push(id);
-        id
+    pub(crate) fn new() -> TreeBuilder {
+        TreeBuilder {
+            children: Vec::new(),
+            in_progress: Vec::new(),
+            pos: tu(0),
+            leaves: HashMap::new(),
+            reuse: None,
+            expected: None,
+        }
     }
 
-    fn add_len(&mut self, child: NodeId) {
-        let range = self.nodes[child.0 as usize].range;
-        grow(&mut self.current().range, range);
+    /// Builds a tree which shares the unchanged nodes with the `old` one.
+    pub(crate) fn reusing(old: &File, edit: &TextEdit) -> TreeBuilder {
+        TreeBuilder {
+            leaves: old.imp.leaves().clone(),
+            reuse: Some(Reuse::new(old.imp.green().clone(), edit)),
+            ..TreeBuilder::new()
+        }
     }
 
-    fn current_id(&self) -> NodeId {
-        *self.in_progress.last().unwrap()
+    pub(crate) fn finish(mut self) -> FinishedTree {
+        assert!(self.in_progress.is_empty());
+        assert_eq!(self.children.len(), 1);
+        FinishedTree {
+            root: self.children.pop().unwrap(),
+            leaves: self.leaves,
+            expected: self.expected,
+        }
     }
 
-    fn current(&mut self) -> &mut NodeData {
-        let NodeId(idx) = self.current_id();
-        &mut self.nodes[idx as usize]
+    fn push_leaf(&mut self, ty: NodeType, len: TextUnit, missing: bool) {
+        assert!(!self.in_progress.is_empty());
+        let leaf = self.leaves.entry((ty, len, missing))
+            .or_insert_with(|| GreenNode::leaf(ty, len, missing))
+            .clone();
+        self.pos += len;
+        self.children.push(leaf)
     }
 }
 
-#[derive(Clone, Copy, PartialEq, Eq, Hash)]
-pub(crate) struct NodeId(pub u32);
-
-
-pub(crate) struct NodeData {
-    pub(crate) ty: NodeType,
-    pub(crate) parent: Option<NodeId>,
-    pub(crate) children: Vec<NodeId>,
-    pub(crate) range: TextRange,
-}
-
-fn grow(left: &mut TextRange, right: TextRange) {
-    let start = left.start();
-    assert_eq!(left.end(), right.start());
-    let end = right.end();
-    *left = TextRange::from_to(start, end)
+pub(crate) struct FinishedTree {
+    pub(crate) root: GreenNode,
+    pub(crate) leaves: Leaves,
+    pub(crate) expected: Option<Expected>,
 }
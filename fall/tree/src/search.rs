@@ -10,7 +10,10 @@ pub fn children_of_type<'f>(node: Node<'f>, ty: NodeType) -> Box<dyn Iterator<It
 }
 
 pub fn subtree<'f>(node: Node<'f>) -> Box<dyn Iterator<Item=Node<'f>> + 'f> {
-    Box::new(node.children().flat_map(subtree).chain(::std::iter::once(node)))
+    Box::new(traversal::walk(node).filter_map(|event| match event {
+        traversal::WalkEvent::Exit(node) => Some(node),
+        traversal::WalkEvent::Enter(_) => None,
+    }))
 }
 
 pub fn descendants_of_type(node: Node, ty: NodeType) -> Vec<Node> {
@@ -58,26 +61,28 @@ pub fn find_leaf_at_offset(node: Node, offset: TextUnit) -> LeafAtOffset {
         return LeafAtOffset::None;
     }
 
-    if is_leaf(node) {
-        return LeafAtOffset::Single(node);
-    }
-
-    let mut children = node.children()
-        .filter(|child| !child.range().is_empty())
-        .filter(|child| child.range().contains_offset_nonstrict(offset));
+    let mut node = node;
+    loop {
+        if is_leaf(node) {
+            return LeafAtOffset::Single(node);
+        }
 
-    let left = children.next().unwrap();
-    let right = children.next();
-    assert!(children.next().is_none());
-    return if let Some(right) = right {
-        match (find_leaf_at_offset(left, offset), find_leaf_at_offset(right, offset)) {
-            (LeafAtOffset::Single(left), LeafAtOffset::Single(right)) =>
-                LeafAtOffset::Between(left, right),
-            _ => unreachable!()
+        let mut children = node.children()
+            .filter(|child| !child.range().is_empty())
+            .filter(|child| child.range().contains_offset_nonstrict(offset));
+
+        let left = children.next().unwrap();
+        let right = children.next();
+        assert!(children.next().is_none());
+        if let Some(right) = right {
+            return match (find_leaf_at_offset(left, offset), find_leaf_at_offset(right, offset)) {
+                (LeafAtOffset::Single(left), LeafAtOffset::Single(right)) =>
+                    LeafAtOffset::Between(left, right),
+                _ => unreachable!()
+            };
         }
-    } else {
-        find_leaf_at_offset(left, offset)
-    };
+        node = left;
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -182,17 +187,61 @@ pub mod ast {
 }
 
 pub mod traversal {
-    use crate::{Node};
+    use crate::Node;
+    use crate::node::NodeChildren;
+
+    #[derive(Clone, Copy, Debug)]
+    pub enum WalkEvent<'f> {
+        Enter(Node<'f>),
+        Exit(Node<'f>),
+    }
+
+    /// Iterates over the subtree, reporting when each node is entered and exited.
+    /// Uses an explicit stack, so arbitrary deep trees are fine.
+    pub fn walk(node: Node) -> Walk {
+        Walk { root: Some(node), stack: Vec::new() }
+    }
+
+    pub struct Walk<'f> {
+        root: Option<Node<'f>>,
+        stack: Vec<(Node<'f>, NodeChildren<'f>)>,
+    }
+
+    impl<'f> Iterator for Walk<'f> {
+        type Item = WalkEvent<'f>;
+
+        fn next(&mut self) -> Option<WalkEvent<'f>> {
+            if let Some(root) = self.root.take() {
+                self.stack.push((root, root.children()));
+                return Some(WalkEvent::Enter(root));
+            }
+            let next_child = self.stack.last_mut()?.1.next();
+            match next_child {
+                Some(child) => {
+                    self.stack.push((child, child.children()));
+                    Some(WalkEvent::Enter(child))
+                }
+                None => {
+                    let (node, _) = self.stack.pop().unwrap();
+                    Some(WalkEvent::Exit(node))
+                }
+            }
+        }
+    }
 
-    pub fn bottom_up<'f, F: FnMut(Node<'f>)>(node: Node<'f>, mut f: F)
-    {
-        go(node, &mut f);
+    pub fn top_down<'f, F: FnMut(Node<'f>)>(node: Node<'f>, mut f: F) {
+        for event in walk(node) {
+            if let WalkEvent::Enter(node) = event {
+                f(node)
+            }
+        }
+    }
 
-        fn go<'f, F: FnMut(Node<'f>)>(node: Node<'f>, f: &mut F) {
-            for child in node.children() {
-                go(child, f)
+    pub fn bottom_up<'f, F: FnMut(Node<'f>)>(node: Node<'f>, mut f: F) {
+        for event in walk(node) {
+            if let WalkEvent::Exit(node) = event {
+                f(node)
             }
-            f(node);
         }
     }
 }
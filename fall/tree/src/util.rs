@@ -1,4 +1,5 @@
 use crate::{File, Node, Language};
+use crate::search::traversal::{self, WalkEvent};
 use std::fmt::Write;
 
 pub fn dump_file(f: &File) -> String {
@@ -9,42 +10,52 @@ pub fn dump_file_ws(f: &File) -> String {
     dump(f.language(), f.root(), &f.text().to_cow(), true)
 }
 
-pub fn walk_tree<F: FnMut(Node)>(node: Node, mut f: F) {
-    go(node, &mut f);
-
-    fn go<F: FnMut(Node)>(node: Node, f: &mut F) {
-        f(node);
-        for child in node.children() {
-            go(child, f)
-        }
-    }
+pub fn walk_tree<F: FnMut(Node)>(node: Node, f: F) {
+    traversal::top_down(node, f)
 }
 
 
 fn dump(lang: &Language, root: Node, text: &str, include_whitespace: bool) -> String {
     let mut buf = String::new();
-    go(lang, 0, root, text, &mut buf, include_whitespace);
-    return buf;
-
-    fn go(lang: &Language, level: usize, n: Node, text: &str, buf: &mut String, include_whitespace: bool) {
+    let mut level = 0;
+    // A whitespace node, whose subtree is not printed.
+    let mut skipped: Option<Node> = None;
+    for event in traversal::walk(root) {
+        let n = match event {
+            WalkEvent::Enter(n) => n,
+            WalkEvent::Exit(n) => {
+                if skipped == Some(n) {
+                    skipped = None;
+                } else if skipped.is_none() {
+                    level -= 1;
+                }
+                continue;
+            }
+        };
+        if skipped.is_some() {
+            continue;
+        }
         if lang.node_type_info(n.ty()).whitespace_like && !include_whitespace {
-            return
+            skipped = Some(n);
+            continue;
         }
 
         for _ in 0..level {
             buf.push_str("  ")
         }
+        level += 1;
 
         let ty_name = lang.node_type_info(n.ty()).name;
-        if n.children().next().is_none() {
+        if n.is_missing() {
+            write!(buf, "MISSING {}\n", ty_name)
+                .unwrap();
+        } else if n.children().next().is_none() {
             write!(buf, "{} {:?}\n", ty_name, &text[n.range()])
                 .unwrap();
         } else {
             write!(buf, "{}\n", ty_name)
                 .unwrap();
-            for child in n.children() {
-                go(lang, level + 1, child, text, buf, include_whitespace);
-            }
         }
     }
+    buf
 }
@@ -10,7 +10,7 @@ mod db;
 mod query;
 
 use self::diagnostics::DiagnosticSink;
-pub use self::query::{CallKind, RefKind, PratVariant, PrattOp, MethodKind, ChildKind, Arity};
+pub use self::query::{CallKind, RefKind, PratVariant, PrattOp, Assoc, Mixfix, MethodKind, ChildKind, Arity};
 
 
 pub struct Analysis<'f> {
@@ -61,6 +61,7 @@ impl<'f> Analysis<'f> {
                 .visit::<AstNodeDef, _>(|rule, _| { self.db.get(query::AstNodeTraits(rule)); })
         );
         self.db.get(query::UnusedRules);
+        self.db.get(query::AllSynRules);
         self.db.get(query::AllLexRules);
 
         let mut result = self.db.diagnostics.lock().unwrap().clone();
@@ -161,6 +162,44 @@ W baz: Unused rule
 W dupe: Unused rule");
 }
 
+#[test]
+fn test_inject_diagnostics() {
+    check_diagnostics(r"
+       pub rule file { foo bar baz }
+       #[inject(lang)] pub rule foo { }
+       #[inject(lang, range)] rule bar { }
+       #[inject(lang, range)] pub rule baz { }
+    ", "\
+E inject(lang): Injection requires a language and a range function
+E inject(lang, range): Only public rules can be injected");
+}
+
+#[test]
+fn test_missing_tokens_diagnostics() {
+    check_diagnostics(r"
+       #[missing_tokens] pub rule file { foo bar }
+       #[missing_tokens] pub rule foo { }
+       pub rule bar { }
+    ", "\
+E missing_tokens: Missing tokens can be enabled only on the first rule");
+}
+
+#[test]
+fn test_left_recursion_diagnostics() {
+    check_diagnostics(r#"
+       tokenizer {
+           plus '+'
+           coloncolon '::'
+           int r"\d+"
+           ident r"\w+"
+       }
+       pub rule file { expr path }
+       rule expr { sum_expr | int }
+       pub rule sum_expr { expr '+' int }
+       pub rule path { path '::' ident | ident }
+    "#, "");
+}
+
 #[test]
 fn test_lex_rule_diagnostics() {
     check_diagnostics(r"
@@ -173,6 +212,44 @@ E class 'trait': Duplicate token
 ");
 }
 
+#[test]
+fn test_lex_mode_diagnostics() {
+    check_diagnostics(r"
+       tokenizer {
+           #[push(string)] quote 'a'
+           #[push(strnig)] other 'b'
+           mode string {
+               #[pop] string_end 'a'
+           }
+           mode string { }
+           mode default { }
+       }
+    ", "\
+E strnig: Unknown mode
+E string: Duplicate mode
+E default: Duplicate mode
+");
+}
+
+#[test]
+fn test_layout_diagnostics() {
+    check_diagnostics(r"
+       tokenizer {
+           #[layout(newline)] newline
+           #[layout(indent)] indent 'x'
+           #[layout(indent)] indent2
+           #[layout(outdent)] dedent
+           ident
+       }
+    ", "\
+E #[layout(newline)] newline: Layout requires newline, indent and dedent tokens
+E #[layout(indent)] indent 'x': Layout tokens have no pattern
+E indent: Duplicate layout token
+E outdent: Unknown layout token
+E ident: Missing token pattern
+");
+}
+
 #[test]
 fn test_ast_diagnostics() {
     check_diagnostics(r"
@@ -13,11 +13,56 @@ impl<'f> db::OnceQExecutor<'f> for super::AllLexRules {
         let mut result = HashMap::new();
         let mut tys = HashSet::new();
         if let Some(tokenizer) = file.tokenizer_def() {
-            for rule in tokenizer.lex_rules() {
+            let mut modes = Vec::new();
+            for mode in tokenizer.modes() {
+                if mode.name() == "default" || modes.contains(&mode.name()) {
+                    d.error(mode.name_ident(), "Duplicate mode");
+                }
+                modes.push(mode.name());
+            }
+
+            let mut layout = Vec::new();
+            for rule in tokenizer.all_lex_rules() {
                 result.insert(rule.token_name(), rule);
                 if !tys.insert(rule.node_type()) {
                     d.error(rule.node(), "Duplicate token");
                 }
+                if let Some(push) = rule.push_mode() {
+                    if push != "default" && !modes.contains(&push) {
+                        let value = rule.attributes()
+                            .and_then(|attrs| attrs.find("push"))
+                            .and_then(|attr| attr.value())
+                            .unwrap();
+                        d.error(value.node(), "Unknown mode");
+                    }
+                }
+                match rule.layout() {
+                    Some(kind) => {
+                        let value = rule.attributes()
+                            .and_then(|attrs| attrs.find("layout"))
+                            .and_then(|attr| attr.value())
+                            .unwrap();
+                        if !["newline", "indent", "dedent"].contains(&kind.to_cow().as_ref()) {
+                            d.error(value.node(), "Unknown layout token");
+                        } else if layout.iter().any(|&(k, _)| k == kind) {
+                            d.error(value.node(), "Duplicate layout token");
+                        }
+                        if rule.token_re().is_some() {
+                            d.error(rule.node(), "Layout tokens have no pattern");
+                        }
+                        layout.push((kind, rule));
+                    }
+                    None => if rule.token_re().is_none() {
+                        d.error(rule.node(), "Missing token pattern");
+                    }
+                }
+            }
+            if let Some(&(_, first)) = layout.first() {
+                let complete = ["newline", "indent", "dedent"].iter()
+                    .all(|&kind| layout.iter().any(|&(k, _)| k == kind));
+                if !complete {
+                    d.error(first.node(), "Layout requires newline, indent and dedent tokens");
+                }
             }
         }
 
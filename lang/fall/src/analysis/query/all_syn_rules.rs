@@ -1,7 +1,7 @@
 use std::collections::hash_map::{self, HashMap};
 use std::sync::Arc;
 
-use fall_tree::Text;
+use fall_tree::{Text, AstNode};
 use crate::analysis::diagnostics::DiagnosticSink;
 use crate::analysis::db::{self, DB};
 use crate::syntax::{SynRule};
@@ -11,7 +11,7 @@ impl<'f> db::OnceQExecutor<'f> for super::AllSynRules {
         let file = db.file();
 
         let mut result = HashMap::new();
-        for rule in file.syn_rules() {
+        for (idx, rule) in file.syn_rules().enumerate() {
             if let Some(ident) = rule.name_ident() {
                 match result.entry(ident.text()) {
                     hash_map::Entry::Vacant(vacant) => {
@@ -22,6 +22,18 @@ impl<'f> db::OnceQExecutor<'f> for super::AllSynRules {
                     }
                 }
             }
+            if let Some(attr) = rule.inject_attr() {
+                if attr.values().count() != 2 {
+                    d.error(attr.node(), "Injection requires a language and a range function");
+                } else if rule.ty_name().is_none() {
+                    d.error(attr.node(), "Only public rules can be injected");
+                }
+            }
+            if idx > 0 {
+                if let Some(attr) = rule.attributes().and_then(|attrs| attrs.find("missing_tokens")) {
+                    d.error(attr.node(), "Missing tokens can be enabled only on the first rule");
+                }
+            }
         }
 
         Arc::new(result)
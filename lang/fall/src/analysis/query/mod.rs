@@ -4,7 +4,7 @@ use std::sync::Arc;
 use fall_tree::Text;
 
 use super::db::Query;
-use crate::syntax::{SynRule, LexRule, RefExpr, Parameter, Expr, CallExpr, MethodDef, AstNodeDef, AstClassDef, AstTraitDef};
+use crate::syntax::{SynRule, LexRule, RefExpr, Parameter, Expr, SeqExpr, CallExpr, MethodDef, AstNodeDef, AstClassDef, AstTraitDef};
 
 #[derive(Eq, PartialEq, Hash, Clone, Debug)]
 pub(crate) struct AllLexRules;
@@ -83,6 +83,7 @@ pub enum CallKind<'f> {
     RuleCall(SynRule<'f>, Arc<Vec<(Parameter<'f>, Expr<'f>)>>),
     PrevIs(Arc<Vec<SynRule<'f>>>),
     Inject(Expr<'f>, Expr<'f>),
+    Pred(Text<'f>),
 }
 
 #[derive(Eq, PartialEq, Hash, Clone, Debug)]
@@ -97,9 +98,10 @@ impl<'f> Query<'f> for ResolveCall<'f> {
 #[derive(Copy, Clone)]
 pub enum PratVariant<'f> {
     Atom(Expr<'f>),
-    Bin(PrattOp<'f>),
+    Bin(PrattOp<'f>, Assoc),
     Postfix(PrattOp<'f>),
     Prefix(PrattOp<'f>),
+    Mixfix(Mixfix<'f>),
 }
 
 #[derive(Copy, Clone)]
@@ -108,6 +110,38 @@ pub struct PrattOp<'f> {
     pub priority: u32
 }
 
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Assoc {
+    Left,
+    Right,
+    NonAssoc,
+}
+
+/// An operator spelled out as a sequence, like `expr '?' expr ':' expr`.
+/// The leading and trailing references to the pratt rule are the operands.
+#[derive(Copy, Clone)]
+pub struct Mixfix<'f> {
+    pub seq: SeqExpr<'f>,
+    pub lhs: bool,
+    pub rhs: bool,
+    pub priority: u32,
+    pub assoc: Assoc,
+}
+
+impl<'f> Mixfix<'f> {
+    /// Parts of the sequence between the operands.
+    pub fn op_parts(&self) -> Vec<Expr<'f>> {
+        let mut parts: Vec<Expr<'f>> = self.seq.parts().collect();
+        if self.rhs {
+            parts.pop();
+        }
+        if self.lhs {
+            parts.remove(0);
+        }
+        parts
+    }
+}
+
 
 #[derive(Eq, PartialEq, Hash, Clone, Debug)]
 pub(crate) struct ResolvePrattVariant<'f>(pub SynRule<'f>);
@@ -40,6 +40,13 @@ impl<'f> db::OnceQExecutor<'f> for super::ResolveCall<'f> {
         };
         let mut not = |d: &mut DiagnosticSink| one(d, &mut |_, arg| Some(CallKind::Not(arg)));
         let mut is_in = |d: &mut DiagnosticSink| one(d, &mut |d, _| resolve_context(db, d, call).map(CallKind::IsIn));
+        let mut pred = |d: &mut DiagnosticSink| one(d, &mut |d, arg| match call.predicate_name() {
+            Some(name) => Some(CallKind::Pred(name)),
+            None => {
+                d.error(arg.node(), "Predicate should be a function name");
+                None
+            }
+        });
 
         let two = |d: &mut DiagnosticSink, kind: &mut dyn FnMut(&mut DiagnosticSink, Expr<'f>, Expr<'f>) -> Option<CallKind<'f>>| {
             expect_args(d, 2);
@@ -91,7 +98,8 @@ impl<'f> db::OnceQExecutor<'f> for super::ResolveCall<'f> {
             ("inject", &mut inject),
             ("enter", &mut enter),
             ("exit", &mut exit),
-            ("prev_is", &mut prev_is)
+            ("prev_is", &mut prev_is),
+            ("pred", &mut pred)
         ];
 
         for (name, kind) in build_in.into_iter() {
@@ -214,6 +222,15 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pred() {
+        check_resolved("rule foo { <^pred is_type> }", r#"Pred("is_type")"#);
+        check_diagnostics(
+            "rule foo { <pred 'is_type'> }",
+            "E 'is_type': Predicate should be a function name",
+        );
+    }
+
     #[test]
     fn check_prev_is() {
         check_resolved(
@@ -35,7 +35,7 @@ fn child_kind<'f>(file: FallFile<'f>, selector: AstSelector<'f>) -> Option<Child
     if let Some(class) = ast_def.ast_classes().find(|c| c.name() == selector.child()) {
         return Some(ChildKind::AstClass(class));
     }
-    if let Some(lex_rule) = file.tokenizer_def().and_then(|td| td.lex_rules().find(|r| r.node_type() == selector.child())) {
+    if let Some(lex_rule) = file.tokenizer_def().and_then(|td| td.all_lex_rules().find(|r| r.node_type() == selector.child())) {
         return Some(ChildKind::Token(lex_rule));
     }
     None
@@ -1,32 +1,49 @@
 use itertools::Itertools;
-use super::{PratVariant, PrattOp};
+use super::{PratVariant, PrattOp, Assoc, Mixfix, RefKind};
 use fall_tree::AstNode;
 use crate::analysis::diagnostics::DiagnosticSink;
 use crate::analysis::db::{self, DB};
+use crate::analysis::query;
 use crate::syntax::Expr;
 
 impl<'f> db::OnceQExecutor<'f> for super::ResolvePrattVariant<'f> {
-    fn execute(self, _: &DB<'f>, d: &mut DiagnosticSink) -> Option<PratVariant<'f>> {
+    fn execute(self, db: &DB<'f>, d: &mut DiagnosticSink) -> Option<PratVariant<'f>> {
         let rule = self.0;
         let name_ident = match rule.name_ident() {
             None => return None,
             Some(n) => n,
         };
-        let kinds = ["atom", "postfix", "prefix", "bin"];
+        let kinds = ["atom", "postfix", "prefix", "bin", "mixfix"];
 
-        let (kind, priority) = {
+        let (kind, attr) = {
             let attrs = match rule.attributes() {
                 None => return None,
                 Some(attrs) => attrs,
             };
             match kinds.iter()
                 .filter_map(|&k| attrs.find(k).map(|a| (k, a)))
-                .next()
-                .map(|(k, a)| (k, a.u32_value())) {
+                .next() {
                 Some(a) => a,
                 None => return None,
             }
         };
+        let priority = attr.u32_value();
+        let assoc = match attr.values().nth(1) {
+            None => None,
+            Some(value) => match value.node().text().to_cow().as_ref() {
+                _ if kind != "bin" && kind != "mixfix" => {
+                    d.error(value.node(), "Only binary and mixfix rules have associativity");
+                    None
+                }
+                "left" => Some(Assoc::Left),
+                "right" => Some(Assoc::Right),
+                "nonassoc" => Some(Assoc::NonAssoc),
+                _ => {
+                    d.error(value.node(), "Unknown associativity, expected left, right or nonassoc");
+                    None
+                }
+            }
+        };
 
         if kind == "atom" {
             if priority.is_some() {
@@ -35,10 +52,10 @@ impl<'f> db::OnceQExecutor<'f> for super::ResolvePrattVariant<'f> {
             return Some(PratVariant::Atom(rule.body()));
         }
 
-        let args = match rule.body() {
+        let seq = match rule.body() {
             Expr::BlockExpr(block) => match block.alts().collect_tuple() {
                 Some((alt, )) => match alt {
-                    Expr::SeqExpr(args) => args.parts(),
+                    Expr::SeqExpr(seq) => seq,
                     _ => return None,
                 },
                 None => {
@@ -51,6 +68,7 @@ impl<'f> db::OnceQExecutor<'f> for super::ResolvePrattVariant<'f> {
             },
             _ => return None,
         };
+        let args = seq.parts();
 
         let result = match kind {
             "postfix" => {
@@ -84,7 +102,8 @@ impl<'f> db::OnceQExecutor<'f> for super::ResolvePrattVariant<'f> {
             "bin" => {
                 match (args.collect_tuple(), priority) {
                     (Some((_lhs, op, _rhs)), Some(priority)) => PratVariant::Bin(
-                        PrattOp { op, priority }
+                        PrattOp { op, priority },
+                        assoc.unwrap_or(Assoc::Left),
                     ),
                     (None, _) => {
                         d.error(
@@ -103,6 +122,53 @@ impl<'f> db::OnceQExecutor<'f> for super::ResolvePrattVariant<'f> {
                     }
                 }
             }
+            "mixfix" => {
+                let is_operand = |part: Expr<'f>| match part {
+                    Expr::RefExpr(ref_) => match db.get(query::ResolveRefExpr(ref_)) {
+                        Some(RefKind::RuleReference(rule)) => rule.is_pratt(),
+                        _ => false,
+                    },
+                    _ => false,
+                };
+                let parts = args.collect::<Vec<_>>();
+                let lhs = parts.first().map_or(false, |&p| is_operand(p));
+                let rhs = parts.len() > 1 && is_operand(*parts.last().unwrap());
+                if !lhs && !rhs {
+                    d.error(
+                        rule.body().node(),
+                        "Mixfix rule requires a leading or a trailing operand"
+                    );
+                    return None;
+                }
+                if parts.len() == lhs as usize + rhs as usize {
+                    d.error(rule.body().node(), "Mixfix rule requires an operator");
+                    return None;
+                }
+                if assoc.is_some() && !(lhs && rhs) {
+                    d.error(
+                        attr.node(),
+                        "Only rules with both operands have associativity"
+                    );
+                }
+                let priority = match priority {
+                    Some(priority) => priority,
+                    None if !rhs => 999,
+                    None => {
+                        d.error(
+                            name_ident,
+                            "Mixfix rule with a trailing operand requires explicit priority"
+                        );
+                        return None;
+                    }
+                };
+                PratVariant::Mixfix(Mixfix {
+                    seq,
+                    lhs,
+                    rhs,
+                    priority,
+                    assoc: assoc.unwrap_or(Assoc::Left),
+                })
+            }
             _ => unreachable!()
         };
         Some(result)
@@ -157,4 +223,33 @@ mod tests {
             "E foo: Binary expression requires explicit priority"
         );
     }
+
+    #[test]
+    fn pratt_assoc() {
+        check_diagnostics(
+            "#[bin(1, up)] rule foo { <eof> <eof> <eof> }",
+            "E up: Unknown associativity, expected left, right or nonassoc"
+        );
+        check_diagnostics(
+            "#[postfix(1, right)] rule foo { <eof> <eof> }",
+            "E right: Only binary and mixfix rules have associativity"
+        );
+    }
+
+    #[test]
+    fn pratt_mixfix() {
+        check_diagnostics(r"
+            #[pratt] rule expr { a | b | c | d | e }
+            #[mixfix(1, right)] pub rule a { expr <eof> expr <eof> expr }
+            #[mixfix(1, right)] pub rule b { expr <eof> }
+            #[mixfix] pub rule c { <eof> expr }
+            #[mixfix(1)] pub rule d { <eof> <eof> }
+            #[mixfix(1)] pub rule e { expr expr }
+        ", "\
+E mixfix(1, right): Only rules with both operands have associativity
+E c: Mixfix rule with a trailing operand requires explicit priority
+E { <eof> <eof> }: Mixfix rule requires a leading or a trailing operand
+E { expr expr }: Mixfix rule requires an operator"
+        );
+    }
 }
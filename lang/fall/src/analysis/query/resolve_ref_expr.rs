@@ -28,7 +28,8 @@ impl<'f> db::OnceQExecutor<'f> for super::ResolveRefExpr<'f> {
         }
         let parent = ref_.node().parent().unwrap();
         if let Some(call) = CallExpr::wrap(parent) {
-            if call.context_name().is_some() && call.args().next().map(|a| a.node()) == Some(ref_.node()) {
+            let is_name = call.context_name().is_some() || call.fn_name() == "pred";
+            if is_name && call.args().next().map(|a| a.node()) == Some(ref_.node()) {
                 return None;
             }
         }
@@ -3,7 +3,7 @@ use crate::analysis::Analysis;
 use fall_tree::{File, TextEdit, TextRange, TextUnit, AstNode};
 use fall_tree::search::find_leaf_at_offset;
 use fall_tree::search::ast;
-use fall_editor::{EditorFileImpl, gen_syntax_tree, FileStructureNode, Diagnostic};
+use fall_editor::{EditorFileImpl, gen_syntax_tree, syntax_errors, FileStructureNode, Diagnostic};
 use fall_editor::hl::Highlights;
 use fall_editor::actions::ActionResult;
 use crate::syntax::lang_fall;
@@ -44,7 +44,9 @@ impl EditorFileImpl for FileWithAnalysis {
     }
 
     fn diagnostics(&self) -> Vec<Diagnostic> {
-        self.record_analysis("diagnostics", |a| a.collect_all_diagnostics())
+        let mut result = syntax_errors(self.file());
+        result.extend(self.record_analysis("diagnostics", |a| a.collect_all_diagnostics()));
+        result
     }
 
     fn context_actions(&self, range: TextRange) -> Vec<&'static str> {
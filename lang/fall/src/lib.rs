@@ -14,7 +14,7 @@ use fall_tree::{AstNode, File};
 mod analysis;
 
 
-pub use self::analysis::{Analysis, FileWithAnalysis, CallKind, RefKind, PratVariant, PrattOp,
+pub use self::analysis::{Analysis, FileWithAnalysis, CallKind, RefKind, PratVariant, PrattOp, Assoc, Mixfix,
                          MethodKind, Arity, ChildKind};
 
 pub fn parse<S: Into<String>>(text: S) -> File {
@@ -2,11 +2,48 @@ use fall_tree::{Text, TextRange, AstNode};
 use fall_tree::search::{children_of_type, child_of_type_exn, child_of_type};
 
 use crate::{STRING, IDENT, SIMPLE_STRING, PUB,
-       LexRule, SynRule, VerbatimDef,
+       TokenizerDef, LexMode, LexRule, SynRule, VerbatimDef,
        RefExpr, AstClassDef, Attributes, Attribute, TestDef,
-       CallExpr};
+       CallExpr, Expr};
+
+impl<'f> TokenizerDef<'f> {
+    /// Rules of all modes, in the order of declaration.
+    pub fn all_lex_rules(&self) -> Box<dyn Iterator<Item=LexRule<'f>> + 'f> {
+        Box::new(self.node().children().flat_map(|child| {
+            match LexMode::wrap(child) {
+                Some(mode) => mode.lex_rules().collect(),
+                None => LexRule::wrap(child).into_iter().collect::<Vec<_>>(),
+            }
+        }))
+    }
+}
 
 impl<'f> LexRule<'f> {
+    /// The mode the rule belongs to, `None` for the `default` mode.
+    pub fn mode(&self) -> Option<LexMode<'f>> {
+        self.node().parent().and_then(LexMode::wrap)
+    }
+
+    pub fn push_mode(&self) -> Option<Text<'f>> {
+        self.attributes()
+            .and_then(|attrs| attrs.find("push"))
+            .and_then(|attr| attr.text_value())
+    }
+
+    pub fn is_pop(&self) -> bool {
+        if let Some(attrs) = self.attributes() {
+            return attrs.has_attribute("pop");
+        }
+        false
+    }
+
+    /// The kind of a layout token, `indent`, `dedent` or `newline`.
+    pub fn layout(&self) -> Option<Text<'f>> {
+        self.attributes()
+            .and_then(|attrs| attrs.find("layout"))
+            .and_then(|attr| attr.text_value())
+    }
+
     pub fn token_re(&self) -> Option<String> {
         let raw = match self.re() {
             Some(raw) => raw,
@@ -86,6 +123,10 @@ impl<'f> SynRule<'f> {
 
     pub fn is_cached(&self) -> bool { self.has_attribute("cached") }
 
+    pub fn is_missing_tokens(&self) -> bool { self.has_attribute("missing_tokens") }
+
+    pub fn is_native(&self) -> bool { self.has_attribute("native") }
+
     fn has_attribute(&self, attribute: &str) -> bool {
         if let Some(attrs) = self.attributes() {
             attrs.has_attribute(attribute)
@@ -98,6 +139,12 @@ impl<'f> SynRule<'f> {
         self.attributes().and_then(|attrs| attrs.find("type"))
     }
 
+    /// `#[inject(language, range)]`: the node's contents are parsed
+    /// with the language returned by the `language` function.
+    pub fn inject_attr(&self) -> Option<Attribute<'f>> {
+        self.attributes().and_then(|attrs| attrs.find("inject"))
+    }
+
     pub fn ty_name(&self) -> Option<Text<'f>> {
         if !self.is_pub() || self.is_pratt() {
             return None;
@@ -149,6 +196,17 @@ impl<'f> CallExpr<'f> {
             .and_then(|arg| child_of_type(arg.node(), SIMPLE_STRING))
             .map(|ctx| lit_body(ctx.text()));
     }
+
+    pub fn predicate_name(&self) -> Option<Text<'f>> {
+        if self.fn_name() != "pred" {
+            return None;
+        }
+        match self.args().next() {
+            Some(Expr::RefExpr(ref_)) if child_of_type(ref_.node(), IDENT).is_some() =>
+                Some(ref_.reference_name()),
+            _ => None,
+        }
+    }
 }
 
 impl<'f> Attributes<'f> {
@@ -11,83 +11,344 @@ pub const VERBATIM: rt::NodeType = rt::NodeType(107);
 pub const AST: rt::NodeType = rt::NodeType(108);
 pub const PUB: rt::NodeType = rt::NodeType(109);
 pub const TEST: rt::NodeType = rt::NodeType(110);
-pub const EQ: rt::NodeType = rt::NodeType(111);
-pub const PIPE: rt::NodeType = rt::NodeType(112);
-pub const STAR: rt::NodeType = rt::NodeType(113);
-pub const QUESTION: rt::NodeType = rt::NodeType(114);
-pub const DOT: rt::NodeType = rt::NodeType(115);
-pub const COMMA: rt::NodeType = rt::NodeType(116);
-pub const COLON: rt::NodeType = rt::NodeType(117);
-pub const HASH: rt::NodeType = rt::NodeType(118);
-pub const L_CURLY: rt::NodeType = rt::NodeType(119);
-pub const R_CURLY: rt::NodeType = rt::NodeType(120);
-pub const L_SQUARE: rt::NodeType = rt::NodeType(121);
-pub const R_SQUARE: rt::NodeType = rt::NodeType(122);
-pub const L_ANGLE: rt::NodeType = rt::NodeType(123);
-pub const R_ANGLE: rt::NodeType = rt::NodeType(124);
-pub const L_PAREN: rt::NodeType = rt::NodeType(125);
-pub const R_PAREN: rt::NodeType = rt::NodeType(126);
-pub const NUMBER: rt::NodeType = rt::NodeType(127);
-pub const SIMPLE_STRING: rt::NodeType = rt::NodeType(128);
-pub const HASH_STRING: rt::NodeType = rt::NodeType(129);
-pub const IDENT: rt::NodeType = rt::NodeType(130);
-pub const FALL_FILE: rt::NodeType = rt::NodeType(131);
-pub const SYN_RULE: rt::NodeType = rt::NodeType(132);
-pub const PARAMETERS: rt::NodeType = rt::NodeType(133);
-pub const PARAMETER: rt::NodeType = rt::NodeType(134);
-pub const REF_EXPR: rt::NodeType = rt::NodeType(135);
-pub const SEQ_EXPR: rt::NodeType = rt::NodeType(136);
-pub const BLOCK_EXPR: rt::NodeType = rt::NodeType(137);
-pub const OPT_EXPR: rt::NodeType = rt::NodeType(138);
-pub const REP_EXPR: rt::NodeType = rt::NodeType(139);
-pub const CALL_EXPR: rt::NodeType = rt::NodeType(140);
-pub const TOKENIZER_DEF: rt::NodeType = rt::NodeType(141);
-pub const LEX_RULE: rt::NodeType = rt::NodeType(142);
-pub const TEST_DEF: rt::NodeType = rt::NodeType(143);
-pub const ATTRIBUTES: rt::NodeType = rt::NodeType(144);
-pub const ATTRIBUTE: rt::NodeType = rt::NodeType(145);
-pub const ATTRIBUTE_VALUE: rt::NodeType = rt::NodeType(146);
-pub const STRING: rt::NodeType = rt::NodeType(147);
-pub const VERBATIM_DEF: rt::NodeType = rt::NodeType(148);
-pub const AST_DEF: rt::NodeType = rt::NodeType(149);
-pub const AST_NODE_DEF: rt::NodeType = rt::NodeType(150);
-pub const AST_CLASS_DEF: rt::NodeType = rt::NodeType(151);
-pub const AST_TRAIT_DEF: rt::NodeType = rt::NodeType(152);
-pub const METHOD_DEF: rt::NodeType = rt::NodeType(153);
-pub const AST_SELECTOR: rt::NodeType = rt::NodeType(154);
+pub const MODE: rt::NodeType = rt::NodeType(111);
+pub const EQ: rt::NodeType = rt::NodeType(112);
+pub const PIPE: rt::NodeType = rt::NodeType(113);
+pub const STAR: rt::NodeType = rt::NodeType(114);
+pub const QUESTION: rt::NodeType = rt::NodeType(115);
+pub const DOT: rt::NodeType = rt::NodeType(116);
+pub const COMMA: rt::NodeType = rt::NodeType(117);
+pub const COLON: rt::NodeType = rt::NodeType(118);
+pub const HASH: rt::NodeType = rt::NodeType(119);
+pub const L_CURLY: rt::NodeType = rt::NodeType(120);
+pub const R_CURLY: rt::NodeType = rt::NodeType(121);
+pub const L_SQUARE: rt::NodeType = rt::NodeType(122);
+pub const R_SQUARE: rt::NodeType = rt::NodeType(123);
+pub const L_ANGLE: rt::NodeType = rt::NodeType(124);
+pub const R_ANGLE: rt::NodeType = rt::NodeType(125);
+pub const L_PAREN: rt::NodeType = rt::NodeType(126);
+pub const R_PAREN: rt::NodeType = rt::NodeType(127);
+pub const NUMBER: rt::NodeType = rt::NodeType(128);
+pub const SIMPLE_STRING: rt::NodeType = rt::NodeType(129);
+pub const HASH_STRING: rt::NodeType = rt::NodeType(130);
+pub const IDENT: rt::NodeType = rt::NodeType(131);
+pub const FALL_FILE: rt::NodeType = rt::NodeType(132);
+pub const SYN_RULE: rt::NodeType = rt::NodeType(133);
+pub const PARAMETERS: rt::NodeType = rt::NodeType(134);
+pub const PARAMETER: rt::NodeType = rt::NodeType(135);
+pub const REF_EXPR: rt::NodeType = rt::NodeType(136);
+pub const SEQ_EXPR: rt::NodeType = rt::NodeType(137);
+pub const BLOCK_EXPR: rt::NodeType = rt::NodeType(138);
+pub const OPT_EXPR: rt::NodeType = rt::NodeType(139);
+pub const REP_EXPR: rt::NodeType = rt::NodeType(140);
+pub const CALL_EXPR: rt::NodeType = rt::NodeType(141);
+pub const TOKENIZER_DEF: rt::NodeType = rt::NodeType(142);
+pub const LEX_RULE: rt::NodeType = rt::NodeType(143);
+pub const LEX_MODE: rt::NodeType = rt::NodeType(144);
+pub const TEST_DEF: rt::NodeType = rt::NodeType(145);
+pub const ATTRIBUTES: rt::NodeType = rt::NodeType(146);
+pub const ATTRIBUTE: rt::NodeType = rt::NodeType(147);
+pub const ATTRIBUTE_VALUE: rt::NodeType = rt::NodeType(148);
+pub const STRING: rt::NodeType = rt::NodeType(149);
+pub const VERBATIM_DEF: rt::NodeType = rt::NodeType(150);
+pub const AST_DEF: rt::NodeType = rt::NodeType(151);
+pub const AST_NODE_DEF: rt::NodeType = rt::NodeType(152);
+pub const AST_CLASS_DEF: rt::NodeType = rt::NodeType(153);
+pub const AST_TRAIT_DEF: rt::NodeType = rt::NodeType(154);
+pub const METHOD_DEF: rt::NodeType = rt::NodeType(155);
+pub const AST_SELECTOR: rt::NodeType = rt::NodeType(156);
 pub fn language() -> &'static rt::Language {
-    fn create_lexer() -> rt::RegexLexer {
-        rt::RegexLexer::new(vec![
-            rt::LexRule::new(WHITESPACE, "\\s+", None),
-            rt::LexRule::new(EOL_COMMENT, "//.*", None),
-            rt::LexRule::new(EQ, "=", None),
-            rt::LexRule::new(PIPE, "\\|", None),
-            rt::LexRule::new(STAR, "\\*", None),
-            rt::LexRule::new(QUESTION, "\\?", None),
-            rt::LexRule::new(DOT, "\\.", None),
-            rt::LexRule::new(COMMA, ",", None),
-            rt::LexRule::new(COLON, ":", None),
-            rt::LexRule::new(HASH, "\\#", None),
-            rt::LexRule::new(L_CURLY, "\\{", None),
-            rt::LexRule::new(R_CURLY, "\\}", None),
-            rt::LexRule::new(L_SQUARE, "\\[", None),
-            rt::LexRule::new(R_SQUARE, "\\]", None),
-            rt::LexRule::new(L_ANGLE, "<", None),
-            rt::LexRule::new(R_ANGLE, ">", None),
-            rt::LexRule::new(L_PAREN, "\\(", None),
-            rt::LexRule::new(R_PAREN, "\\)", None),
-            rt::LexRule::new(NUMBER, "\\d+", None),
-            rt::LexRule::new(SIMPLE_STRING, "\'([^\'\\\\]|\\\\.)*\'", None),
-            rt::LexRule::new(HASH_STRING, "r#*\"", Some(parse_raw_string)),
-            rt::LexRule::new(IDENT, "\\w+", None),
-        ])
+    fn create_lexer() -> rt::DfaLexer {
+        static RULES: &[rt::DfaRule] = &[
+            rt::DfaRule { ty: WHITESPACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: EOL_COMMENT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: EQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: PIPE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STAR, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: QUESTION, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: DOT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: COMMA, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: COLON, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: HASH, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: L_CURLY, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: R_CURLY, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: L_SQUARE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: R_SQUARE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: L_ANGLE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: R_ANGLE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: L_PAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: R_PAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: NUMBER, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: SIMPLE_STRING, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: HASH_STRING, mode: 0, transition: rt::ModeTransition::Stay, f: Some((2, parse_raw_string)) },
+            rt::DfaRule { ty: IDENT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+        ];
+        static ASCII_CLASSES: [u8; 128] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 0, 3, 4, 0, 0, 0, 5, 6, 7, 8, 0, 9, 0, 10, 11, 12, 12, 12, 12, 12, 12, 12, 12, 12, 12, 13, 0, 14, 15, 16, 17,
+            0, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 19, 20, 21, 0, 18,
+            0, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 22, 18, 18, 18, 18, 18, 18, 18, 18, 23, 24, 25, 0, 0,
+        ];
+        static UNICODE_CLASSES: &[(u32, u8)] = &[
+            (0x80, 0), (0x85, 1), (0x86, 0), (0xA0, 1), (0xA1, 0), (0xAA, 18), (0xAB, 0), (0xB5, 18),
+            (0xB6, 0), (0xBA, 18), (0xBB, 0), (0xC0, 18), (0xD7, 0), (0xD8, 18), (0xF7, 0), (0xF8, 18),
+            (0x2C2, 0), (0x2C6, 18), (0x2D2, 0), (0x2E0, 18), (0x2E5, 0), (0x2EC, 18), (0x2ED, 0), (0x2EE, 18),
+            (0x2EF, 0), (0x300, 18), (0x375, 0), (0x376, 18), (0x378, 0), (0x37A, 18), (0x37E, 0), (0x37F, 18),
+            (0x380, 0), (0x386, 18), (0x387, 0), (0x388, 18), (0x38B, 0), (0x38C, 18), (0x38D, 0), (0x38E, 18),
+            (0x3A2, 0), (0x3A3, 18), (0x3F6, 0), (0x3F7, 18), (0x482, 0), (0x483, 18), (0x530, 0), (0x531, 18),
+            (0x557, 0), (0x559, 18), (0x55A, 0), (0x560, 18), (0x589, 0), (0x591, 18), (0x5BE, 0), (0x5BF, 18),
+            (0x5C0, 0), (0x5C1, 18), (0x5C3, 0), (0x5C4, 18), (0x5C6, 0), (0x5C7, 18), (0x5C8, 0), (0x5D0, 18),
+            (0x5EB, 0), (0x5EF, 18), (0x5F3, 0), (0x610, 18), (0x61B, 0), (0x620, 18), (0x660, 12), (0x66A, 0),
+            (0x66E, 18), (0x6D4, 0), (0x6D5, 18), (0x6DD, 0), (0x6DF, 18), (0x6E9, 0), (0x6EA, 18), (0x6F0, 12),
+            (0x6FA, 18), (0x6FD, 0), (0x6FF, 18), (0x700, 0), (0x710, 18), (0x74B, 0), (0x74D, 18), (0x7B2, 0),
+            (0x7C0, 12), (0x7CA, 18), (0x7F6, 0), (0x7FA, 18), (0x7FB, 0), (0x7FD, 18), (0x7FE, 0), (0x800, 18),
+            (0x82E, 0), (0x840, 18), (0x85C, 0), (0x860, 18), (0x86B, 0), (0x870, 18), (0x888, 0), (0x889, 18),
+            (0x88F, 0), (0x897, 18), (0x8E2, 0), (0x8E3, 18), (0x964, 0), (0x966, 12), (0x970, 0), (0x971, 18),
+            (0x984, 0), (0x985, 18), (0x98D, 0), (0x98F, 18), (0x991, 0), (0x993, 18), (0x9A9, 0), (0x9AA, 18),
+            (0x9B1, 0), (0x9B2, 18), (0x9B3, 0), (0x9B6, 18), (0x9BA, 0), (0x9BC, 18), (0x9C5, 0), (0x9C7, 18),
+            (0x9C9, 0), (0x9CB, 18), (0x9CF, 0), (0x9D7, 18), (0x9D8, 0), (0x9DC, 18), (0x9DE, 0), (0x9DF, 18),
+            (0x9E4, 0), (0x9E6, 12), (0x9F0, 18), (0x9F2, 0), (0x9FC, 18), (0x9FD, 0), (0x9FE, 18), (0x9FF, 0),
+            (0xA01, 18), (0xA04, 0), (0xA05, 18), (0xA0B, 0), (0xA0F, 18), (0xA11, 0), (0xA13, 18), (0xA29, 0),
+            (0xA2A, 18), (0xA31, 0), (0xA32, 18), (0xA34, 0), (0xA35, 18), (0xA37, 0), (0xA38, 18), (0xA3A, 0),
+            (0xA3C, 18), (0xA3D, 0), (0xA3E, 18), (0xA43, 0), (0xA47, 18), (0xA49, 0), (0xA4B, 18), (0xA4E, 0),
+            (0xA51, 18), (0xA52, 0), (0xA59, 18), (0xA5D, 0), (0xA5E, 18), (0xA5F, 0), (0xA66, 12), (0xA70, 18),
+            (0xA76, 0), (0xA81, 18), (0xA84, 0), (0xA85, 18), (0xA8E, 0), (0xA8F, 18), (0xA92, 0), (0xA93, 18),
+            (0xAA9, 0), (0xAAA, 18), (0xAB1, 0), (0xAB2, 18), (0xAB4, 0), (0xAB5, 18), (0xABA, 0), (0xABC, 18),
+            (0xAC6, 0), (0xAC7, 18), (0xACA, 0), (0xACB, 18), (0xACE, 0), (0xAD0, 18), (0xAD1, 0), (0xAE0, 18),
+            (0xAE4, 0), (0xAE6, 12), (0xAF0, 0), (0xAF9, 18), (0xB00, 0), (0xB01, 18), (0xB04, 0), (0xB05, 18),
+            (0xB0D, 0), (0xB0F, 18), (0xB11, 0), (0xB13, 18), (0xB29, 0), (0xB2A, 18), (0xB31, 0), (0xB32, 18),
+            (0xB34, 0), (0xB35, 18), (0xB3A, 0), (0xB3C, 18), (0xB45, 0), (0xB47, 18), (0xB49, 0), (0xB4B, 18),
+            (0xB4E, 0), (0xB55, 18), (0xB58, 0), (0xB5C, 18), (0xB5E, 0), (0xB5F, 18), (0xB64, 0), (0xB66, 12),
+            (0xB70, 0), (0xB71, 18), (0xB72, 0), (0xB82, 18), (0xB84, 0), (0xB85, 18), (0xB8B, 0), (0xB8E, 18),
+            (0xB91, 0), (0xB92, 18), (0xB96, 0), (0xB99, 18), (0xB9B, 0), (0xB9C, 18), (0xB9D, 0), (0xB9E, 18),
+            (0xBA0, 0), (0xBA3, 18), (0xBA5, 0), (0xBA8, 18), (0xBAB, 0), (0xBAE, 18), (0xBBA, 0), (0xBBE, 18),
+            (0xBC3, 0), (0xBC6, 18), (0xBC9, 0), (0xBCA, 18), (0xBCE, 0), (0xBD0, 18), (0xBD1, 0), (0xBD7, 18),
+            (0xBD8, 0), (0xBE6, 12), (0xBF0, 0), (0xC00, 18), (0xC0D, 0), (0xC0E, 18), (0xC11, 0), (0xC12, 18),
+            (0xC29, 0), (0xC2A, 18), (0xC3A, 0), (0xC3C, 18), (0xC45, 0), (0xC46, 18), (0xC49, 0), (0xC4A, 18),
+            (0xC4E, 0), (0xC55, 18), (0xC57, 0), (0xC58, 18), (0xC5B, 0), (0xC5D, 18), (0xC5E, 0), (0xC60, 18),
+            (0xC64, 0), (0xC66, 12), (0xC70, 0), (0xC80, 18), (0xC84, 0), (0xC85, 18), (0xC8D, 0), (0xC8E, 18),
+            (0xC91, 0), (0xC92, 18), (0xCA9, 0), (0xCAA, 18), (0xCB4, 0), (0xCB5, 18), (0xCBA, 0), (0xCBC, 18),
+            (0xCC5, 0), (0xCC6, 18), (0xCC9, 0), (0xCCA, 18), (0xCCE, 0), (0xCD5, 18), (0xCD7, 0), (0xCDD, 18),
+            (0xCDF, 0), (0xCE0, 18), (0xCE4, 0), (0xCE6, 12), (0xCF0, 0), (0xCF1, 18), (0xCF4, 0), (0xD00, 18),
+            (0xD0D, 0), (0xD0E, 18), (0xD11, 0), (0xD12, 18), (0xD45, 0), (0xD46, 18), (0xD49, 0), (0xD4A, 18),
+            (0xD4F, 0), (0xD54, 18), (0xD58, 0), (0xD5F, 18), (0xD64, 0), (0xD66, 12), (0xD70, 0), (0xD7A, 18),
+            (0xD80, 0), (0xD81, 18), (0xD84, 0), (0xD85, 18), (0xD97, 0), (0xD9A, 18), (0xDB2, 0), (0xDB3, 18),
+            (0xDBC, 0), (0xDBD, 18), (0xDBE, 0), (0xDC0, 18), (0xDC7, 0), (0xDCA, 18), (0xDCB, 0), (0xDCF, 18),
+            (0xDD5, 0), (0xDD6, 18), (0xDD7, 0), (0xDD8, 18), (0xDE0, 0), (0xDE6, 12), (0xDF0, 0), (0xDF2, 18),
+            (0xDF4, 0), (0xE01, 18), (0xE3B, 0), (0xE40, 18), (0xE4F, 0), (0xE50, 12), (0xE5A, 0), (0xE81, 18),
+            (0xE83, 0), (0xE84, 18), (0xE85, 0), (0xE86, 18), (0xE8B, 0), (0xE8C, 18), (0xEA4, 0), (0xEA5, 18),
+            (0xEA6, 0), (0xEA7, 18), (0xEBE, 0), (0xEC0, 18), (0xEC5, 0), (0xEC6, 18), (0xEC7, 0), (0xEC8, 18),
+            (0xECF, 0), (0xED0, 12), (0xEDA, 0), (0xEDC, 18), (0xEE0, 0), (0xF00, 18), (0xF01, 0), (0xF18, 18),
+            (0xF1A, 0), (0xF20, 12), (0xF2A, 0), (0xF35, 18), (0xF36, 0), (0xF37, 18), (0xF38, 0), (0xF39, 18),
+            (0xF3A, 0), (0xF3E, 18), (0xF48, 0), (0xF49, 18), (0xF6D, 0), (0xF71, 18), (0xF85, 0), (0xF86, 18),
+            (0xF98, 0), (0xF99, 18), (0xFBD, 0), (0xFC6, 18), (0xFC7, 0), (0x1000, 18), (0x1040, 12), (0x104A, 0),
+            (0x1050, 18), (0x1090, 12), (0x109A, 18), (0x109E, 0), (0x10A0, 18), (0x10C6, 0), (0x10C7, 18), (0x10C8, 0),
+            (0x10CD, 18), (0x10CE, 0), (0x10D0, 18), (0x10FB, 0), (0x10FC, 18), (0x1249, 0), (0x124A, 18), (0x124E, 0),
+            (0x1250, 18), (0x1257, 0), (0x1258, 18), (0x1259, 0), (0x125A, 18), (0x125E, 0), (0x1260, 18), (0x1289, 0),
+            (0x128A, 18), (0x128E, 0), (0x1290, 18), (0x12B1, 0), (0x12B2, 18), (0x12B6, 0), (0x12B8, 18), (0x12BF, 0),
+            (0x12C0, 18), (0x12C1, 0), (0x12C2, 18), (0x12C6, 0), (0x12C8, 18), (0x12D7, 0), (0x12D8, 18), (0x1311, 0),
+            (0x1312, 18), (0x1316, 0), (0x1318, 18), (0x135B, 0), (0x135D, 18), (0x1360, 0), (0x1380, 18), (0x1390, 0),
+            (0x13A0, 18), (0x13F6, 0), (0x13F8, 18), (0x13FE, 0), (0x1401, 18), (0x166D, 0), (0x166F, 18), (0x1680, 1),
+            (0x1681, 18), (0x169B, 0), (0x16A0, 18), (0x16EB, 0), (0x16EE, 18), (0x16F9, 0), (0x1700, 18), (0x1716, 0),
+            (0x171F, 18), (0x1735, 0), (0x1740, 18), (0x1754, 0), (0x1760, 18), (0x176D, 0), (0x176E, 18), (0x1771, 0),
+            (0x1772, 18), (0x1774, 0), (0x1780, 18), (0x17D4, 0), (0x17D7, 18), (0x17D8, 0), (0x17DC, 18), (0x17DE, 0),
+            (0x17E0, 12), (0x17EA, 0), (0x180B, 18), (0x180E, 0), (0x180F, 18), (0x1810, 12), (0x181A, 0), (0x1820, 18),
+            (0x1879, 0), (0x1880, 18), (0x18AB, 0), (0x18B0, 18), (0x18F6, 0), (0x1900, 18), (0x191F, 0), (0x1920, 18),
+            (0x192C, 0), (0x1930, 18), (0x193C, 0), (0x1946, 12), (0x1950, 18), (0x196E, 0), (0x1970, 18), (0x1975, 0),
+            (0x1980, 18), (0x19AC, 0), (0x19B0, 18), (0x19CA, 0), (0x19D0, 12), (0x19DA, 0), (0x1A00, 18), (0x1A1C, 0),
+            (0x1A20, 18), (0x1A5F, 0), (0x1A60, 18), (0x1A7D, 0), (0x1A7F, 18), (0x1A80, 12), (0x1A8A, 0), (0x1A90, 12),
+            (0x1A9A, 0), (0x1AA7, 18), (0x1AA8, 0), (0x1AB0, 18), (0x1ACF, 0), (0x1B00, 18), (0x1B4D, 0), (0x1B50, 12),
+            (0x1B5A, 0), (0x1B6B, 18), (0x1B74, 0), (0x1B80, 18), (0x1BB0, 12), (0x1BBA, 18), (0x1BF4, 0), (0x1C00, 18),
+            (0x1C38, 0), (0x1C40, 12), (0x1C4A, 0), (0x1C4D, 18), (0x1C50, 12), (0x1C5A, 18), (0x1C7E, 0), (0x1C80, 18),
+            (0x1C8B, 0), (0x1C90, 18), (0x1CBB, 0), (0x1CBD, 18), (0x1CC0, 0), (0x1CD0, 18), (0x1CD3, 0), (0x1CD4, 18),
+            (0x1CFB, 0), (0x1D00, 18), (0x1F16, 0), (0x1F18, 18), (0x1F1E, 0), (0x1F20, 18), (0x1F46, 0), (0x1F48, 18),
+            (0x1F4E, 0), (0x1F50, 18), (0x1F58, 0), (0x1F59, 18), (0x1F5A, 0), (0x1F5B, 18), (0x1F5C, 0), (0x1F5D, 18),
+            (0x1F5E, 0), (0x1F5F, 18), (0x1F7E, 0), (0x1F80, 18), (0x1FB5, 0), (0x1FB6, 18), (0x1FBD, 0), (0x1FBE, 18),
+            (0x1FBF, 0), (0x1FC2, 18), (0x1FC5, 0), (0x1FC6, 18), (0x1FCD, 0), (0x1FD0, 18), (0x1FD4, 0), (0x1FD6, 18),
+            (0x1FDC, 0), (0x1FE0, 18), (0x1FED, 0), (0x1FF2, 18), (0x1FF5, 0), (0x1FF6, 18), (0x1FFD, 0), (0x2000, 1),
+            (0x200B, 0), (0x200C, 18), (0x200E, 0), (0x2028, 1), (0x202A, 0), (0x202F, 1), (0x2030, 0), (0x203F, 18),
+            (0x2041, 0), (0x2054, 18), (0x2055, 0), (0x205F, 1), (0x2060, 0), (0x2071, 18), (0x2072, 0), (0x207F, 18),
+            (0x2080, 0), (0x2090, 18), (0x209D, 0), (0x20D0, 18), (0x20F1, 0), (0x2102, 18), (0x2103, 0), (0x2107, 18),
+            (0x2108, 0), (0x210A, 18), (0x2114, 0), (0x2115, 18), (0x2116, 0), (0x2119, 18), (0x211E, 0), (0x2124, 18),
+            (0x2125, 0), (0x2126, 18), (0x2127, 0), (0x2128, 18), (0x2129, 0), (0x212A, 18), (0x212E, 0), (0x212F, 18),
+            (0x213A, 0), (0x213C, 18), (0x2140, 0), (0x2145, 18), (0x214A, 0), (0x214E, 18), (0x214F, 0), (0x2160, 18),
+            (0x2189, 0), (0x24B6, 18), (0x24EA, 0), (0x2C00, 18), (0x2CE5, 0), (0x2CEB, 18), (0x2CF4, 0), (0x2D00, 18),
+            (0x2D26, 0), (0x2D27, 18), (0x2D28, 0), (0x2D2D, 18), (0x2D2E, 0), (0x2D30, 18), (0x2D68, 0), (0x2D6F, 18),
+            (0x2D70, 0), (0x2D7F, 18), (0x2D97, 0), (0x2DA0, 18), (0x2DA7, 0), (0x2DA8, 18), (0x2DAF, 0), (0x2DB0, 18),
+            (0x2DB7, 0), (0x2DB8, 18), (0x2DBF, 0), (0x2DC0, 18), (0x2DC7, 0), (0x2DC8, 18), (0x2DCF, 0), (0x2DD0, 18),
+            (0x2DD7, 0), (0x2DD8, 18), (0x2DDF, 0), (0x2DE0, 18), (0x2E00, 0), (0x2E2F, 18), (0x2E30, 0), (0x3000, 1),
+            (0x3001, 0), (0x3005, 18), (0x3008, 0), (0x3021, 18), (0x3030, 0), (0x3031, 18), (0x3036, 0), (0x3038, 18),
+            (0x303D, 0), (0x3041, 18), (0x3097, 0), (0x3099, 18), (0x309B, 0), (0x309D, 18), (0x30A0, 0), (0x30A1, 18),
+            (0x30FB, 0), (0x30FC, 18), (0x3100, 0), (0x3105, 18), (0x3130, 0), (0x3131, 18), (0x318F, 0), (0x31A0, 18),
+            (0x31C0, 0), (0x31F0, 18), (0x3200, 0), (0x3400, 18), (0x4DC0, 0), (0x4E00, 18), (0xA48D, 0), (0xA4D0, 18),
+            (0xA4FE, 0), (0xA500, 18), (0xA60D, 0), (0xA610, 18), (0xA620, 12), (0xA62A, 18), (0xA62C, 0), (0xA640, 18),
+            (0xA673, 0), (0xA674, 18), (0xA67E, 0), (0xA67F, 18), (0xA6F2, 0), (0xA717, 18), (0xA720, 0), (0xA722, 18),
+            (0xA789, 0), (0xA78B, 18), (0xA7CE, 0), (0xA7D0, 18), (0xA7D2, 0), (0xA7D3, 18), (0xA7D4, 0), (0xA7D5, 18),
+            (0xA7DD, 0), (0xA7F2, 18), (0xA828, 0), (0xA82C, 18), (0xA82D, 0), (0xA840, 18), (0xA874, 0), (0xA880, 18),
+            (0xA8C6, 0), (0xA8D0, 12), (0xA8DA, 0), (0xA8E0, 18), (0xA8F8, 0), (0xA8FB, 18), (0xA8FC, 0), (0xA8FD, 18),
+            (0xA900, 12), (0xA90A, 18), (0xA92E, 0), (0xA930, 18), (0xA954, 0), (0xA960, 18), (0xA97D, 0), (0xA980, 18),
+            (0xA9C1, 0), (0xA9CF, 18), (0xA9D0, 12), (0xA9DA, 0), (0xA9E0, 18), (0xA9F0, 12), (0xA9FA, 18), (0xA9FF, 0),
+            (0xAA00, 18), (0xAA37, 0), (0xAA40, 18), (0xAA4E, 0), (0xAA50, 12), (0xAA5A, 0), (0xAA60, 18), (0xAA77, 0),
+            (0xAA7A, 18), (0xAAC3, 0), (0xAADB, 18), (0xAADE, 0), (0xAAE0, 18), (0xAAF0, 0), (0xAAF2, 18), (0xAAF7, 0),
+            (0xAB01, 18), (0xAB07, 0), (0xAB09, 18), (0xAB0F, 0), (0xAB11, 18), (0xAB17, 0), (0xAB20, 18), (0xAB27, 0),
+            (0xAB28, 18), (0xAB2F, 0), (0xAB30, 18), (0xAB5B, 0), (0xAB5C, 18), (0xAB6A, 0), (0xAB70, 18), (0xABEB, 0),
+            (0xABEC, 18), (0xABEE, 0), (0xABF0, 12), (0xABFA, 0), (0xAC00, 18), (0xD7A4, 0), (0xD7B0, 18), (0xD7C7, 0),
+            (0xD7CB, 18), (0xD7FC, 0), (0xF900, 18), (0xFA6E, 0), (0xFA70, 18), (0xFADA, 0), (0xFB00, 18), (0xFB07, 0),
+            (0xFB13, 18), (0xFB18, 0), (0xFB1D, 18), (0xFB29, 0), (0xFB2A, 18), (0xFB37, 0), (0xFB38, 18), (0xFB3D, 0),
+            (0xFB3E, 18), (0xFB3F, 0), (0xFB40, 18), (0xFB42, 0), (0xFB43, 18), (0xFB45, 0), (0xFB46, 18), (0xFBB2, 0),
+            (0xFBD3, 18), (0xFD3E, 0), (0xFD50, 18), (0xFD90, 0), (0xFD92, 18), (0xFDC8, 0), (0xFDF0, 18), (0xFDFC, 0),
+            (0xFE00, 18), (0xFE10, 0), (0xFE20, 18), (0xFE30, 0), (0xFE33, 18), (0xFE35, 0), (0xFE4D, 18), (0xFE50, 0),
+            (0xFE70, 18), (0xFE75, 0), (0xFE76, 18), (0xFEFD, 0), (0xFF10, 12), (0xFF1A, 0), (0xFF21, 18), (0xFF3B, 0),
+            (0xFF3F, 18), (0xFF40, 0), (0xFF41, 18), (0xFF5B, 0), (0xFF66, 18), (0xFFBF, 0), (0xFFC2, 18), (0xFFC8, 0),
+            (0xFFCA, 18), (0xFFD0, 0), (0xFFD2, 18), (0xFFD8, 0), (0xFFDA, 18), (0xFFDD, 0), (0x10000, 18), (0x1000C, 0),
+            (0x1000D, 18), (0x10027, 0), (0x10028, 18), (0x1003B, 0), (0x1003C, 18), (0x1003E, 0), (0x1003F, 18), (0x1004E, 0),
+            (0x10050, 18), (0x1005E, 0), (0x10080, 18), (0x100FB, 0), (0x10140, 18), (0x10175, 0), (0x101FD, 18), (0x101FE, 0),
+            (0x10280, 18), (0x1029D, 0), (0x102A0, 18), (0x102D1, 0), (0x102E0, 18), (0x102E1, 0), (0x10300, 18), (0x10320, 0),
+            (0x1032D, 18), (0x1034B, 0), (0x10350, 18), (0x1037B, 0), (0x10380, 18), (0x1039E, 0), (0x103A0, 18), (0x103C4, 0),
+            (0x103C8, 18), (0x103D0, 0), (0x103D1, 18), (0x103D6, 0), (0x10400, 18), (0x1049E, 0), (0x104A0, 12), (0x104AA, 0),
+            (0x104B0, 18), (0x104D4, 0), (0x104D8, 18), (0x104FC, 0), (0x10500, 18), (0x10528, 0), (0x10530, 18), (0x10564, 0),
+            (0x10570, 18), (0x1057B, 0), (0x1057C, 18), (0x1058B, 0), (0x1058C, 18), (0x10593, 0), (0x10594, 18), (0x10596, 0),
+            (0x10597, 18), (0x105A2, 0), (0x105A3, 18), (0x105B2, 0), (0x105B3, 18), (0x105BA, 0), (0x105BB, 18), (0x105BD, 0),
+            (0x105C0, 18), (0x105F4, 0), (0x10600, 18), (0x10737, 0), (0x10740, 18), (0x10756, 0), (0x10760, 18), (0x10768, 0),
+            (0x10780, 18), (0x10786, 0), (0x10787, 18), (0x107B1, 0), (0x107B2, 18), (0x107BB, 0), (0x10800, 18), (0x10806, 0),
+            (0x10808, 18), (0x10809, 0), (0x1080A, 18), (0x10836, 0), (0x10837, 18), (0x10839, 0), (0x1083C, 18), (0x1083D, 0),
+            (0x1083F, 18), (0x10856, 0), (0x10860, 18), (0x10877, 0), (0x10880, 18), (0x1089F, 0), (0x108E0, 18), (0x108F3, 0),
+            (0x108F4, 18), (0x108F6, 0), (0x10900, 18), (0x10916, 0), (0x10920, 18), (0x1093A, 0), (0x10980, 18), (0x109B8, 0),
+            (0x109BE, 18), (0x109C0, 0), (0x10A00, 18), (0x10A04, 0), (0x10A05, 18), (0x10A07, 0), (0x10A0C, 18), (0x10A14, 0),
+            (0x10A15, 18), (0x10A18, 0), (0x10A19, 18), (0x10A36, 0), (0x10A38, 18), (0x10A3B, 0), (0x10A3F, 18), (0x10A40, 0),
+            (0x10A60, 18), (0x10A7D, 0), (0x10A80, 18), (0x10A9D, 0), (0x10AC0, 18), (0x10AC8, 0), (0x10AC9, 18), (0x10AE7, 0),
+            (0x10B00, 18), (0x10B36, 0), (0x10B40, 18), (0x10B56, 0), (0x10B60, 18), (0x10B73, 0), (0x10B80, 18), (0x10B92, 0),
+            (0x10C00, 18), (0x10C49, 0), (0x10C80, 18), (0x10CB3, 0), (0x10CC0, 18), (0x10CF3, 0), (0x10D00, 18), (0x10D28, 0),
+            (0x10D30, 12), (0x10D3A, 0), (0x10D40, 12), (0x10D4A, 18), (0x10D66, 0), (0x10D69, 18), (0x10D6E, 0), (0x10D6F, 18),
+            (0x10D86, 0), (0x10E80, 18), (0x10EAA, 0), (0x10EAB, 18), (0x10EAD, 0), (0x10EB0, 18), (0x10EB2, 0), (0x10EC2, 18),
+            (0x10EC5, 0), (0x10EFC, 18), (0x10F1D, 0), (0x10F27, 18), (0x10F28, 0), (0x10F30, 18), (0x10F51, 0), (0x10F70, 18),
+            (0x10F86, 0), (0x10FB0, 18), (0x10FC5, 0), (0x10FE0, 18), (0x10FF7, 0), (0x11000, 18), (0x11047, 0), (0x11066, 12),
+            (0x11070, 18), (0x11076, 0), (0x1107F, 18), (0x110BB, 0), (0x110C2, 18), (0x110C3, 0), (0x110D0, 18), (0x110E9, 0),
+            (0x110F0, 12), (0x110FA, 0), (0x11100, 18), (0x11135, 0), (0x11136, 12), (0x11140, 0), (0x11144, 18), (0x11148, 0),
+            (0x11150, 18), (0x11174, 0), (0x11176, 18), (0x11177, 0), (0x11180, 18), (0x111C5, 0), (0x111C9, 18), (0x111CD, 0),
+            (0x111CE, 18), (0x111D0, 12), (0x111DA, 18), (0x111DB, 0), (0x111DC, 18), (0x111DD, 0), (0x11200, 18), (0x11212, 0),
+            (0x11213, 18), (0x11238, 0), (0x1123E, 18), (0x11242, 0), (0x11280, 18), (0x11287, 0), (0x11288, 18), (0x11289, 0),
+            (0x1128A, 18), (0x1128E, 0), (0x1128F, 18), (0x1129E, 0), (0x1129F, 18), (0x112A9, 0), (0x112B0, 18), (0x112EB, 0),
+            (0x112F0, 12), (0x112FA, 0), (0x11300, 18), (0x11304, 0), (0x11305, 18), (0x1130D, 0), (0x1130F, 18), (0x11311, 0),
+            (0x11313, 18), (0x11329, 0), (0x1132A, 18), (0x11331, 0), (0x11332, 18), (0x11334, 0), (0x11335, 18), (0x1133A, 0),
+            (0x1133B, 18), (0x11345, 0), (0x11347, 18), (0x11349, 0), (0x1134B, 18), (0x1134E, 0), (0x11350, 18), (0x11351, 0),
+            (0x11357, 18), (0x11358, 0), (0x1135D, 18), (0x11364, 0), (0x11366, 18), (0x1136D, 0), (0x11370, 18), (0x11375, 0),
+            (0x11380, 18), (0x1138A, 0), (0x1138B, 18), (0x1138C, 0), (0x1138E, 18), (0x1138F, 0), (0x11390, 18), (0x113B6, 0),
+            (0x113B7, 18), (0x113C1, 0), (0x113C2, 18), (0x113C3, 0), (0x113C5, 18), (0x113C6, 0), (0x113C7, 18), (0x113CB, 0),
+            (0x113CC, 18), (0x113D4, 0), (0x113E1, 18), (0x113E3, 0), (0x11400, 18), (0x1144B, 0), (0x11450, 12), (0x1145A, 0),
+            (0x1145E, 18), (0x11462, 0), (0x11480, 18), (0x114C6, 0), (0x114C7, 18), (0x114C8, 0), (0x114D0, 12), (0x114DA, 0),
+            (0x11580, 18), (0x115B6, 0), (0x115B8, 18), (0x115C1, 0), (0x115D8, 18), (0x115DE, 0), (0x11600, 18), (0x11641, 0),
+            (0x11644, 18), (0x11645, 0), (0x11650, 12), (0x1165A, 0), (0x11680, 18), (0x116B9, 0), (0x116C0, 12), (0x116CA, 0),
+            (0x116D0, 12), (0x116E4, 0), (0x11700, 18), (0x1171B, 0), (0x1171D, 18), (0x1172C, 0), (0x11730, 12), (0x1173A, 0),
+            (0x11740, 18), (0x11747, 0), (0x11800, 18), (0x1183B, 0), (0x118A0, 18), (0x118E0, 12), (0x118EA, 0), (0x118FF, 18),
+            (0x11907, 0), (0x11909, 18), (0x1190A, 0), (0x1190C, 18), (0x11914, 0), (0x11915, 18), (0x11917, 0), (0x11918, 18),
+            (0x11936, 0), (0x11937, 18), (0x11939, 0), (0x1193B, 18), (0x11944, 0), (0x11950, 12), (0x1195A, 0), (0x119A0, 18),
+            (0x119A8, 0), (0x119AA, 18), (0x119D8, 0), (0x119DA, 18), (0x119E2, 0), (0x119E3, 18), (0x119E5, 0), (0x11A00, 18),
+            (0x11A3F, 0), (0x11A47, 18), (0x11A48, 0), (0x11A50, 18), (0x11A9A, 0), (0x11A9D, 18), (0x11A9E, 0), (0x11AB0, 18),
+            (0x11AF9, 0), (0x11BC0, 18), (0x11BE1, 0), (0x11BF0, 12), (0x11BFA, 0), (0x11C00, 18), (0x11C09, 0), (0x11C0A, 18),
+            (0x11C37, 0), (0x11C38, 18), (0x11C41, 0), (0x11C50, 12), (0x11C5A, 0), (0x11C72, 18), (0x11C90, 0), (0x11C92, 18),
+            (0x11CA8, 0), (0x11CA9, 18), (0x11CB7, 0), (0x11D00, 18), (0x11D07, 0), (0x11D08, 18), (0x11D0A, 0), (0x11D0B, 18),
+            (0x11D37, 0), (0x11D3A, 18), (0x11D3B, 0), (0x11D3C, 18), (0x11D3E, 0), (0x11D3F, 18), (0x11D48, 0), (0x11D50, 12),
+            (0x11D5A, 0), (0x11D60, 18), (0x11D66, 0), (0x11D67, 18), (0x11D69, 0), (0x11D6A, 18), (0x11D8F, 0), (0x11D90, 18),
+            (0x11D92, 0), (0x11D93, 18), (0x11D99, 0), (0x11DA0, 12), (0x11DAA, 0), (0x11EE0, 18), (0x11EF7, 0), (0x11F00, 18),
+            (0x11F11, 0), (0x11F12, 18), (0x11F3B, 0), (0x11F3E, 18), (0x11F43, 0), (0x11F50, 12), (0x11F5A, 18), (0x11F5B, 0),
+            (0x11FB0, 18), (0x11FB1, 0), (0x12000, 18), (0x1239A, 0), (0x12400, 18), (0x1246F, 0), (0x12480, 18), (0x12544, 0),
+            (0x12F90, 18), (0x12FF1, 0), (0x13000, 18), (0x13430, 0), (0x13440, 18), (0x13456, 0), (0x13460, 18), (0x143FB, 0),
+            (0x14400, 18), (0x14647, 0), (0x16100, 18), (0x16130, 12), (0x1613A, 0), (0x16800, 18), (0x16A39, 0), (0x16A40, 18),
+            (0x16A5F, 0), (0x16A60, 12), (0x16A6A, 0), (0x16A70, 18), (0x16ABF, 0), (0x16AC0, 12), (0x16ACA, 0), (0x16AD0, 18),
+            (0x16AEE, 0), (0x16AF0, 18), (0x16AF5, 0), (0x16B00, 18), (0x16B37, 0), (0x16B40, 18), (0x16B44, 0), (0x16B50, 12),
+            (0x16B5A, 0), (0x16B63, 18), (0x16B78, 0), (0x16B7D, 18), (0x16B90, 0), (0x16D40, 18), (0x16D6D, 0), (0x16D70, 12),
+            (0x16D7A, 0), (0x16E40, 18), (0x16E80, 0), (0x16F00, 18), (0x16F4B, 0), (0x16F4F, 18), (0x16F88, 0), (0x16F8F, 18),
+            (0x16FA0, 0), (0x16FE0, 18), (0x16FE2, 0), (0x16FE3, 18), (0x16FE5, 0), (0x16FF0, 18), (0x16FF2, 0), (0x17000, 18),
+            (0x187F8, 0), (0x18800, 18), (0x18CD6, 0), (0x18CFF, 18), (0x18D09, 0), (0x1AFF0, 18), (0x1AFF4, 0), (0x1AFF5, 18),
+            (0x1AFFC, 0), (0x1AFFD, 18), (0x1AFFF, 0), (0x1B000, 18), (0x1B123, 0), (0x1B132, 18), (0x1B133, 0), (0x1B150, 18),
+            (0x1B153, 0), (0x1B155, 18), (0x1B156, 0), (0x1B164, 18), (0x1B168, 0), (0x1B170, 18), (0x1B2FC, 0), (0x1BC00, 18),
+            (0x1BC6B, 0), (0x1BC70, 18), (0x1BC7D, 0), (0x1BC80, 18), (0x1BC89, 0), (0x1BC90, 18), (0x1BC9A, 0), (0x1BC9D, 18),
+            (0x1BC9F, 0), (0x1CCF0, 12), (0x1CCFA, 0), (0x1CF00, 18), (0x1CF2E, 0), (0x1CF30, 18), (0x1CF47, 0), (0x1D165, 18),
+            (0x1D16A, 0), (0x1D16D, 18), (0x1D173, 0), (0x1D17B, 18), (0x1D183, 0), (0x1D185, 18), (0x1D18C, 0), (0x1D1AA, 18),
+            (0x1D1AE, 0), (0x1D242, 18), (0x1D245, 0), (0x1D400, 18), (0x1D455, 0), (0x1D456, 18), (0x1D49D, 0), (0x1D49E, 18),
+            (0x1D4A0, 0), (0x1D4A2, 18), (0x1D4A3, 0), (0x1D4A5, 18), (0x1D4A7, 0), (0x1D4A9, 18), (0x1D4AD, 0), (0x1D4AE, 18),
+            (0x1D4BA, 0), (0x1D4BB, 18), (0x1D4BC, 0), (0x1D4BD, 18), (0x1D4C4, 0), (0x1D4C5, 18), (0x1D506, 0), (0x1D507, 18),
+            (0x1D50B, 0), (0x1D50D, 18), (0x1D515, 0), (0x1D516, 18), (0x1D51D, 0), (0x1D51E, 18), (0x1D53A, 0), (0x1D53B, 18),
+            (0x1D53F, 0), (0x1D540, 18), (0x1D545, 0), (0x1D546, 18), (0x1D547, 0), (0x1D54A, 18), (0x1D551, 0), (0x1D552, 18),
+            (0x1D6A6, 0), (0x1D6A8, 18), (0x1D6C1, 0), (0x1D6C2, 18), (0x1D6DB, 0), (0x1D6DC, 18), (0x1D6FB, 0), (0x1D6FC, 18),
+            (0x1D715, 0), (0x1D716, 18), (0x1D735, 0), (0x1D736, 18), (0x1D74F, 0), (0x1D750, 18), (0x1D76F, 0), (0x1D770, 18),
+            (0x1D789, 0), (0x1D78A, 18), (0x1D7A9, 0), (0x1D7AA, 18), (0x1D7C3, 0), (0x1D7C4, 18), (0x1D7CC, 0), (0x1D7CE, 12),
+            (0x1D800, 0), (0x1DA00, 18), (0x1DA37, 0), (0x1DA3B, 18), (0x1DA6D, 0), (0x1DA75, 18), (0x1DA76, 0), (0x1DA84, 18),
+            (0x1DA85, 0), (0x1DA9B, 18), (0x1DAA0, 0), (0x1DAA1, 18), (0x1DAB0, 0), (0x1DF00, 18), (0x1DF1F, 0), (0x1DF25, 18),
+            (0x1DF2B, 0), (0x1E000, 18), (0x1E007, 0), (0x1E008, 18), (0x1E019, 0), (0x1E01B, 18), (0x1E022, 0), (0x1E023, 18),
+            (0x1E025, 0), (0x1E026, 18), (0x1E02B, 0), (0x1E030, 18), (0x1E06E, 0), (0x1E08F, 18), (0x1E090, 0), (0x1E100, 18),
+            (0x1E12D, 0), (0x1E130, 18), (0x1E13E, 0), (0x1E140, 12), (0x1E14A, 0), (0x1E14E, 18), (0x1E14F, 0), (0x1E290, 18),
+            (0x1E2AF, 0), (0x1E2C0, 18), (0x1E2F0, 12), (0x1E2FA, 0), (0x1E4D0, 18), (0x1E4F0, 12), (0x1E4FA, 0), (0x1E5D0, 18),
+            (0x1E5F1, 12), (0x1E5FB, 0), (0x1E7E0, 18), (0x1E7E7, 0), (0x1E7E8, 18), (0x1E7EC, 0), (0x1E7ED, 18), (0x1E7EF, 0),
+            (0x1E7F0, 18), (0x1E7FF, 0), (0x1E800, 18), (0x1E8C5, 0), (0x1E8D0, 18), (0x1E8D7, 0), (0x1E900, 18), (0x1E94C, 0),
+            (0x1E950, 12), (0x1E95A, 0), (0x1EE00, 18), (0x1EE04, 0), (0x1EE05, 18), (0x1EE20, 0), (0x1EE21, 18), (0x1EE23, 0),
+            (0x1EE24, 18), (0x1EE25, 0), (0x1EE27, 18), (0x1EE28, 0), (0x1EE29, 18), (0x1EE33, 0), (0x1EE34, 18), (0x1EE38, 0),
+            (0x1EE39, 18), (0x1EE3A, 0), (0x1EE3B, 18), (0x1EE3C, 0), (0x1EE42, 18), (0x1EE43, 0), (0x1EE47, 18), (0x1EE48, 0),
+            (0x1EE49, 18), (0x1EE4A, 0), (0x1EE4B, 18), (0x1EE4C, 0), (0x1EE4D, 18), (0x1EE50, 0), (0x1EE51, 18), (0x1EE53, 0),
+            (0x1EE54, 18), (0x1EE55, 0), (0x1EE57, 18), (0x1EE58, 0), (0x1EE59, 18), (0x1EE5A, 0), (0x1EE5B, 18), (0x1EE5C, 0),
+            (0x1EE5D, 18), (0x1EE5E, 0), (0x1EE5F, 18), (0x1EE60, 0), (0x1EE61, 18), (0x1EE63, 0), (0x1EE64, 18), (0x1EE65, 0),
+            (0x1EE67, 18), (0x1EE6B, 0), (0x1EE6C, 18), (0x1EE73, 0), (0x1EE74, 18), (0x1EE78, 0), (0x1EE79, 18), (0x1EE7D, 0),
+            (0x1EE7E, 18), (0x1EE7F, 0), (0x1EE80, 18), (0x1EE8A, 0), (0x1EE8B, 18), (0x1EE9C, 0), (0x1EEA1, 18), (0x1EEA4, 0),
+            (0x1EEA5, 18), (0x1EEAA, 0), (0x1EEAB, 18), (0x1EEBC, 0), (0x1F130, 18), (0x1F14A, 0), (0x1F150, 18), (0x1F16A, 0),
+            (0x1F170, 18), (0x1F18A, 0), (0x1FBF0, 12), (0x1FBFA, 0), (0x20000, 18), (0x2A6E0, 0), (0x2A700, 18), (0x2B73A, 0),
+            (0x2B740, 18), (0x2B81E, 0), (0x2B820, 18), (0x2CEA2, 0), (0x2CEB0, 18), (0x2EBE1, 0), (0x2EBF0, 18), (0x2EE5E, 0),
+            (0x2F800, 18), (0x2FA1E, 0), (0x30000, 18), (0x3134B, 0), (0x31350, 18), (0x323B0, 0), (0xE0100, 18), (0xE01F0, 0),
+        ];
+        static TRANSITIONS: &[u16] = &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 3, 3, 0, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 0, 20, 18, 21, 22, 23,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0,
+            0, 3, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            5, 5, 5, 5, 5, 25, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 26, 5, 5, 5, 5, 5,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 0, 0, 18, 0, 0, 0, 18, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 18, 0, 0, 0, 18, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 28, 24, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            5, 5, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+            27, 27, 0, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27, 27,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        static ACCEPTS: &[u16] = &[0, 0, 0, 1, 10, 0, 17, 18, 5, 8, 7, 0, 19, 9, 15, 3, 16, 6, 22, 13, 14, 11, 4, 12, 0, 20, 0, 2, 21];
+        static KEYWORDS: &[(&str, u16)] = &[
+        ];
+        rt::DfaLexer {
+            rules: RULES,
+            ascii_classes: &ASCII_CLASSES,
+            unicode_classes: UNICODE_CLASSES,
+            n_classes: 26,
+            transitions: TRANSITIONS,
+            accepts: ACCEPTS,
+            starts: &[1],
+            keyword_seed: 0,
+            keywords: KEYWORDS,
+            layout: None,
+        }
     }
+
     fn create_parser_definition() -> rt::ParserDefinition {
-        let parser_json = r##"[{"Pub":{"ty":32,"body":37,"replaceable":false}},{"Or":[39,41,43,45,47,49,51]},{"Or":[52,53,54,55,56]},{"Pub":{"ty":33,"body":77,"replaceable":false}},{"Pub":{"ty":34,"body":83,"replaceable":false}},{"Pub":{"ty":35,"body":86,"replaceable":false}},{"Pratt":{"atoms":[12,7,9],"prefixes":[],"infixes":[{"ty":39,"op":87,"priority":999,"has_rhs":false},{"ty":40,"op":88,"priority":999,"has_rhs":false}]}},{"Pub":{"ty":36,"body":93,"replaceable":false}},{"Pub":{"ty":37,"body":96,"replaceable":false}},{"Pub":{"ty":38,"body":106,"replaceable":false}},{"Pub":{"ty":39,"body":109,"replaceable":false}},{"Pub":{"ty":40,"body":112,"replaceable":false}},{"Pub":{"ty":41,"body":118,"replaceable":false}},{"Pub":{"ty":42,"body":123,"replaceable":false}},{"Pub":{"ty":43,"body":128,"replaceable":false}},{"Pub":{"ty":44,"body":132,"replaceable":false}},{"Pub":{"ty":45,"body":139,"replaceable":false}},{"Pub":{"ty":46,"body":147,"replaceable":false}},{"Pub":{"ty":47,"body":152,"replaceable":false}},{"Pub":{"ty":48,"body":157,"replaceable":false}},{"Or":[167]},{"Or":[172]},{"Or":[176]},{"Or":[180]},{"Or":[184]},{"Or":[199]},{"Pub":{"ty":49,"body":203,"replaceable":false}},{"Pub":{"ty":50,"body":221,"replaceable":false}},{"Pub":{"ty":51,"body":242,"replaceable":false}},{"Pub":{"ty":52,"body":249,"replaceable":false}},{"Pub":{"ty":53,"body":255,"replaceable":false}},{"Pub":{"ty":54,"body":258,"replaceable":false}},{"Pub":{"ty":55,"body":262,"replaceable":false}},{"Or":[266,269,271,273]},{"WithSkip":[1,2]},{"Rep":34},{"And":[[35],null]},{"Or":[36]},{"ContextualToken":[6,"tokenizer"]},{"And":[[38],null]},{"ContextualToken":[10,"pub"]},{"And":[[40],null]},{"ContextualToken":[7,"rule"]},{"And":[[42],null]},{"Token":19},{"And":[[44],null]},{"ContextualToken":[8,"verbatim"]},{"And":[[46],null]},{"ContextualToken":[9,"ast"]},{"And":[[48],null]},{"ContextualToken":[11,"test"]},{"And":[[50],null]},{"And":[[13],null]},{"And":[[3],null]},{"And":[[26],null]},{"And":[[27],null]},{"And":[[15],null]},{"Opt":16},{"ContextualToken":[10,"pub"]},{"Opt":58},{"ContextualToken":[7,"rule"]},{"Token":31},{"Opt":4},{"Token":20},{"Token":21},{"And":[[63,22,64],null]},{"Token":20},{"Not":1},"Any",{"And":[[67,68],null]},{"Or":[69]},{"Rep":70},{"And":[[66,71],null]},{"Or":[65,72]},{"Layer":[73,9]},{"And":[[57,59,60,61,62,74],3]},{"Or":[75]},{"Cached":76},{"Token":26},{"Call":[20,[[0,5]]]},{"Layer":[23,79]},{"Token":27},{"And":[[78,80,81],null]},{"Or":[82]},{"Token":31},{"And":[[84],null]},{"Or":[85]},{"Token":15},{"Token":14},{"Token":31},{"And":[[89],null]},{"Token":29},{"And":[[91],null]},{"Or":[90,92]},{"Rep":6},{"And":[[94],null]},{"Or":[95]},{"Opt":8},{"Token":13},{"And":[[98,8],null]},{"Or":[99]},{"Rep":100},{"And":[[97,101],null]},{"Or":[102]},{"Call":[21,[[1,103]]]},{"And":[[104],null]},{"Or":[105]},{"Token":15},{"And":[[6,107],null]},{"Or":[108]},{"Token":14},{"And":[[6,110],null]},{"Or":[111]},{"Token":24},{"Token":31},{"Rep":6},{"Token":25},{"And":[[113,114,115,116],null]},{"Or":[117]},{"ContextualToken":[6,"tokenizer"]},{"Rep":14},{"Call":[21,[[1,120]]]},{"And":[[119,121],1]},{"Or":[122]},{"Opt":16},{"Token":31},{"Opt":19},{"And":[[124,125,19,126],2]},{"Or":[127]},{"ContextualToken":[11,"test"]},{"Token":30},{"And":[[129,130],1]},{"Or":[131]},{"Token":19},{"Token":22},{"Call":[20,[[0,17]]]},{"Layer":[24,135]},{"Token":23},{"And":[[133,134,136,137],null]},{"Or":[138]},{"Token":31},{"Token":26},{"Token":27},{"And":[[141,18,142],null]},{"Or":[143]},{"Opt":144},{"And":[[140,145],null]},{"Or":[146]},{"Token":28},{"And":[[148],null]},{"Token":31},{"And":[[150],null]},{"Or":[149,151]},{"Token":29},{"And":[[153],null]},{"Token":30},{"And":[[155],null]},{"Or":[154,156]},{"Var":0},"Eof",{"And":[[159],null]},{"Token":17},{"And":[[161],null]},{"Or":[160,162]},{"And":[[158,163],1]},{"Or":[164]},{"Rep":165},{"And":[[166],null]},{"Token":20},{"Var":1},{"Layer":[22,169]},{"Token":21},{"And":[[168,170,171],1]},{"Token":20},{"Token":21},{"Call":[25,[[2,173],[3,174]]]},{"And":[[175],null]},{"Token":26},{"Token":27},{"Call":[25,[[2,177],[3,178]]]},{"And":[[179],null]},{"Token":22},{"Token":23},{"Call":[25,[[2,181],[3,182]]]},{"And":[[183],null]},{"Var":2},{"Var":2},{"Var":3},{"Call":[25,[[2,186],[3,187]]]},{"Var":3},{"And":[[185,188,189],null]},{"Var":3},{"Not":191},"Any",{"And":[[192,193],null]},{"Or":[194]},{"And":[[195],null]},{"Or":[190,196]},{"Rep":197},{"And":[[198],null]},{"ContextualToken":[8,"verbatim"]},{"Token":30},{"And":[[200,201],1]},{"Or":[202]},{"ContextualToken":[9,"ast"]},{"Token":20},{"ContextualToken":[3,"node"]},{"And":[[206],null]},{"ContextualToken":[4,"class"]},{"And":[[208],null]},{"ContextualToken":[5,"trait"]},{"And":[[210],null]},{"Or":[207,209,211]},{"And":[[28],null]},{"And":[[29],null]},{"And":[[30],null]},{"Or":[213,214,215]},{"WithSkip":[212,216]},{"Rep":217},{"Token":21},{"And":[[204,205,218,219],1]},{"Or":[220]},{"ContextualToken":[3,"node"]},{"Token":31},{"Token":18},{"Token":31},{"Token":17},{"And":[[226],null]},{"Token":20},{"Not":228},{"Not":229},{"And":[[230],null]},{"Or":[227,231]},{"And":[[225,232],null]},{"Or":[233]},{"Rep":234},{"And":[[224,235],null]},{"Or":[236]},{"Opt":237},{"Rep":31},{"Call":[21,[[1,239]]]},{"And":[[222,223,238,240],1]},{"Or":[241]},{"ContextualToken":[4,"class"]},{"Token":31},{"Token":31},{"Rep":245},{"Call":[21,[[1,246]]]},{"And":[[243,244,247],1]},{"Or":[248]},{"ContextualToken":[5,"trait"]},{"Token":31},{"Rep":31},{"Call":[21,[[1,252]]]},{"And":[[250,251,253],1]},{"Or":[254]},{"Token":31},{"And":[[256,32],null]},{"Or":[257]},{"Token":31},{"Opt":33},{"And":[[259,260],null]},{"Or":[261]},{"Token":15},{"Token":16},{"Token":31},{"And":[[263,264,265],null]},{"Token":16},{"Token":31},{"And":[[267,268],null]},{"Token":14},{"And":[[270],null]},{"Token":15},{"And":[[272],null]}]"##;
+        let parser_json = r##"[{"Pub":{"ty":33,"body":38,"replaceable":false}},{"Or":[40,42,44,46,48,50,52]},{"Or":[53,54,55,56,57]},{"Pub":{"ty":34,"body":78,"replaceable":false}},{"Pub":{"ty":35,"body":84,"replaceable":false}},{"Pub":{"ty":36,"body":87,"replaceable":false}},{"Pratt":{"atoms":[12,7,9],"prefixes":[],"infixes":[{"ty":40,"op":88,"priority":999,"has_rhs":false,"assoc":"Left"},{"ty":41,"op":89,"priority":999,"has_rhs":false,"assoc":"Left"}]}},{"Pub":{"ty":37,"body":94,"replaceable":false}},{"Pub":{"ty":38,"body":97,"replaceable":false}},{"Pub":{"ty":39,"body":107,"replaceable":false}},{"Pub":{"ty":40,"body":110,"replaceable":false}},{"Pub":{"ty":41,"body":113,"replaceable":false}},{"Pub":{"ty":42,"body":119,"replaceable":false}},{"Pub":{"ty":43,"body":127,"replaceable":false}},{"Pub":{"ty":44,"body":135,"replaceable":false}},{"Pub":{"ty":45,"body":141,"replaceable":false}},{"Pub":{"ty":46,"body":145,"replaceable":false}},{"Pub":{"ty":47,"body":152,"replaceable":false}},{"Pub":{"ty":48,"body":164,"replaceable":false}},{"Pub":{"ty":49,"body":169,"replaceable":false}},{"Pub":{"ty":50,"body":174,"replaceable":false}},{"Or":[184]},{"Or":[189]},{"Or":[193]},{"Or":[197]},{"Or":[201]},{"Or":[216]},{"Pub":{"ty":51,"body":220,"replaceable":false}},{"Pub":{"ty":52,"body":238,"replaceable":false}},{"Pub":{"ty":53,"body":259,"replaceable":false}},{"Pub":{"ty":54,"body":266,"replaceable":false}},{"Pub":{"ty":55,"body":272,"replaceable":false}},{"Pub":{"ty":56,"body":275,"replaceable":false}},{"Pub":{"ty":57,"body":279,"replaceable":false}},{"Or":[283,286,288,290]},{"WithSkip":[1,2]},{"Rep":35},{"And":[[36],null]},{"Or":[37]},{"ContextualToken":[6,"tokenizer"]},{"And":[[39],null]},{"ContextualToken":[10,"pub"]},{"And":[[41],null]},{"ContextualToken":[7,"rule"]},{"And":[[43],null]},{"Token":20},{"And":[[45],null]},{"ContextualToken":[8,"verbatim"]},{"And":[[47],null]},{"ContextualToken":[9,"ast"]},{"And":[[49],null]},{"ContextualToken":[11,"test"]},{"And":[[51],null]},{"And":[[13],null]},{"And":[[3],null]},{"And":[[27],null]},{"And":[[28],null]},{"And":[[16],null]},{"Opt":17},{"ContextualToken":[10,"pub"]},{"Opt":59},{"ContextualToken":[7,"rule"]},{"Token":32},{"Opt":4},{"Token":21},{"Token":22},{"And":[[64,23,65],null]},{"Token":21},{"Not":1},"Any",{"And":[[68,69],null]},{"Or":[70]},{"Rep":71},{"And":[[67,72],null]},{"Or":[66,73]},{"Layer":[74,9]},{"And":[[58,60,61,62,63,75],3]},{"Or":[76]},{"Cached":77},{"Token":27},{"Call":[21,[[0,5]]]},{"Layer":[24,80]},{"Token":28},{"And":[[79,81,82],null]},{"Or":[83]},{"Token":32},{"And":[[85],null]},{"Or":[86]},{"Token":16},{"Token":15},{"Token":32},{"And":[[90],null]},{"Token":30},{"And":[[92],null]},{"Or":[91,93]},{"Rep":6},{"And":[[95],null]},{"Or":[96]},{"Opt":8},{"Token":14},{"And":[[99,8],null]},{"Or":[100]},{"Rep":101},{"And":[[98,102],null]},{"Or":[103]},{"Call":[22,[[1,104]]]},{"And":[[105],null]},{"Or":[106]},{"Token":16},{"And":[[6,108],null]},{"Or":[109]},{"Token":15},{"And":[[6,111],null]},{"Or":[112]},{"Token":25},{"Token":32},{"Rep":6},{"Token":26},{"And":[[114,115,116,117],null]},{"Or":[118]},{"ContextualToken":[6,"tokenizer"]},{"And":[[15],null]},{"And":[[14],null]},{"Or":[121,122]},{"Rep":123},{"Call":[22,[[1,124]]]},{"And":[[120,125],1]},{"Or":[126]},{"Opt":17},{"Token":32},{"Opt":20},{"And":[[20,130],null]},{"Or":[131]},{"Opt":132},{"And":[[128,129,133],2]},{"Or":[134]},{"ContextualToken":[12,"mode"]},{"Token":32},{"Rep":14},{"Call":[22,[[1,138]]]},{"And":[[136,137,139],2]},{"Or":[140]},{"ContextualToken":[11,"test"]},{"Token":31},{"And":[[142,143],1]},{"Or":[144]},{"Token":20},{"Token":23},{"Call":[21,[[0,18]]]},{"Layer":[25,148]},{"Token":24},{"And":[[146,147,149,150],null]},{"Or":[151]},{"Token":32},{"Token":27},{"Token":18},{"And":[[155,19],null]},{"Or":[156]},{"Rep":157},{"Token":28},{"And":[[154,19,158,159],null]},{"Or":[160]},{"Opt":161},{"And":[[153,162],null]},{"Or":[163]},{"Token":29},{"And":[[165],null]},{"Token":32},{"And":[[167],null]},{"Or":[166,168]},{"Token":30},{"And":[[170],null]},{"Token":31},{"And":[[172],null]},{"Or":[171,173]},{"Var":0},"Eof",{"And":[[176],null]},{"Token":18},{"And":[[178],null]},{"Or":[177,179]},{"And":[[175,180],1]},{"Or":[181]},{"Rep":182},{"And":[[183],null]},{"Token":21},{"Var":1},{"Layer":[23,186]},{"Token":22},{"And":[[185,187,188],1]},{"Token":21},{"Token":22},{"Call":[26,[[2,190],[3,191]]]},{"And":[[192],null]},{"Token":27},{"Token":28},{"Call":[26,[[2,194],[3,195]]]},{"And":[[196],null]},{"Token":23},{"Token":24},{"Call":[26,[[2,198],[3,199]]]},{"And":[[200],null]},{"Var":2},{"Var":2},{"Var":3},{"Call":[26,[[2,203],[3,204]]]},{"Var":3},{"And":[[202,205,206],null]},{"Var":3},{"Not":208},"Any",{"And":[[209,210],null]},{"Or":[211]},{"And":[[212],null]},{"Or":[207,213]},{"Rep":214},{"And":[[215],null]},{"ContextualToken":[8,"verbatim"]},{"Token":31},{"And":[[217,218],1]},{"Or":[219]},{"ContextualToken":[9,"ast"]},{"Token":21},{"ContextualToken":[3,"node"]},{"And":[[223],null]},{"ContextualToken":[4,"class"]},{"And":[[225],null]},{"ContextualToken":[5,"trait"]},{"And":[[227],null]},{"Or":[224,226,228]},{"And":[[29],null]},{"And":[[30],null]},{"And":[[31],null]},{"Or":[230,231,232]},{"WithSkip":[229,233]},{"Rep":234},{"Token":22},{"And":[[221,222,235,236],1]},{"Or":[237]},{"ContextualToken":[3,"node"]},{"Token":32},{"Token":19},{"Token":32},{"Token":18},{"And":[[243],null]},{"Token":21},{"Not":245},{"Not":246},{"And":[[247],null]},{"Or":[244,248]},{"And":[[242,249],null]},{"Or":[250]},{"Rep":251},{"And":[[241,252],null]},{"Or":[253]},{"Opt":254},{"Rep":32},{"Call":[22,[[1,256]]]},{"And":[[239,240,255,257],1]},{"Or":[258]},{"ContextualToken":[4,"class"]},{"Token":32},{"Token":32},{"Rep":262},{"Call":[22,[[1,263]]]},{"And":[[260,261,264],1]},{"Or":[265]},{"ContextualToken":[5,"trait"]},{"Token":32},{"Rep":32},{"Call":[22,[[1,269]]]},{"And":[[267,268,270],1]},{"Or":[271]},{"Token":32},{"And":[[273,33],null]},{"Or":[274]},{"Token":32},{"Opt":34},{"And":[[276,277],null]},{"Or":[278]},{"Token":16},{"Token":17},{"Token":32},{"And":[[280,281,282],null]},{"Token":17},{"Token":32},{"And":[[284,285],null]},{"Token":15},{"And":[[287],null]},{"Token":16},{"And":[[289],null]}]"##;
         ::fall_parse::ParserDefinition {
             node_types: vec![
                 rt::ERROR,
-                WHITESPACE, EOL_COMMENT, NODE, CLASS, TRAIT, TOKENIZER, RULE, VERBATIM, AST, PUB, TEST, EQ, PIPE, STAR, QUESTION, DOT, COMMA, COLON, HASH, L_CURLY, R_CURLY, L_SQUARE, R_SQUARE, L_ANGLE, R_ANGLE, L_PAREN, R_PAREN, NUMBER, SIMPLE_STRING, HASH_STRING, IDENT, FALL_FILE, SYN_RULE, PARAMETERS, PARAMETER, REF_EXPR, SEQ_EXPR, BLOCK_EXPR, OPT_EXPR, REP_EXPR, CALL_EXPR, TOKENIZER_DEF, LEX_RULE, TEST_DEF, ATTRIBUTES, ATTRIBUTE, ATTRIBUTE_VALUE, STRING, VERBATIM_DEF, AST_DEF, AST_NODE_DEF, AST_CLASS_DEF, AST_TRAIT_DEF, METHOD_DEF, AST_SELECTOR,
+                WHITESPACE, EOL_COMMENT, NODE, CLASS, TRAIT, TOKENIZER, RULE, VERBATIM, AST, PUB, TEST, MODE, EQ, PIPE, STAR, QUESTION, DOT, COMMA, COLON, HASH, L_CURLY, R_CURLY, L_SQUARE, R_SQUARE, L_ANGLE, R_ANGLE, L_PAREN, R_PAREN, NUMBER, SIMPLE_STRING, HASH_STRING, IDENT, FALL_FILE, SYN_RULE, PARAMETERS, PARAMETER, REF_EXPR, SEQ_EXPR, BLOCK_EXPR, OPT_EXPR, REP_EXPR, CALL_EXPR, TOKENIZER_DEF, LEX_RULE, LEX_MODE, TEST_DEF, ATTRIBUTES, ATTRIBUTE, ATTRIBUTE_VALUE, STRING, VERBATIM_DEF, AST_DEF, AST_NODE_DEF, AST_CLASS_DEF, AST_TRAIT_DEF, METHOD_DEF, AST_SELECTOR, 
             ],
             syntactical_rules: rt::parser_from_str(parser_json),
             .. Default::default()
@@ -96,25 +357,27 @@ pub fn language() -> &'static rt::Language {
     use self::rt::*;
     lazy_static! {
         static ref LANG: rt::Language = {
-            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::RegexLexer };
+            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::DfaLexer };
             impl rt::LanguageImpl for Impl {
                 fn parse(
                     &self,
                     text: rt::Text,
+                    options: &rt::ParseOptions,
                     metrics: &rt::Metrics,
                     builder: &mut rt::TreeBuilder,
                 ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
-                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, metrics, builder)
+                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, options, metrics, builder)
                 }
                 fn reparse(
                     &self,
                     incremental_data: &dyn std::any::Any,
                     edit: &rt::TextEdit,
                     new_text: rt::Text,
+                    options: &rt::ParseOptions,
                     metrics: &rt::Metrics,
                     builder: &mut rt::TreeBuilder,
                 ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
-                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, metrics, builder)
+                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, options, metrics, builder)
                 }
                 fn node_type_info(&self, ty: rt::NodeType) -> rt::NodeTypeInfo {
                     match ty {
@@ -130,6 +393,7 @@ pub fn language() -> &'static rt::Language {
                         AST => rt::NodeTypeInfo { name: "AST", whitespace_like: false },
                         PUB => rt::NodeTypeInfo { name: "PUB", whitespace_like: false },
                         TEST => rt::NodeTypeInfo { name: "TEST", whitespace_like: false },
+                        MODE => rt::NodeTypeInfo { name: "MODE", whitespace_like: false },
                         EQ => rt::NodeTypeInfo { name: "EQ", whitespace_like: false },
                         PIPE => rt::NodeTypeInfo { name: "PIPE", whitespace_like: false },
                         STAR => rt::NodeTypeInfo { name: "STAR", whitespace_like: false },
@@ -162,6 +426,7 @@ pub fn language() -> &'static rt::Language {
                         CALL_EXPR => rt::NodeTypeInfo { name: "CALL_EXPR", whitespace_like: false },
                         TOKENIZER_DEF => rt::NodeTypeInfo { name: "TOKENIZER_DEF", whitespace_like: false },
                         LEX_RULE => rt::NodeTypeInfo { name: "LEX_RULE", whitespace_like: false },
+                        LEX_MODE => rt::NodeTypeInfo { name: "LEX_MODE", whitespace_like: false },
                         TEST_DEF => rt::NodeTypeInfo { name: "TEST_DEF", whitespace_like: false },
                         ATTRIBUTES => rt::NodeTypeInfo { name: "ATTRIBUTES", whitespace_like: false },
                         ATTRIBUTE => rt::NodeTypeInfo { name: "ATTRIBUTE", whitespace_like: false },
@@ -247,6 +512,9 @@ impl<'f> TokenizerDef<'f> {
     pub fn lex_rules(&self) -> rt::AstChildren<'f, LexRule<'f>> {
         rt::AstChildren::new(self.node().children())
     }
+    pub fn modes(&self) -> rt::AstChildren<'f, LexMode<'f>> {
+        rt::AstChildren::new(self.node().children())
+    }
 }
 impl<'f> ::std::fmt::Debug for TokenizerDef<'f> {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
@@ -255,6 +523,43 @@ impl<'f> ::std::fmt::Debug for TokenizerDef<'f> {
         Ok(())
     }
 }
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct LexMode<'f> { node: rt::Node<'f> }
+impl<'f> rt::AstNode<'f> for LexMode<'f> {
+    fn wrap(node: rt::Node<'f>) -> Option<Self> {
+        if node.ty() == LEX_MODE {
+            Some(LexMode { node })
+        } else {
+            None
+        }
+    }
+    fn node(self) -> rt::Node<'f> { self.node }
+}
+
+impl<'f> LexMode<'f> {
+    
+    pub fn name_ident(&self) -> rt::Node<'f> {
+        self.node().children().find(|n| n.ty() == IDENT).unwrap()
+    }
+    
+    pub fn name(&self) -> rt::Text<'f> {
+        rt::child_of_type_exn(self.node(), IDENT).text()
+    }
+    
+    pub fn lex_rules(&self) -> rt::AstChildren<'f, LexRule<'f>> {
+        rt::AstChildren::new(self.node().children())
+    }
+    
+}
+
+impl<'f> ::std::fmt::Debug for LexMode<'f> {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
+        f.write_str("LexMode@")?;
+        self.node().range().fmt(f)?;
+        Ok(())
+    }
+}
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct LexRule<'f> { node: rt::Node<'f> }
 impl<'f> rt::AstNode<'f> for LexRule<'f> {
@@ -409,6 +714,10 @@ impl<'f> Attribute<'f> {
     pub fn value(&self) -> Option<AttributeValue<'f>> {
         rt::AstChildren::new(self.node().children()).next()
     }
+    
+    pub fn values(&self) -> rt::AstChildren<'f, AttributeValue<'f>> {
+        rt::AstChildren::new(self.node().children())
+    }
 }
 impl<'f> ::std::fmt::Debug for Attribute<'f> {
     fn fmt(&self, f: &mut ::std::fmt::Formatter) -> ::std::fmt::Result {
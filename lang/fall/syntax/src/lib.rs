@@ -19,6 +19,7 @@ pub use self::fall::{
     AST,
     PUB,
     TEST,
+    MODE,
     EQ,
     PIPE,
     STAR,
@@ -51,6 +52,7 @@ pub use self::fall::{
     CALL_EXPR,
     TOKENIZER_DEF,
     LEX_RULE,
+    LEX_MODE,
     TEST_DEF,
     ATTRIBUTES,
     ATTRIBUTE,
@@ -70,6 +72,7 @@ pub use self::fall::{
     FallFile,
     TokenizerDef,
     LexRule,
+    LexMode,
     SynRule,
     Parameters,
     Parameter,
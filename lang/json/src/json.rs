@@ -20,20 +20,88 @@ pub const PRIMITIVE: rt::NodeType = rt::NodeType(115);
 
 
 pub fn language() -> &'static rt::Language {
-    fn create_lexer() -> rt::RegexLexer {
-        rt::RegexLexer::new(vec![
-            rt::LexRule::new(WHITESPACE, "\\s+", None),
-            rt::LexRule::new(LBRACE, "\\{", None),
-            rt::LexRule::new(RBRACE, "\\}", None),
-            rt::LexRule::new(LBRACK, "\\[", None),
-            rt::LexRule::new(RBRACK, "\\]", None),
-            rt::LexRule::new(COLON, ":", None),
-            rt::LexRule::new(COMMA, ",", None),
-            rt::LexRule::new(NULL, "null", None),
-            rt::LexRule::new(BOOL, "true|false", None),
-            rt::LexRule::new(STRING, "\"[^\"]*\"", None),
-            rt::LexRule::new(NUMBER, "\\d+", None),
-        ])
+    fn create_lexer() -> rt::DfaLexer {
+        static RULES: &[rt::DfaRule] = &[
+            rt::DfaRule { ty: WHITESPACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LBRACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RBRACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LBRACK, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RBRACK, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: COLON, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: COMMA, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: NULL, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: BOOL, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STRING, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: NUMBER, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+        ];
+        static ASCII_CLASSES: [u8; 128] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 3, 0, 0, 0, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 5, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 7, 0, 0,
+            0, 8, 0, 0, 0, 9, 10, 0, 0, 0, 0, 0, 11, 0, 12, 0, 0, 0, 13, 14, 15, 16, 0, 0, 0, 0, 0, 17, 0, 18, 0, 0,
+        ];
+        static UNICODE_CLASSES: &[(u32, u8)] = &[
+            (0x80, 0), (0x85, 1), (0x86, 0), (0xA0, 1), (0xA1, 0), (0x660, 4), (0x66A, 0), (0x6F0, 4),
+            (0x6FA, 0), (0x7C0, 4), (0x7CA, 0), (0x966, 4), (0x970, 0), (0x9E6, 4), (0x9F0, 0), (0xA66, 4),
+            (0xA70, 0), (0xAE6, 4), (0xAF0, 0), (0xB66, 4), (0xB70, 0), (0xBE6, 4), (0xBF0, 0), (0xC66, 4),
+            (0xC70, 0), (0xCE6, 4), (0xCF0, 0), (0xD66, 4), (0xD70, 0), (0xDE6, 4), (0xDF0, 0), (0xE50, 4),
+            (0xE5A, 0), (0xED0, 4), (0xEDA, 0), (0xF20, 4), (0xF2A, 0), (0x1040, 4), (0x104A, 0), (0x1090, 4),
+            (0x109A, 0), (0x1680, 1), (0x1681, 0), (0x17E0, 4), (0x17EA, 0), (0x1810, 4), (0x181A, 0), (0x1946, 4),
+            (0x1950, 0), (0x19D0, 4), (0x19DA, 0), (0x1A80, 4), (0x1A8A, 0), (0x1A90, 4), (0x1A9A, 0), (0x1B50, 4),
+            (0x1B5A, 0), (0x1BB0, 4), (0x1BBA, 0), (0x1C40, 4), (0x1C4A, 0), (0x1C50, 4), (0x1C5A, 0), (0x2000, 1),
+            (0x200B, 0), (0x2028, 1), (0x202A, 0), (0x202F, 1), (0x2030, 0), (0x205F, 1), (0x2060, 0), (0x3000, 1),
+            (0x3001, 0), (0xA620, 4), (0xA62A, 0), (0xA8D0, 4), (0xA8DA, 0), (0xA900, 4), (0xA90A, 0), (0xA9D0, 4),
+            (0xA9DA, 0), (0xA9F0, 4), (0xA9FA, 0), (0xAA50, 4), (0xAA5A, 0), (0xABF0, 4), (0xABFA, 0), (0xFF10, 4),
+            (0xFF1A, 0), (0x104A0, 4), (0x104AA, 0), (0x10D30, 4), (0x10D3A, 0), (0x10D40, 4), (0x10D4A, 0), (0x11066, 4),
+            (0x11070, 0), (0x110F0, 4), (0x110FA, 0), (0x11136, 4), (0x11140, 0), (0x111D0, 4), (0x111DA, 0), (0x112F0, 4),
+            (0x112FA, 0), (0x11450, 4), (0x1145A, 0), (0x114D0, 4), (0x114DA, 0), (0x11650, 4), (0x1165A, 0), (0x116C0, 4),
+            (0x116CA, 0), (0x116D0, 4), (0x116E4, 0), (0x11730, 4), (0x1173A, 0), (0x118E0, 4), (0x118EA, 0), (0x11950, 4),
+            (0x1195A, 0), (0x11BF0, 4), (0x11BFA, 0), (0x11C50, 4), (0x11C5A, 0), (0x11D50, 4), (0x11D5A, 0), (0x11DA0, 4),
+            (0x11DAA, 0), (0x11F50, 4), (0x11F5A, 0), (0x16130, 4), (0x1613A, 0), (0x16A60, 4), (0x16A6A, 0), (0x16AC0, 4),
+            (0x16ACA, 0), (0x16B50, 4), (0x16B5A, 0), (0x16D70, 4), (0x16D7A, 0), (0x1CCF0, 4), (0x1CCFA, 0), (0x1D7CE, 4),
+            (0x1D800, 0), (0x1E140, 4), (0x1E14A, 0), (0x1E2F0, 4), (0x1E2FA, 0), (0x1E4F0, 4), (0x1E4FA, 0), (0x1E5F1, 4),
+            (0x1E5FB, 0), (0x1E950, 4), (0x1E95A, 0), (0x1FBF0, 4), (0x1FBFA, 0),
+        ];
+        static TRANSITIONS: &[u16] = &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 2, 3, 4, 5, 6, 7, 8, 0, 0, 9, 0, 10, 0, 0, 11, 0, 12, 13,
+            0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            3, 3, 14, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3, 3,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        static ACCEPTS: &[u16] = &[0, 0, 1, 0, 7, 11, 6, 4, 5, 0, 0, 0, 2, 3, 10, 0, 0, 0, 0, 0, 0, 8, 9];
+        static KEYWORDS: &[(&str, u16)] = &[
+        ];
+        rt::DfaLexer {
+            rules: RULES,
+            ascii_classes: &ASCII_CLASSES,
+            unicode_classes: UNICODE_CLASSES,
+            n_classes: 19,
+            transitions: TRANSITIONS,
+            accepts: ACCEPTS,
+            starts: &[1],
+            keyword_seed: 0,
+            keywords: KEYWORDS,
+            layout: None,
+        }
     }
 
     fn create_parser_definition() -> rt::ParserDefinition {
@@ -52,15 +120,16 @@ pub fn language() -> &'static rt::Language {
     use self::rt::*;
     lazy_static! {
         static ref LANG: rt::Language = {
-            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::RegexLexer };
+            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::DfaLexer };
             impl rt::LanguageImpl for Impl {
                 fn parse(
                     &self,
                     text: rt::Text,
+                    options: &rt::ParseOptions,
                     metrics: &rt::Metrics,
                     builder: &mut rt::TreeBuilder,
                 ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
-                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, metrics, builder)
+                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, options, metrics, builder)
                 }
 
                 fn reparse(
@@ -68,10 +137,11 @@ pub fn language() -> &'static rt::Language {
                     incremental_data: &dyn std::any::Any,
                     edit: &rt::TextEdit,
                     new_text: rt::Text,
+                    options: &rt::ParseOptions,
                     metrics: &rt::Metrics,
                     builder: &mut rt::TreeBuilder,
                 ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
-                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, metrics, builder)
+                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, options, metrics, builder)
                 }
 
                 fn node_type_info(&self, ty: rt::NodeType) -> rt::NodeTypeInfo {
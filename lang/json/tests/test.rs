@@ -1,8 +1,13 @@
 extern crate fall_tree;
 extern crate lang_json;
 
+use std::sync::Arc;
+use std::sync::atomic::AtomicBool;
+
+use fall_tree::{tu, dump_file, dump_file_ws, File, Node, NodePtr, ParseOptions, SyntaxNode, TextBuf, TextEditBuilder, TextRange};
 use fall_tree::test_util::check_syntax;
-use lang_json::lang_json;
+use fall_tree::search::{ancestors, find_leaf_at_offset};
+use lang_json::{lang_json, OBJECT};
 
 #[test]
 fn leading_trailing_space() {
@@ -59,6 +64,56 @@ FILE
 "#);
 }
 
+#[test]
+fn comma1_errors() {
+    let file = lang_json().parse(r##"{ "a": 1 "b": 2 }"##);
+    let errors = file.errors();
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].0.is_empty());
+    assert_eq!(errors[0].1, "COMMA or end of file expected");
+
+    let expected = file.expected().unwrap();
+    assert_eq!(expected.offset, tu(9));
+    assert_eq!(expected.describe(file.language()), "COMMA or end of file");
+}
+
+#[test]
+fn parse_budget() {
+    let text = format!("[{}]", vec!["{ \"a\": [1, 2, 3] }"; 100].join(", "));
+    let check = |options: ParseOptions| {
+        let file = lang_json().parse_with_options(text.as_str(), &options);
+        assert_eq!(file.metrics().get("parse budget exhausted"), Some(1));
+        assert_eq!(file.root().range().end(), file.text().len());
+        assert!(!file.errors().is_empty());
+        assert!(file.incremental_data().is_none());
+    };
+    check(ParseOptions { tick_budget: Some(100), ..Default::default() });
+    check(ParseOptions { cancelled: Some(Arc::new(AtomicBool::new(true))), ..Default::default() });
+
+    let file = lang_json().parse_with_options(text.as_str(), &ParseOptions::default());
+    assert_eq!(file.metrics().get("parse budget exhausted"), None);
+    assert!(file.errors().is_empty());
+}
+
+#[test]
+fn deep_nesting() {
+    let file = lang_json().parse("[".repeat(100_000));
+    assert_eq!(file.metrics().get("nesting limit exceeded"), Some(1));
+    assert!(file.errors().iter().any(|&(_, ref message)| message == "nesting is too deep"));
+    assert!(!dump_file(&file).is_empty());
+}
+
+#[test]
+fn moderate_nesting() {
+    let arrays = format!(r#"{{"a": {}{}}}"#, "[".repeat(200), "]".repeat(200));
+    let objects = format!("{}1{}", r#"{"a": "#.repeat(200), "}".repeat(200));
+    for text in &[arrays, objects] {
+        let file = lang_json().parse(text.as_str());
+        assert_eq!(file.metrics().get("nesting limit exceeded"), None);
+        assert!(file.errors().is_empty());
+    }
+}
+
 #[test]
 fn comma2() {
     check_syntax(&lang_json(), r##"{ "a": 1, "b": 2, }"##, r#"
@@ -230,6 +285,106 @@ fn accidentally_quadratic() {
     assert!(1000 < ticks && ticks < 2000, "spend {} ticks", ticks);
 }
 
+#[test]
+fn reparse_only_the_edited_object() {
+    let items = r#"{"a": [1, 2], "b": null}, "#.repeat(100);
+    let text: TextBuf = format!(r#"[{}{{"c": 1}}, {}{{}}]"#, items, items).into();
+    let file = lang_json().parse(text.as_text());
+    let full = file.metrics().get("reparsed tokens").unwrap();
+
+    let at = tu((items.len() + r#"[{"c": 1"#.len()) as u32);
+    let edit = {
+        let mut b = TextEditBuilder::new(text.as_text());
+        b.insert(at, r#", "d": [true, {"e": false}]"#.to_string());
+        b.build()
+    };
+    let file = lang_json().reparse(&file, &edit);
+    let fresh_file = lang_json().parse(edit.apply(text.as_text()).as_text());
+    assert_eq!(dump_file_ws(&fresh_file), dump_file_ws(&file));
+
+    let reparsed = file.metrics().get("reparsed tokens").unwrap();
+    assert!(reparsed < 20 && reparsed * 100 < full, "reparsed: {}", reparsed);
+}
+
+#[test]
+fn reparse_shares_unchanged_nodes() {
+    let text: TextBuf = r#"[{"a": [1, 2]}, {"b": null}, {"c": [true]}]"#.into();
+    let file = lang_json().parse(text.as_text());
+    let edit = {
+        let mut b = TextEditBuilder::new(text.as_text());
+        b.replace(TextRange::from_len(tu(22), tu(4)), "false".to_string());
+        b.build()
+    };
+    let new_file = lang_json().reparse(&file, &edit);
+    assert_eq!(new_file.text().to_string(), r#"[{"a": [1, 2]}, {"b": false}, {"c": [true]}]"#);
+
+    fn elements(file: &File) -> Vec<Node> {
+        file.root().children().next().unwrap().children().filter(|n| n.ty() == OBJECT).collect()
+    }
+    let (old, new) = (elements(&file), elements(&new_file));
+    assert_eq!(old.len(), 3);
+    assert!(old[0].same_subtree(new[0]));
+    assert!(!old[1].same_subtree(new[1]));
+    assert!(old[2].same_subtree(new[2]));
+    assert!(!file.root().same_subtree(new_file.root()));
+}
+
+#[test]
+fn reparse_maps_reused_nodes() {
+    let text: TextBuf = r#"[{"a": [1, 2]}, {"b": null}, {"c": [true]}]"#.into();
+    let file = lang_json().parse(text.as_text());
+    let edit = {
+        let mut b = TextEditBuilder::new(text.as_text());
+        b.replace(TextRange::from_len(tu(22), tu(4)), "false".to_string());
+        b.build()
+    };
+    let (new_file, map) = lang_json().reparse_with_map(&file, &edit);
+
+    let old = find_leaf_at_offset(file.root(), tu(37)).right_biased().unwrap();
+    assert_eq!(old.text().to_string(), "true");
+    let new = new_file.node(map.get(old.id()).unwrap()).unwrap();
+    assert_eq!(new.text().to_string(), "true");
+    assert_eq!(new.range(), TextRange::from_len(old.range().start() + tu(1), old.range().len()));
+    let object = |node: Node| ancestors(node).find(|n| n.ty() == OBJECT).unwrap().id();
+    assert_eq!(map.get(object(old)), Some(object(new)));
+
+    let null = find_leaf_at_offset(file.root(), tu(23)).right_biased().unwrap();
+    assert_eq!(null.text().to_string(), "null");
+    assert!(ancestors(null).all(|node| map.get(node.id()).is_none()));
+
+    assert_eq!(new_file.node(old.id()), None);
+    assert_eq!(file.node(object(old)).map(|n| n.text().to_string()), Some(r#"{"c": [true]}"#.to_string()));
+}
+
+#[test]
+fn owned_nodes_outlive_borrows() {
+    let text: TextBuf = r#"[{"a": [1, 2]}, {"b": null}]"#.into();
+    let file = Arc::new(lang_json().parse(text.as_text()));
+    let objects: Vec<SyntaxNode> = SyntaxNode::root(file.clone())
+        .children().next().unwrap()
+        .children().filter(|n| n.ty() == OBJECT)
+        .collect();
+    drop(file);
+
+    let b = ::std::thread::spawn(move || objects[1].clone()).join().unwrap();
+    assert_eq!(b.text().to_string(), r#"{"b": null}"#);
+    assert_eq!(b.parent().unwrap().parent().unwrap(), SyntaxNode::root(b.file().clone()));
+    assert_eq!(SyntaxNode::new(b.file(), b.node()), b);
+
+    let ptr = b.ptr();
+    assert_eq!(ptr.resolve(b.file()), Some(b.node()));
+    let edit = {
+        let mut builder = TextEditBuilder::new(text.as_text());
+        builder.insert(tu(1), r#"{}, "#.to_string());
+        builder.build()
+    };
+    let new_file = b.file().edit(&edit);
+    let new_b = ptr.map(&edit).resolve(&new_file).unwrap();
+    assert_eq!(new_b.text().to_string(), r#"{"b": null}"#);
+    assert_eq!(NodePtr::new(new_b), ptr.map(&edit));
+    assert_eq!(ptr.resolve(&new_file), None);
+}
+
 #[test]
 fn example() {
     check_syntax(&lang_json(), r#"
@@ -0,0 +1,6 @@
+extern crate fall_parse;
+
+mod python;
+
+pub use self::python::*;
+pub use self::python::language as lang_python;
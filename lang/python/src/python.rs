@@ -0,0 +1,347 @@
+use fall_parse::runtime as rt;
+pub use self::rt::ERROR;
+
+pub const WHITESPACE: rt::NodeType = rt::NodeType(100);
+pub const COMMENT: rt::NodeType = rt::NodeType(101);
+pub const NEWLINE: rt::NodeType = rt::NodeType(102);
+pub const INDENT: rt::NodeType = rt::NodeType(103);
+pub const DEDENT: rt::NodeType = rt::NodeType(104);
+pub const L_PAREN: rt::NodeType = rt::NodeType(105);
+pub const R_PAREN: rt::NodeType = rt::NodeType(106);
+pub const L_BRACK: rt::NodeType = rt::NodeType(107);
+pub const R_BRACK: rt::NodeType = rt::NodeType(108);
+pub const COLON: rt::NodeType = rt::NodeType(109);
+pub const COMMA: rt::NodeType = rt::NodeType(110);
+pub const DOT: rt::NodeType = rt::NodeType(111);
+pub const EQ: rt::NodeType = rt::NodeType(112);
+pub const EQEQ: rt::NodeType = rt::NodeType(113);
+pub const NEQ: rt::NodeType = rt::NodeType(114);
+pub const LT: rt::NodeType = rt::NodeType(115);
+pub const GT: rt::NodeType = rt::NodeType(116);
+pub const PLUS: rt::NodeType = rt::NodeType(117);
+pub const MINUS: rt::NodeType = rt::NodeType(118);
+pub const STAR: rt::NodeType = rt::NodeType(119);
+pub const SLASH: rt::NodeType = rt::NodeType(120);
+pub const DEF: rt::NodeType = rt::NodeType(121);
+pub const RETURN: rt::NodeType = rt::NodeType(122);
+pub const PASS: rt::NodeType = rt::NodeType(123);
+pub const IF: rt::NodeType = rt::NodeType(124);
+pub const ELIF: rt::NodeType = rt::NodeType(125);
+pub const ELSE: rt::NodeType = rt::NodeType(126);
+pub const WHILE: rt::NodeType = rt::NodeType(127);
+pub const FOR: rt::NodeType = rt::NodeType(128);
+pub const IN: rt::NodeType = rt::NodeType(129);
+pub const AND: rt::NodeType = rt::NodeType(130);
+pub const OR: rt::NodeType = rt::NodeType(131);
+pub const NUMBER: rt::NodeType = rt::NodeType(132);
+pub const STRING: rt::NodeType = rt::NodeType(133);
+pub const IDENT: rt::NodeType = rt::NodeType(134);
+pub const FILE: rt::NodeType = rt::NodeType(135);
+pub const DEF_STMT: rt::NodeType = rt::NodeType(136);
+pub const PARAM_LIST: rt::NodeType = rt::NodeType(137);
+pub const PARAM: rt::NodeType = rt::NodeType(138);
+pub const IF_STMT: rt::NodeType = rt::NodeType(139);
+pub const ELIF_CLAUSE: rt::NodeType = rt::NodeType(140);
+pub const ELSE_CLAUSE: rt::NodeType = rt::NodeType(141);
+pub const WHILE_STMT: rt::NodeType = rt::NodeType(142);
+pub const FOR_STMT: rt::NodeType = rt::NodeType(143);
+pub const BLOCK: rt::NodeType = rt::NodeType(144);
+pub const PASS_STMT: rt::NodeType = rt::NodeType(145);
+pub const RETURN_STMT: rt::NodeType = rt::NodeType(146);
+pub const ASSIGN_STMT: rt::NodeType = rt::NodeType(147);
+pub const EXPR_STMT: rt::NodeType = rt::NodeType(148);
+pub const EXPR: rt::NodeType = rt::NodeType(149);
+pub const NAME_EXPR: rt::NodeType = rt::NodeType(150);
+pub const LITERAL: rt::NodeType = rt::NodeType(151);
+pub const PAREN_EXPR: rt::NodeType = rt::NodeType(152);
+pub const LIST_EXPR: rt::NodeType = rt::NodeType(153);
+pub const CALL_EXPR: rt::NodeType = rt::NodeType(154);
+pub const ARG_LIST: rt::NodeType = rt::NodeType(155);
+pub const FIELD_EXPR: rt::NodeType = rt::NodeType(156);
+pub const NEG_EXPR: rt::NodeType = rt::NodeType(157);
+pub const PRODUCT_EXPR: rt::NodeType = rt::NodeType(158);
+pub const SUM_EXPR: rt::NodeType = rt::NodeType(159);
+pub const COMPARISON: rt::NodeType = rt::NodeType(160);
+pub const AND_EXPR: rt::NodeType = rt::NodeType(161);
+pub const OR_EXPR: rt::NodeType = rt::NodeType(162);
+
+
+pub fn language() -> &'static rt::Language {
+    fn create_lexer() -> rt::DfaLexer {
+        static RULES: &[rt::DfaRule] = &[
+            rt::DfaRule { ty: WHITESPACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: COMMENT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: L_PAREN, mode: 0, transition: rt::ModeTransition::Push(0), f: None },
+            rt::DfaRule { ty: R_PAREN, mode: 0, transition: rt::ModeTransition::Pop, f: None },
+            rt::DfaRule { ty: L_BRACK, mode: 0, transition: rt::ModeTransition::Push(0), f: None },
+            rt::DfaRule { ty: R_BRACK, mode: 0, transition: rt::ModeTransition::Pop, f: None },
+            rt::DfaRule { ty: COLON, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: COMMA, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: DOT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: EQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: EQEQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: NEQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: GT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: PLUS, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: MINUS, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STAR, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: SLASH, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: DEF, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RETURN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: PASS, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: IF, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: ELIF, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: ELSE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: WHILE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: FOR, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: IN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: AND, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: OR, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: NUMBER, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STRING, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: IDENT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+        ];
+        static ASCII_CLASSES: [u8; 128] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 3, 4, 5, 0, 0, 0, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 15, 15, 15, 15, 15, 15, 15, 15, 15, 16, 0, 17, 18, 19, 0,
+            0, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 21, 0, 22, 0, 20,
+            0, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 20, 0, 0, 0, 0, 0,
+        ];
+        static UNICODE_CLASSES: &[(u32, u8)] = &[
+            (0x80, 0),
+        ];
+        static TRANSITIONS: &[u16] = &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 2, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22,
+            0, 2, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 0,
+            4, 4, 0, 4, 24, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4, 4,
+            5, 5, 0, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5, 5,
+            6, 6, 0, 6, 6, 6, 24, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 25, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 20, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        static ACCEPTS: &[u16] = &[0, 0, 1, 0, 0, 2, 0, 3, 4, 17, 15, 8, 16, 9, 18, 30, 7, 13, 10, 14, 32, 5, 6, 12, 31, 11];
+        static KEYWORDS: &[(&str, u16)] = &[
+            ("pass", 20),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("elif", 22),
+            ("", 0),
+            ("", 0),
+            ("and", 27),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("while", 24),
+            ("return", 19),
+            ("for", 25),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("or", 28),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("def", 18),
+            ("", 0),
+            ("in", 26),
+            ("", 0),
+            ("else", 23),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("if", 21),
+            ("", 0),
+        ];
+        rt::DfaLexer {
+            rules: RULES,
+            ascii_classes: &ASCII_CLASSES,
+            unicode_classes: UNICODE_CLASSES,
+            n_classes: 23,
+            transitions: TRANSITIONS,
+            accepts: ACCEPTS,
+            starts: &[1],
+            keyword_seed: 0,
+            keywords: KEYWORDS,
+            layout: Some(rt::Layout {
+                indent: INDENT,
+                dedent: DEDENT,
+                newline: NEWLINE,
+                skip: &[WHITESPACE, COMMENT],
+            }),
+        }
+    }
+
+    fn create_parser_definition() -> rt::ParserDefinition {
+        let parser_json = r##"[{"Pub":{"ty":36,"body":37,"replaceable":false}},{"Or":[38,39,40,41,42,43,44,45]},{"Or":[47,49,51,53,55,57,58]},{"Pub":{"ty":37,"body":63,"replaceable":false}},{"Pub":{"ty":38,"body":68,"replaceable":false}},{"Pub":{"ty":39,"body":71,"replaceable":false}},{"Pub":{"ty":40,"body":77,"replaceable":false}},{"Pub":{"ty":41,"body":81,"replaceable":false}},{"Pub":{"ty":42,"body":85,"replaceable":false}},{"Pub":{"ty":43,"body":89,"replaceable":false}},{"Pub":{"ty":44,"body":95,"replaceable":false}},{"Or":[96,97]},{"Pub":{"ty":45,"body":103,"replaceable":false}},{"Or":[104,105,106,107]},{"Pub":{"ty":46,"body":111,"replaceable":false}},{"Pub":{"ty":47,"body":116,"replaceable":false}},{"Pub":{"ty":48,"body":120,"replaceable":false}},{"Pub":{"ty":49,"body":123,"replaceable":false}},{"Pratt":{"atoms":[20,21,22,23],"prefixes":[{"ty":58,"op":128,"priority":999}],"infixes":[{"ty":55,"op":25,"priority":999,"has_rhs":false,"assoc":"Left"},{"ty":57,"op":127,"priority":999,"has_rhs":false,"assoc":"Left"},{"ty":59,"op":133,"priority":6,"has_rhs":true,"assoc":"Left"},{"ty":60,"op":138,"priority":5,"has_rhs":true,"assoc":"Left"},{"ty":61,"op":147,"priority":4,"has_rhs":true,"assoc":"Left"},{"ty":62,"op":148,"priority":2,"has_rhs":true,"assoc":"Left"},{"ty":63,"op":149,"priority":1,"has_rhs":true,"assoc":"Left"}]}},{"Or":[151,153,155,157,159,161]},{"Pub":{"ty":51,"body":164,"replaceable":false}},{"Pub":{"ty":52,"body":169,"replaceable":false}},{"Pub":{"ty":53,"body":173,"replaceable":false}},{"Pub":{"ty":54,"body":178,"replaceable":false}},{"Pub":{"ty":55,"body":180,"replaceable":false}},{"Pub":{"ty":56,"body":185,"replaceable":false}},{"Pub":{"ty":57,"body":191,"replaceable":false}},{"Pub":{"ty":58,"body":194,"replaceable":false}},{"Pub":{"ty":59,"body":201,"replaceable":false}},{"Pub":{"ty":60,"body":208,"replaceable":false}},{"Pub":{"ty":61,"body":219,"replaceable":false}},{"Pub":{"ty":62,"body":222,"replaceable":false}},{"Pub":{"ty":63,"body":225,"replaceable":false}},{"Or":[237]},{"WithSkip":[2,1]},{"Rep":34},{"And":[[35],null]},{"Or":[36]},{"And":[[3],null]},{"And":[[6],null]},{"And":[[9],null]},{"And":[[10],null]},{"And":[[14],null]},{"And":[[15],null]},{"And":[[16],null]},{"And":[[17],null]},{"Token":22},{"And":[[46],null]},{"Token":25},{"And":[[48],null]},{"Token":28},{"And":[[50],null]},{"Token":29},{"And":[[52],null]},{"Token":24},{"And":[[54],null]},{"Token":23},{"And":[[56],null]},{"And":[[19],null]},{"Token":22},{"Token":35},{"Token":10},{"And":[[59,60,4,61,11],1]},{"Or":[62]},{"Token":6},{"Call":[33,[[0,5]]]},{"Token":7},{"And":[[64,65,66],1]},{"Or":[67]},{"Token":35},{"And":[[69],null]},{"Or":[70]},{"Token":25},{"Token":10},{"Rep":7},{"Opt":8},{"And":[[72,18,73,11,74,75],1]},{"Or":[76]},{"Token":26},{"Token":10},{"And":[[78,18,79,11],1]},{"Or":[80]},{"Token":27},{"Token":10},{"And":[[82,83,11],1]},{"Or":[84]},{"Token":28},{"Token":10},{"And":[[86,18,87,11],1]},{"Or":[88]},{"Token":29},{"Token":35},{"Token":30},{"Token":10},{"And":[[90,91,92,18,93,11],1]},{"Or":[94]},{"And":[[12],null]},{"And":[[13],null]},{"Token":3},{"Token":4},{"Rep":1},{"Token":5},{"And":[[98,99,100,101],2]},{"Or":[102]},{"And":[[14],null]},{"And":[[15],null]},{"And":[[16],null]},{"And":[[17],null]},{"Token":24},{"Token":3},{"And":[[108,109],1]},{"Or":[110]},{"Token":23},{"Opt":18},{"Token":3},{"And":[[112,113,114],1]},{"Or":[115]},{"Token":13},{"Token":3},{"And":[[18,117,18,118],2]},{"Or":[119]},{"Token":3},{"And":[[18,121],1]},{"Or":[122]},{"Token":12},{"Token":35},{"And":[[124,125],null]},{"Or":[126]},{"Token":19},{"Token":20},{"And":[[129],null]},{"Token":21},{"And":[[131],null]},{"Or":[130,132]},{"Token":18},{"And":[[134],null]},{"Token":19},{"And":[[136],null]},{"Or":[135,137]},{"Token":14},{"And":[[139],null]},{"Token":15},{"And":[[141],null]},{"Token":16},{"And":[[143],null]},{"Token":17},{"And":[[145],null]},{"Or":[140,142,144,146]},{"Token":31},{"Token":32},{"Token":35},{"And":[[150],null]},{"Token":33},{"And":[[152],null]},{"Token":34},{"And":[[154],null]},{"Token":6},{"And":[[156],null]},{"Token":8},{"And":[[158],null]},{"Token":19},{"And":[[160],null]},{"Token":35},{"And":[[162],null]},{"Or":[163]},{"Token":33},{"And":[[165],null]},{"Token":34},{"And":[[167],null]},{"Or":[166,168]},{"Token":6},{"Token":7},{"And":[[170,18,171],1]},{"Or":[172]},{"Token":8},{"Call":[33,[[0,18]]]},{"Token":9},{"And":[[174,175,176],1]},{"Or":[177]},{"And":[[18,25],null]},{"Or":[179]},{"Token":6},{"Call":[33,[[0,18]]]},{"Token":7},{"And":[[181,182,183],1]},{"Or":[184]},{"Token":12},{"Token":35},{"And":[[186,187],null]},{"Or":[188]},{"And":[[18,189],null]},{"Or":[190]},{"Token":19},{"And":[[192,18],null]},{"Or":[193]},{"Token":20},{"And":[[195],null]},{"Token":21},{"And":[[197],null]},{"Or":[196,198]},{"And":[[18,199,18],null]},{"Or":[200]},{"Token":18},{"And":[[202],null]},{"Token":19},{"And":[[204],null]},{"Or":[203,205]},{"And":[[18,206,18],null]},{"Or":[207]},{"Token":14},{"And":[[209],null]},{"Token":15},{"And":[[211],null]},{"Token":16},{"And":[[213],null]},{"Token":17},{"And":[[215],null]},{"Or":[210,212,214,216]},{"And":[[18,217,18],null]},{"Or":[218]},{"Token":31},{"And":[[18,220,18],null]},{"Or":[221]},{"Token":32},{"And":[[18,223,18],null]},{"Or":[224]},{"Var":0},{"Token":11},{"Var":0},{"And":[[227,228],null]},{"Or":[229]},{"Rep":230},{"Token":11},{"Opt":232},{"And":[[226,231,233],null]},{"Or":[234]},{"Opt":235},{"And":[[236],null]}]"##;
+
+        ::fall_parse::ParserDefinition {
+            node_types: vec![
+                rt::ERROR,
+                WHITESPACE, COMMENT, NEWLINE, INDENT, DEDENT, L_PAREN, R_PAREN, L_BRACK, R_BRACK, COLON, COMMA, DOT, EQ, EQEQ, NEQ, LT, GT, PLUS, MINUS, STAR, SLASH, DEF, RETURN, PASS, IF, ELIF, ELSE, WHILE, FOR, IN, AND, OR, NUMBER, STRING, IDENT, FILE, DEF_STMT, PARAM_LIST, PARAM, IF_STMT, ELIF_CLAUSE, ELSE_CLAUSE, WHILE_STMT, FOR_STMT, BLOCK, PASS_STMT, RETURN_STMT, ASSIGN_STMT, EXPR_STMT, EXPR, NAME_EXPR, LITERAL, PAREN_EXPR, LIST_EXPR, CALL_EXPR, ARG_LIST, FIELD_EXPR, NEG_EXPR, PRODUCT_EXPR, SUM_EXPR, COMPARISON, AND_EXPR, OR_EXPR,
+            ],
+            syntactical_rules: rt::parser_from_str(parser_json),
+            
+            .. Default::default()
+        }
+    }
+    use self::rt::*;
+    lazy_static! {
+        static ref LANG: rt::Language = {
+            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::DfaLexer };
+            impl rt::LanguageImpl for Impl {
+                fn parse(
+                    &self,
+                    text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, options, metrics, builder)
+                }
+
+                fn reparse(
+                    &self,
+                    incremental_data: &dyn std::any::Any,
+                    edit: &rt::TextEdit,
+                    new_text: rt::Text,
+                    options: &rt::ParseOptions,
+                    metrics: &rt::Metrics,
+                    builder: &mut rt::TreeBuilder,
+                ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
+                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, options, metrics, builder)
+                }
+
+                fn node_type_info(&self, ty: rt::NodeType) -> rt::NodeTypeInfo {
+                    match ty {
+                        ERROR => rt::NodeTypeInfo { name: "ERROR", whitespace_like: false },
+                        WHITESPACE => rt::NodeTypeInfo { name: "WHITESPACE", whitespace_like: true },
+                        COMMENT => rt::NodeTypeInfo { name: "COMMENT", whitespace_like: true },
+                        NEWLINE => rt::NodeTypeInfo { name: "NEWLINE", whitespace_like: false },
+                        INDENT => rt::NodeTypeInfo { name: "INDENT", whitespace_like: false },
+                        DEDENT => rt::NodeTypeInfo { name: "DEDENT", whitespace_like: false },
+                        L_PAREN => rt::NodeTypeInfo { name: "L_PAREN", whitespace_like: false },
+                        R_PAREN => rt::NodeTypeInfo { name: "R_PAREN", whitespace_like: false },
+                        L_BRACK => rt::NodeTypeInfo { name: "L_BRACK", whitespace_like: false },
+                        R_BRACK => rt::NodeTypeInfo { name: "R_BRACK", whitespace_like: false },
+                        COLON => rt::NodeTypeInfo { name: "COLON", whitespace_like: false },
+                        COMMA => rt::NodeTypeInfo { name: "COMMA", whitespace_like: false },
+                        DOT => rt::NodeTypeInfo { name: "DOT", whitespace_like: false },
+                        EQ => rt::NodeTypeInfo { name: "EQ", whitespace_like: false },
+                        EQEQ => rt::NodeTypeInfo { name: "EQEQ", whitespace_like: false },
+                        NEQ => rt::NodeTypeInfo { name: "NEQ", whitespace_like: false },
+                        LT => rt::NodeTypeInfo { name: "LT", whitespace_like: false },
+                        GT => rt::NodeTypeInfo { name: "GT", whitespace_like: false },
+                        PLUS => rt::NodeTypeInfo { name: "PLUS", whitespace_like: false },
+                        MINUS => rt::NodeTypeInfo { name: "MINUS", whitespace_like: false },
+                        STAR => rt::NodeTypeInfo { name: "STAR", whitespace_like: false },
+                        SLASH => rt::NodeTypeInfo { name: "SLASH", whitespace_like: false },
+                        DEF => rt::NodeTypeInfo { name: "DEF", whitespace_like: false },
+                        RETURN => rt::NodeTypeInfo { name: "RETURN", whitespace_like: false },
+                        PASS => rt::NodeTypeInfo { name: "PASS", whitespace_like: false },
+                        IF => rt::NodeTypeInfo { name: "IF", whitespace_like: false },
+                        ELIF => rt::NodeTypeInfo { name: "ELIF", whitespace_like: false },
+                        ELSE => rt::NodeTypeInfo { name: "ELSE", whitespace_like: false },
+                        WHILE => rt::NodeTypeInfo { name: "WHILE", whitespace_like: false },
+                        FOR => rt::NodeTypeInfo { name: "FOR", whitespace_like: false },
+                        IN => rt::NodeTypeInfo { name: "IN", whitespace_like: false },
+                        AND => rt::NodeTypeInfo { name: "AND", whitespace_like: false },
+                        OR => rt::NodeTypeInfo { name: "OR", whitespace_like: false },
+                        NUMBER => rt::NodeTypeInfo { name: "NUMBER", whitespace_like: false },
+                        STRING => rt::NodeTypeInfo { name: "STRING", whitespace_like: false },
+                        IDENT => rt::NodeTypeInfo { name: "IDENT", whitespace_like: false },
+                        FILE => rt::NodeTypeInfo { name: "FILE", whitespace_like: false },
+                        DEF_STMT => rt::NodeTypeInfo { name: "DEF_STMT", whitespace_like: false },
+                        PARAM_LIST => rt::NodeTypeInfo { name: "PARAM_LIST", whitespace_like: false },
+                        PARAM => rt::NodeTypeInfo { name: "PARAM", whitespace_like: false },
+                        IF_STMT => rt::NodeTypeInfo { name: "IF_STMT", whitespace_like: false },
+                        ELIF_CLAUSE => rt::NodeTypeInfo { name: "ELIF_CLAUSE", whitespace_like: false },
+                        ELSE_CLAUSE => rt::NodeTypeInfo { name: "ELSE_CLAUSE", whitespace_like: false },
+                        WHILE_STMT => rt::NodeTypeInfo { name: "WHILE_STMT", whitespace_like: false },
+                        FOR_STMT => rt::NodeTypeInfo { name: "FOR_STMT", whitespace_like: false },
+                        BLOCK => rt::NodeTypeInfo { name: "BLOCK", whitespace_like: false },
+                        PASS_STMT => rt::NodeTypeInfo { name: "PASS_STMT", whitespace_like: false },
+                        RETURN_STMT => rt::NodeTypeInfo { name: "RETURN_STMT", whitespace_like: false },
+                        ASSIGN_STMT => rt::NodeTypeInfo { name: "ASSIGN_STMT", whitespace_like: false },
+                        EXPR_STMT => rt::NodeTypeInfo { name: "EXPR_STMT", whitespace_like: false },
+                        EXPR => rt::NodeTypeInfo { name: "EXPR", whitespace_like: false },
+                        NAME_EXPR => rt::NodeTypeInfo { name: "NAME_EXPR", whitespace_like: false },
+                        LITERAL => rt::NodeTypeInfo { name: "LITERAL", whitespace_like: false },
+                        PAREN_EXPR => rt::NodeTypeInfo { name: "PAREN_EXPR", whitespace_like: false },
+                        LIST_EXPR => rt::NodeTypeInfo { name: "LIST_EXPR", whitespace_like: false },
+                        CALL_EXPR => rt::NodeTypeInfo { name: "CALL_EXPR", whitespace_like: false },
+                        ARG_LIST => rt::NodeTypeInfo { name: "ARG_LIST", whitespace_like: false },
+                        FIELD_EXPR => rt::NodeTypeInfo { name: "FIELD_EXPR", whitespace_like: false },
+                        NEG_EXPR => rt::NodeTypeInfo { name: "NEG_EXPR", whitespace_like: false },
+                        PRODUCT_EXPR => rt::NodeTypeInfo { name: "PRODUCT_EXPR", whitespace_like: false },
+                        SUM_EXPR => rt::NodeTypeInfo { name: "SUM_EXPR", whitespace_like: false },
+                        COMPARISON => rt::NodeTypeInfo { name: "COMPARISON", whitespace_like: false },
+                        AND_EXPR => rt::NodeTypeInfo { name: "AND_EXPR", whitespace_like: false },
+                        OR_EXPR => rt::NodeTypeInfo { name: "OR_EXPR", whitespace_like: false },
+                        _ => panic!("Unknown rt::NodeType: {:?}", ty)
+                    }
+                }
+            }
+
+            rt::Language::new(Impl {
+                parser_definition: create_parser_definition(),
+                lexer: create_lexer()
+            })
+        };
+    }
+
+    &*LANG
+}
@@ -0,0 +1,211 @@
+extern crate fall_tree;
+extern crate lang_python;
+
+use fall_tree::{dump_file_ws, TextBuf, TextEditBuilder, TextRange, tu};
+use fall_tree::test_util::{check_syntax, report_diff};
+use lang_python::lang_python;
+
+#[test]
+fn blocks() {
+    check_syntax(&lang_python(), "\
+if x:
+    y = 1
+    if z:
+        pass
+w
+", r#"
+FILE
+  IF_STMT
+    IF "if"
+    NAME_EXPR
+      IDENT "x"
+    COLON ":"
+    BLOCK
+      NEWLINE ""
+      INDENT ""
+      ASSIGN_STMT
+        NAME_EXPR
+          IDENT "y"
+        EQ "="
+        LITERAL
+          NUMBER "1"
+        NEWLINE ""
+      IF_STMT
+        IF "if"
+        NAME_EXPR
+          IDENT "z"
+        COLON ":"
+        BLOCK
+          NEWLINE ""
+          INDENT ""
+          PASS_STMT
+            PASS "pass"
+            NEWLINE ""
+          DEDENT ""
+      DEDENT ""
+  EXPR_STMT
+    NAME_EXPR
+      IDENT "w"
+    NEWLINE ""
+"#);
+}
+
+#[test]
+fn layout_is_lossless() {
+    let text = "def f(a):\n  # comment\n\n  return a\n";
+    let file = lang_python().parse(text.to_owned());
+    assert_eq!(file.text().to_string(), text);
+    report_diff(r##"
+FILE
+  DEF_STMT
+    DEF "def"
+    WHITESPACE " "
+    IDENT "f"
+    PARAM_LIST
+      L_PAREN "("
+      PARAM
+        IDENT "a"
+      R_PAREN ")"
+    COLON ":"
+    BLOCK
+      NEWLINE ""
+      WHITESPACE "\n  "
+      COMMENT "# comment"
+      WHITESPACE "\n\n  "
+      INDENT ""
+      RETURN_STMT
+        RETURN "return"
+        WHITESPACE " "
+        NAME_EXPR
+          IDENT "a"
+        NEWLINE ""
+      WHITESPACE "\n"
+      DEDENT ""
+"##, &dump_file_ws(&file));
+}
+
+#[test]
+fn brackets_suspend_layout() {
+    check_syntax(&lang_python(), "\
+xs = [
+  1,
+    2,
+]
+f(a,
+  b)
+", r#"
+FILE
+  ASSIGN_STMT
+    NAME_EXPR
+      IDENT "xs"
+    EQ "="
+    LIST_EXPR
+      L_BRACK "["
+      LITERAL
+        NUMBER "1"
+      COMMA ","
+      LITERAL
+        NUMBER "2"
+      COMMA ","
+      R_BRACK "]"
+    NEWLINE ""
+  EXPR_STMT
+    CALL_EXPR
+      NAME_EXPR
+        IDENT "f"
+      ARG_LIST
+        L_PAREN "("
+        NAME_EXPR
+          IDENT "a"
+        COMMA ","
+        NAME_EXPR
+          IDENT "b"
+        R_PAREN ")"
+    NEWLINE ""
+"#);
+}
+
+#[test]
+fn dedent_to_unknown_level() {
+    check_syntax(&lang_python(), "\
+while x:
+    a
+  b
+", r#"
+FILE
+  WHILE_STMT
+    WHILE "while"
+    NAME_EXPR
+      IDENT "x"
+    COLON ":"
+    BLOCK
+      NEWLINE ""
+      INDENT ""
+      EXPR_STMT
+        NAME_EXPR
+          IDENT "a"
+        NEWLINE ""
+      DEDENT ""
+  ERROR
+    INDENT ""
+  EXPR_STMT
+    NAME_EXPR
+      IDENT "b"
+    NEWLINE ""
+  ERROR
+    DEDENT ""
+"#);
+}
+
+#[test]
+fn no_trailing_newline() {
+    check_syntax(&lang_python(), "for x in xs: pass", r#"
+FILE
+  FOR_STMT
+    FOR "for"
+    IDENT "x"
+    IN "in"
+    NAME_EXPR
+      IDENT "xs"
+    COLON ":"
+    PASS_STMT
+      PASS "pass"
+      NEWLINE ""
+"#);
+}
+
+#[test]
+fn relex_tracks_indentation() {
+    let mut text: TextBuf = "\
+def f():
+    if x:
+        y
+    z
+w
+".into();
+    let mut file = lang_python().parse(text.to_string());
+
+    let edits: Vec<fn(&mut TextEditBuilder)> = vec![
+        // Indent `z` into the `if` block.
+        |b| b.insert(tu(31), "    ".to_string()),
+        // Dedent `y` out of it.
+        |b| b.delete(TextRange::from_len(tu(19), tu(4))),
+        // Open a bracket, so the following lines are joined.
+        |b| b.insert(tu(13), "(".to_string()),
+        |b| b.delete(TextRange::from_len(tu(13), tu(1))),
+        // Break the line in the middle of the `if`.
+        |b| b.insert(tu(15), "\n".to_string()),
+        |b| b.insert(tu(0), "  ".to_string()),
+    ];
+    for edit in edits {
+        let edit = {
+            let mut b = TextEditBuilder::new(text.as_text());
+            edit(&mut b);
+            b.build()
+        };
+        text = edit.apply(text.as_text());
+        file = file.edit(&edit);
+        let fresh_file = lang_python().parse(text.to_string());
+        report_diff(&dump_file_ws(&fresh_file), &dump_file_ws(&file));
+    }
+}
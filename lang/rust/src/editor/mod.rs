@@ -16,7 +16,6 @@ use self::file_symbols::process_symbols;
 mod symbol_index;
 pub use self::symbol_index::SymbolIndex;
 
-mod line_index;
 mod fst_subseq;
 
 pub struct RustEditorFile {
@@ -4,10 +4,9 @@ use fst;
 use fst::IntoStreamer;
 use file;
 
-use fall_tree::{TextRange, NodeType};
+use fall_tree::{TextRange, NodeType, LineIndex, LineCol};
 use indxr::{FileIndex, IndexableFileSet};
 
-use crate::editor::line_index::{LineCol, LineIndex};
 use crate::editor::fst_subseq::FstSubSeq;
 use crate::editor::file_symbols::process_symbols;
 
@@ -99,7 +98,7 @@ impl FileSymbols {
                 ty: node.ty(),
                 name: name.to_string(),
                 range,
-                lc_range: [line_index.translate(range.start()), line_index.translate(range.end())],
+                lc_range: [line_index.line_col(range.start()), line_index.line_col(range.end())],
             })
         });
         symbols.sort_by_key(|s| s.name.to_lowercase());
@@ -1,7 +1,6 @@
 extern crate serde;
 #[macro_use]
 extern crate serde_derive;
-extern crate ordslice;
 extern crate fst;
 extern crate file;
 
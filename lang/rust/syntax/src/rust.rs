@@ -205,97 +205,690 @@ pub const BLOCK_MACRO: rt::NodeType = rt::NodeType(301);
 pub const LINE_MACRO: rt::NodeType = rt::NodeType(302);
 pub const TT: rt::NodeType = rt::NodeType(303);
 pub fn language() -> &'static rt::Language {
-    fn create_lexer() -> rt::RegexLexer {
-        rt::RegexLexer::new(vec![
-            rt::LexRule::new(WHITESPACE, "\\s+", None),
-            rt::LexRule::new(LINE_COMMENT, "//.*\\n?", None),
-            rt::LexRule::new(BLOCK_COMMENT, "/\\*", Some(parse_block_comment)),
-            rt::LexRule::new(AS, "as", None),
-            rt::LexRule::new(CRATE, "crate", None),
-            rt::LexRule::new(EXTERN, "extern", None),
-            rt::LexRule::new(FN, "fn", None),
-            rt::LexRule::new(LET, "let", None),
-            rt::LexRule::new(PUB, "pub", None),
-            rt::LexRule::new(STRUCT, "struct", None),
-            rt::LexRule::new(USE, "use", None),
-            rt::LexRule::new(MOD, "mod", None),
-            rt::LexRule::new(IF, "if", None),
-            rt::LexRule::new(ELSE, "else", None),
-            rt::LexRule::new(ENUM, "enum", None),
-            rt::LexRule::new(IMPL, "impl", None),
-            rt::LexRule::new(SELF, "self", None),
-            rt::LexRule::new(SUPER, "super", None),
-            rt::LexRule::new(TYPE, "type", None),
-            rt::LexRule::new(CONST, "const", None),
-            rt::LexRule::new(STATIC, "static", None),
-            rt::LexRule::new(FOR, "for", None),
-            rt::LexRule::new(LOOP, "loop", None),
-            rt::LexRule::new(WHILE, "while", None),
-            rt::LexRule::new(MOVE, "move", None),
-            rt::LexRule::new(MUT, "mut", None),
-            rt::LexRule::new(REF, "ref", None),
-            rt::LexRule::new(TRAIT, "trait", None),
-            rt::LexRule::new(MATCH, "match", None),
-            rt::LexRule::new(RETURN, "return", None),
-            rt::LexRule::new(CONTINUE, "continue", None),
-            rt::LexRule::new(BREAK, "break", None),
-            rt::LexRule::new(IN, "in", None),
-            rt::LexRule::new(UNSAFE, "unsafe", None),
-            rt::LexRule::new(WHERE, "where", None),
-            rt::LexRule::new(L_PAREN, "\\(", None),
-            rt::LexRule::new(R_PAREN, "\\)", None),
-            rt::LexRule::new(L_CURLY, "\\{", None),
-            rt::LexRule::new(R_CURLY, "\\}", None),
-            rt::LexRule::new(L_ANGLE, "<", None),
-            rt::LexRule::new(R_ANGLE, ">", None),
-            rt::LexRule::new(L_BRACK, "\\[", None),
-            rt::LexRule::new(R_BRACK, "\\]", None),
-            rt::LexRule::new(THIN_ARROW, "\\->", None),
-            rt::LexRule::new(FAT_ARROW, "=>", None),
-            rt::LexRule::new(EQ, "=", None),
-            rt::LexRule::new(EQEQ, "==", None),
-            rt::LexRule::new(BANGEQ, "!=", None),
-            rt::LexRule::new(GTET, ">=", None),
-            rt::LexRule::new(LTEQ, "<=", None),
-            rt::LexRule::new(SEMI, ";", None),
-            rt::LexRule::new(COLON, ":", None),
-            rt::LexRule::new(COLONCOLON, "::", None),
-            rt::LexRule::new(COMMA, ",", None),
-            rt::LexRule::new(DOT, "\\.", None),
-            rt::LexRule::new(DOTDOT, "\\.\\.", None),
-            rt::LexRule::new(DOTDOTDOT, "\\.\\.\\.", None),
-            rt::LexRule::new(HASH, "\\#", None),
-            rt::LexRule::new(DOLLAR, "\\$", None),
-            rt::LexRule::new(STAR, "\\*", None),
-            rt::LexRule::new(STAR_EQ, "\\*=", None),
-            rt::LexRule::new(SLASH, "/", None),
-            rt::LexRule::new(SLASH_EQ, "/=", None),
-            rt::LexRule::new(PERCENT, "%", None),
-            rt::LexRule::new(PERCENT_EQ, "%=", None),
-            rt::LexRule::new(PLUS, "\\+", None),
-            rt::LexRule::new(PLUS_EQ, "\\+=", None),
-            rt::LexRule::new(MINUS, "\\-", None),
-            rt::LexRule::new(MINUS_EQ, "\\-=", None),
-            rt::LexRule::new(AMPERSAND, "\\&", None),
-            rt::LexRule::new(AMPERSAND_EQ, "\\&=", None),
-            rt::LexRule::new(PIPE, "\\|", None),
-            rt::LexRule::new(PIPE_EQ, "\\|=", None),
-            rt::LexRule::new(UNDERSCORE, "_", None),
-            rt::LexRule::new(BANG, "!", None),
-            rt::LexRule::new(QUESTION, "\\?", None),
-            rt::LexRule::new(CARET, "\\^", None),
-            rt::LexRule::new(CARET_EQ, "\\^=", None),
-            rt::LexRule::new(CHAR, "\'\\\\\'\'|\'[^\']\'|\'(\\\\|\\p{XID_Continue}|\\{|\\})*\'", None),
-            rt::LexRule::new(LIFETIME, "\'\\p{XID_Continue}*", None),
-            rt::LexRule::new(BOOL, "true|false", None),
-            rt::LexRule::new(NUMBER, "\\d+", None),
-            rt::LexRule::new(STRING, "\"(\\\\\"|[^\"])*\"", None),
-            rt::LexRule::new(RAW_STRING, "r#*\"", Some(parse_raw_string)),
-            rt::LexRule::new(IDENT, "(\\p{XID_Start}|_)\\p{XID_Continue}*", None),
-        ])
+    fn create_lexer() -> rt::DfaLexer {
+        static RULES: &[rt::DfaRule] = &[
+            rt::DfaRule { ty: WHITESPACE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LINE_COMMENT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: BLOCK_COMMENT, mode: 0, transition: rt::ModeTransition::Stay, f: Some((2, parse_block_comment)) },
+            rt::DfaRule { ty: AS, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: CRATE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: EXTERN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: FN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LET, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: PUB, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STRUCT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: USE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: MOD, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: IF, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: ELSE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: ENUM, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: IMPL, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: SELF, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: SUPER, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: TYPE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: CONST, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STATIC, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: FOR, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LOOP, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: WHILE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: MOVE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: MUT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: REF, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: TRAIT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: MATCH, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RETURN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: CONTINUE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: BREAK, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: IN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: UNSAFE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: WHERE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: L_PAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: R_PAREN, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: L_CURLY, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: R_CURLY, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: L_ANGLE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: R_ANGLE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: L_BRACK, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: R_BRACK, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: THIN_ARROW, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: FAT_ARROW, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: EQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: EQEQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: BANGEQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: GTET, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LTEQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: SEMI, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: COLON, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: COLONCOLON, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: COMMA, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: DOT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: DOTDOT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: DOTDOTDOT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: HASH, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: DOLLAR, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STAR, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STAR_EQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: SLASH, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: SLASH_EQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: PERCENT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: PERCENT_EQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: PLUS, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: PLUS_EQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: MINUS, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: MINUS_EQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: AMPERSAND, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: AMPERSAND_EQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: PIPE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: PIPE_EQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: UNDERSCORE, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: BANG, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: QUESTION, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: CARET, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: CARET_EQ, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: CHAR, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: LIFETIME, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: BOOL, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: NUMBER, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: STRING, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+            rt::DfaRule { ty: RAW_STRING, mode: 0, transition: rt::ModeTransition::Stay, f: Some((3, parse_raw_string)) },
+            rt::DfaRule { ty: IDENT, mode: 0, transition: rt::ModeTransition::Stay, f: None },
+        ];
+        static ASCII_CLASSES: [u8; 128] = [
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 2, 1, 1, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            1, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 18, 18, 18, 18, 18, 18, 18, 18, 18, 19, 20, 21, 22, 23, 24,
+            0, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 25, 26, 27, 28, 29, 30,
+            0, 31, 25, 25, 25, 32, 33, 25, 25, 25, 25, 25, 34, 25, 25, 25, 25, 25, 35, 36, 37, 38, 25, 25, 25, 25, 25, 39, 40, 41, 0, 0,
+        ];
+        static UNICODE_CLASSES: &[(u32, u8)] = &[
+            (0x80, 0), (0x85, 1), (0x86, 0), (0xA0, 1), (0xA1, 0), (0xAA, 25), (0xAB, 0), (0xB5, 25),
+            (0xB6, 0), (0xB7, 42), (0xB8, 0), (0xBA, 25), (0xBB, 0), (0xC0, 25), (0xD7, 0), (0xD8, 25),
+            (0xF7, 0), (0xF8, 25), (0x2C2, 0), (0x2C6, 25), (0x2D2, 0), (0x2E0, 25), (0x2E5, 0), (0x2EC, 25),
+            (0x2ED, 0), (0x2EE, 25), (0x2EF, 0), (0x300, 42), (0x370, 25), (0x375, 0), (0x376, 25), (0x378, 0),
+            (0x37B, 25), (0x37E, 0), (0x37F, 25), (0x380, 0), (0x386, 25), (0x387, 42), (0x388, 25), (0x38B, 0),
+            (0x38C, 25), (0x38D, 0), (0x38E, 25), (0x3A2, 0), (0x3A3, 25), (0x3F6, 0), (0x3F7, 25), (0x482, 0),
+            (0x483, 42), (0x488, 0), (0x48A, 25), (0x530, 0), (0x531, 25), (0x557, 0), (0x559, 25), (0x55A, 0),
+            (0x560, 25), (0x589, 0), (0x591, 42), (0x5BE, 0), (0x5BF, 42), (0x5C0, 0), (0x5C1, 42), (0x5C3, 0),
+            (0x5C4, 42), (0x5C6, 0), (0x5C7, 42), (0x5C8, 0), (0x5D0, 25), (0x5EB, 0), (0x5EF, 25), (0x5F3, 0),
+            (0x610, 42), (0x61B, 0), (0x620, 25), (0x64B, 42), (0x660, 18), (0x66A, 0), (0x66E, 25), (0x670, 42),
+            (0x671, 25), (0x6D4, 0), (0x6D5, 25), (0x6D6, 42), (0x6DD, 0), (0x6DF, 42), (0x6E5, 25), (0x6E7, 42),
+            (0x6E9, 0), (0x6EA, 42), (0x6EE, 25), (0x6F0, 18), (0x6FA, 25), (0x6FD, 0), (0x6FF, 25), (0x700, 0),
+            (0x710, 25), (0x711, 42), (0x712, 25), (0x730, 42), (0x74B, 0), (0x74D, 25), (0x7A6, 42), (0x7B1, 25),
+            (0x7B2, 0), (0x7C0, 18), (0x7CA, 25), (0x7EB, 42), (0x7F4, 25), (0x7F6, 0), (0x7FA, 25), (0x7FB, 0),
+            (0x7FD, 42), (0x7FE, 0), (0x800, 25), (0x816, 42), (0x81A, 25), (0x81B, 42), (0x824, 25), (0x825, 42),
+            (0x828, 25), (0x829, 42), (0x82E, 0), (0x840, 25), (0x859, 42), (0x85C, 0), (0x860, 25), (0x86B, 0),
+            (0x870, 25), (0x888, 0), (0x889, 25), (0x88F, 0), (0x897, 42), (0x8A0, 25), (0x8CA, 42), (0x8E2, 0),
+            (0x8E3, 42), (0x904, 25), (0x93A, 42), (0x93D, 25), (0x93E, 42), (0x950, 25), (0x951, 42), (0x958, 25),
+            (0x962, 42), (0x964, 0), (0x966, 18), (0x970, 0), (0x971, 25), (0x981, 42), (0x984, 0), (0x985, 25),
+            (0x98D, 0), (0x98F, 25), (0x991, 0), (0x993, 25), (0x9A9, 0), (0x9AA, 25), (0x9B1, 0), (0x9B2, 25),
+            (0x9B3, 0), (0x9B6, 25), (0x9BA, 0), (0x9BC, 42), (0x9BD, 25), (0x9BE, 42), (0x9C5, 0), (0x9C7, 42),
+            (0x9C9, 0), (0x9CB, 42), (0x9CE, 25), (0x9CF, 0), (0x9D7, 42), (0x9D8, 0), (0x9DC, 25), (0x9DE, 0),
+            (0x9DF, 25), (0x9E2, 42), (0x9E4, 0), (0x9E6, 18), (0x9F0, 25), (0x9F2, 0), (0x9FC, 25), (0x9FD, 0),
+            (0x9FE, 42), (0x9FF, 0), (0xA01, 42), (0xA04, 0), (0xA05, 25), (0xA0B, 0), (0xA0F, 25), (0xA11, 0),
+            (0xA13, 25), (0xA29, 0), (0xA2A, 25), (0xA31, 0), (0xA32, 25), (0xA34, 0), (0xA35, 25), (0xA37, 0),
+            (0xA38, 25), (0xA3A, 0), (0xA3C, 42), (0xA3D, 0), (0xA3E, 42), (0xA43, 0), (0xA47, 42), (0xA49, 0),
+            (0xA4B, 42), (0xA4E, 0), (0xA51, 42), (0xA52, 0), (0xA59, 25), (0xA5D, 0), (0xA5E, 25), (0xA5F, 0),
+            (0xA66, 18), (0xA70, 42), (0xA72, 25), (0xA75, 42), (0xA76, 0), (0xA81, 42), (0xA84, 0), (0xA85, 25),
+            (0xA8E, 0), (0xA8F, 25), (0xA92, 0), (0xA93, 25), (0xAA9, 0), (0xAAA, 25), (0xAB1, 0), (0xAB2, 25),
+            (0xAB4, 0), (0xAB5, 25), (0xABA, 0), (0xABC, 42), (0xABD, 25), (0xABE, 42), (0xAC6, 0), (0xAC7, 42),
+            (0xACA, 0), (0xACB, 42), (0xACE, 0), (0xAD0, 25), (0xAD1, 0), (0xAE0, 25), (0xAE2, 42), (0xAE4, 0),
+            (0xAE6, 18), (0xAF0, 0), (0xAF9, 25), (0xAFA, 42), (0xB00, 0), (0xB01, 42), (0xB04, 0), (0xB05, 25),
+            (0xB0D, 0), (0xB0F, 25), (0xB11, 0), (0xB13, 25), (0xB29, 0), (0xB2A, 25), (0xB31, 0), (0xB32, 25),
+            (0xB34, 0), (0xB35, 25), (0xB3A, 0), (0xB3C, 42), (0xB3D, 25), (0xB3E, 42), (0xB45, 0), (0xB47, 42),
+            (0xB49, 0), (0xB4B, 42), (0xB4E, 0), (0xB55, 42), (0xB58, 0), (0xB5C, 25), (0xB5E, 0), (0xB5F, 25),
+            (0xB62, 42), (0xB64, 0), (0xB66, 18), (0xB70, 0), (0xB71, 25), (0xB72, 0), (0xB82, 42), (0xB83, 25),
+            (0xB84, 0), (0xB85, 25), (0xB8B, 0), (0xB8E, 25), (0xB91, 0), (0xB92, 25), (0xB96, 0), (0xB99, 25),
+            (0xB9B, 0), (0xB9C, 25), (0xB9D, 0), (0xB9E, 25), (0xBA0, 0), (0xBA3, 25), (0xBA5, 0), (0xBA8, 25),
+            (0xBAB, 0), (0xBAE, 25), (0xBBA, 0), (0xBBE, 42), (0xBC3, 0), (0xBC6, 42), (0xBC9, 0), (0xBCA, 42),
+            (0xBCE, 0), (0xBD0, 25), (0xBD1, 0), (0xBD7, 42), (0xBD8, 0), (0xBE6, 18), (0xBF0, 0), (0xC00, 42),
+            (0xC05, 25), (0xC0D, 0), (0xC0E, 25), (0xC11, 0), (0xC12, 25), (0xC29, 0), (0xC2A, 25), (0xC3A, 0),
+            (0xC3C, 42), (0xC3D, 25), (0xC3E, 42), (0xC45, 0), (0xC46, 42), (0xC49, 0), (0xC4A, 42), (0xC4E, 0),
+            (0xC55, 42), (0xC57, 0), (0xC58, 25), (0xC5B, 0), (0xC5D, 25), (0xC5E, 0), (0xC60, 25), (0xC62, 42),
+            (0xC64, 0), (0xC66, 18), (0xC70, 0), (0xC80, 25), (0xC81, 42), (0xC84, 0), (0xC85, 25), (0xC8D, 0),
+            (0xC8E, 25), (0xC91, 0), (0xC92, 25), (0xCA9, 0), (0xCAA, 25), (0xCB4, 0), (0xCB5, 25), (0xCBA, 0),
+            (0xCBC, 42), (0xCBD, 25), (0xCBE, 42), (0xCC5, 0), (0xCC6, 42), (0xCC9, 0), (0xCCA, 42), (0xCCE, 0),
+            (0xCD5, 42), (0xCD7, 0), (0xCDD, 25), (0xCDF, 0), (0xCE0, 25), (0xCE2, 42), (0xCE4, 0), (0xCE6, 18),
+            (0xCF0, 0), (0xCF1, 25), (0xCF3, 42), (0xCF4, 0), (0xD00, 42), (0xD04, 25), (0xD0D, 0), (0xD0E, 25),
+            (0xD11, 0), (0xD12, 25), (0xD3B, 42), (0xD3D, 25), (0xD3E, 42), (0xD45, 0), (0xD46, 42), (0xD49, 0),
+            (0xD4A, 42), (0xD4E, 25), (0xD4F, 0), (0xD54, 25), (0xD57, 42), (0xD58, 0), (0xD5F, 25), (0xD62, 42),
+            (0xD64, 0), (0xD66, 18), (0xD70, 0), (0xD7A, 25), (0xD80, 0), (0xD81, 42), (0xD84, 0), (0xD85, 25),
+            (0xD97, 0), (0xD9A, 25), (0xDB2, 0), (0xDB3, 25), (0xDBC, 0), (0xDBD, 25), (0xDBE, 0), (0xDC0, 25),
+            (0xDC7, 0), (0xDCA, 42), (0xDCB, 0), (0xDCF, 42), (0xDD5, 0), (0xDD6, 42), (0xDD7, 0), (0xDD8, 42),
+            (0xDE0, 0), (0xDE6, 18), (0xDF0, 0), (0xDF2, 42), (0xDF4, 0), (0xE01, 25), (0xE31, 42), (0xE32, 25),
+            (0xE33, 42), (0xE3B, 0), (0xE40, 25), (0xE47, 42), (0xE4F, 0), (0xE50, 18), (0xE5A, 0), (0xE81, 25),
+            (0xE83, 0), (0xE84, 25), (0xE85, 0), (0xE86, 25), (0xE8B, 0), (0xE8C, 25), (0xEA4, 0), (0xEA5, 25),
+            (0xEA6, 0), (0xEA7, 25), (0xEB1, 42), (0xEB2, 25), (0xEB3, 42), (0xEBD, 25), (0xEBE, 0), (0xEC0, 25),
+            (0xEC5, 0), (0xEC6, 25), (0xEC7, 0), (0xEC8, 42), (0xECF, 0), (0xED0, 18), (0xEDA, 0), (0xEDC, 25),
+            (0xEE0, 0), (0xF00, 25), (0xF01, 0), (0xF18, 42), (0xF1A, 0), (0xF20, 18), (0xF2A, 0), (0xF35, 42),
+            (0xF36, 0), (0xF37, 42), (0xF38, 0), (0xF39, 42), (0xF3A, 0), (0xF3E, 42), (0xF40, 25), (0xF48, 0),
+            (0xF49, 25), (0xF6D, 0), (0xF71, 42), (0xF85, 0), (0xF86, 42), (0xF88, 25), (0xF8D, 42), (0xF98, 0),
+            (0xF99, 42), (0xFBD, 0), (0xFC6, 42), (0xFC7, 0), (0x1000, 25), (0x102B, 42), (0x103F, 25), (0x1040, 18),
+            (0x104A, 0), (0x1050, 25), (0x1056, 42), (0x105A, 25), (0x105E, 42), (0x1061, 25), (0x1062, 42), (0x1065, 25),
+            (0x1067, 42), (0x106E, 25), (0x1071, 42), (0x1075, 25), (0x1082, 42), (0x108E, 25), (0x108F, 42), (0x1090, 18),
+            (0x109A, 42), (0x109E, 0), (0x10A0, 25), (0x10C6, 0), (0x10C7, 25), (0x10C8, 0), (0x10CD, 25), (0x10CE, 0),
+            (0x10D0, 25), (0x10FB, 0), (0x10FC, 25), (0x1249, 0), (0x124A, 25), (0x124E, 0), (0x1250, 25), (0x1257, 0),
+            (0x1258, 25), (0x1259, 0), (0x125A, 25), (0x125E, 0), (0x1260, 25), (0x1289, 0), (0x128A, 25), (0x128E, 0),
+            (0x1290, 25), (0x12B1, 0), (0x12B2, 25), (0x12B6, 0), (0x12B8, 25), (0x12BF, 0), (0x12C0, 25), (0x12C1, 0),
+            (0x12C2, 25), (0x12C6, 0), (0x12C8, 25), (0x12D7, 0), (0x12D8, 25), (0x1311, 0), (0x1312, 25), (0x1316, 0),
+            (0x1318, 25), (0x135B, 0), (0x135D, 42), (0x1360, 0), (0x1369, 42), (0x1372, 0), (0x1380, 25), (0x1390, 0),
+            (0x13A0, 25), (0x13F6, 0), (0x13F8, 25), (0x13FE, 0), (0x1401, 25), (0x166D, 0), (0x166F, 25), (0x1680, 1),
+            (0x1681, 25), (0x169B, 0), (0x16A0, 25), (0x16EB, 0), (0x16EE, 25), (0x16F9, 0), (0x1700, 25), (0x1712, 42),
+            (0x1716, 0), (0x171F, 25), (0x1732, 42), (0x1735, 0), (0x1740, 25), (0x1752, 42), (0x1754, 0), (0x1760, 25),
+            (0x176D, 0), (0x176E, 25), (0x1771, 0), (0x1772, 42), (0x1774, 0), (0x1780, 25), (0x17B4, 42), (0x17D4, 0),
+            (0x17D7, 25), (0x17D8, 0), (0x17DC, 25), (0x17DD, 42), (0x17DE, 0), (0x17E0, 18), (0x17EA, 0), (0x180B, 42),
+            (0x180E, 0), (0x180F, 42), (0x1810, 18), (0x181A, 0), (0x1820, 25), (0x1879, 0), (0x1880, 25), (0x18A9, 42),
+            (0x18AA, 25), (0x18AB, 0), (0x18B0, 25), (0x18F6, 0), (0x1900, 25), (0x191F, 0), (0x1920, 42), (0x192C, 0),
+            (0x1930, 42), (0x193C, 0), (0x1946, 18), (0x1950, 25), (0x196E, 0), (0x1970, 25), (0x1975, 0), (0x1980, 25),
+            (0x19AC, 0), (0x19B0, 25), (0x19CA, 0), (0x19D0, 18), (0x19DA, 42), (0x19DB, 0), (0x1A00, 25), (0x1A17, 42),
+            (0x1A1C, 0), (0x1A20, 25), (0x1A55, 42), (0x1A5F, 0), (0x1A60, 42), (0x1A7D, 0), (0x1A7F, 42), (0x1A80, 18),
+            (0x1A8A, 0), (0x1A90, 18), (0x1A9A, 0), (0x1AA7, 25), (0x1AA8, 0), (0x1AB0, 42), (0x1ABE, 0), (0x1ABF, 42),
+            (0x1ACF, 0), (0x1B00, 42), (0x1B05, 25), (0x1B34, 42), (0x1B45, 25), (0x1B4D, 0), (0x1B50, 18), (0x1B5A, 0),
+            (0x1B6B, 42), (0x1B74, 0), (0x1B80, 42), (0x1B83, 25), (0x1BA1, 42), (0x1BAE, 25), (0x1BB0, 18), (0x1BBA, 25),
+            (0x1BE6, 42), (0x1BF4, 0), (0x1C00, 25), (0x1C24, 42), (0x1C38, 0), (0x1C40, 18), (0x1C4A, 0), (0x1C4D, 25),
+            (0x1C50, 18), (0x1C5A, 25), (0x1C7E, 0), (0x1C80, 25), (0x1C8B, 0), (0x1C90, 25), (0x1CBB, 0), (0x1CBD, 25),
+            (0x1CC0, 0), (0x1CD0, 42), (0x1CD3, 0), (0x1CD4, 42), (0x1CE9, 25), (0x1CED, 42), (0x1CEE, 25), (0x1CF4, 42),
+            (0x1CF5, 25), (0x1CF7, 42), (0x1CFA, 25), (0x1CFB, 0), (0x1D00, 25), (0x1DC0, 42), (0x1E00, 25), (0x1F16, 0),
+            (0x1F18, 25), (0x1F1E, 0), (0x1F20, 25), (0x1F46, 0), (0x1F48, 25), (0x1F4E, 0), (0x1F50, 25), (0x1F58, 0),
+            (0x1F59, 25), (0x1F5A, 0), (0x1F5B, 25), (0x1F5C, 0), (0x1F5D, 25), (0x1F5E, 0), (0x1F5F, 25), (0x1F7E, 0),
+            (0x1F80, 25), (0x1FB5, 0), (0x1FB6, 25), (0x1FBD, 0), (0x1FBE, 25), (0x1FBF, 0), (0x1FC2, 25), (0x1FC5, 0),
+            (0x1FC6, 25), (0x1FCD, 0), (0x1FD0, 25), (0x1FD4, 0), (0x1FD6, 25), (0x1FDC, 0), (0x1FE0, 25), (0x1FED, 0),
+            (0x1FF2, 25), (0x1FF5, 0), (0x1FF6, 25), (0x1FFD, 0), (0x2000, 1), (0x200B, 0), (0x200C, 42), (0x200E, 0),
+            (0x2028, 1), (0x202A, 0), (0x202F, 1), (0x2030, 0), (0x203F, 42), (0x2041, 0), (0x2054, 42), (0x2055, 0),
+            (0x205F, 1), (0x2060, 0), (0x2071, 25), (0x2072, 0), (0x207F, 25), (0x2080, 0), (0x2090, 25), (0x209D, 0),
+            (0x20D0, 42), (0x20DD, 0), (0x20E1, 42), (0x20E2, 0), (0x20E5, 42), (0x20F1, 0), (0x2102, 25), (0x2103, 0),
+            (0x2107, 25), (0x2108, 0), (0x210A, 25), (0x2114, 0), (0x2115, 25), (0x2116, 0), (0x2118, 25), (0x211E, 0),
+            (0x2124, 25), (0x2125, 0), (0x2126, 25), (0x2127, 0), (0x2128, 25), (0x2129, 0), (0x212A, 25), (0x213A, 0),
+            (0x213C, 25), (0x2140, 0), (0x2145, 25), (0x214A, 0), (0x214E, 25), (0x214F, 0), (0x2160, 25), (0x2189, 0),
+            (0x2C00, 25), (0x2CE5, 0), (0x2CEB, 25), (0x2CEF, 42), (0x2CF2, 25), (0x2CF4, 0), (0x2D00, 25), (0x2D26, 0),
+            (0x2D27, 25), (0x2D28, 0), (0x2D2D, 25), (0x2D2E, 0), (0x2D30, 25), (0x2D68, 0), (0x2D6F, 25), (0x2D70, 0),
+            (0x2D7F, 42), (0x2D80, 25), (0x2D97, 0), (0x2DA0, 25), (0x2DA7, 0), (0x2DA8, 25), (0x2DAF, 0), (0x2DB0, 25),
+            (0x2DB7, 0), (0x2DB8, 25), (0x2DBF, 0), (0x2DC0, 25), (0x2DC7, 0), (0x2DC8, 25), (0x2DCF, 0), (0x2DD0, 25),
+            (0x2DD7, 0), (0x2DD8, 25), (0x2DDF, 0), (0x2DE0, 42), (0x2E00, 0), (0x3000, 1), (0x3001, 0), (0x3005, 25),
+            (0x3008, 0), (0x3021, 25), (0x302A, 42), (0x3030, 0), (0x3031, 25), (0x3036, 0), (0x3038, 25), (0x303D, 0),
+            (0x3041, 25), (0x3097, 0), (0x3099, 42), (0x309B, 0), (0x309D, 25), (0x30A0, 0), (0x30A1, 25), (0x30FB, 42),
+            (0x30FC, 25), (0x3100, 0), (0x3105, 25), (0x3130, 0), (0x3131, 25), (0x318F, 0), (0x31A0, 25), (0x31C0, 0),
+            (0x31F0, 25), (0x3200, 0), (0x3400, 25), (0x4DC0, 0), (0x4E00, 25), (0xA48D, 0), (0xA4D0, 25), (0xA4FE, 0),
+            (0xA500, 25), (0xA60D, 0), (0xA610, 25), (0xA620, 18), (0xA62A, 25), (0xA62C, 0), (0xA640, 25), (0xA66F, 42),
+            (0xA670, 0), (0xA674, 42), (0xA67E, 0), (0xA67F, 25), (0xA69E, 42), (0xA6A0, 25), (0xA6F0, 42), (0xA6F2, 0),
+            (0xA717, 25), (0xA720, 0), (0xA722, 25), (0xA789, 0), (0xA78B, 25), (0xA7CE, 0), (0xA7D0, 25), (0xA7D2, 0),
+            (0xA7D3, 25), (0xA7D4, 0), (0xA7D5, 25), (0xA7DD, 0), (0xA7F2, 25), (0xA802, 42), (0xA803, 25), (0xA806, 42),
+            (0xA807, 25), (0xA80B, 42), (0xA80C, 25), (0xA823, 42), (0xA828, 0), (0xA82C, 42), (0xA82D, 0), (0xA840, 25),
+            (0xA874, 0), (0xA880, 42), (0xA882, 25), (0xA8B4, 42), (0xA8C6, 0), (0xA8D0, 18), (0xA8DA, 0), (0xA8E0, 42),
+            (0xA8F2, 25), (0xA8F8, 0), (0xA8FB, 25), (0xA8FC, 0), (0xA8FD, 25), (0xA8FF, 42), (0xA900, 18), (0xA90A, 25),
+            (0xA926, 42), (0xA92E, 0), (0xA930, 25), (0xA947, 42), (0xA954, 0), (0xA960, 25), (0xA97D, 0), (0xA980, 42),
+            (0xA984, 25), (0xA9B3, 42), (0xA9C1, 0), (0xA9CF, 25), (0xA9D0, 18), (0xA9DA, 0), (0xA9E0, 25), (0xA9E5, 42),
+            (0xA9E6, 25), (0xA9F0, 18), (0xA9FA, 25), (0xA9FF, 0), (0xAA00, 25), (0xAA29, 42), (0xAA37, 0), (0xAA40, 25),
+            (0xAA43, 42), (0xAA44, 25), (0xAA4C, 42), (0xAA4E, 0), (0xAA50, 18), (0xAA5A, 0), (0xAA60, 25), (0xAA77, 0),
+            (0xAA7A, 25), (0xAA7B, 42), (0xAA7E, 25), (0xAAB0, 42), (0xAAB1, 25), (0xAAB2, 42), (0xAAB5, 25), (0xAAB7, 42),
+            (0xAAB9, 25), (0xAABE, 42), (0xAAC0, 25), (0xAAC1, 42), (0xAAC2, 25), (0xAAC3, 0), (0xAADB, 25), (0xAADE, 0),
+            (0xAAE0, 25), (0xAAEB, 42), (0xAAF0, 0), (0xAAF2, 25), (0xAAF5, 42), (0xAAF7, 0), (0xAB01, 25), (0xAB07, 0),
+            (0xAB09, 25), (0xAB0F, 0), (0xAB11, 25), (0xAB17, 0), (0xAB20, 25), (0xAB27, 0), (0xAB28, 25), (0xAB2F, 0),
+            (0xAB30, 25), (0xAB5B, 0), (0xAB5C, 25), (0xAB6A, 0), (0xAB70, 25), (0xABE3, 42), (0xABEB, 0), (0xABEC, 42),
+            (0xABEE, 0), (0xABF0, 18), (0xABFA, 0), (0xAC00, 25), (0xD7A4, 0), (0xD7B0, 25), (0xD7C7, 0), (0xD7CB, 25),
+            (0xD7FC, 0), (0xF900, 25), (0xFA6E, 0), (0xFA70, 25), (0xFADA, 0), (0xFB00, 25), (0xFB07, 0), (0xFB13, 25),
+            (0xFB18, 0), (0xFB1D, 25), (0xFB1E, 42), (0xFB1F, 25), (0xFB29, 0), (0xFB2A, 25), (0xFB37, 0), (0xFB38, 25),
+            (0xFB3D, 0), (0xFB3E, 25), (0xFB3F, 0), (0xFB40, 25), (0xFB42, 0), (0xFB43, 25), (0xFB45, 0), (0xFB46, 25),
+            (0xFBB2, 0), (0xFBD3, 25), (0xFC5E, 0), (0xFC64, 25), (0xFD3E, 0), (0xFD50, 25), (0xFD90, 0), (0xFD92, 25),
+            (0xFDC8, 0), (0xFDF0, 25), (0xFDFA, 0), (0xFE00, 42), (0xFE10, 0), (0xFE20, 42), (0xFE30, 0), (0xFE33, 42),
+            (0xFE35, 0), (0xFE4D, 42), (0xFE50, 0), (0xFE71, 25), (0xFE72, 0), (0xFE73, 25), (0xFE74, 0), (0xFE77, 25),
+            (0xFE78, 0), (0xFE79, 25), (0xFE7A, 0), (0xFE7B, 25), (0xFE7C, 0), (0xFE7D, 25), (0xFE7E, 0), (0xFE7F, 25),
+            (0xFEFD, 0), (0xFF10, 18), (0xFF1A, 0), (0xFF21, 25), (0xFF3B, 0), (0xFF3F, 42), (0xFF40, 0), (0xFF41, 25),
+            (0xFF5B, 0), (0xFF65, 42), (0xFF66, 25), (0xFF9E, 42), (0xFFA0, 25), (0xFFBF, 0), (0xFFC2, 25), (0xFFC8, 0),
+            (0xFFCA, 25), (0xFFD0, 0), (0xFFD2, 25), (0xFFD8, 0), (0xFFDA, 25), (0xFFDD, 0), (0x10000, 25), (0x1000C, 0),
+            (0x1000D, 25), (0x10027, 0), (0x10028, 25), (0x1003B, 0), (0x1003C, 25), (0x1003E, 0), (0x1003F, 25), (0x1004E, 0),
+            (0x10050, 25), (0x1005E, 0), (0x10080, 25), (0x100FB, 0), (0x10140, 25), (0x10175, 0), (0x101FD, 42), (0x101FE, 0),
+            (0x10280, 25), (0x1029D, 0), (0x102A0, 25), (0x102D1, 0), (0x102E0, 42), (0x102E1, 0), (0x10300, 25), (0x10320, 0),
+            (0x1032D, 25), (0x1034B, 0), (0x10350, 25), (0x10376, 42), (0x1037B, 0), (0x10380, 25), (0x1039E, 0), (0x103A0, 25),
+            (0x103C4, 0), (0x103C8, 25), (0x103D0, 0), (0x103D1, 25), (0x103D6, 0), (0x10400, 25), (0x1049E, 0), (0x104A0, 18),
+            (0x104AA, 0), (0x104B0, 25), (0x104D4, 0), (0x104D8, 25), (0x104FC, 0), (0x10500, 25), (0x10528, 0), (0x10530, 25),
+            (0x10564, 0), (0x10570, 25), (0x1057B, 0), (0x1057C, 25), (0x1058B, 0), (0x1058C, 25), (0x10593, 0), (0x10594, 25),
+            (0x10596, 0), (0x10597, 25), (0x105A2, 0), (0x105A3, 25), (0x105B2, 0), (0x105B3, 25), (0x105BA, 0), (0x105BB, 25),
+            (0x105BD, 0), (0x105C0, 25), (0x105F4, 0), (0x10600, 25), (0x10737, 0), (0x10740, 25), (0x10756, 0), (0x10760, 25),
+            (0x10768, 0), (0x10780, 25), (0x10786, 0), (0x10787, 25), (0x107B1, 0), (0x107B2, 25), (0x107BB, 0), (0x10800, 25),
+            (0x10806, 0), (0x10808, 25), (0x10809, 0), (0x1080A, 25), (0x10836, 0), (0x10837, 25), (0x10839, 0), (0x1083C, 25),
+            (0x1083D, 0), (0x1083F, 25), (0x10856, 0), (0x10860, 25), (0x10877, 0), (0x10880, 25), (0x1089F, 0), (0x108E0, 25),
+            (0x108F3, 0), (0x108F4, 25), (0x108F6, 0), (0x10900, 25), (0x10916, 0), (0x10920, 25), (0x1093A, 0), (0x10980, 25),
+            (0x109B8, 0), (0x109BE, 25), (0x109C0, 0), (0x10A00, 25), (0x10A01, 42), (0x10A04, 0), (0x10A05, 42), (0x10A07, 0),
+            (0x10A0C, 42), (0x10A10, 25), (0x10A14, 0), (0x10A15, 25), (0x10A18, 0), (0x10A19, 25), (0x10A36, 0), (0x10A38, 42),
+            (0x10A3B, 0), (0x10A3F, 42), (0x10A40, 0), (0x10A60, 25), (0x10A7D, 0), (0x10A80, 25), (0x10A9D, 0), (0x10AC0, 25),
+            (0x10AC8, 0), (0x10AC9, 25), (0x10AE5, 42), (0x10AE7, 0), (0x10B00, 25), (0x10B36, 0), (0x10B40, 25), (0x10B56, 0),
+            (0x10B60, 25), (0x10B73, 0), (0x10B80, 25), (0x10B92, 0), (0x10C00, 25), (0x10C49, 0), (0x10C80, 25), (0x10CB3, 0),
+            (0x10CC0, 25), (0x10CF3, 0), (0x10D00, 25), (0x10D24, 42), (0x10D28, 0), (0x10D30, 18), (0x10D3A, 0), (0x10D40, 18),
+            (0x10D4A, 25), (0x10D66, 0), (0x10D69, 42), (0x10D6E, 0), (0x10D6F, 25), (0x10D86, 0), (0x10E80, 25), (0x10EAA, 0),
+            (0x10EAB, 42), (0x10EAD, 0), (0x10EB0, 25), (0x10EB2, 0), (0x10EC2, 25), (0x10EC5, 0), (0x10EFC, 42), (0x10F00, 25),
+            (0x10F1D, 0), (0x10F27, 25), (0x10F28, 0), (0x10F30, 25), (0x10F46, 42), (0x10F51, 0), (0x10F70, 25), (0x10F82, 42),
+            (0x10F86, 0), (0x10FB0, 25), (0x10FC5, 0), (0x10FE0, 25), (0x10FF7, 0), (0x11000, 42), (0x11003, 25), (0x11038, 42),
+            (0x11047, 0), (0x11066, 18), (0x11070, 42), (0x11071, 25), (0x11073, 42), (0x11075, 25), (0x11076, 0), (0x1107F, 42),
+            (0x11083, 25), (0x110B0, 42), (0x110BB, 0), (0x110C2, 42), (0x110C3, 0), (0x110D0, 25), (0x110E9, 0), (0x110F0, 18),
+            (0x110FA, 0), (0x11100, 42), (0x11103, 25), (0x11127, 42), (0x11135, 0), (0x11136, 18), (0x11140, 0), (0x11144, 25),
+            (0x11145, 42), (0x11147, 25), (0x11148, 0), (0x11150, 25), (0x11173, 42), (0x11174, 0), (0x11176, 25), (0x11177, 0),
+            (0x11180, 42), (0x11183, 25), (0x111B3, 42), (0x111C1, 25), (0x111C5, 0), (0x111C9, 42), (0x111CD, 0), (0x111CE, 42),
+            (0x111D0, 18), (0x111DA, 25), (0x111DB, 0), (0x111DC, 25), (0x111DD, 0), (0x11200, 25), (0x11212, 0), (0x11213, 25),
+            (0x1122C, 42), (0x11238, 0), (0x1123E, 42), (0x1123F, 25), (0x11241, 42), (0x11242, 0), (0x11280, 25), (0x11287, 0),
+            (0x11288, 25), (0x11289, 0), (0x1128A, 25), (0x1128E, 0), (0x1128F, 25), (0x1129E, 0), (0x1129F, 25), (0x112A9, 0),
+            (0x112B0, 25), (0x112DF, 42), (0x112EB, 0), (0x112F0, 18), (0x112FA, 0), (0x11300, 42), (0x11304, 0), (0x11305, 25),
+            (0x1130D, 0), (0x1130F, 25), (0x11311, 0), (0x11313, 25), (0x11329, 0), (0x1132A, 25), (0x11331, 0), (0x11332, 25),
+            (0x11334, 0), (0x11335, 25), (0x1133A, 0), (0x1133B, 42), (0x1133D, 25), (0x1133E, 42), (0x11345, 0), (0x11347, 42),
+            (0x11349, 0), (0x1134B, 42), (0x1134E, 0), (0x11350, 25), (0x11351, 0), (0x11357, 42), (0x11358, 0), (0x1135D, 25),
+            (0x11362, 42), (0x11364, 0), (0x11366, 42), (0x1136D, 0), (0x11370, 42), (0x11375, 0), (0x11380, 25), (0x1138A, 0),
+            (0x1138B, 25), (0x1138C, 0), (0x1138E, 25), (0x1138F, 0), (0x11390, 25), (0x113B6, 0), (0x113B7, 25), (0x113B8, 42),
+            (0x113C1, 0), (0x113C2, 42), (0x113C3, 0), (0x113C5, 42), (0x113C6, 0), (0x113C7, 42), (0x113CB, 0), (0x113CC, 42),
+            (0x113D1, 25), (0x113D2, 42), (0x113D3, 25), (0x113D4, 0), (0x113E1, 42), (0x113E3, 0), (0x11400, 25), (0x11435, 42),
+            (0x11447, 25), (0x1144B, 0), (0x11450, 18), (0x1145A, 0), (0x1145E, 42), (0x1145F, 25), (0x11462, 0), (0x11480, 25),
+            (0x114B0, 42), (0x114C4, 25), (0x114C6, 0), (0x114C7, 25), (0x114C8, 0), (0x114D0, 18), (0x114DA, 0), (0x11580, 25),
+            (0x115AF, 42), (0x115B6, 0), (0x115B8, 42), (0x115C1, 0), (0x115D8, 25), (0x115DC, 42), (0x115DE, 0), (0x11600, 25),
+            (0x11630, 42), (0x11641, 0), (0x11644, 25), (0x11645, 0), (0x11650, 18), (0x1165A, 0), (0x11680, 25), (0x116AB, 42),
+            (0x116B8, 25), (0x116B9, 0), (0x116C0, 18), (0x116CA, 0), (0x116D0, 18), (0x116E4, 0), (0x11700, 25), (0x1171B, 0),
+            (0x1171D, 42), (0x1172C, 0), (0x11730, 18), (0x1173A, 0), (0x11740, 25), (0x11747, 0), (0x11800, 25), (0x1182C, 42),
+            (0x1183B, 0), (0x118A0, 25), (0x118E0, 18), (0x118EA, 0), (0x118FF, 25), (0x11907, 0), (0x11909, 25), (0x1190A, 0),
+            (0x1190C, 25), (0x11914, 0), (0x11915, 25), (0x11917, 0), (0x11918, 25), (0x11930, 42), (0x11936, 0), (0x11937, 42),
+            (0x11939, 0), (0x1193B, 42), (0x1193F, 25), (0x11940, 42), (0x11941, 25), (0x11942, 42), (0x11944, 0), (0x11950, 18),
+            (0x1195A, 0), (0x119A0, 25), (0x119A8, 0), (0x119AA, 25), (0x119D1, 42), (0x119D8, 0), (0x119DA, 42), (0x119E1, 25),
+            (0x119E2, 0), (0x119E3, 25), (0x119E4, 42), (0x119E5, 0), (0x11A00, 25), (0x11A01, 42), (0x11A0B, 25), (0x11A33, 42),
+            (0x11A3A, 25), (0x11A3B, 42), (0x11A3F, 0), (0x11A47, 42), (0x11A48, 0), (0x11A50, 25), (0x11A51, 42), (0x11A5C, 25),
+            (0x11A8A, 42), (0x11A9A, 0), (0x11A9D, 25), (0x11A9E, 0), (0x11AB0, 25), (0x11AF9, 0), (0x11BC0, 25), (0x11BE1, 0),
+            (0x11BF0, 18), (0x11BFA, 0), (0x11C00, 25), (0x11C09, 0), (0x11C0A, 25), (0x11C2F, 42), (0x11C37, 0), (0x11C38, 42),
+            (0x11C40, 25), (0x11C41, 0), (0x11C50, 18), (0x11C5A, 0), (0x11C72, 25), (0x11C90, 0), (0x11C92, 42), (0x11CA8, 0),
+            (0x11CA9, 42), (0x11CB7, 0), (0x11D00, 25), (0x11D07, 0), (0x11D08, 25), (0x11D0A, 0), (0x11D0B, 25), (0x11D31, 42),
+            (0x11D37, 0), (0x11D3A, 42), (0x11D3B, 0), (0x11D3C, 42), (0x11D3E, 0), (0x11D3F, 42), (0x11D46, 25), (0x11D47, 42),
+            (0x11D48, 0), (0x11D50, 18), (0x11D5A, 0), (0x11D60, 25), (0x11D66, 0), (0x11D67, 25), (0x11D69, 0), (0x11D6A, 25),
+            (0x11D8A, 42), (0x11D8F, 0), (0x11D90, 42), (0x11D92, 0), (0x11D93, 42), (0x11D98, 25), (0x11D99, 0), (0x11DA0, 18),
+            (0x11DAA, 0), (0x11EE0, 25), (0x11EF3, 42), (0x11EF7, 0), (0x11F00, 42), (0x11F02, 25), (0x11F03, 42), (0x11F04, 25),
+            (0x11F11, 0), (0x11F12, 25), (0x11F34, 42), (0x11F3B, 0), (0x11F3E, 42), (0x11F43, 0), (0x11F50, 18), (0x11F5A, 42),
+            (0x11F5B, 0), (0x11FB0, 25), (0x11FB1, 0), (0x12000, 25), (0x1239A, 0), (0x12400, 25), (0x1246F, 0), (0x12480, 25),
+            (0x12544, 0), (0x12F90, 25), (0x12FF1, 0), (0x13000, 25), (0x13430, 0), (0x13440, 42), (0x13441, 25), (0x13447, 42),
+            (0x13456, 0), (0x13460, 25), (0x143FB, 0), (0x14400, 25), (0x14647, 0), (0x16100, 25), (0x1611E, 42), (0x16130, 18),
+            (0x1613A, 0), (0x16800, 25), (0x16A39, 0), (0x16A40, 25), (0x16A5F, 0), (0x16A60, 18), (0x16A6A, 0), (0x16A70, 25),
+            (0x16ABF, 0), (0x16AC0, 18), (0x16ACA, 0), (0x16AD0, 25), (0x16AEE, 0), (0x16AF0, 42), (0x16AF5, 0), (0x16B00, 25),
+            (0x16B30, 42), (0x16B37, 0), (0x16B40, 25), (0x16B44, 0), (0x16B50, 18), (0x16B5A, 0), (0x16B63, 25), (0x16B78, 0),
+            (0x16B7D, 25), (0x16B90, 0), (0x16D40, 25), (0x16D6D, 0), (0x16D70, 18), (0x16D7A, 0), (0x16E40, 25), (0x16E80, 0),
+            (0x16F00, 25), (0x16F4B, 0), (0x16F4F, 42), (0x16F50, 25), (0x16F51, 42), (0x16F88, 0), (0x16F8F, 42), (0x16F93, 25),
+            (0x16FA0, 0), (0x16FE0, 25), (0x16FE2, 0), (0x16FE3, 25), (0x16FE4, 42), (0x16FE5, 0), (0x16FF0, 42), (0x16FF2, 0),
+            (0x17000, 25), (0x187F8, 0), (0x18800, 25), (0x18CD6, 0), (0x18CFF, 25), (0x18D09, 0), (0x1AFF0, 25), (0x1AFF4, 0),
+            (0x1AFF5, 25), (0x1AFFC, 0), (0x1AFFD, 25), (0x1AFFF, 0), (0x1B000, 25), (0x1B123, 0), (0x1B132, 25), (0x1B133, 0),
+            (0x1B150, 25), (0x1B153, 0), (0x1B155, 25), (0x1B156, 0), (0x1B164, 25), (0x1B168, 0), (0x1B170, 25), (0x1B2FC, 0),
+            (0x1BC00, 25), (0x1BC6B, 0), (0x1BC70, 25), (0x1BC7D, 0), (0x1BC80, 25), (0x1BC89, 0), (0x1BC90, 25), (0x1BC9A, 0),
+            (0x1BC9D, 42), (0x1BC9F, 0), (0x1CCF0, 18), (0x1CCFA, 0), (0x1CF00, 42), (0x1CF2E, 0), (0x1CF30, 42), (0x1CF47, 0),
+            (0x1D165, 42), (0x1D16A, 0), (0x1D16D, 42), (0x1D173, 0), (0x1D17B, 42), (0x1D183, 0), (0x1D185, 42), (0x1D18C, 0),
+            (0x1D1AA, 42), (0x1D1AE, 0), (0x1D242, 42), (0x1D245, 0), (0x1D400, 25), (0x1D455, 0), (0x1D456, 25), (0x1D49D, 0),
+            (0x1D49E, 25), (0x1D4A0, 0), (0x1D4A2, 25), (0x1D4A3, 0), (0x1D4A5, 25), (0x1D4A7, 0), (0x1D4A9, 25), (0x1D4AD, 0),
+            (0x1D4AE, 25), (0x1D4BA, 0), (0x1D4BB, 25), (0x1D4BC, 0), (0x1D4BD, 25), (0x1D4C4, 0), (0x1D4C5, 25), (0x1D506, 0),
+            (0x1D507, 25), (0x1D50B, 0), (0x1D50D, 25), (0x1D515, 0), (0x1D516, 25), (0x1D51D, 0), (0x1D51E, 25), (0x1D53A, 0),
+            (0x1D53B, 25), (0x1D53F, 0), (0x1D540, 25), (0x1D545, 0), (0x1D546, 25), (0x1D547, 0), (0x1D54A, 25), (0x1D551, 0),
+            (0x1D552, 25), (0x1D6A6, 0), (0x1D6A8, 25), (0x1D6C1, 0), (0x1D6C2, 25), (0x1D6DB, 0), (0x1D6DC, 25), (0x1D6FB, 0),
+            (0x1D6FC, 25), (0x1D715, 0), (0x1D716, 25), (0x1D735, 0), (0x1D736, 25), (0x1D74F, 0), (0x1D750, 25), (0x1D76F, 0),
+            (0x1D770, 25), (0x1D789, 0), (0x1D78A, 25), (0x1D7A9, 0), (0x1D7AA, 25), (0x1D7C3, 0), (0x1D7C4, 25), (0x1D7CC, 0),
+            (0x1D7CE, 18), (0x1D800, 0), (0x1DA00, 42), (0x1DA37, 0), (0x1DA3B, 42), (0x1DA6D, 0), (0x1DA75, 42), (0x1DA76, 0),
+            (0x1DA84, 42), (0x1DA85, 0), (0x1DA9B, 42), (0x1DAA0, 0), (0x1DAA1, 42), (0x1DAB0, 0), (0x1DF00, 25), (0x1DF1F, 0),
+            (0x1DF25, 25), (0x1DF2B, 0), (0x1E000, 42), (0x1E007, 0), (0x1E008, 42), (0x1E019, 0), (0x1E01B, 42), (0x1E022, 0),
+            (0x1E023, 42), (0x1E025, 0), (0x1E026, 42), (0x1E02B, 0), (0x1E030, 25), (0x1E06E, 0), (0x1E08F, 42), (0x1E090, 0),
+            (0x1E100, 25), (0x1E12D, 0), (0x1E130, 42), (0x1E137, 25), (0x1E13E, 0), (0x1E140, 18), (0x1E14A, 0), (0x1E14E, 25),
+            (0x1E14F, 0), (0x1E290, 25), (0x1E2AE, 42), (0x1E2AF, 0), (0x1E2C0, 25), (0x1E2EC, 42), (0x1E2F0, 18), (0x1E2FA, 0),
+            (0x1E4D0, 25), (0x1E4EC, 42), (0x1E4F0, 18), (0x1E4FA, 0), (0x1E5D0, 25), (0x1E5EE, 42), (0x1E5F0, 25), (0x1E5F1, 18),
+            (0x1E5FB, 0), (0x1E7E0, 25), (0x1E7E7, 0), (0x1E7E8, 25), (0x1E7EC, 0), (0x1E7ED, 25), (0x1E7EF, 0), (0x1E7F0, 25),
+            (0x1E7FF, 0), (0x1E800, 25), (0x1E8C5, 0), (0x1E8D0, 42), (0x1E8D7, 0), (0x1E900, 25), (0x1E944, 42), (0x1E94B, 25),
+            (0x1E94C, 0), (0x1E950, 18), (0x1E95A, 0), (0x1EE00, 25), (0x1EE04, 0), (0x1EE05, 25), (0x1EE20, 0), (0x1EE21, 25),
+            (0x1EE23, 0), (0x1EE24, 25), (0x1EE25, 0), (0x1EE27, 25), (0x1EE28, 0), (0x1EE29, 25), (0x1EE33, 0), (0x1EE34, 25),
+            (0x1EE38, 0), (0x1EE39, 25), (0x1EE3A, 0), (0x1EE3B, 25), (0x1EE3C, 0), (0x1EE42, 25), (0x1EE43, 0), (0x1EE47, 25),
+            (0x1EE48, 0), (0x1EE49, 25), (0x1EE4A, 0), (0x1EE4B, 25), (0x1EE4C, 0), (0x1EE4D, 25), (0x1EE50, 0), (0x1EE51, 25),
+            (0x1EE53, 0), (0x1EE54, 25), (0x1EE55, 0), (0x1EE57, 25), (0x1EE58, 0), (0x1EE59, 25), (0x1EE5A, 0), (0x1EE5B, 25),
+            (0x1EE5C, 0), (0x1EE5D, 25), (0x1EE5E, 0), (0x1EE5F, 25), (0x1EE60, 0), (0x1EE61, 25), (0x1EE63, 0), (0x1EE64, 25),
+            (0x1EE65, 0), (0x1EE67, 25), (0x1EE6B, 0), (0x1EE6C, 25), (0x1EE73, 0), (0x1EE74, 25), (0x1EE78, 0), (0x1EE79, 25),
+            (0x1EE7D, 0), (0x1EE7E, 25), (0x1EE7F, 0), (0x1EE80, 25), (0x1EE8A, 0), (0x1EE8B, 25), (0x1EE9C, 0), (0x1EEA1, 25),
+            (0x1EEA4, 0), (0x1EEA5, 25), (0x1EEAA, 0), (0x1EEAB, 25), (0x1EEBC, 0), (0x1FBF0, 18), (0x1FBFA, 0), (0x20000, 25),
+            (0x2A6E0, 0), (0x2A700, 25), (0x2B73A, 0), (0x2B740, 25), (0x2B81E, 0), (0x2B820, 25), (0x2CEA2, 0), (0x2CEB0, 25),
+            (0x2EBE1, 0), (0x2EBF0, 25), (0x2EE5E, 0), (0x2F800, 25), (0x2FA1E, 0), (0x30000, 25), (0x3134B, 0), (0x31350, 25),
+            (0x323B0, 0), (0xE0100, 42), (0xE01F0, 0),
+        ];
+        static TRANSITIONS: &[u16] = &[
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 4, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 26, 27, 28, 0, 29, 30, 27, 27, 27, 31, 27, 27, 27, 32, 27, 33, 34, 35, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 37, 0, 0, 0, 0, 0, 0, 0,
+            0, 4, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 38, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            6, 6, 6, 6, 39, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 40, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            43, 43, 43, 43, 43, 43, 43, 43, 43, 44, 43, 43, 43, 43, 43, 43, 43, 43, 45, 43, 43, 43, 43, 43, 43, 45, 43, 46, 43, 43, 45, 45, 45, 45, 45, 45, 45, 45, 45, 47, 43, 47, 45,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 0, 54, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 55, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 57, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 59, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 27, 27, 27, 27, 27, 27, 27, 27, 27, 0, 0, 0, 27,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 27, 61, 27, 27, 27, 27, 27, 27, 27, 0, 0, 0, 27,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 27, 27, 27, 27, 27, 62, 27, 27, 27, 0, 0, 0, 27,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 65, 37, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            6, 6, 6, 6, 66, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 40, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 45, 0, 47, 0, 0, 45, 45, 45, 45, 45, 45, 45, 45, 45, 47, 0, 47, 45,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 67, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 47, 0, 47, 0, 0, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 0, 47, 47,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 47, 0, 0, 0, 0, 0, 0, 47, 0, 47, 0, 0, 47, 47, 47, 47, 47, 47, 47, 47, 47, 47, 0, 47, 47,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 68, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            53, 53, 69, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53, 53,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 27, 27, 27, 27, 70, 27, 27, 27, 27, 0, 0, 0, 27,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 27, 27, 27, 27, 27, 27, 27, 27, 71, 0, 0, 0, 27,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            6, 6, 6, 6, 39, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 40, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6, 6,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 27, 27, 27, 27, 27, 27, 71, 27, 27, 0, 0, 0, 27,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 27, 27, 72, 27, 27, 27, 27, 27, 27, 0, 0, 0, 27,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 0, 0, 27, 0, 0, 0, 0, 27, 27, 27, 27, 27, 27, 27, 27, 27, 0, 0, 0, 27,
+        ];
+        static ACCEPTS: &[u16] = &[0, 0, 0, 0, 1, 75, 0, 58, 59, 64, 70, 80, 36, 37, 60, 66, 54, 68, 55, 62, 82, 52, 51, 40, 46, 41, 76, 85, 42, 43, 77, 85, 85, 38, 72, 39, 0, 0, 48, 83, 0, 65, 71, 0, 79, 80, 0, 0, 61, 67, 69, 44, 56, 2, 63, 53, 50, 47, 45, 49, 78, 85, 85, 73, 3, 84, 83, 79, 57, 2, 85, 85, 81];
+        static KEYWORDS: &[(&str, u16)] = &[
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("const", 19),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("struct", 9),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("return", 29),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("impl", 15),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("else", 13),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("super", 17),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("fn", 6),
+            ("", 0),
+            ("", 0),
+            ("where", 34),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("if", 12),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("pub", 8),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("trait", 27),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("use", 10),
+            ("type", 18),
+            ("while", 23),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("mod", 11),
+            ("continue", 30),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("for", 21),
+            ("", 0),
+            ("let", 7),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("mut", 25),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("as", 3),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("extern", 5),
+            ("crate", 4),
+            ("", 0),
+            ("", 0),
+            ("static", 20),
+            ("", 0),
+            ("", 0),
+            ("_", 73),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("move", 24),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("match", 28),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("loop", 22),
+            ("", 0),
+            ("", 0),
+            ("in", 32),
+            ("", 0),
+            ("break", 31),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("self", 16),
+            ("enum", 14),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("", 0),
+            ("unsafe", 33),
+            ("", 0),
+            ("", 0),
+            ("ref", 26),
+            ("", 0),
+        ];
+        rt::DfaLexer {
+            rules: RULES,
+            ascii_classes: &ASCII_CLASSES,
+            unicode_classes: UNICODE_CLASSES,
+            n_classes: 43,
+            transitions: TRANSITIONS,
+            accepts: ACCEPTS,
+            starts: &[1],
+            keyword_seed: 4,
+            keywords: KEYWORDS,
+            layout: None,
+        }
     }
+
     fn create_parser_definition() -> rt::ParserDefinition {
-        let parser_json = r##"[{"Pub":{"ty":93,"body":158,"replaceable":false}},{"Or":[161]},{"Or":[163,165,167,169,171,173,175,176]},{"Cached":193},{"Pub":{"ty":94,"body":209,"replaceable":false}},{"Pub":{"ty":95,"body":215,"replaceable":false}},{"Pub":{"ty":96,"body":221,"replaceable":false}},{"Pub":{"ty":97,"body":228,"replaceable":false}},{"Pub":{"ty":98,"body":244,"replaceable":false}},{"Or":[246]},{"Pub":{"ty":99,"body":251,"replaceable":false}},{"Or":[257]},{"Pub":{"ty":100,"body":260,"replaceable":false}},{"Pub":{"ty":101,"body":266,"replaceable":false}},{"Pub":{"ty":102,"body":283,"replaceable":false}},{"Pub":{"ty":103,"body":302,"replaceable":false}},{"Pub":{"ty":104,"body":307,"replaceable":false}},{"Pub":{"ty":105,"body":310,"replaceable":false}},{"Pub":{"ty":106,"body":317,"replaceable":false}},{"Pub":{"ty":107,"body":330,"replaceable":false}},{"Pub":{"ty":108,"body":339,"replaceable":false}},{"Pub":{"ty":109,"body":352,"replaceable":false}},{"Pub":{"ty":110,"body":360,"replaceable":false}},{"Pub":{"ty":111,"body":372,"replaceable":false}},{"Or":[373,374,375,376]},{"Or":[378,380,382,384,386,388,390,392,397]},{"Pub":{"ty":112,"body":407,"replaceable":false}},{"Pub":{"ty":113,"body":421,"replaceable":false}},{"Pub":{"ty":114,"body":425,"replaceable":false}},{"Pub":{"ty":115,"body":429,"replaceable":false}},{"Or":[437]},{"Pub":{"ty":116,"body":444,"replaceable":false}},{"Pub":{"ty":117,"body":448,"replaceable":false}},{"Or":[468]},{"Pub":{"ty":118,"body":473,"replaceable":false}},{"Pub":{"ty":119,"body":493,"replaceable":false}},{"Pub":{"ty":120,"body":503,"replaceable":false}},{"Pub":{"ty":121,"body":518,"replaceable":false}},{"Or":[519]},{"Or":[521]},{"Or":[523]},{"Pratt":{"atoms":[42,44],"prefixes":[],"infixes":[{"ty":122,"op":526,"priority":999,"has_rhs":false}]}},{"Pub":{"ty":122,"body":530,"replaceable":false}},{"Pub":{"ty":122,"body":535,"replaceable":false}},{"Pub":{"ty":123,"body":542,"replaceable":false}},{"Pub":{"ty":124,"body":556,"replaceable":false}},{"Pub":{"ty":125,"body":582,"replaceable":false}},{"Pub":{"ty":126,"body":587,"replaceable":false}},{"Pub":{"ty":127,"body":591,"replaceable":false}},{"Pub":{"ty":128,"body":597,"replaceable":false}},{"Or":[598,599,600,601,602,603,604,605,606,607]},{"Pub":{"ty":129,"body":609,"replaceable":false}},{"Pub":{"ty":130,"body":611,"replaceable":false}},{"Pub":{"ty":131,"body":619,"replaceable":false}},{"Pub":{"ty":132,"body":622,"replaceable":false}},{"Pub":{"ty":133,"body":626,"replaceable":false}},{"Pub":{"ty":134,"body":632,"replaceable":true}},{"PubReplace":{"ty":135,"body":636}},{"Pub":{"ty":136,"body":639,"replaceable":false}},{"Pub":{"ty":137,"body":648,"replaceable":false}},{"Pub":{"ty":138,"body":655,"replaceable":false}},{"Pub":{"ty":100,"body":661,"replaceable":false}},{"Pub":{"ty":139,"body":664,"replaceable":false}},{"Or":[665,666,667,668,669,670,671]},{"Pub":{"ty":140,"body":674,"replaceable":false}},{"Pub":{"ty":141,"body":680,"replaceable":true}},{"PubReplace":{"ty":142,"body":692}},{"PubReplace":{"ty":143,"body":704}},{"Pub":{"ty":144,"body":711,"replaceable":false}},{"Pub":{"ty":145,"body":718,"replaceable":false}},{"Pub":{"ty":146,"body":720,"replaceable":false}},{"Pub":{"ty":147,"body":724,"replaceable":false}},{"Pub":{"ty":148,"body":730,"replaceable":true}},{"PubReplace":{"ty":149,"body":734}},{"Pub":{"ty":150,"body":739,"replaceable":false}},{"Pratt":{"atoms":[79,80,83,84,86,87,89,90,91,98,99,102,103,105,109,110,138],"prefixes":[{"ty":184,"op":120,"priority":999},{"ty":185,"op":768,"priority":999},{"ty":186,"op":769,"priority":999},{"ty":187,"op":770,"priority":999},{"ty":197,"op":140,"priority":2}],"infixes":[{"ty":177,"op":745,"priority":999,"has_rhs":false},{"ty":178,"op":754,"priority":999,"has_rhs":false},{"ty":180,"op":762,"priority":999,"has_rhs":false},{"ty":181,"op":763,"priority":999,"has_rhs":false},{"ty":182,"op":764,"priority":999,"has_rhs":false},{"ty":183,"op":767,"priority":999,"has_rhs":false},{"ty":188,"op":778,"priority":11,"has_rhs":true},{"ty":189,"op":784,"priority":10,"has_rhs":true},{"ty":190,"op":790,"priority":9,"has_rhs":true},{"ty":191,"op":796,"priority":8,"has_rhs":true},{"ty":192,"op":798,"priority":7,"has_rhs":true},{"ty":193,"op":804,"priority":6,"has_rhs":true},{"ty":194,"op":805,"priority":5,"has_rhs":true},{"ty":195,"op":807,"priority":4,"has_rhs":true},{"ty":196,"op":809,"priority":3,"has_rhs":true},{"ty":197,"op":810,"priority":2,"has_rhs":true},{"ty":197,"op":139,"priority":2,"has_rhs":false},{"ty":198,"op":834,"priority":1,"has_rhs":true}]}},{"Or":[835,837,839,841,843,845,847,849,851,853,855,857,859,861,863,865,867,869,871,873,875,877,879,881,883,885]},{"Or":[887]},{"Or":[891]},{"Pub":{"ty":152,"body":902,"replaceable":false}},{"Pub":{"ty":153,"body":910,"replaceable":true}},{"PubReplace":{"ty":154,"body":923}},{"Pub":{"ty":155,"body":930,"replaceable":false}},{"Pub":{"ty":156,"body":934,"replaceable":false}},{"Pub":{"ty":157,"body":941,"replaceable":true}},{"PubReplace":{"ty":158,"body":946}},{"Pub":{"ty":159,"body":959,"replaceable":false}},{"Pub":{"ty":160,"body":971,"replaceable":false}},{"Or":[979]},{"Pub":{"ty":161,"body":983,"replaceable":false}},{"Pub":{"ty":162,"body":992,"replaceable":false}},{"Pub":{"ty":163,"body":1002,"replaceable":false}},{"Or":[1003,1004,1005,1006]},{"Or":[1008,1010,1011,1012]},{"Pub":{"ty":164,"body":1021,"replaceable":false}},{"Pub":{"ty":165,"body":1024,"replaceable":false}},{"Pub":{"ty":166,"body":1027,"replaceable":false}},{"Pub":{"ty":167,"body":1038,"replaceable":false}},{"Pub":{"ty":168,"body":1048,"replaceable":false}},{"Pub":{"ty":169,"body":1052,"replaceable":false}},{"Or":[1058]},{"Or":[1060]},{"Pub":{"ty":170,"body":1064,"replaceable":false}},{"Pub":{"ty":171,"body":1069,"replaceable":false}},{"Or":[1072]},{"Pub":{"ty":172,"body":1077,"replaceable":false}},{"Pub":{"ty":173,"body":1087,"replaceable":false}},{"Or":[1093]},{"Pub":{"ty":174,"body":1096,"replaceable":false}},{"Pub":{"ty":175,"body":1098,"replaceable":false}},{"Pub":{"ty":176,"body":1100,"replaceable":false}},{"Pub":{"ty":177,"body":1108,"replaceable":false}},{"Pub":{"ty":178,"body":1119,"replaceable":false}},{"Or":[1123]},{"Pub":{"ty":179,"body":1125,"replaceable":false}},{"Pub":{"ty":180,"body":1135,"replaceable":false}},{"Pub":{"ty":181,"body":1138,"replaceable":false}},{"Pub":{"ty":182,"body":1141,"replaceable":false}},{"Pub":{"ty":183,"body":1146,"replaceable":false}},{"Pub":{"ty":184,"body":1148,"replaceable":false}},{"Or":[1154]},{"Pub":{"ty":185,"body":1157,"replaceable":false}},{"Pub":{"ty":186,"body":1160,"replaceable":false}},{"Pub":{"ty":187,"body":1163,"replaceable":false}},{"Pub":{"ty":188,"body":1173,"replaceable":false}},{"Pub":{"ty":189,"body":1181,"replaceable":false}},{"Pub":{"ty":190,"body":1189,"replaceable":false}},{"Or":[1193,1197]},{"Pub":{"ty":191,"body":1205,"replaceable":false}},{"Pub":{"ty":192,"body":1209,"replaceable":false}},{"Pub":{"ty":193,"body":1217,"replaceable":false}},{"Pub":{"ty":194,"body":1220,"replaceable":false}},{"Or":[1222,1224,1226,1228,1230,1232]},{"Pub":{"ty":195,"body":1236,"replaceable":false}},{"Pub":{"ty":196,"body":1240,"replaceable":false}},{"Pub":{"ty":197,"body":1243,"replaceable":false}},{"Pub":{"ty":197,"body":1245,"replaceable":false}},{"Pub":{"ty":197,"body":1247,"replaceable":false}},{"Pub":{"ty":197,"body":1251,"replaceable":false}},{"Or":[1253,1255]},{"Or":[1263]},{"Pub":{"ty":198,"body":1289,"replaceable":false}},{"Pub":{"ty":199,"body":1292,"replaceable":false}},{"Pub":{"ty":200,"body":1296,"replaceable":false}},{"Or":[1299]},{"Or":[1301]},{"Pub":{"ty":201,"body":1311,"replaceable":false}},{"Pub":{"ty":202,"body":1319,"replaceable":false}},{"Pub":{"ty":203,"body":1334,"replaceable":false}},{"Pub":{"ty":204,"body":1363,"replaceable":false}},{"Or":[1373]},{"Or":[1378]},{"Or":[1383]},{"Or":[1388]},{"Or":[1393]},{"Or":[1401]},{"Or":[1416]},{"And":[[1],null]},{"Or":[157]},{"WithSkip":[2,3]},{"Rep":159},{"And":[[160],null]},{"Token":11},{"And":[[162],null]},{"ContextualToken":[4,"union"]},{"And":[[164],null]},{"Token":16},{"And":[[166],null]},{"Token":12},{"And":[[168],null]},{"Token":13},{"And":[[170],null]},{"Token":17},{"And":[[172],null]},{"Token":29},{"And":[[174],null]},{"And":[[25],null]},{"Opt":36},{"And":[[145,177],null]},{"Or":[178]},{"And":[[24],null]},{"And":[[4],null]},{"And":[[7],null]},{"And":[[15],null]},{"And":[[18],null]},{"And":[[20],null]},{"And":[[21],null]},{"And":[[22],null]},{"And":[[29],null]},{"Or":[180,181,182,183,184,185,186,187,188]},{"Inject":[179,189]},{"And":[[190],null]},{"And":[[28],null]},{"Or":[191,192]},{"Token":12},{"And":[[48],null]},{"Token":60},{"And":[[196,5],null]},{"Or":[197]},{"Opt":198},{"And":[[199],null]},{"Or":[195,200]},{"And":[[38,201],null]},{"Token":60},{"Opt":203},{"And":[[204,5],null]},{"Or":[202,205]},{"Token":58},{"And":[[194,206,207],1]},{"Or":[208]},{"Token":67},{"And":[[210],null]},{"Call":[150,[[2,6]]]},{"Call":[151,[[3,212]]]},{"And":[[213],null]},{"Or":[211,214]},{"Token":18},{"And":[[216],null]},{"Token":92},{"Opt":48},{"And":[[218,219],1]},{"Or":[217,220]},{"Token":7},{"Token":6},{"Token":92},{"Opt":48},{"Token":58},{"And":[[222,223,224,225,226],2]},{"Or":[227]},{"Token":21},{"Opt":229},{"Token":35},{"Opt":231},{"Opt":10},{"Token":8},{"Token":92},{"Opt":31},{"Opt":9},{"Opt":37},{"And":[[91],null]},{"Token":58},{"And":[[240],null]},{"Or":[239,241]},{"And":[[230,232,233,234,235,236,11,237,238,242],4]},{"Or":[243]},{"Token":51},{"And":[[245,49],null]},{"Token":7},{"Token":90},{"Opt":248},{"And":[[247,249],null]},{"Or":[250]},{"Opt":14},{"Call":[150,[[2,12]]]},{"And":[[252,253],null]},{"Or":[254]},{"Call":[152,[[4,255]]]},{"And":[[256],null]},{"Token":59},{"And":[[63,258,49],1]},{"Or":[259]},{"Token":59},{"And":[[261,49],null]},{"Or":[262]},{"Opt":263},{"And":[[63,264],null]},{"Or":[265]},{"And":[[120],null]},{"Token":27},{"And":[[268],null]},{"Or":[267,269]},{"Opt":270},{"Token":18},{"Token":59},{"And":[[273,49],null]},{"Or":[274]},{"Opt":275},{"Token":61},{"And":[[277],null]},"Eof",{"And":[[279],null]},{"Or":[278,280]},{"And":[[271,272,276,281],2]},{"Or":[282]},{"Token":11},{"And":[[284],null]},{"ContextualToken":[4,"union"]},{"And":[[286],null]},{"Or":[285,287]},{"Token":92},{"Opt":31},{"Call":[150,[[2,16]]]},{"Call":[151,[[3,291]]]},{"And":[[292],null]},{"Token":58},{"And":[[294],null]},{"Call":[150,[[2,17]]]},{"Call":[152,[[4,296]]]},{"Token":58},{"And":[[297,298],null]},{"Or":[293,295,299]},{"And":[[288,289,290,300],1]},{"Or":[301]},{"Opt":36},{"Token":92},{"Token":59},{"And":[[303,304,305,49],2]},{"Or":[306]},{"Opt":36},{"And":[[308,49],null]},{"Or":[309]},{"Token":16},{"Token":92},{"Opt":31},{"Call":[150,[[2,19]]]},{"Call":[151,[[3,314]]]},{"And":[[311,312,313,315],1]},{"Or":[316]},{"Token":92},{"Token":53},{"And":[[319,75],null]},{"Call":[150,[[2,17]]]},{"Call":[152,[[4,321]]]},{"And":[[322],null]},{"Call":[150,[[2,16]]]},{"Call":[151,[[3,324]]]},{"And":[[325],null]},{"Or":[320,323,326]},{"Opt":327},{"And":[[318,328],1]},{"Or":[329]},{"Token":13},{"Token":92},{"Token":58},{"And":[[333],null]},{"Call":[151,[[3,1]]]},{"And":[[335],null]},{"Or":[334,336]},{"And":[[331,332,337],1]},{"Or":[338]},{"Token":35},{"Opt":340},{"Token":17},{"Opt":31},{"Token":23},{"And":[[344,49],null]},{"Or":[345]},{"Opt":346},{"And":[[49,347],null]},{"Or":[348]},{"Opt":37},{"And":[[341,342,343,349,350,23],2]},{"Or":[351]},{"Opt":36},{"Token":29},{"Token":92},{"Opt":31},{"Opt":33},{"Opt":37},{"And":[[145,353,354,355,356,357,358,23],3]},{"Or":[359]},{"Opt":36},{"And":[[145,361],null]},{"Or":[362]},{"Inject":[363,24]},{"And":[[364],null]},{"And":[[28],null]},{"Or":[365,366]},{"WithSkip":[25,367]},{"Rep":368},{"Call":[151,[[3,369]]]},{"And":[[370],null]},{"Or":[371]},{"And":[[8],null]},{"And":[[26],null]},{"And":[[27],null]},{"And":[[143],null]},{"Token":10},{"And":[[377],null]},{"Token":8},{"And":[[379],null]},{"Token":20},{"And":[[381],null]},{"Token":21},{"And":[[383],null]},{"Token":22},{"And":[[385],null]},{"Token":35},{"And":[[387],null]},{"Token":65},{"And":[[389],null]},{"Token":7},{"And":[[391],null]},{"Token":92},{"Token":82},{"And":[[393,394],null]},{"Or":[395]},{"And":[[396],null]},{"Token":20},{"Token":92},{"Opt":31},{"Token":53},{"And":[[401,49],null]},{"Or":[402]},{"Opt":403},{"Token":58},{"And":[[398,399,400,404,405],1]},{"Or":[406]},{"Token":21},{"And":[[408],null]},{"Token":22},{"And":[[410],null]},{"Or":[409,411]},{"Token":92},{"Token":59},{"Token":53},{"And":[[415,75],null]},{"Or":[416]},{"Opt":417},{"Token":58},{"And":[[412,413,414,49,418,419],1]},{"Or":[420]},{"And":[[147],null]},{"Token":58},{"And":[[148,423],null]},{"Or":[422,424]},{"Rep":30},{"Call":[151,[[3,426]]]},{"And":[[10,427],null]},{"Or":[428]},{"Opt":36},{"And":[[145,430],null]},{"Or":[431]},{"And":[[8],null]},{"And":[[27],null]},{"Or":[433,434]},{"Inject":[432,435]},{"And":[[436],null]},{"Call":[150,[[2,35]]]},{"Call":[150,[[2,32]]]},{"And":[[438,439],null]},{"Or":[440]},{"Call":[153,[[5,441]]]},{"And":[[442],null]},{"Or":[443]},{"Token":92},{"Opt":33},{"And":[[445,446],1]},{"Or":[447]},{"Token":59},{"Token":73},{"And":[[450],null]},"Eof",{"And":[[452],null]},{"Token":61},{"And":[[454],null]},{"Token":39},{"And":[[456],null]},{"Token":36},{"And":[[458],null]},{"Or":[455,457,459]},{"Not":460},{"Not":461},{"And":[[462],null]},{"Or":[451,453,463]},{"And":[[34,464],1]},{"Or":[465]},{"Rep":466},{"And":[[449,467],null]},{"Token":87},{"And":[[469],null]},{"And":[[51],null]},{"And":[[62],null]},{"Or":[470,471,472]},{"Token":87},{"Token":59},{"Token":87},{"Token":73},{"And":[[477],null]},"Eof",{"And":[[479],null]},{"Token":61},{"Not":481},{"Not":482},{"And":[[483],null]},{"Or":[478,480,484]},{"And":[[476,485],1]},{"Or":[486]},{"Rep":487},{"And":[[475,488],null]},{"Or":[489]},{"Opt":490},{"And":[[474,491],1]},{"Or":[492]},{"Token":10},{"Token":6},{"And":[[495],null]},{"Token":19},{"And":[[497],null]},{"Or":[496,498]},{"Call":[152,[[4,499]]]},{"Opt":500},{"And":[[494,501],null]},{"Or":[502]},{"Token":36},{"Token":61},{"And":[[505],null]},"Eof",{"And":[[507],null]},{"Token":39},{"Not":509},{"Not":510},{"And":[[511],null]},{"Or":[506,508,512]},{"And":[[49,33,513],null]},{"Or":[514]},{"Rep":515},{"And":[[504,516],1]},{"Or":[517]},{"And":[[41],null]},{"Enter":[3,41]},{"And":[[520],null]},{"Enter":[1,41]},{"And":[[522],null]},{"Token":60},{"And":[[524,45],null]},{"Or":[525]},{"Token":60},{"Opt":527},{"And":[[528,45],null]},{"Or":[529]},{"Token":60},{"And":[[531,45],null]},{"Or":[532]},{"And":[[41,533],null]},{"Or":[534]},{"Token":5},{"And":[[49,536,49],null]},{"Or":[537]},{"Call":[153,[[5,538]]]},{"Token":60},{"And":[[539,540,45],null]},{"Or":[541]},{"Token":92},{"And":[[543],null]},{"Token":18},{"And":[[545],null]},{"Token":19},{"And":[[547],null]},{"Or":[544,546,548]},{"And":[[46],null]},{"IsIn":3},{"And":[[551,47],null]},{"Or":[550,552]},{"Opt":553},{"And":[[549,554],null]},{"Or":[555]},{"IsIn":3},{"And":[[557],null]},{"IsIn":1},{"Token":60},{"And":[[559,560],null]},{"Or":[558,561]},{"Token":87},{"Call":[150,[[2,563]]]},{"Token":92},{"Token":53},{"And":[[565,566],null]},{"Or":[567]},{"Not":568},{"And":[[569,49],null]},{"Or":[570]},{"Call":[150,[[2,571]]]},{"Token":92},{"Token":53},{"And":[[573,574,49],null]},{"Or":[575]},{"Call":[150,[[2,576]]]},{"And":[[564,572,577],null]},{"Or":[578]},{"Call":[153,[[5,579]]]},{"And":[[562,580],null]},{"Or":[581]},{"Call":[150,[[2,49]]]},{"Call":[152,[[4,583]]]},{"Opt":9},{"And":[[584,585],null]},{"Or":[586]},{"Token":5},{"Token":92},{"And":[[588,589],null]},{"Or":[590]},{"Token":73},{"And":[[592,34],null]},{"Or":[593]},{"Rep":594},{"And":[[50,595],null]},{"Or":[596]},{"And":[[51],null]},{"And":[[52],null]},{"And":[[53],null]},{"And":[[54],null]},{"And":[[55],null]},{"And":[[56],null]},{"And":[[58],null]},{"And":[[59],null]},{"And":[[60],null]},{"And":[[62],null]},{"And":[[39],null]},{"Or":[608]},{"And":[[120,49],null]},{"Or":[610]},{"Token":67},{"Token":21},{"And":[[613],null]},{"Token":27},{"And":[[615],null]},{"Or":[614,616]},{"And":[[612,617,49],null]},{"Or":[618]},{"Token":81},{"And":[[620],null]},{"Or":[621]},{"Token":37},{"Token":38},{"And":[[623,624],null]},{"Or":[625]},{"Opt":57},{"And":[[49,627],null]},{"Or":[628]},{"Call":[152,[[4,629]]]},{"And":[[630],null]},{"Or":[631]},{"Token":61},{"Call":[150,[[2,49]]]},{"And":[[633,634],null]},{"Or":[635]},{"Token":82},{"And":[[637],null]},{"Or":[638]},{"Token":58},{"And":[[640,75],null]},{"Or":[641]},{"Opt":642},{"And":[[49,643],null]},{"Or":[644]},{"Call":[154,[[6,645]]]},{"And":[[646],null]},{"Or":[647]},{"Opt":10},{"Token":8},{"Call":[150,[[2,61]]]},{"Call":[152,[[4,651]]]},{"Opt":9},{"And":[[649,650,652,653],2]},{"Or":[654]},{"Token":59},{"And":[[63,656],null]},{"Or":[657]},{"Opt":658},{"And":[[659,49],null]},{"Or":[660]},{"Token":23},{"And":[[662,31,49],1]},{"Or":[663]},{"And":[[64],null]},{"And":[[65],null]},{"And":[[69],null]},{"And":[[70],null]},{"And":[[71],null]},{"And":[[72],null]},{"And":[[74],null]},{"Token":81},{"And":[[672],null]},{"Or":[673]},{"And":[[66],null]},{"And":[[67],null]},{"Or":[675,676]},{"Opt":677},{"And":[[40,678],null]},{"Or":[679]},{"Call":[150,[[2,63]]]},{"Token":63},{"Token":61},{"Opt":683},{"And":[[682,684],null]},{"Or":[685]},{"Opt":686},{"And":[[681,687],null]},{"Or":[688]},{"Call":[152,[[4,689]]]},{"And":[[690],null]},{"Or":[691]},{"Call":[150,[[2,68]]]},{"Token":63},{"Token":61},{"Opt":695},{"And":[[694,696],null]},{"Or":[697]},{"Opt":698},{"And":[[693,699],null]},{"Or":[700]},{"Call":[151,[[3,701]]]},{"And":[[702],null]},{"Or":[703]},{"Token":59},{"Not":705},{"And":[[69,706],null]},{"Token":92},{"Token":59},{"And":[[708,709,63],2]},{"Or":[707,710]},{"Token":28},{"Opt":712},{"Token":27},{"Opt":714},{"Token":92},{"And":[[713,715,716],null]},{"Or":[717]},{"And":[[79],null]},{"Or":[719]},{"Token":37},{"Token":38},{"And":[[721,722],null]},{"Or":[723]},{"Opt":73},{"And":[[63,725],null]},{"Or":[726]},{"Call":[152,[[4,727]]]},{"And":[[728],null]},{"Or":[729]},{"Token":61},{"Call":[150,[[2,63]]]},{"And":[[731,732],null]},{"Or":[733]},{"Token":77},{"Token":27},{"Opt":736},{"And":[[735,737,63],null]},{"Or":[738]},{"Token":62},{"Token":92},{"Enter":[1,46]},{"Opt":742},{"And":[[740,741,743,113],null]},{"Or":[744]},{"IsIn":2},{"Not":77},{"And":[[746,747],null]},{"IsIn":2},{"Not":749},{"And":[[750],null]},{"Or":[748,751]},{"And":[[752,113],null]},{"Or":[753]},{"Token":62},{"Token":92},{"And":[[756],null]},{"Token":89},{"And":[[758],null]},{"Or":[757,759]},{"And":[[755,760],null]},{"Or":[761]},{"Call":[154,[[6,75]]]},{"Token":83},{"Token":5},{"And":[[765,49],null]},{"Or":[766]},{"Token":67},{"Token":75},{"Token":82},{"Token":67},{"And":[[771],null]},{"Token":69},{"And":[[773],null]},{"Token":71},{"And":[[775],null]},{"Or":[772,774,776]},{"Call":[127,[[1,777]]]},{"Token":73},{"And":[[779],null]},{"Token":75},{"And":[[781],null]},{"Or":[780,782]},{"Call":[127,[[1,783]]]},{"ContextualToken":[45,"<<"]},{"And":[[785],null]},{"ContextualToken":[47,">>"]},{"And":[[787],null]},{"Or":[786,788]},{"Call":[127,[[1,789]]]},{"Token":77},{"Token":77},{"Not":792},{"And":[[791,793],null]},{"Or":[794]},{"Call":[127,[[1,795]]]},{"Token":84},{"Call":[127,[[1,797]]]},{"Token":79},{"Token":79},{"Not":800},{"And":[[799,801],null]},{"Or":[802]},{"Call":[127,[[1,803]]]},{"Call":[127,[[1,132]]]},{"ContextualToken":[49,"&&"]},{"Call":[127,[[1,806]]]},{"ContextualToken":[50,"||"]},{"Call":[127,[[1,808]]]},{"Call":[127,[[1,140]]]},{"Token":53},{"And":[[811],null]},{"Token":74},{"And":[[813],null]},{"Token":76},{"And":[[815],null]},{"Token":68},{"And":[[817],null]},{"Token":70},{"And":[[819],null]},{"Token":72},{"And":[[821],null]},{"Token":78},{"And":[[823],null]},{"Token":80},{"And":[[825],null]},{"Token":85},{"And":[[827],null]},{"ContextualToken":[48,">>="]},{"And":[[829],null]},{"ContextualToken":[46,"<<="]},{"And":[[831],null]},{"Or":[812,814,816,818,820,822,824,826,828,830,832]},{"Call":[127,[[1,833]]]},{"And":[[79],null]},{"Token":92},{"And":[[836],null]},{"Token":87},{"And":[[838],null]},{"Token":18},{"And":[[840],null]},{"Token":19},{"And":[[842],null]},{"Token":41},{"And":[[844],null]},{"Token":60},{"And":[[846],null]},{"Token":37},{"And":[[848],null]},{"Token":43},{"And":[[850],null]},{"Token":79},{"And":[[852],null]},{"Token":31},{"And":[[854],null]},{"Token":39},{"And":[[856],null]},{"Token":14},{"And":[[858],null]},{"Token":25},{"And":[[860],null]},{"Token":24},{"And":[[862],null]},{"Token":23},{"And":[[864],null]},{"Token":30},{"And":[[866],null]},{"Token":26},{"And":[[868],null]},{"Token":32},{"And":[[870],null]},{"Token":33},{"And":[[872],null]},{"Token":77},{"And":[[874],null]},{"Token":67},{"And":[[876],null]},{"Token":75},{"And":[[878],null]},{"Token":82},{"And":[[880],null]},{"Token":63},{"And":[[882],null]},{"Token":64},{"And":[[884],null]},{"PrevIs":[163,168,169,170,171,172,175]},{"And":[[886],null]},{"Var":0},{"Exit":[2,888]},{"Exit":[0,889]},{"And":[[890],null]},{"Token":89},{"And":[[892],null]},{"Token":90},{"And":[[894],null]},{"Token":91},{"And":[[896],null]},{"Token":86},{"And":[[898],null]},{"Token":88},{"And":[[900],null]},{"Or":[893,895,897,899,901]},{"Token":92},{"Token":82},{"And":[[903,904],null]},{"Or":[905]},{"Not":906},{"Opt":81},{"And":[[907,40,908],null]},{"Or":[909]},{"IsIn":0},{"Not":911},{"Call":[150,[[2,82]]]},{"Token":63},{"Call":[78,[[0,75]]]},{"And":[[914,915],null]},{"Or":[916]},{"Opt":917},{"And":[[913,918],null]},{"Or":[919]},{"Call":[151,[[3,920]]]},{"And":[[912,921],null]},{"Or":[922]},{"Token":92},{"Token":59},{"And":[[925,75],null]},{"Or":[926]},{"Opt":927},{"And":[[924,928],1]},{"Or":[929]},{"Token":37},{"Token":38},{"And":[[931,932],null]},{"Or":[933]},{"Call":[78,[[0,75]]]},{"Opt":85},{"And":[[935,936],null]},{"Or":[937]},{"Call":[152,[[4,938]]]},{"And":[[939],null]},{"Or":[940]},{"Token":61},{"Call":[78,[[0,75]]]},{"Call":[150,[[2,943]]]},{"And":[[942,944],null]},{"Or":[945]},{"Token":58},{"And":[[947,75],null]},{"Token":61},{"Call":[150,[[2,75]]]},{"And":[[949,950],null]},{"Or":[948,951]},{"Opt":952},{"And":[[75,953],null]},{"Or":[954]},{"Call":[78,[[0,955]]]},{"Call":[154,[[6,956]]]},{"And":[[957],null]},{"Or":[958]},{"Token":26},{"Opt":960},{"Token":79},{"Rep":88},{"Token":79},{"Token":51},{"And":[[965,49,91],null]},{"Call":[78,[[0,75]]]},{"And":[[967],null]},{"Or":[966,968]},{"And":[[961,962,963,964,969],null]},{"Or":[970]},{"Token":61},{"And":[[972],null]},{"Token":79},{"Not":974},{"Not":975},{"And":[[976],null]},{"Or":[973,977]},{"And":[[13,978],1]},{"Token":31},{"Opt":75},{"And":[[980,981],null]},{"Or":[982]},{"Token":33},{"And":[[984],null]},{"Token":32},{"And":[[986],null]},{"Or":[985,987]},{"Token":87},{"Opt":989},{"And":[[988,990],null]},{"Or":[991]},{"Token":35},{"Opt":993},{"WithSkip":[93,92]},{"Rep":995},{"Call":[151,[[3,996]]]},{"And":[[994,997],null]},{"Or":[998]},{"Call":[78,[[0,999]]]},{"And":[[1000],null]},{"Or":[1001]},{"And":[[94],null]},{"And":[[96],null]},{"And":[[3],null]},{"And":[[97],null]},{"Token":9},{"And":[[1007],null]},{"Token":58},{"And":[[1009],null]},{"And":[[2],null]},{"And":[[76],null]},{"Token":9},{"Opt":95},{"Token":53},{"And":[[1015,75],1]},{"Or":[1016]},{"Opt":1017},{"Token":58},{"And":[[1013,63,1014,1018,1019],1]},{"Or":[1020]},{"Token":59},{"And":[[1022,49],null]},{"Or":[1023]},{"Token":58},{"And":[[1025],null]},{"Or":[1026]},{"And":[[77],null]},"Eof",{"And":[[1029],null]},{"Token":58},{"And":[[1031],null]},{"Or":[1028,1030,1032]},{"And":[[75,1033],1]},{"Or":[1034]},{"Enter":[2,1035]},{"And":[[1036],null]},{"Or":[1037]},{"Token":14},{"Token":15},{"And":[[91],null]},{"And":[[98],null]},{"Or":[1041,1042]},{"And":[[1040,1043],null]},{"Or":[1044]},{"Opt":1045},{"And":[[1039,100,91,1046],1]},{"Or":[1047]},{"Opt":104},{"Token":25},{"And":[[1049,1050,100,91],2]},{"Or":[1051]},{"Token":9},{"Token":53},{"And":[[1053,63,1054],1]},{"Or":[1055]},{"Opt":1056},{"And":[[1057,101],1]},{"Enter":[0,75]},{"And":[[1059],null]},{"Opt":104},{"Token":24},{"And":[[1061,1062,91],2]},{"Or":[1063]},{"Opt":104},{"Token":23},{"Token":34},{"And":[[1065,1066,63,1067,101,91],2]},{"Or":[1068]},{"Token":87},{"Token":59},{"And":[[1070,1071],null]},{"Token":30},{"Rep":106},{"Call":[151,[[3,1074]]]},{"And":[[1073,101,1075],1]},{"Or":[1076]},{"Token":52},{"Enter":[2,75]},{"Token":61},{"And":[[1080],null]},"Eof",{"And":[[1082],null]},{"And":[[77],null]},{"Or":[1081,1083,1084]},{"And":[[107,1078,1079,1085],1]},{"Or":[1086]},{"Token":79},{"And":[[1088,63],null]},{"Or":[1089]},{"Rep":1090},{"Opt":108},{"And":[[63,1091,1092],null]},{"Token":14},{"And":[[1094,75],null]},{"Or":[1095]},{"And":[[147],null]},{"Or":[1097]},{"And":[[148],null]},{"Or":[1099]},{"Token":62},{"Token":92},{"Enter":[1,46]},{"Opt":1103},{"And":[[1101,1102,1104,113],null]},{"Or":[1105]},{"And":[[75,1106],null]},{"Or":[1107]},{"IsIn":2},{"Not":77},{"And":[[1109,1110],null]},{"IsIn":2},{"Not":1112},{"And":[[1113],null]},{"Or":[1111,1114]},{"And":[[1115,113],null]},{"Or":[1116]},{"And":[[75,1117],null]},{"Or":[1118]},{"Call":[150,[[2,114]]]},{"Call":[152,[[4,1120]]]},{"Call":[78,[[0,1121]]]},{"And":[[1122],null]},{"And":[[75],null]},{"Or":[1124]},{"Token":62},{"Token":92},{"And":[[1127],null]},{"Token":89},{"And":[[1129],null]},{"Or":[1128,1130]},{"And":[[1126,1131],null]},{"Or":[1132]},{"And":[[75,1133],null]},{"Or":[1134]},{"Call":[154,[[6,75]]]},{"And":[[75,1136],null]},{"Or":[1137]},{"Token":83},{"And":[[75,1139],null]},{"Or":[1140]},{"Token":5},{"And":[[1142,49],null]},{"Or":[1143]},{"And":[[75,1144],null]},{"Or":[1145]},{"And":[[120,75],null]},{"Or":[1147]},{"Token":77},{"Token":87},{"Opt":1150},{"Token":27},{"Opt":1152},{"And":[[1149,1151,1153],null]},{"Token":67},{"And":[[1155,75],null]},{"Or":[1156]},{"Token":75},{"And":[[1158,75],null]},{"Or":[1159]},{"Token":82},{"And":[[1161,75],null]},{"Or":[1162]},{"Token":67},{"And":[[1164],null]},{"Token":69},{"And":[[1166],null]},{"Token":71},{"And":[[1168],null]},{"Or":[1165,1167,1169]},{"Call":[127,[[1,1170]]]},{"And":[[75,1171,75],null]},{"Or":[1172]},{"Token":73},{"And":[[1174],null]},{"Token":75},{"And":[[1176],null]},{"Or":[1175,1177]},{"Call":[127,[[1,1178]]]},{"And":[[75,1179,75],null]},{"Or":[1180]},{"ContextualToken":[45,"<<"]},{"And":[[1182],null]},{"ContextualToken":[47,">>"]},{"And":[[1184],null]},{"Or":[1183,1185]},{"Call":[127,[[1,1186]]]},{"And":[[75,1187,75],null]},{"Or":[1188]},{"IsIn":2},{"Not":77},{"Var":1},{"And":[[1190,1191,1192],null]},{"IsIn":2},{"Not":1194},{"Var":1},{"And":[[1195,1196],null]},{"Token":77},{"Token":77},{"Not":1199},{"And":[[1198,1200],null]},{"Or":[1201]},{"Call":[127,[[1,1202]]]},{"And":[[75,1203,75],null]},{"Or":[1204]},{"Token":84},{"Call":[127,[[1,1206]]]},{"And":[[75,1207,75],null]},{"Or":[1208]},{"Token":79},{"Token":79},{"Not":1211},{"And":[[1210,1212],null]},{"Or":[1213]},{"Call":[127,[[1,1214]]]},{"And":[[75,1215,75],null]},{"Or":[1216]},{"Call":[127,[[1,132]]]},{"And":[[75,1218,75],null]},{"Or":[1219]},{"Token":54},{"And":[[1221],null]},{"Token":55},{"And":[[1223],null]},{"Token":41},{"And":[[1225],null]},{"Token":42},{"And":[[1227],null]},{"Token":57},{"And":[[1229],null]},{"Token":56},{"And":[[1231],null]},{"ContextualToken":[49,"&&"]},{"Call":[127,[[1,1233]]]},{"And":[[75,1234,75],null]},{"Or":[1235]},{"ContextualToken":[50,"||"]},{"Call":[127,[[1,1237]]]},{"And":[[75,1238,75],null]},{"Or":[1239]},{"Call":[127,[[1,140]]]},{"And":[[75,1241,75],null]},{"Or":[1242]},{"And":[[140,75],null]},{"Or":[1244]},{"And":[[75,139],null]},{"Or":[1246]},{"Token":63},{"Not":76},{"And":[[1248,1249],null]},{"Or":[1250]},{"Token":63},{"And":[[1252],null]},{"Token":64},{"And":[[1254],null]},{"Not":76},{"Not":1256},{"Token":39},{"IsIn":0},{"And":[[1258,1259],null]},{"Or":[1260]},{"Not":1261},{"And":[[139,1257,1262],null]},{"Token":53},{"And":[[1264],null]},{"Token":74},{"And":[[1266],null]},{"Token":76},{"And":[[1268],null]},{"Token":68},{"And":[[1270],null]},{"Token":70},{"And":[[1272],null]},{"Token":72},{"And":[[1274],null]},{"Token":78},{"And":[[1276],null]},{"Token":80},{"And":[[1278],null]},{"Token":85},{"And":[[1280],null]},{"ContextualToken":[48,">>="]},{"And":[[1282],null]},{"ContextualToken":[46,"<<="]},{"And":[[1284],null]},{"Or":[1265,1267,1269,1271,1273,1275,1277,1279,1281,1283,1285]},{"Call":[127,[[1,1286]]]},{"And":[[75,1287,75],null]},{"Or":[1288]},{"Token":65},{"And":[[1290,144],null]},{"Or":[1291]},{"Token":65},{"Token":82},{"And":[[1293,1294,144],null]},{"Or":[1295]},{"Call":[150,[[2,146]]]},{"Call":[154,[[6,1297]]]},{"And":[[1298],null]},{"Rep":142},{"And":[[1300],null]},{"Token":92},{"Token":53},{"And":[[1303,75],null]},{"Call":[150,[[2,146]]]},{"Call":[152,[[4,1305]]]},{"And":[[1306],null]},{"Or":[1304,1307]},{"Opt":1308},{"And":[[1302,1309],1]},{"Or":[1310]},{"Token":92},{"Token":82},{"Token":92},{"Opt":1314},{"Rep":149},{"Call":[151,[[3,1316]]]},{"And":[[1312,1313,1315,1317],null]},{"Or":[1318]},{"Token":92},{"Token":82},{"Token":92},{"Opt":1322},{"Token":37},{"Rep":149},{"Token":38},{"And":[[1324,1325,1326],null]},{"Token":43},{"Rep":149},{"Token":44},{"And":[[1328,1329,1330],null]},{"Or":[1327,1331]},{"And":[[1320,1321,1323,1332],null]},{"Or":[1333]},{"Token":37},{"And":[[1335],null]},{"Token":38},{"And":[[1337],null]},{"Token":39},{"And":[[1339],null]},{"Token":40},{"And":[[1341],null]},{"Token":43},{"And":[[1343],null]},{"Token":44},{"And":[[1345],null]},{"Or":[1336,1338,1340,1342,1344,1346]},{"Not":1347},"Any",{"And":[[1348,1349],null]},{"Token":37},{"Rep":149},{"Token":38},{"And":[[1351,1352,1353],null]},{"Token":43},{"Rep":149},{"Token":44},{"And":[[1355,1356,1357],null]},{"Token":39},{"Rep":149},{"Token":40},{"And":[[1359,1360,1361],null]},{"Or":[1350,1354,1358,1362]},{"Var":2},"Eof",{"And":[[1365],null]},{"Token":61},{"And":[[1367],null]},{"Or":[1366,1368]},{"And":[[1364,1369],1]},{"Or":[1370]},{"Rep":1371},{"And":[[1372],null]},{"Token":39},{"Token":40},{"Var":3},{"Call":[155,[[7,1374],[8,1375],[9,1376]]]},{"And":[[1377],null]},{"Token":37},{"Token":38},{"Var":4},{"Call":[155,[[7,1379],[8,1380],[9,1381]]]},{"And":[[1382],null]},{"Token":41},{"Token":42},{"Var":5},{"Call":[155,[[7,1384],[8,1385],[9,1386]]]},{"And":[[1387],null]},{"Token":43},{"Token":44},{"Var":6},{"Call":[155,[[7,1389],[8,1390],[9,1391]]]},{"And":[[1392],null]},{"Var":7},{"Var":7},{"Var":8},{"Call":[156,[[10,1395],[11,1396]]]},{"Var":9},{"Layer":[1397,1398]},{"Var":8},{"And":[[1394,1399,1400],1]},{"Var":10},{"Var":10},{"Var":11},{"Call":[156,[[10,1403],[11,1404]]]},{"Var":11},{"And":[[1402,1405,1406],1]},{"Var":11},{"Not":1408},"Any",{"And":[[1409,1410],null]},{"Or":[1411]},{"And":[[1412],null]},{"Or":[1407,1413]},{"Rep":1414},{"And":[[1415],null]}]"##;
+        let parser_json = r##"[{"Pub":{"ty":93,"body":158,"replaceable":false}},{"Or":[161]},{"Or":[163,165,167,169,171,173,175,176]},{"Cached":193},{"Pub":{"ty":94,"body":209,"replaceable":false}},{"Pub":{"ty":95,"body":215,"replaceable":false}},{"Pub":{"ty":96,"body":221,"replaceable":false}},{"Pub":{"ty":97,"body":228,"replaceable":false}},{"Pub":{"ty":98,"body":244,"replaceable":false}},{"Or":[246]},{"Pub":{"ty":99,"body":251,"replaceable":false}},{"Or":[257]},{"Pub":{"ty":100,"body":260,"replaceable":false}},{"Pub":{"ty":101,"body":266,"replaceable":false}},{"Pub":{"ty":102,"body":283,"replaceable":false}},{"Pub":{"ty":103,"body":302,"replaceable":false}},{"Pub":{"ty":104,"body":307,"replaceable":false}},{"Pub":{"ty":105,"body":310,"replaceable":false}},{"Pub":{"ty":106,"body":317,"replaceable":false}},{"Pub":{"ty":107,"body":330,"replaceable":false}},{"Pub":{"ty":108,"body":339,"replaceable":false}},{"Pub":{"ty":109,"body":352,"replaceable":false}},{"Pub":{"ty":110,"body":360,"replaceable":false}},{"Pub":{"ty":111,"body":372,"replaceable":false}},{"Or":[373,374,375,376]},{"Or":[378,380,382,384,386,388,390,392,397]},{"Pub":{"ty":112,"body":407,"replaceable":false}},{"Pub":{"ty":113,"body":421,"replaceable":false}},{"Pub":{"ty":114,"body":425,"replaceable":false}},{"Pub":{"ty":115,"body":429,"replaceable":false}},{"Or":[437]},{"Pub":{"ty":116,"body":444,"replaceable":false}},{"Pub":{"ty":117,"body":448,"replaceable":false}},{"Or":[468]},{"Pub":{"ty":118,"body":473,"replaceable":false}},{"Pub":{"ty":119,"body":493,"replaceable":false}},{"Pub":{"ty":120,"body":503,"replaceable":false}},{"Pub":{"ty":121,"body":518,"replaceable":false}},{"Or":[519]},{"Or":[521]},{"Or":[523]},{"Pratt":{"atoms":[42,44],"prefixes":[],"infixes":[{"ty":122,"op":526,"priority":999,"has_rhs":false,"assoc":"Left"}]}},{"Pub":{"ty":122,"body":530,"replaceable":false}},{"Pub":{"ty":122,"body":535,"replaceable":false}},{"Pub":{"ty":123,"body":542,"replaceable":false}},{"Pub":{"ty":124,"body":556,"replaceable":false}},{"Pub":{"ty":125,"body":582,"replaceable":false}},{"Pub":{"ty":126,"body":587,"replaceable":false}},{"Pub":{"ty":127,"body":591,"replaceable":false}},{"Pub":{"ty":128,"body":597,"replaceable":false}},{"Or":[598,599,600,601,602,603,604,605,606,607]},{"Pub":{"ty":129,"body":609,"replaceable":false}},{"Pub":{"ty":130,"body":611,"replaceable":false}},{"Pub":{"ty":131,"body":619,"replaceable":false}},{"Pub":{"ty":132,"body":622,"replaceable":false}},{"Pub":{"ty":133,"body":626,"replaceable":false}},{"Pub":{"ty":134,"body":632,"replaceable":true}},{"PubReplace":{"ty":135,"body":636}},{"Pub":{"ty":136,"body":639,"replaceable":false}},{"Pub":{"ty":137,"body":648,"replaceable":false}},{"Pub":{"ty":138,"body":655,"replaceable":false}},{"Pub":{"ty":100,"body":661,"replaceable":false}},{"Pub":{"ty":139,"body":664,"replaceable":false}},{"Or":[665,666,667,668,669,670,671]},{"Pub":{"ty":140,"body":674,"replaceable":false}},{"Pub":{"ty":141,"body":680,"replaceable":true}},{"PubReplace":{"ty":142,"body":692}},{"PubReplace":{"ty":143,"body":704}},{"Pub":{"ty":144,"body":711,"replaceable":false}},{"Pub":{"ty":145,"body":718,"replaceable":false}},{"Pub":{"ty":146,"body":720,"replaceable":false}},{"Pub":{"ty":147,"body":724,"replaceable":false}},{"Pub":{"ty":148,"body":730,"replaceable":true}},{"PubReplace":{"ty":149,"body":734}},{"Pub":{"ty":150,"body":739,"replaceable":false}},{"Pratt":{"atoms":[79,80,83,84,86,87,89,90,91,98,99,102,103,105,109,110,138],"prefixes":[{"ty":184,"op":120,"priority":999},{"ty":185,"op":768,"priority":999},{"ty":186,"op":769,"priority":999},{"ty":187,"op":770,"priority":999},{"ty":197,"op":140,"priority":2}],"infixes":[{"ty":177,"op":745,"priority":999,"has_rhs":false,"assoc":"Left"},{"ty":178,"op":754,"priority":999,"has_rhs":false,"assoc":"Left"},{"ty":180,"op":762,"priority":999,"has_rhs":false,"assoc":"Left"},{"ty":181,"op":763,"priority":999,"has_rhs":false,"assoc":"Left"},{"ty":182,"op":764,"priority":999,"has_rhs":false,"assoc":"Left"},{"ty":183,"op":767,"priority":999,"has_rhs":false,"assoc":"Left"},{"ty":188,"op":778,"priority":11,"has_rhs":true,"assoc":"Left"},{"ty":189,"op":784,"priority":10,"has_rhs":true,"assoc":"Left"},{"ty":190,"op":790,"priority":9,"has_rhs":true,"assoc":"Left"},{"ty":191,"op":796,"priority":8,"has_rhs":true,"assoc":"Left"},{"ty":192,"op":798,"priority":7,"has_rhs":true,"assoc":"Left"},{"ty":193,"op":804,"priority":6,"has_rhs":true,"assoc":"Left"},{"ty":194,"op":805,"priority":5,"has_rhs":true,"assoc":"Left"},{"ty":195,"op":807,"priority":4,"has_rhs":true,"assoc":"Left"},{"ty":196,"op":809,"priority":3,"has_rhs":true,"assoc":"Left"},{"ty":197,"op":810,"priority":2,"has_rhs":true,"assoc":"Left"},{"ty":197,"op":139,"priority":2,"has_rhs":false,"assoc":"Left"},{"ty":198,"op":834,"priority":1,"has_rhs":true,"assoc":"Left"}]}},{"Or":[835,837,839,841,843,845,847,849,851,853,855,857,859,861,863,865,867,869,871,873,875,877,879,881,883,885]},{"Or":[887]},{"Or":[891]},{"Pub":{"ty":152,"body":902,"replaceable":false}},{"Pub":{"ty":153,"body":910,"replaceable":true}},{"PubReplace":{"ty":154,"body":923}},{"Pub":{"ty":155,"body":930,"replaceable":false}},{"Pub":{"ty":156,"body":934,"replaceable":false}},{"Pub":{"ty":157,"body":941,"replaceable":true}},{"PubReplace":{"ty":158,"body":946}},{"Pub":{"ty":159,"body":959,"replaceable":false}},{"Pub":{"ty":160,"body":971,"replaceable":false}},{"Or":[979]},{"Pub":{"ty":161,"body":983,"replaceable":false}},{"Pub":{"ty":162,"body":992,"replaceable":false}},{"Pub":{"ty":163,"body":1002,"replaceable":false}},{"Or":[1003,1004,1005,1006]},{"Or":[1008,1010,1011,1012]},{"Pub":{"ty":164,"body":1021,"replaceable":false}},{"Pub":{"ty":165,"body":1024,"replaceable":false}},{"Pub":{"ty":166,"body":1027,"replaceable":false}},{"Pub":{"ty":167,"body":1038,"replaceable":false}},{"Pub":{"ty":168,"body":1048,"replaceable":false}},{"Pub":{"ty":169,"body":1052,"replaceable":false}},{"Or":[1058]},{"Or":[1060]},{"Pub":{"ty":170,"body":1064,"replaceable":false}},{"Pub":{"ty":171,"body":1069,"replaceable":false}},{"Or":[1072]},{"Pub":{"ty":172,"body":1077,"replaceable":false}},{"Pub":{"ty":173,"body":1087,"replaceable":false}},{"Or":[1093]},{"Pub":{"ty":174,"body":1096,"replaceable":false}},{"Pub":{"ty":175,"body":1098,"replaceable":false}},{"Pub":{"ty":176,"body":1100,"replaceable":false}},{"Pub":{"ty":177,"body":1108,"replaceable":false}},{"Pub":{"ty":178,"body":1119,"replaceable":false}},{"Or":[1123]},{"Pub":{"ty":179,"body":1125,"replaceable":false}},{"Pub":{"ty":180,"body":1135,"replaceable":false}},{"Pub":{"ty":181,"body":1138,"replaceable":false}},{"Pub":{"ty":182,"body":1141,"replaceable":false}},{"Pub":{"ty":183,"body":1146,"replaceable":false}},{"Pub":{"ty":184,"body":1148,"replaceable":false}},{"Or":[1154]},{"Pub":{"ty":185,"body":1157,"replaceable":false}},{"Pub":{"ty":186,"body":1160,"replaceable":false}},{"Pub":{"ty":187,"body":1163,"replaceable":false}},{"Pub":{"ty":188,"body":1173,"replaceable":false}},{"Pub":{"ty":189,"body":1181,"replaceable":false}},{"Pub":{"ty":190,"body":1189,"replaceable":false}},{"Or":[1193,1197]},{"Pub":{"ty":191,"body":1205,"replaceable":false}},{"Pub":{"ty":192,"body":1209,"replaceable":false}},{"Pub":{"ty":193,"body":1217,"replaceable":false}},{"Pub":{"ty":194,"body":1220,"replaceable":false}},{"Or":[1222,1224,1226,1228,1230,1232]},{"Pub":{"ty":195,"body":1236,"replaceable":false}},{"Pub":{"ty":196,"body":1240,"replaceable":false}},{"Pub":{"ty":197,"body":1243,"replaceable":false}},{"Pub":{"ty":197,"body":1245,"replaceable":false}},{"Pub":{"ty":197,"body":1247,"replaceable":false}},{"Pub":{"ty":197,"body":1251,"replaceable":false}},{"Or":[1253,1255]},{"Or":[1263]},{"Pub":{"ty":198,"body":1289,"replaceable":false}},{"Pub":{"ty":199,"body":1292,"replaceable":false}},{"Pub":{"ty":200,"body":1296,"replaceable":false}},{"Or":[1299]},{"Or":[1301]},{"Pub":{"ty":201,"body":1311,"replaceable":false}},{"Pub":{"ty":202,"body":1319,"replaceable":false}},{"Pub":{"ty":203,"body":1334,"replaceable":false}},{"Pub":{"ty":204,"body":1363,"replaceable":false}},{"Or":[1373]},{"Or":[1378]},{"Or":[1383]},{"Or":[1388]},{"Or":[1393]},{"Or":[1401]},{"Or":[1416]},{"And":[[1],null]},{"Or":[157]},{"WithSkip":[2,3]},{"Rep":159},{"And":[[160],null]},{"Token":11},{"And":[[162],null]},{"ContextualToken":[4,"union"]},{"And":[[164],null]},{"Token":16},{"And":[[166],null]},{"Token":12},{"And":[[168],null]},{"Token":13},{"And":[[170],null]},{"Token":17},{"And":[[172],null]},{"Token":29},{"And":[[174],null]},{"And":[[25],null]},{"Opt":36},{"And":[[145,177],null]},{"Or":[178]},{"And":[[24],null]},{"And":[[4],null]},{"And":[[7],null]},{"And":[[15],null]},{"And":[[18],null]},{"And":[[20],null]},{"And":[[21],null]},{"And":[[22],null]},{"And":[[29],null]},{"Or":[180,181,182,183,184,185,186,187,188]},{"Inject":[179,189]},{"And":[[190],null]},{"And":[[28],null]},{"Or":[191,192]},{"Token":12},{"And":[[48],null]},{"Token":60},{"And":[[196,5],null]},{"Or":[197]},{"Opt":198},{"And":[[199],null]},{"Or":[195,200]},{"And":[[38,201],null]},{"Token":60},{"Opt":203},{"And":[[204,5],null]},{"Or":[202,205]},{"Token":58},{"And":[[194,206,207],1]},{"Or":[208]},{"Token":67},{"And":[[210],null]},{"Call":[150,[[2,6]]]},{"Call":[151,[[3,212]]]},{"And":[[213],null]},{"Or":[211,214]},{"Token":18},{"And":[[216],null]},{"Token":92},{"Opt":48},{"And":[[218,219],1]},{"Or":[217,220]},{"Token":7},{"Token":6},{"Token":92},{"Opt":48},{"Token":58},{"And":[[222,223,224,225,226],2]},{"Or":[227]},{"Token":21},{"Opt":229},{"Token":35},{"Opt":231},{"Opt":10},{"Token":8},{"Token":92},{"Opt":31},{"Opt":9},{"Opt":37},{"And":[[91],null]},{"Token":58},{"And":[[240],null]},{"Or":[239,241]},{"And":[[230,232,233,234,235,236,11,237,238,242],4]},{"Or":[243]},{"Token":51},{"And":[[245,49],null]},{"Token":7},{"Token":90},{"Opt":248},{"And":[[247,249],null]},{"Or":[250]},{"Opt":14},{"Call":[150,[[2,12]]]},{"And":[[252,253],null]},{"Or":[254]},{"Call":[152,[[4,255]]]},{"And":[[256],null]},{"Token":59},{"And":[[63,258,49],1]},{"Or":[259]},{"Token":59},{"And":[[261,49],null]},{"Or":[262]},{"Opt":263},{"And":[[63,264],null]},{"Or":[265]},{"And":[[120],null]},{"Token":27},{"And":[[268],null]},{"Or":[267,269]},{"Opt":270},{"Token":18},{"Token":59},{"And":[[273,49],null]},{"Or":[274]},{"Opt":275},{"Token":61},{"And":[[277],null]},"Eof",{"And":[[279],null]},{"Or":[278,280]},{"And":[[271,272,276,281],2]},{"Or":[282]},{"Token":11},{"And":[[284],null]},{"ContextualToken":[4,"union"]},{"And":[[286],null]},{"Or":[285,287]},{"Token":92},{"Opt":31},{"Call":[150,[[2,16]]]},{"Call":[151,[[3,291]]]},{"And":[[292],null]},{"Token":58},{"And":[[294],null]},{"Call":[150,[[2,17]]]},{"Call":[152,[[4,296]]]},{"Token":58},{"And":[[297,298],null]},{"Or":[293,295,299]},{"And":[[288,289,290,300],1]},{"Or":[301]},{"Opt":36},{"Token":92},{"Token":59},{"And":[[303,304,305,49],2]},{"Or":[306]},{"Opt":36},{"And":[[308,49],null]},{"Or":[309]},{"Token":16},{"Token":92},{"Opt":31},{"Call":[150,[[2,19]]]},{"Call":[151,[[3,314]]]},{"And":[[311,312,313,315],1]},{"Or":[316]},{"Token":92},{"Token":53},{"And":[[319,75],null]},{"Call":[150,[[2,17]]]},{"Call":[152,[[4,321]]]},{"And":[[322],null]},{"Call":[150,[[2,16]]]},{"Call":[151,[[3,324]]]},{"And":[[325],null]},{"Or":[320,323,326]},{"Opt":327},{"And":[[318,328],1]},{"Or":[329]},{"Token":13},{"Token":92},{"Token":58},{"And":[[333],null]},{"Call":[151,[[3,1]]]},{"And":[[335],null]},{"Or":[334,336]},{"And":[[331,332,337],1]},{"Or":[338]},{"Token":35},{"Opt":340},{"Token":17},{"Opt":31},{"Token":23},{"And":[[344,49],null]},{"Or":[345]},{"Opt":346},{"And":[[49,347],null]},{"Or":[348]},{"Opt":37},{"And":[[341,342,343,349,350,23],2]},{"Or":[351]},{"Opt":36},{"Token":29},{"Token":92},{"Opt":31},{"Opt":33},{"Opt":37},{"And":[[145,353,354,355,356,357,358,23],3]},{"Or":[359]},{"Opt":36},{"And":[[145,361],null]},{"Or":[362]},{"Inject":[363,24]},{"And":[[364],null]},{"And":[[28],null]},{"Or":[365,366]},{"WithSkip":[25,367]},{"Rep":368},{"Call":[151,[[3,369]]]},{"And":[[370],null]},{"Or":[371]},{"And":[[8],null]},{"And":[[26],null]},{"And":[[27],null]},{"And":[[143],null]},{"Token":10},{"And":[[377],null]},{"Token":8},{"And":[[379],null]},{"Token":20},{"And":[[381],null]},{"Token":21},{"And":[[383],null]},{"Token":22},{"And":[[385],null]},{"Token":35},{"And":[[387],null]},{"Token":65},{"And":[[389],null]},{"Token":7},{"And":[[391],null]},{"Token":92},{"Token":82},{"And":[[393,394],null]},{"Or":[395]},{"And":[[396],null]},{"Token":20},{"Token":92},{"Opt":31},{"Token":53},{"And":[[401,49],null]},{"Or":[402]},{"Opt":403},{"Token":58},{"And":[[398,399,400,404,405],1]},{"Or":[406]},{"Token":21},{"And":[[408],null]},{"Token":22},{"And":[[410],null]},{"Or":[409,411]},{"Token":92},{"Token":59},{"Token":53},{"And":[[415,75],null]},{"Or":[416]},{"Opt":417},{"Token":58},{"And":[[412,413,414,49,418,419],1]},{"Or":[420]},{"And":[[147],null]},{"Token":58},{"And":[[148,423],null]},{"Or":[422,424]},{"Rep":30},{"Call":[151,[[3,426]]]},{"And":[[10,427],null]},{"Or":[428]},{"Opt":36},{"And":[[145,430],null]},{"Or":[431]},{"And":[[8],null]},{"And":[[27],null]},{"Or":[433,434]},{"Inject":[432,435]},{"And":[[436],null]},{"Call":[150,[[2,35]]]},{"Call":[150,[[2,32]]]},{"And":[[438,439],null]},{"Or":[440]},{"Call":[153,[[5,441]]]},{"And":[[442],null]},{"Or":[443]},{"Token":92},{"Opt":33},{"And":[[445,446],1]},{"Or":[447]},{"Token":59},{"Token":73},{"And":[[450],null]},"Eof",{"And":[[452],null]},{"Token":61},{"And":[[454],null]},{"Token":39},{"And":[[456],null]},{"Token":36},{"And":[[458],null]},{"Or":[455,457,459]},{"Not":460},{"Not":461},{"And":[[462],null]},{"Or":[451,453,463]},{"And":[[34,464],1]},{"Or":[465]},{"Rep":466},{"And":[[449,467],null]},{"Token":87},{"And":[[469],null]},{"And":[[51],null]},{"And":[[62],null]},{"Or":[470,471,472]},{"Token":87},{"Token":59},{"Token":87},{"Token":73},{"And":[[477],null]},"Eof",{"And":[[479],null]},{"Token":61},{"Not":481},{"Not":482},{"And":[[483],null]},{"Or":[478,480,484]},{"And":[[476,485],1]},{"Or":[486]},{"Rep":487},{"And":[[475,488],null]},{"Or":[489]},{"Opt":490},{"And":[[474,491],1]},{"Or":[492]},{"Token":10},{"Token":6},{"And":[[495],null]},{"Token":19},{"And":[[497],null]},{"Or":[496,498]},{"Call":[152,[[4,499]]]},{"Opt":500},{"And":[[494,501],null]},{"Or":[502]},{"Token":36},{"Token":61},{"And":[[505],null]},"Eof",{"And":[[507],null]},{"Token":39},{"Not":509},{"Not":510},{"And":[[511],null]},{"Or":[506,508,512]},{"And":[[49,33,513],null]},{"Or":[514]},{"Rep":515},{"And":[[504,516],1]},{"Or":[517]},{"And":[[41],null]},{"Enter":[3,41]},{"And":[[520],null]},{"Enter":[1,41]},{"And":[[522],null]},{"Token":60},{"And":[[524,45],null]},{"Or":[525]},{"Token":60},{"Opt":527},{"And":[[528,45],null]},{"Or":[529]},{"Token":60},{"And":[[531,45],null]},{"Or":[532]},{"And":[[41,533],null]},{"Or":[534]},{"Token":5},{"And":[[49,536,49],null]},{"Or":[537]},{"Call":[153,[[5,538]]]},{"Token":60},{"And":[[539,540,45],null]},{"Or":[541]},{"Token":92},{"And":[[543],null]},{"Token":18},{"And":[[545],null]},{"Token":19},{"And":[[547],null]},{"Or":[544,546,548]},{"And":[[46],null]},{"IsIn":3},{"And":[[551,47],null]},{"Or":[550,552]},{"Opt":553},{"And":[[549,554],null]},{"Or":[555]},{"IsIn":3},{"And":[[557],null]},{"IsIn":1},{"Token":60},{"And":[[559,560],null]},{"Or":[558,561]},{"Token":87},{"Call":[150,[[2,563]]]},{"Token":92},{"Token":53},{"And":[[565,566],null]},{"Or":[567]},{"Not":568},{"And":[[569,49],null]},{"Or":[570]},{"Call":[150,[[2,571]]]},{"Token":92},{"Token":53},{"And":[[573,574,49],null]},{"Or":[575]},{"Call":[150,[[2,576]]]},{"And":[[564,572,577],null]},{"Or":[578]},{"Call":[153,[[5,579]]]},{"And":[[562,580],null]},{"Or":[581]},{"Call":[150,[[2,49]]]},{"Call":[152,[[4,583]]]},{"Opt":9},{"And":[[584,585],null]},{"Or":[586]},{"Token":5},{"Token":92},{"And":[[588,589],null]},{"Or":[590]},{"Token":73},{"And":[[592,34],null]},{"Or":[593]},{"Rep":594},{"And":[[50,595],null]},{"Or":[596]},{"And":[[51],null]},{"And":[[52],null]},{"And":[[53],null]},{"And":[[54],null]},{"And":[[55],null]},{"And":[[56],null]},{"And":[[58],null]},{"And":[[59],null]},{"And":[[60],null]},{"And":[[62],null]},{"And":[[39],null]},{"Or":[608]},{"And":[[120,49],null]},{"Or":[610]},{"Token":67},{"Token":21},{"And":[[613],null]},{"Token":27},{"And":[[615],null]},{"Or":[614,616]},{"And":[[612,617,49],null]},{"Or":[618]},{"Token":81},{"And":[[620],null]},{"Or":[621]},{"Token":37},{"Token":38},{"And":[[623,624],null]},{"Or":[625]},{"Opt":57},{"And":[[49,627],null]},{"Or":[628]},{"Call":[152,[[4,629]]]},{"And":[[630],null]},{"Or":[631]},{"Token":61},{"Call":[150,[[2,49]]]},{"And":[[633,634],null]},{"Or":[635]},{"Token":82},{"And":[[637],null]},{"Or":[638]},{"Token":58},{"And":[[640,75],null]},{"Or":[641]},{"Opt":642},{"And":[[49,643],null]},{"Or":[644]},{"Call":[154,[[6,645]]]},{"And":[[646],null]},{"Or":[647]},{"Opt":10},{"Token":8},{"Call":[150,[[2,61]]]},{"Call":[152,[[4,651]]]},{"Opt":9},{"And":[[649,650,652,653],2]},{"Or":[654]},{"Token":59},{"And":[[63,656],null]},{"Or":[657]},{"Opt":658},{"And":[[659,49],null]},{"Or":[660]},{"Token":23},{"And":[[662,31,49],1]},{"Or":[663]},{"And":[[64],null]},{"And":[[65],null]},{"And":[[69],null]},{"And":[[70],null]},{"And":[[71],null]},{"And":[[72],null]},{"And":[[74],null]},{"Token":81},{"And":[[672],null]},{"Or":[673]},{"And":[[66],null]},{"And":[[67],null]},{"Or":[675,676]},{"Opt":677},{"And":[[40,678],null]},{"Or":[679]},{"Call":[150,[[2,63]]]},{"Token":63},{"Token":61},{"Opt":683},{"And":[[682,684],null]},{"Or":[685]},{"Opt":686},{"And":[[681,687],null]},{"Or":[688]},{"Call":[152,[[4,689]]]},{"And":[[690],null]},{"Or":[691]},{"Call":[150,[[2,68]]]},{"Token":63},{"Token":61},{"Opt":695},{"And":[[694,696],null]},{"Or":[697]},{"Opt":698},{"And":[[693,699],null]},{"Or":[700]},{"Call":[151,[[3,701]]]},{"And":[[702],null]},{"Or":[703]},{"Token":59},{"Not":705},{"And":[[69,706],null]},{"Token":92},{"Token":59},{"And":[[708,709,63],2]},{"Or":[707,710]},{"Token":28},{"Opt":712},{"Token":27},{"Opt":714},{"Token":92},{"And":[[713,715,716],null]},{"Or":[717]},{"And":[[79],null]},{"Or":[719]},{"Token":37},{"Token":38},{"And":[[721,722],null]},{"Or":[723]},{"Opt":73},{"And":[[63,725],null]},{"Or":[726]},{"Call":[152,[[4,727]]]},{"And":[[728],null]},{"Or":[729]},{"Token":61},{"Call":[150,[[2,63]]]},{"And":[[731,732],null]},{"Or":[733]},{"Token":77},{"Token":27},{"Opt":736},{"And":[[735,737,63],null]},{"Or":[738]},{"Token":62},{"Token":92},{"Enter":[1,46]},{"Opt":742},{"And":[[740,741,743,113],null]},{"Or":[744]},{"IsIn":2},{"Not":77},{"And":[[746,747],null]},{"IsIn":2},{"Not":749},{"And":[[750],null]},{"Or":[748,751]},{"And":[[752,113],null]},{"Or":[753]},{"Token":62},{"Token":92},{"And":[[756],null]},{"Token":89},{"And":[[758],null]},{"Or":[757,759]},{"And":[[755,760],null]},{"Or":[761]},{"Call":[154,[[6,75]]]},{"Token":83},{"Token":5},{"And":[[765,49],null]},{"Or":[766]},{"Token":67},{"Token":75},{"Token":82},{"Token":67},{"And":[[771],null]},{"Token":69},{"And":[[773],null]},{"Token":71},{"And":[[775],null]},{"Or":[772,774,776]},{"Call":[127,[[1,777]]]},{"Token":73},{"And":[[779],null]},{"Token":75},{"And":[[781],null]},{"Or":[780,782]},{"Call":[127,[[1,783]]]},{"ContextualToken":[45,"<<"]},{"And":[[785],null]},{"ContextualToken":[47,">>"]},{"And":[[787],null]},{"Or":[786,788]},{"Call":[127,[[1,789]]]},{"Token":77},{"Token":77},{"Not":792},{"And":[[791,793],null]},{"Or":[794]},{"Call":[127,[[1,795]]]},{"Token":84},{"Call":[127,[[1,797]]]},{"Token":79},{"Token":79},{"Not":800},{"And":[[799,801],null]},{"Or":[802]},{"Call":[127,[[1,803]]]},{"Call":[127,[[1,132]]]},{"ContextualToken":[49,"&&"]},{"Call":[127,[[1,806]]]},{"ContextualToken":[50,"||"]},{"Call":[127,[[1,808]]]},{"Call":[127,[[1,140]]]},{"Token":53},{"And":[[811],null]},{"Token":74},{"And":[[813],null]},{"Token":76},{"And":[[815],null]},{"Token":68},{"And":[[817],null]},{"Token":70},{"And":[[819],null]},{"Token":72},{"And":[[821],null]},{"Token":78},{"And":[[823],null]},{"Token":80},{"And":[[825],null]},{"Token":85},{"And":[[827],null]},{"ContextualToken":[48,">>="]},{"And":[[829],null]},{"ContextualToken":[46,"<<="]},{"And":[[831],null]},{"Or":[812,814,816,818,820,822,824,826,828,830,832]},{"Call":[127,[[1,833]]]},{"And":[[79],null]},{"Token":92},{"And":[[836],null]},{"Token":87},{"And":[[838],null]},{"Token":18},{"And":[[840],null]},{"Token":19},{"And":[[842],null]},{"Token":41},{"And":[[844],null]},{"Token":60},{"And":[[846],null]},{"Token":37},{"And":[[848],null]},{"Token":43},{"And":[[850],null]},{"Token":79},{"And":[[852],null]},{"Token":31},{"And":[[854],null]},{"Token":39},{"And":[[856],null]},{"Token":14},{"And":[[858],null]},{"Token":25},{"And":[[860],null]},{"Token":24},{"And":[[862],null]},{"Token":23},{"And":[[864],null]},{"Token":30},{"And":[[866],null]},{"Token":26},{"And":[[868],null]},{"Token":32},{"And":[[870],null]},{"Token":33},{"And":[[872],null]},{"Token":77},{"And":[[874],null]},{"Token":67},{"And":[[876],null]},{"Token":75},{"And":[[878],null]},{"Token":82},{"And":[[880],null]},{"Token":63},{"And":[[882],null]},{"Token":64},{"And":[[884],null]},{"PrevIs":[163,168,169,170,171,172,175]},{"And":[[886],null]},{"Var":0},{"Exit":[2,888]},{"Exit":[0,889]},{"And":[[890],null]},{"Token":89},{"And":[[892],null]},{"Token":90},{"And":[[894],null]},{"Token":91},{"And":[[896],null]},{"Token":86},{"And":[[898],null]},{"Token":88},{"And":[[900],null]},{"Or":[893,895,897,899,901]},{"Token":92},{"Token":82},{"And":[[903,904],null]},{"Or":[905]},{"Not":906},{"Opt":81},{"And":[[907,40,908],null]},{"Or":[909]},{"IsIn":0},{"Not":911},{"Call":[150,[[2,82]]]},{"Token":63},{"Call":[78,[[0,75]]]},{"And":[[914,915],null]},{"Or":[916]},{"Opt":917},{"And":[[913,918],null]},{"Or":[919]},{"Call":[151,[[3,920]]]},{"And":[[912,921],null]},{"Or":[922]},{"Token":92},{"Token":59},{"And":[[925,75],null]},{"Or":[926]},{"Opt":927},{"And":[[924,928],1]},{"Or":[929]},{"Token":37},{"Token":38},{"And":[[931,932],null]},{"Or":[933]},{"Call":[78,[[0,75]]]},{"Opt":85},{"And":[[935,936],null]},{"Or":[937]},{"Call":[152,[[4,938]]]},{"And":[[939],null]},{"Or":[940]},{"Token":61},{"Call":[78,[[0,75]]]},{"Call":[150,[[2,943]]]},{"And":[[942,944],null]},{"Or":[945]},{"Token":58},{"And":[[947,75],null]},{"Token":61},{"Call":[150,[[2,75]]]},{"And":[[949,950],null]},{"Or":[948,951]},{"Opt":952},{"And":[[75,953],null]},{"Or":[954]},{"Call":[78,[[0,955]]]},{"Call":[154,[[6,956]]]},{"And":[[957],null]},{"Or":[958]},{"Token":26},{"Opt":960},{"Token":79},{"Rep":88},{"Token":79},{"Token":51},{"And":[[965,49,91],null]},{"Call":[78,[[0,75]]]},{"And":[[967],null]},{"Or":[966,968]},{"And":[[961,962,963,964,969],null]},{"Or":[970]},{"Token":61},{"And":[[972],null]},{"Token":79},{"Not":974},{"Not":975},{"And":[[976],null]},{"Or":[973,977]},{"And":[[13,978],1]},{"Token":31},{"Opt":75},{"And":[[980,981],null]},{"Or":[982]},{"Token":33},{"And":[[984],null]},{"Token":32},{"And":[[986],null]},{"Or":[985,987]},{"Token":87},{"Opt":989},{"And":[[988,990],null]},{"Or":[991]},{"Token":35},{"Opt":993},{"WithSkip":[93,92]},{"Rep":995},{"Call":[151,[[3,996]]]},{"And":[[994,997],null]},{"Or":[998]},{"Call":[78,[[0,999]]]},{"And":[[1000],null]},{"Or":[1001]},{"And":[[94],null]},{"And":[[96],null]},{"And":[[3],null]},{"And":[[97],null]},{"Token":9},{"And":[[1007],null]},{"Token":58},{"And":[[1009],null]},{"And":[[2],null]},{"And":[[76],null]},{"Token":9},{"Opt":95},{"Token":53},{"And":[[1015,75],1]},{"Or":[1016]},{"Opt":1017},{"Token":58},{"And":[[1013,63,1014,1018,1019],1]},{"Or":[1020]},{"Token":59},{"And":[[1022,49],null]},{"Or":[1023]},{"Token":58},{"And":[[1025],null]},{"Or":[1026]},{"And":[[77],null]},"Eof",{"And":[[1029],null]},{"Token":58},{"And":[[1031],null]},{"Or":[1028,1030,1032]},{"And":[[75,1033],1]},{"Or":[1034]},{"Enter":[2,1035]},{"And":[[1036],null]},{"Or":[1037]},{"Token":14},{"Token":15},{"And":[[91],null]},{"And":[[98],null]},{"Or":[1041,1042]},{"And":[[1040,1043],null]},{"Or":[1044]},{"Opt":1045},{"And":[[1039,100,91,1046],1]},{"Or":[1047]},{"Opt":104},{"Token":25},{"And":[[1049,1050,100,91],2]},{"Or":[1051]},{"Token":9},{"Token":53},{"And":[[1053,63,1054],1]},{"Or":[1055]},{"Opt":1056},{"And":[[1057,101],1]},{"Enter":[0,75]},{"And":[[1059],null]},{"Opt":104},{"Token":24},{"And":[[1061,1062,91],2]},{"Or":[1063]},{"Opt":104},{"Token":23},{"Token":34},{"And":[[1065,1066,63,1067,101,91],2]},{"Or":[1068]},{"Token":87},{"Token":59},{"And":[[1070,1071],null]},{"Token":30},{"Rep":106},{"Call":[151,[[3,1074]]]},{"And":[[1073,101,1075],1]},{"Or":[1076]},{"Token":52},{"Enter":[2,75]},{"Token":61},{"And":[[1080],null]},"Eof",{"And":[[1082],null]},{"And":[[77],null]},{"Or":[1081,1083,1084]},{"And":[[107,1078,1079,1085],1]},{"Or":[1086]},{"Token":79},{"And":[[1088,63],null]},{"Or":[1089]},{"Rep":1090},{"Opt":108},{"And":[[63,1091,1092],null]},{"Token":14},{"And":[[1094,75],null]},{"Or":[1095]},{"And":[[147],null]},{"Or":[1097]},{"And":[[148],null]},{"Or":[1099]},{"Token":62},{"Token":92},{"Enter":[1,46]},{"Opt":1103},{"And":[[1101,1102,1104,113],null]},{"Or":[1105]},{"And":[[75,1106],null]},{"Or":[1107]},{"IsIn":2},{"Not":77},{"And":[[1109,1110],null]},{"IsIn":2},{"Not":1112},{"And":[[1113],null]},{"Or":[1111,1114]},{"And":[[1115,113],null]},{"Or":[1116]},{"And":[[75,1117],null]},{"Or":[1118]},{"Call":[150,[[2,114]]]},{"Call":[152,[[4,1120]]]},{"Call":[78,[[0,1121]]]},{"And":[[1122],null]},{"And":[[75],null]},{"Or":[1124]},{"Token":62},{"Token":92},{"And":[[1127],null]},{"Token":89},{"And":[[1129],null]},{"Or":[1128,1130]},{"And":[[1126,1131],null]},{"Or":[1132]},{"And":[[75,1133],null]},{"Or":[1134]},{"Call":[154,[[6,75]]]},{"And":[[75,1136],null]},{"Or":[1137]},{"Token":83},{"And":[[75,1139],null]},{"Or":[1140]},{"Token":5},{"And":[[1142,49],null]},{"Or":[1143]},{"And":[[75,1144],null]},{"Or":[1145]},{"And":[[120,75],null]},{"Or":[1147]},{"Token":77},{"Token":87},{"Opt":1150},{"Token":27},{"Opt":1152},{"And":[[1149,1151,1153],null]},{"Token":67},{"And":[[1155,75],null]},{"Or":[1156]},{"Token":75},{"And":[[1158,75],null]},{"Or":[1159]},{"Token":82},{"And":[[1161,75],null]},{"Or":[1162]},{"Token":67},{"And":[[1164],null]},{"Token":69},{"And":[[1166],null]},{"Token":71},{"And":[[1168],null]},{"Or":[1165,1167,1169]},{"Call":[127,[[1,1170]]]},{"And":[[75,1171,75],null]},{"Or":[1172]},{"Token":73},{"And":[[1174],null]},{"Token":75},{"And":[[1176],null]},{"Or":[1175,1177]},{"Call":[127,[[1,1178]]]},{"And":[[75,1179,75],null]},{"Or":[1180]},{"ContextualToken":[45,"<<"]},{"And":[[1182],null]},{"ContextualToken":[47,">>"]},{"And":[[1184],null]},{"Or":[1183,1185]},{"Call":[127,[[1,1186]]]},{"And":[[75,1187,75],null]},{"Or":[1188]},{"IsIn":2},{"Not":77},{"Var":1},{"And":[[1190,1191,1192],null]},{"IsIn":2},{"Not":1194},{"Var":1},{"And":[[1195,1196],null]},{"Token":77},{"Token":77},{"Not":1199},{"And":[[1198,1200],null]},{"Or":[1201]},{"Call":[127,[[1,1202]]]},{"And":[[75,1203,75],null]},{"Or":[1204]},{"Token":84},{"Call":[127,[[1,1206]]]},{"And":[[75,1207,75],null]},{"Or":[1208]},{"Token":79},{"Token":79},{"Not":1211},{"And":[[1210,1212],null]},{"Or":[1213]},{"Call":[127,[[1,1214]]]},{"And":[[75,1215,75],null]},{"Or":[1216]},{"Call":[127,[[1,132]]]},{"And":[[75,1218,75],null]},{"Or":[1219]},{"Token":54},{"And":[[1221],null]},{"Token":55},{"And":[[1223],null]},{"Token":41},{"And":[[1225],null]},{"Token":42},{"And":[[1227],null]},{"Token":57},{"And":[[1229],null]},{"Token":56},{"And":[[1231],null]},{"ContextualToken":[49,"&&"]},{"Call":[127,[[1,1233]]]},{"And":[[75,1234,75],null]},{"Or":[1235]},{"ContextualToken":[50,"||"]},{"Call":[127,[[1,1237]]]},{"And":[[75,1238,75],null]},{"Or":[1239]},{"Call":[127,[[1,140]]]},{"And":[[75,1241,75],null]},{"Or":[1242]},{"And":[[140,75],null]},{"Or":[1244]},{"And":[[75,139],null]},{"Or":[1246]},{"Token":63},{"Not":76},{"And":[[1248,1249],null]},{"Or":[1250]},{"Token":63},{"And":[[1252],null]},{"Token":64},{"And":[[1254],null]},{"Not":76},{"Not":1256},{"Token":39},{"IsIn":0},{"And":[[1258,1259],null]},{"Or":[1260]},{"Not":1261},{"And":[[139,1257,1262],null]},{"Token":53},{"And":[[1264],null]},{"Token":74},{"And":[[1266],null]},{"Token":76},{"And":[[1268],null]},{"Token":68},{"And":[[1270],null]},{"Token":70},{"And":[[1272],null]},{"Token":72},{"And":[[1274],null]},{"Token":78},{"And":[[1276],null]},{"Token":80},{"And":[[1278],null]},{"Token":85},{"And":[[1280],null]},{"ContextualToken":[48,">>="]},{"And":[[1282],null]},{"ContextualToken":[46,"<<="]},{"And":[[1284],null]},{"Or":[1265,1267,1269,1271,1273,1275,1277,1279,1281,1283,1285]},{"Call":[127,[[1,1286]]]},{"And":[[75,1287,75],null]},{"Or":[1288]},{"Token":65},{"And":[[1290,144],null]},{"Or":[1291]},{"Token":65},{"Token":82},{"And":[[1293,1294,144],null]},{"Or":[1295]},{"Call":[150,[[2,146]]]},{"Call":[154,[[6,1297]]]},{"And":[[1298],null]},{"Rep":142},{"And":[[1300],null]},{"Token":92},{"Token":53},{"And":[[1303,75],null]},{"Call":[150,[[2,146]]]},{"Call":[152,[[4,1305]]]},{"And":[[1306],null]},{"Or":[1304,1307]},{"Opt":1308},{"And":[[1302,1309],1]},{"Or":[1310]},{"Token":92},{"Token":82},{"Token":92},{"Opt":1314},{"Rep":149},{"Call":[151,[[3,1316]]]},{"And":[[1312,1313,1315,1317],null]},{"Or":[1318]},{"Token":92},{"Token":82},{"Token":92},{"Opt":1322},{"Token":37},{"Rep":149},{"Token":38},{"And":[[1324,1325,1326],null]},{"Token":43},{"Rep":149},{"Token":44},{"And":[[1328,1329,1330],null]},{"Or":[1327,1331]},{"And":[[1320,1321,1323,1332],null]},{"Or":[1333]},{"Token":37},{"And":[[1335],null]},{"Token":38},{"And":[[1337],null]},{"Token":39},{"And":[[1339],null]},{"Token":40},{"And":[[1341],null]},{"Token":43},{"And":[[1343],null]},{"Token":44},{"And":[[1345],null]},{"Or":[1336,1338,1340,1342,1344,1346]},{"Not":1347},"Any",{"And":[[1348,1349],null]},{"Token":37},{"Rep":149},{"Token":38},{"And":[[1351,1352,1353],null]},{"Token":43},{"Rep":149},{"Token":44},{"And":[[1355,1356,1357],null]},{"Token":39},{"Rep":149},{"Token":40},{"And":[[1359,1360,1361],null]},{"Or":[1350,1354,1358,1362]},{"Var":2},"Eof",{"And":[[1365],null]},{"Token":61},{"And":[[1367],null]},{"Or":[1366,1368]},{"And":[[1364,1369],1]},{"Or":[1370]},{"Rep":1371},{"And":[[1372],null]},{"Token":39},{"Token":40},{"Var":3},{"Call":[155,[[7,1374],[8,1375],[9,1376]]]},{"And":[[1377],null]},{"Token":37},{"Token":38},{"Var":4},{"Call":[155,[[7,1379],[8,1380],[9,1381]]]},{"And":[[1382],null]},{"Token":41},{"Token":42},{"Var":5},{"Call":[155,[[7,1384],[8,1385],[9,1386]]]},{"And":[[1387],null]},{"Token":43},{"Token":44},{"Var":6},{"Call":[155,[[7,1389],[8,1390],[9,1391]]]},{"And":[[1392],null]},{"Var":7},{"Var":7},{"Var":8},{"Call":[156,[[10,1395],[11,1396]]]},{"Var":9},{"Layer":[1397,1398]},{"Var":8},{"And":[[1394,1399,1400],1]},{"Var":10},{"Var":10},{"Var":11},{"Call":[156,[[10,1403],[11,1404]]]},{"Var":11},{"And":[[1402,1405,1406],1]},{"Var":11},{"Not":1408},"Any",{"And":[[1409,1410],null]},{"Or":[1411]},{"And":[[1412],null]},{"Or":[1407,1413]},{"Rep":1414},{"And":[[1415],null]}]"##;
         ::fall_parse::ParserDefinition {
             node_types: vec![
                 rt::ERROR,
@@ -309,25 +902,27 @@ pub fn language() -> &'static rt::Language {
     use self::rt::*;
     lazy_static! {
         static ref LANG: rt::Language = {
-            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::RegexLexer };
+            struct Impl { parser_definition: rt::ParserDefinition, lexer: rt::DfaLexer };
             impl rt::LanguageImpl for Impl {
                 fn parse(
                     &self,
                     text: rt::Text,
+                    options: &rt::ParseOptions,
                     metrics: &rt::Metrics,
                     builder: &mut rt::TreeBuilder,
                 ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
-                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, metrics, builder)
+                    rt::parse(&LANG, &self.lexer, &self.parser_definition, text, options, metrics, builder)
                 }
                 fn reparse(
                     &self,
                     incremental_data: &dyn std::any::Any,
                     edit: &rt::TextEdit,
                     new_text: rt::Text,
+                    options: &rt::ParseOptions,
                     metrics: &rt::Metrics,
                     builder: &mut rt::TreeBuilder,
                 ) -> Option<Box<dyn std::any::Any + Sync + Send>> {
-                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, metrics, builder)
+                    rt::reparse(&LANG, &self.lexer, &self.parser_definition, incremental_data, edit, new_text, options, metrics, builder)
                 }
                 fn node_type_info(&self, ty: rt::NodeType) -> rt::NodeTypeInfo {
                     match ty {
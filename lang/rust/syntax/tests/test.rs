@@ -6,9 +6,10 @@ extern crate lang_rust_syntax;
 use std::path::{Path, PathBuf};
 
 use fall_tree::test_util::{check_syntax_ws, check_syntax, check_directory, check_inline_tests};
-use fall_tree::{TextRange, tu, FileEdit};
-use fall_tree::search::ast;
-use lang_rust_syntax::{lang_rust, FnDef, NameOwner};
+use fall_tree::{TextRange, TextBuf, TextEditBuilder, tu, FileEdit, dump_file_ws};
+use fall_tree::test_util::report_diff;
+use fall_tree::search::{ast, find_leaf_at_offset, descendants_of_type};
+use lang_rust_syntax::{lang_rust, FnDef, NameOwner, SUM_EXPR};
 
 #[test]
 fn inline_tests() {
@@ -215,7 +216,110 @@ trait T {
     assert!(incremental_tics < 800, "too many ticks: {}", incremental_tics);
 }
 
+#[test]
+fn relex_inside_unterminated_string() {
+    let prefix = "fn foo() {}\n".repeat(100);
+    let mut text: TextBuf = format!("{}fn bar() {{ let s = \"hello", prefix).into();
+    let mut file = lang_rust().parse(text.as_text());
+
+    for typed in [" world", "\"", ";", " }"].iter() {
+        let edit = {
+            let mut b = TextEditBuilder::new(text.as_text());
+            b.insert(text.as_text().len(), typed.to_string());
+            b.build()
+        };
+        text = edit.apply(text.as_text());
+        file = lang_rust().reparse(&file, &edit);
+        let fresh_file = lang_rust().parse(text.as_text());
+        report_diff(&dump_file_ws(&fresh_file), &dump_file_ws(&file));
+
+        let relexed = file.metrics().get("relexed region").unwrap();
+        assert!(typed.len() as u64 <= relexed && relexed < 20, "relexed: {}", relexed);
+    }
+}
+
+#[test]
+fn expected_after_reparse() {
+    let text: TextBuf = "fn f() { a }".into();
+    let file = lang_rust().parse(text.as_text());
+    let edit = {
+        let mut b = TextEditBuilder::new(text.as_text());
+        b.insert(tu(0), " ".to_string());
+        b.build()
+    };
+    let file = lang_rust().reparse(&file, &edit);
+    let fresh_file = lang_rust().parse(edit.apply(text.as_text()).as_text());
+    assert!(!fresh_file.expected().unwrap().tokens.is_empty());
+    assert_eq!(file.expected(), fresh_file.expected());
+}
+
+#[test]
+fn tokens_longer_than_the_lexer_window() {
+    let literal = format!("\"{}\"", "ы".repeat(10_000));
+    let text: TextBuf = format!("fn foo() {{ let s = {}; }}\n", literal).into();
+    let file = lang_rust().parse(text.as_text());
+    assert!(file.errors().is_empty());
+    let leaf = find_leaf_at_offset(file.root(), tu(30)).right_biased().unwrap();
+    assert_eq!(leaf.text().to_string(), literal);
+
+    let edit = {
+        let mut b = TextEditBuilder::new(text.as_text());
+        b.insert(tu(20), "ы".to_string());
+        b.build()
+    };
+    let file = lang_rust().reparse(&file, &edit);
+    let fresh_file = lang_rust().parse(edit.apply(text.as_text()).as_text());
+    report_diff(&dump_file_ws(&fresh_file), &dump_file_ws(&file));
+}
 
+#[test]
+fn reparse_only_the_edited_block() {
+    let prefix = "fn foo() { let x = 1; }\n".repeat(100);
+    let mut text: TextBuf = format!("{}fn bar() {{ let y = 2; }}\n{}", prefix, prefix).into();
+    let mut file = lang_rust().parse(text.as_text());
+    let full = file.metrics().get("reparsed tokens").unwrap();
+
+    let mut at = tu((prefix.len() + "fn bar() { let y = 2".len()) as u32);
+    for typed in [" + 1", " * foo(x)", " - bar[92]"].iter() {
+        let edit = {
+            let mut b = TextEditBuilder::new(text.as_text());
+            b.insert(at, typed.to_string());
+            b.build()
+        };
+        at += tu(typed.len() as u32);
+        text = edit.apply(text.as_text());
+        file = lang_rust().reparse(&file, &edit);
+        let fresh_file = lang_rust().parse(text.as_text());
+        report_diff(&dump_file_ws(&fresh_file), &dump_file_ws(&file));
+        assert_eq!(fresh_file.expected(), file.expected());
+
+        let reparsed = file.metrics().get("reparsed tokens").unwrap();
+        assert!(reparsed < 20 && reparsed * 100 < full, "reparsed: {}", reparsed);
+    }
+}
+
+
+
+#[test]
+fn moderate_nesting() {
+    let parens = format!("fn f() {{ {}1{} }}", "(".repeat(100), ")".repeat(100));
+    let blocks = format!("fn f() {}{}", "{".repeat(100), "}".repeat(100));
+    for text in &[parens, blocks] {
+        let file = lang_rust().parse(text.as_str());
+        assert!(file.errors().is_empty());
+    }
+}
+
+#[test]
+fn deep_chain() {
+    let n = 100_000;
+    let text = format!("fn f() {{ x = 1{}; }}", " + 1".repeat(n));
+    let file = lang_rust().parse(text.as_str());
+    assert!(file.errors().is_empty());
+    // The dump of such a tree is quadratic in size, because of the indentation.
+    let sums = descendants_of_type(file.root(), SUM_EXPR);
+    assert_eq!(sums.len(), n);
+}
 
 #[test]
 fn performance_test() {